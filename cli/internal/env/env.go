@@ -243,3 +243,16 @@ func (evm EnvironmentVariableMap) FromWildcardsUnresolved(wildcardPatterns []str
 
 	return evm.fromWildcards(wildcardPatterns)
 }
+
+// DotEnvFiles returns the dotenv files that turbopack loads for a mode, from
+// the highest to the lowest precedence. `.env.local` is not loaded in the
+// `test` mode, so tests produce the same results for everyone. This mirrors
+// `dotenv_files` in the turborepo-env crate, and both are tested against
+// `crates/turborepo-env/fixtures/dotenv-files.json`.
+func DotEnvFiles(mode string) []string {
+	files := []string{".env." + mode + ".local"}
+	if mode != "test" {
+		files = append(files, ".env.local")
+	}
+	return append(files, ".env."+mode, ".env")
+}
@@ -1,10 +1,13 @@
 package env
 
 import (
+	"encoding/json"
+	"os"
 	"reflect"
 	"testing"
 
 	"github.com/vercel/turbo/cli/internal/ffi"
+	"github.com/vercel/turbo/cli/internal/turbopath"
 	"gotest.tools/v3/assert"
 )
 
@@ -213,3 +216,22 @@ func TestGetEnvVarsFromWildcards(t *testing.T) {
 		})
 	}
 }
+
+// TestDotEnvFiles checks DotEnvFiles against the cases of the turborepo-env
+// crate, which defines the files that turbopack loads.
+func TestDotEnvFiles(t *testing.T) {
+	defaultCwd, err := os.Getwd()
+	assert.NilError(t, err, "os.Getwd")
+	fixturePath := turbopath.AbsoluteSystemPath(defaultCwd).UntypedJoin("../../../crates/turborepo-env/fixtures/dotenv-files.json")
+	bytes, err := os.ReadFile(fixturePath.ToString())
+	assert.NilError(t, err, "unable to read fixture")
+
+	var tests map[string][]string
+	assert.NilError(t, json.Unmarshal(bytes, &tests), "unable to parse fixture")
+	assert.Assert(t, len(tests) > 0, "fixture has no cases")
+	for mode, want := range tests {
+		t.Run(mode, func(t *testing.T) {
+			assert.DeepEqual(t, DotEnvFiles(mode), want)
+		})
+	}
+}
@@ -73,6 +73,7 @@ func optsFromArgs(args *turbostate.ParsedArgsFromRust) (*Opts, error) {
 	opts.runOpts.ExperimentalSpaceID = runPayload.ExperimentalSpaceID
 	opts.runOpts.EnvMode = runPayload.EnvMode
 	opts.runOpts.FrameworkInference = runPayload.FrameworkInference
+	opts.runOpts.DotEnvMode = runPayload.DotEnvMode
 
 	// Runcache flags
 	opts.runcacheOpts.SkipReads = runPayload.Force
@@ -305,6 +306,7 @@ func (r *run) run(ctx gocontext.Context, targets []string, executionState *turbo
 		g.WorkspaceInfos,
 		g.TaskDefinitions,
 		r.base.RepoRoot,
+		rs.Opts.runOpts.DotEnvMode,
 	)
 
 	if err != nil {
@@ -73,12 +73,15 @@ type packageFileHashInputs struct {
 
 // CalculateFileHashes hashes each unique package-inputs combination that is present
 // in the task graph. Must be called before calculating task hashes.
+// When dotEnvMode is set, the dotenv files loaded for that mode are hashed as
+// well, relative to each package.
 func (th *Tracker) CalculateFileHashes(
 	allTasks []dag.Vertex,
 	workerCount int,
 	workspaceInfos workspace.Catalog,
 	taskDefinitions map[string]*fs.TaskDefinition,
 	repoRoot turbopath.AbsoluteSystemPath,
+	dotEnvMode string,
 ) error {
 	hashTasks := make(util.Set)
 
@@ -131,9 +134,15 @@ func (th *Tracker) CalculateFileHashes(
 
 				// Make sure we include specified .env files in the file hash.
 				// Handled separately because these are not globs!
-				if len(packageFileHashInputs.taskDefinition.DotEnv) > 0 {
+				dotEnv := packageFileHashInputs.taskDefinition.DotEnv.ToSystemPathArray()
+				if dotEnvMode != "" {
+					for _, file := range env.DotEnvFiles(dotEnvMode) {
+						dotEnv = append(dotEnv, turbopath.AnchoredSystemPath(file))
+					}
+				}
+				if len(dotEnv) > 0 {
 					packagePath := pkg.Dir.RestoreAnchor(repoRoot)
-					dotEnvObject, err := hashing.GetHashesForExistingFiles(packagePath, packageFileHashInputs.taskDefinition.DotEnv.ToSystemPathArray())
+					dotEnvObject, err := hashing.GetHashesForExistingFiles(packagePath, dotEnv)
 					if err != nil {
 						return err
 					}
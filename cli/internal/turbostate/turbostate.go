@@ -28,6 +28,7 @@ type RunPayload struct {
 	Concurrency        string       `json:"concurrency"`
 	ContinueExecution  bool         `json:"continue_execution"`
 	DryRun             string       `json:"dry_run"`
+	DotEnvMode         string       `json:"dotenv_mode"`
 	Filter             []string     `json:"filter"`
 	Force              bool         `json:"force"`
 	FrameworkInference bool         `json:"framework_inference"`
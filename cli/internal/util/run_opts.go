@@ -29,6 +29,8 @@ type RunOpts struct {
 	EnvMode EnvMode
 	// Whether or not to infer the framework for each workspace.
 	FrameworkInference bool
+	// The mode whose dotenv files are hashed for each workspace.
+	DotEnvMode string
 	// The filename to write a perf profile.
 	Profile string
 	// If true, continue task executions even if a task fails.
@@ -7,6 +7,7 @@ use std::{
     env::current_dir,
     fs,
     future::Future,
+    io::BufWriter,
     path::{Path, PathBuf},
     pin::Pin,
     sync::Arc,
@@ -39,13 +40,17 @@ use turbopack::{
     resolve_options_context::ResolveOptionsContext, transition::TransitionsByNameVc,
     ModuleAssetContextVc,
 };
-use turbopack_cli_utils::issue::{ConsoleUiVc, IssueSeverityCliOption, LogOptions};
+use turbopack_cli_utils::{
+    issue::{ConsoleUiVc, IssueSeverityCliOption, LogOptions},
+    issue_format::{IssueFormat, StructuredIssueOptions, StructuredIssueReporterVc},
+    issue_rules::IssueRules,
+};
 use turbopack_core::{
     asset::{Asset, AssetVc, AssetsVc},
     compile_time_info::CompileTimeInfo,
     context::{AssetContext, AssetContextVc},
     environment::{EnvironmentVc, ExecutionEnvironment, NodeJsEnvironment},
-    issue::{IssueContextExt, IssueReporter, IssueSeverity, IssueVc},
+    issue::{IssueContextExt, IssueReporter, IssueReporterVc, IssueSeverity, IssueVc},
     reference::all_assets,
     resolve::options::{ImportMapping, ResolvedMap},
     source_asset::SourceAssetVc,
@@ -61,7 +66,7 @@ use crate::nft_json::NftJsonAssetVc;
     serde(rename_all = "camelCase")
 )]
 #[derive(Debug, Clone)]
-struct CacheArgs {
+pub struct CacheArgs {
     #[clap(long)]
     cache: Option<String>,
 
@@ -124,6 +129,20 @@ pub struct CommonArgs {
     /// Expand the log details.
     log_detail: bool,
 
+    #[cfg_attr(
+        feature = "cli",
+        clap(long, value_enum, default_value_t = IssueFormat::Console)
+    )]
+    #[cfg_attr(feature = "node-api", serde(default))]
+    /// The format in which issues are reported.
+    issue_format: IssueFormat,
+
+    #[cfg_attr(feature = "cli", clap(long))]
+    #[cfg_attr(feature = "node-api", serde(default))]
+    /// Write issues to this file instead of stdout. Only used with the
+    /// `ndjson` and `sarif` issue formats, and required for `sarif`.
+    issue_output: Option<PathBuf>,
+
     /// Whether to skip the glob logic
     /// assume the provided input is not glob even if it contains `*` and `[]`
     #[cfg_attr(feature = "cli", clap(short, long))]
@@ -135,6 +154,18 @@ pub struct CommonArgs {
     #[cfg_attr(feature = "cli", clap(long))]
     #[cfg_attr(feature = "serializable", serde(default))]
     pub memory_limit: Option<usize>,
+
+    /// Record task executions and write them as Chrome trace events to the
+    /// provided file.
+    #[cfg_attr(feature = "cli", clap(long, env = "TURBO_TASKS_CHROME_TRACE"))]
+    #[cfg_attr(feature = "node-api", serde(default))]
+    chrome_trace: Option<String>,
+
+    /// Record task executions and write them as pprof profile aggregated per
+    /// function to the provided file.
+    #[cfg_attr(feature = "cli", clap(long, env = "TURBO_TASKS_PPROF"))]
+    #[cfg_attr(feature = "node-api", serde(default))]
+    pprof: Option<String>,
 }
 
 #[cfg_attr(feature = "cli", derive(Parser))]
@@ -317,6 +348,8 @@ pub async fn start(
     let &CommonArgs {
         visualize_graph,
         memory_limit,
+        ref chrome_trace,
+        ref pprof,
         #[cfg(feature = "persistent_cache")]
             cache: CacheArgs {
             ref cache,
@@ -328,19 +361,22 @@ pub async fn start(
     if let Some(cache) = cache {
         use tokio::time::timeout;
         use turbo_tasks_memory::MemoryBackendWithPersistedGraph;
-        use turbo_tasks_rocksdb::RocksDbPersistedGraph;
+        use turbo_tasks_sled::SledPersistedGraph;
+
+        // The persisted graph backend doesn't record task executions
+        if chrome_trace.is_some() || pprof.is_some() {
+            anyhow::bail!("--chrome-trace and --pprof can't be used together with --cache");
+        }
 
-        run(
-            &args,
+        return run(
+            args.clone(),
             || {
                 let start = Instant::now();
-                let backend = MemoryBackendWithPersistedGraph::new(
-                    RocksDbPersistedGraph::new(cache).unwrap(),
-                );
+                let backend = MemoryBackendWithPersistedGraph::new(SledPersistedGraph::new(cache)?);
                 let tt = TurboTasks::new(backend);
                 let elapsed = start.elapsed();
                 println!("restored cache {}", FormatDuration(elapsed));
-                tt
+                Ok(tt)
             },
             |tt, _, duration| async move {
                 let mut start = Instant::now();
@@ -367,20 +403,43 @@ pub async fn start(
                 drop(tt);
                 let elapsed = start.elapsed();
                 println!("writing cache {}", FormatDuration(elapsed));
+                Ok(())
             },
+            module_options,
+            resolve_options,
         )
         .await;
-        return;
     }
 
     run(
         args.clone(),
         || {
-            turbo_tasks.cloned().unwrap_or_else(|| {
+            let tt = turbo_tasks.cloned().unwrap_or_else(|| {
                 TurboTasks::new(MemoryBackend::new(memory_limit.unwrap_or(usize::MAX)))
-            })
+            });
+            if chrome_trace.is_some() || pprof.is_some() {
+                tt.backend().set_profiling(true);
+            }
+            Ok(tt)
         },
         |tt, root_task, _| async move {
+            if chrome_trace.is_some() || pprof.is_some() {
+                let profile = tt.backend().take_profile();
+                if let Some(chrome_trace) = chrome_trace {
+                    fs::File::create(chrome_trace)
+                        .map_err(anyhow::Error::from)
+                        .and_then(|file| profile.write_chrome_trace(BufWriter::new(file)))
+                        .with_context(|| format!("unable to write {chrome_trace}"))?;
+                    println!("{chrome_trace} written");
+                }
+                if let Some(pprof) = pprof {
+                    fs::File::create(pprof)
+                        .map_err(anyhow::Error::from)
+                        .and_then(|file| profile.write_pprof(BufWriter::new(file)))
+                        .with_context(|| format!("unable to write {pprof}"))?;
+                    println!("{pprof} written");
+                }
+            }
             if visualize_graph {
                 let mut stats = Stats::new();
                 let b = tt.backend();
@@ -395,6 +454,7 @@ pub async fn start(
                 fs::write("graph.html", viz::graph::wrap_html(&graph)).unwrap();
                 println!("graph.html written");
             }
+            Ok(())
         },
         module_options,
         resolve_options,
@@ -402,9 +462,9 @@ pub async fn start(
     .await
 }
 
-async fn run<B: Backend + 'static, F: Future<Output = ()>>(
+async fn run<B: Backend + 'static, F: Future<Output = Result<()>>>(
     args: Arc<Args>,
-    create_tt: impl Fn() -> Arc<TurboTasks<B>>,
+    create_tt: impl Fn() -> Result<Arc<TurboTasks<B>>>,
     final_finish: impl FnOnce(Arc<TurboTasks<B>>, TaskId, Duration) -> F,
     module_options: Option<ModuleOptionsContext>,
     resolve_options: Option<ResolveOptionsContext>,
@@ -414,6 +474,8 @@ async fn run<B: Backend + 'static, F: Future<Output = ()>>(
         show_all,
         log_detail,
         log_level,
+        issue_format,
+        ref issue_output,
         ..
     } = args.common();
 
@@ -451,7 +513,7 @@ async fn run<B: Backend + 'static, F: Future<Output = ()>>(
             } = tt
                 .get_or_wait_aggregated_update_info(Duration::from_millis(100))
                 .await;
-            final_finish(tt, root_task, dur).await;
+            final_finish(tt, root_task, dur).await?;
             let dur2 = start.elapsed();
             println!(
                 "done in {} ({} compilation, {} task execution, {} tasks)",
@@ -467,16 +529,29 @@ async fn run<B: Backend + 'static, F: Future<Output = ()>>(
         matches!(&*args, Args::Annotate { .. }) || matches!(&*args, Args::Print { .. });
     let (sender, mut receiver) = channel(1);
     let dir = current_dir().unwrap();
-    let tt = create_tt();
+    let tt = create_tt()?;
     let module_options = TransientInstance::new(module_options.unwrap_or_default());
     let resolve_options = TransientInstance::new(resolve_options.unwrap_or_default());
-    let log_options = TransientInstance::new(LogOptions {
+    let log_options = LogOptions {
         current_dir: dir.clone(),
         project_dir: dir.clone(),
         show_all,
         log_detail,
         log_level: log_level.map_or_else(|| IssueSeverity::Error, |l| l.0),
-    });
+        issue_rules: IssueRules::default(),
+    };
+    let structured_issue_options =
+        issue_format
+            .structured(issue_output.as_deref())?
+            .map(|format| {
+                TransientInstance::new(StructuredIssueOptions {
+                    format,
+                    output: issue_output.clone(),
+                    tool_name: "node-file-trace".to_string(),
+                    log_options: log_options.clone(),
+                })
+            });
+    let log_options = TransientInstance::new(log_options);
     let task = tt.spawn_root_task(move || {
         let dir = dir.clone();
         let args = args.clone();
@@ -484,6 +559,7 @@ async fn run<B: Backend + 'static, F: Future<Output = ()>>(
         let module_options = module_options.clone();
         let resolve_options = resolve_options.clone();
         let log_options = log_options.clone();
+        let structured_issue_options = structured_issue_options.clone();
         Box::pin(async move {
             let output = main_operation(
                 TransientValue::new(dir.clone()),
@@ -498,9 +574,11 @@ async fn run<B: Backend + 'static, F: Future<Output = ()>>(
                 .strongly_consistent()
                 .await?;
 
-            let console_ui = ConsoleUiVc::new(log_options);
-            console_ui
-                .as_issue_reporter()
+            let issue_reporter: IssueReporterVc = match structured_issue_options {
+                Some(options) => StructuredIssueReporterVc::new(options).into(),
+                None => ConsoleUiVc::new(log_options).into(),
+            };
+            issue_reporter
                 .report_issues(TransientInstance::new(issues), source)
                 .await?;
 
@@ -1,11 +1,18 @@
-use std::{env, sync::MutexGuard};
-
-use anyhow::{anyhow, Context, Result};
+use anyhow::{anyhow, bail, Context, Result};
 use indexmap::IndexMap;
 use turbo_tasks::ValueToString;
 use turbo_tasks_fs::{FileContent, FileSystemPathVc};
 
-use crate::{EnvMapVc, ProcessEnv, ProcessEnvVc, GLOBAL_ENV_LOCK};
+use crate::{
+    expand::{expand_dotenv, ExpandedDotenv},
+    parse::{parse, DotenvValue},
+    EnvMapVc, ProcessEnv, ProcessEnvVc,
+};
+
+/// The variables defined in a dotenv file, with the references to other
+/// variables not expanded yet.
+#[turbo_tasks::value(transparent, serialization = "none")]
+pub struct DotenvEntries(#[turbo_tasks(trace_ignore)] IndexMap<String, DotenvValue>);
 
 /// Load the environment variables defined via a dotenv file, with an
 /// optional prior state that we can lookup already defined variables
@@ -32,45 +39,32 @@ impl DotenvProcessEnvVc {
         }
     }
 
+    /// Parses the dotenv file. A missing file defines no variables.
     #[turbo_tasks::function]
-    pub async fn read_all_with_prior(self, prior: EnvMapVc) -> Result<EnvMapVc> {
+    pub async fn read_entries(self) -> Result<DotenvEntriesVc> {
         let this = self.await?;
-        let prior = prior.await?;
-
         let file = this.path.read().await?;
-        if let FileContent::Content(f) = &*file {
-            let res;
-            let vars;
-            {
-                let lock = GLOBAL_ENV_LOCK.lock().unwrap();
-
-                // Unfortunately, dotenvy only looks up variable references from the global env.
-                // So we must mutate while we process. Afterwards, we can restore the initial
-                // state.
-                let initial = env::vars().collect();
-
-                restore_env(&initial, &prior, &lock);
-
-                // from_read will load parse and evalute the Read, and set variables
-                // into the global env. If a later dotenv defines an already defined
-                // var, it'll be ignored.
-                res = dotenvy::from_read(f.read());
-
-                vars = env::vars().collect();
-                restore_env(&vars, &initial, &lock);
-            }
-
-            if let Err(e) = res {
-                return Err(e).context(anyhow!(
-                    "unable to read {} for env vars",
-                    this.path.to_string().await?
-                ));
-            }
+        let FileContent::Content(f) = &*file else {
+            return Ok(DotenvEntriesVc::cell(IndexMap::new()));
+        };
+        match parse(&f.content().to_str()?) {
+            Ok(entries) => Ok(DotenvEntriesVc::cell(entries)),
+            Err(e) => Err(e).context(anyhow!(
+                "unable to read {} for env vars",
+                this.path.to_string().await?
+            )),
+        }
+    }
 
-            Ok(EnvMapVc::cell(vars))
-        } else {
-            Ok(EnvMapVc::cell(prior.clone_value()))
+    #[turbo_tasks::function]
+    pub async fn read_all_with_prior(self, prior: EnvMapVc) -> Result<EnvMapVc> {
+        let prior = prior.await?;
+        let entries = self.read_entries().await?;
+        let ExpandedDotenv { vars, cycles } = expand_dotenv(&prior, [&*entries]);
+        if let Some(cycle) = cycles.first() {
+            bail!("cyclic reference between variables: {}", cycle.join(" -> "));
         }
+        Ok(EnvMapVc::cell(vars))
     }
 }
 
@@ -82,22 +76,3 @@ impl ProcessEnv for DotenvProcessEnv {
         Ok(self_vc.read_all_with_prior(prior))
     }
 }
-
-/// Restores the global env variables to mirror `to`.
-fn restore_env(
-    from: &IndexMap<String, String>,
-    to: &IndexMap<String, String>,
-    _lock: &MutexGuard<()>,
-) {
-    for key in from.keys() {
-        if !to.contains_key(key) {
-            env::remove_var(key);
-        }
-    }
-    for (key, value) in to {
-        match from.get(key) {
-            Some(v) if v == value => {}
-            _ => env::set_var(key, value),
-        }
-    }
-}
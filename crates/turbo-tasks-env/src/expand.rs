@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+
+use indexmap::IndexMap;
+
+use crate::parse::{DotenvValue, ValuePart};
+
+/// The variables of an env with dotenv files merged in.
+pub struct ExpandedDotenv {
+    pub vars: IndexMap<String, String>,
+    /// The reference cycles between variables, e.g. `["A", "B", "A"]`. The
+    /// references that close a cycle are expanded to an empty string.
+    pub cycles: Vec<Vec<String>>,
+}
+
+/// Merges the variables of dotenv files, ordered from the highest to the
+/// lowest precedence, into `env`. A variable that is already defined keeps its
+/// value.
+///
+/// References to other variables are expanded with the merged variables, so a
+/// variable can reference a variable defined in a file with a lower
+/// precedence. The values from `env` are not expanded.
+pub fn expand_dotenv<'a>(
+    env: &IndexMap<String, String>,
+    files: impl IntoIterator<Item = &'a IndexMap<String, DotenvValue>>,
+) -> ExpandedDotenv {
+    let mut raw = IndexMap::new();
+    for file in files {
+        for (name, value) in file {
+            if !env.contains_key(name) {
+                raw.entry(name.as_str()).or_insert(value);
+            }
+        }
+    }
+
+    let mut expander = Expander {
+        env,
+        raw: &raw,
+        expanded: HashMap::new(),
+        stack: Vec::new(),
+        cycles: Vec::new(),
+    };
+    let mut vars = env.clone();
+    for name in raw.keys() {
+        let value = expander.expand_var(name);
+        vars.insert(name.to_string(), value);
+    }
+    ExpandedDotenv {
+        vars,
+        cycles: expander.cycles,
+    }
+}
+
+struct Expander<'a> {
+    env: &'a IndexMap<String, String>,
+    raw: &'a IndexMap<&'a str, &'a DotenvValue>,
+    expanded: HashMap<&'a str, String>,
+    /// The variables that are currently being expanded.
+    stack: Vec<&'a str>,
+    cycles: Vec<Vec<String>>,
+}
+
+impl<'a> Expander<'a> {
+    fn lookup(&mut self, name: &str) -> Option<String> {
+        if let Some(value) = self.env.get(name) {
+            return Some(value.clone());
+        }
+        let raw = self.raw;
+        let (name, _) = raw.get_key_value(name)?;
+        Some(self.expand_var(name))
+    }
+
+    fn expand_var(&mut self, name: &'a str) -> String {
+        if let Some(value) = self.expanded.get(name) {
+            return value.clone();
+        }
+        if let Some(start) = self.stack.iter().position(|n| *n == name) {
+            let mut cycle: Vec<_> = self.stack[start..].iter().map(|n| n.to_string()).collect();
+            cycle.push(name.to_string());
+            self.cycles.push(cycle);
+            return String::new();
+        }
+
+        let raw = self.raw;
+        self.stack.push(name);
+        let value = self.expand_parts(&raw[name].0);
+        self.stack.pop();
+        self.expanded.insert(name, value.clone());
+        value
+    }
+
+    fn expand_parts(&mut self, parts: &'a [ValuePart]) -> String {
+        let mut value = String::new();
+        for part in parts {
+            match part {
+                ValuePart::Literal(literal) => value.push_str(literal),
+                ValuePart::Reference {
+                    name,
+                    default,
+                    default_if_empty,
+                } => match (self.lookup(name), default) {
+                    (Some(referenced), Some(default))
+                        if referenced.is_empty() && *default_if_empty =>
+                    {
+                        value.push_str(&self.expand_parts(default))
+                    }
+                    (Some(referenced), _) => value.push_str(&referenced),
+                    (None, Some(default)) => value.push_str(&self.expand_parts(default)),
+                    (None, None) => {}
+                },
+            }
+        }
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indexmap::IndexMap;
+
+    use super::expand_dotenv;
+    use crate::parse::parse;
+
+    fn vars(env: &[(&str, &str)], files: &[&str]) -> (IndexMap<String, String>, Vec<Vec<String>>) {
+        let env = env
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        let files = files
+            .iter()
+            .map(|file| parse(file).unwrap())
+            .collect::<Vec<_>>();
+        let expanded = expand_dotenv(&env, &files);
+        (expanded.vars, expanded.cycles)
+    }
+
+    #[test]
+    fn expands_across_files_in_precedence_order() {
+        let (vars, cycles) = vars(
+            &[("HOME", "/home/me"), ("API", "from-env")],
+            &[
+                "URL=${HOST}:${PORT:-3000}\nAPI=from-local",
+                "HOST=localhost\nPORT=\nDIR=$HOME/app\nAPI_URL=$URL/$API",
+            ],
+        );
+        assert!(cycles.is_empty());
+        assert_eq!(vars["URL"], "localhost:3000");
+        assert_eq!(vars["DIR"], "/home/me/app");
+        assert_eq!(vars["API"], "from-env");
+        assert_eq!(vars["API_URL"], "localhost:3000/from-env");
+    }
+
+    #[test]
+    fn applies_defaults() {
+        let (vars, _) = vars(
+            &[],
+            &["EMPTY=\nA=${EMPTY-a}\nB=${EMPTY:-b}\nC=${MISSING-${B}c}\nD=$MISSING"],
+        );
+        assert_eq!(vars["A"], "");
+        assert_eq!(vars["B"], "b");
+        assert_eq!(vars["C"], "bc");
+        assert_eq!(vars["D"], "");
+    }
+
+    #[test]
+    fn reports_cycles() {
+        let (vars, cycles) = vars(&[], &["A=a${B}\nB=b${A}", "C=${C:-c}"]);
+        assert_eq!(vars["A"], "ab");
+        assert_eq!(
+            cycles,
+            vec![
+                vec!["A".to_string(), "B".to_string(), "A".to_string()],
+                vec!["C".to_string(), "C".to_string()],
+            ]
+        );
+    }
+}
@@ -3,7 +3,9 @@
 mod command_line;
 mod custom;
 mod dotenv;
+mod expand;
 mod filter;
+mod parse;
 
 use std::{env, sync::Mutex};
 
@@ -12,8 +14,12 @@ use indexmap::IndexMap;
 use turbo_tasks::primitives::OptionStringVc;
 
 pub use self::{
-    command_line::CommandLineProcessEnvVc, custom::CustomProcessEnvVc, dotenv::DotenvProcessEnvVc,
+    command_line::CommandLineProcessEnvVc,
+    custom::CustomProcessEnvVc,
+    dotenv::{DotenvEntries, DotenvEntriesVc, DotenvProcessEnvVc},
+    expand::{expand_dotenv, ExpandedDotenv},
     filter::FilterProcessEnvVc,
+    parse::DotenvValue,
 };
 
 #[turbo_tasks::value(transparent)]
@@ -0,0 +1,339 @@
+use anyhow::{anyhow, Result};
+use indexmap::IndexMap;
+
+/// A part of a value in a dotenv file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum ValuePart {
+    Literal(String),
+    /// A `$NAME`, `${NAME}`, `${NAME-default}` or `${NAME:-default}`
+    /// reference to another variable.
+    Reference {
+        name: String,
+        default: Option<Vec<ValuePart>>,
+        /// Whether the default is also used when the variable is empty.
+        default_if_empty: bool,
+    },
+}
+
+/// A value in a dotenv file, with the references to other variables not
+/// expanded yet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DotenvValue(pub(crate) Vec<ValuePart>);
+
+/// Parses the variables defined in a dotenv file. When a variable is defined
+/// more than once, the first definition wins.
+pub(crate) fn parse(text: &str) -> Result<IndexMap<String, DotenvValue>> {
+    let mut parser = Parser {
+        rest: text,
+        line: 1,
+    };
+    let mut entries = IndexMap::new();
+    while let Some((name, value)) = parser.parse_entry()? {
+        entries.entry(name).or_insert(DotenvValue(value));
+    }
+    Ok(entries)
+}
+
+#[derive(Default)]
+struct Parts(Vec<ValuePart>);
+
+impl Parts {
+    fn push_char(&mut self, c: char) {
+        if let Some(ValuePart::Literal(literal)) = self.0.last_mut() {
+            literal.push(c);
+        } else {
+            self.0.push(ValuePart::Literal(c.to_string()));
+        }
+    }
+}
+
+struct Parser<'a> {
+    rest: &'a str,
+    line: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.rest.chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.rest = &self.rest[c.len_utf8()..];
+        if c == '\n' {
+            self.line += 1;
+        }
+        Some(c)
+    }
+
+    fn error(&self, message: &str) -> anyhow::Error {
+        anyhow!("{message} on line {}", self.line)
+    }
+
+    fn skip_inline_whitespace(&mut self) {
+        while matches!(self.peek(), Some(' ' | '\t' | '\r')) {
+            self.bump();
+        }
+    }
+
+    fn skip_line(&mut self) {
+        while !matches!(self.bump(), None | Some('\n')) {}
+    }
+
+    fn parse_entry(&mut self) -> Result<Option<(String, Vec<ValuePart>)>> {
+        loop {
+            match self.peek() {
+                None => return Ok(None),
+                Some('#') => self.skip_line(),
+                Some(c) if c.is_whitespace() => {
+                    self.bump();
+                }
+                Some(_) => break,
+            }
+        }
+
+        let mut name = self.parse_name()?;
+        self.skip_inline_whitespace();
+        // `export` is either a prefix or a variable name itself.
+        if name == "export" && self.peek() != Some('=') {
+            name = self.parse_name()?;
+            self.skip_inline_whitespace();
+        }
+        if self.peek() != Some('=') {
+            return Err(self.error(&format!("expected `=` after `{name}`")));
+        }
+        self.bump();
+        self.skip_inline_whitespace();
+        let value = self.parse_value()?;
+        Ok(Some((name, value)))
+    }
+
+    fn parse_name(&mut self) -> Result<String> {
+        let len = self
+            .rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '.'))
+            .unwrap_or(self.rest.len());
+        let name = &self.rest[..len];
+        if !name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+            return Err(self.error("expected a variable name"));
+        }
+        self.rest = &self.rest[len..];
+        Ok(name.to_string())
+    }
+
+    /// Parses a value up to the end of the line. Quoted parts of the value can
+    /// span multiple lines.
+    fn parse_value(&mut self) -> Result<Vec<ValuePart>> {
+        let mut parts = Parts::default();
+        loop {
+            match self.peek() {
+                None => break,
+                Some('\n') => {
+                    self.bump();
+                    break;
+                }
+                Some('#') if parts.0.is_empty() => {
+                    self.skip_line();
+                    break;
+                }
+                Some(' ' | '\t' | '\r') => {
+                    self.skip_inline_whitespace();
+                    match self.peek() {
+                        None | Some('\n' | '#') => self.skip_line(),
+                        Some(_) => {
+                            return Err(self.error(
+                                "unexpected whitespace in an unquoted value, quote the value",
+                            ))
+                        }
+                    }
+                    break;
+                }
+                Some('\'') => {
+                    self.bump();
+                    loop {
+                        match self.bump() {
+                            None => return Err(self.error("unterminated single quote")),
+                            Some('\'') => break,
+                            Some(c) => parts.push_char(c),
+                        }
+                    }
+                }
+                Some('"') => {
+                    self.bump();
+                    loop {
+                        match self.peek() {
+                            None => return Err(self.error("unterminated double quote")),
+                            Some('"') => {
+                                self.bump();
+                                break;
+                            }
+                            Some('\\') => self.parse_escape(&mut parts)?,
+                            Some('$') => self.parse_reference(&mut parts)?,
+                            Some(c) => {
+                                self.bump();
+                                parts.push_char(c);
+                            }
+                        }
+                    }
+                }
+                Some('\\') => self.parse_escape(&mut parts)?,
+                Some('$') => self.parse_reference(&mut parts)?,
+                Some(c) => {
+                    self.bump();
+                    parts.push_char(c);
+                }
+            }
+        }
+        Ok(parts.0)
+    }
+
+    fn parse_escape(&mut self, parts: &mut Parts) -> Result<()> {
+        self.bump();
+        match self.bump() {
+            Some(c @ ('\\' | '\'' | '"' | '$' | ' ')) => parts.push_char(c),
+            Some('n') => parts.push_char('\n'),
+            _ => return Err(self.error("invalid escape sequence")),
+        }
+        Ok(())
+    }
+
+    fn parse_reference(&mut self, parts: &mut Parts) -> Result<()> {
+        self.bump();
+        let braced = self.peek() == Some('{');
+        if braced {
+            self.bump();
+        }
+        let len = self
+            .rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(self.rest.len());
+        let name = self.rest[..len].to_string();
+        self.rest = &self.rest[len..];
+        if !braced {
+            if name.is_empty() {
+                parts.push_char('$');
+            } else {
+                parts.0.push(ValuePart::Reference {
+                    name,
+                    default: None,
+                    default_if_empty: false,
+                });
+            }
+            return Ok(());
+        }
+
+        if name.is_empty() {
+            return Err(self.error("expected a variable name after `${`"));
+        }
+        let (default, default_if_empty) = if self.rest.starts_with('}') {
+            (None, false)
+        } else if let Some(rest) = self.rest.strip_prefix(":-") {
+            self.rest = rest;
+            (Some(self.parse_default()?), true)
+        } else if let Some(rest) = self.rest.strip_prefix('-') {
+            self.rest = rest;
+            (Some(self.parse_default()?), false)
+        } else {
+            return Err(self.error(&format!("expected `}}` to close `${{{name}`")));
+        };
+        self.bump();
+        parts.0.push(ValuePart::Reference {
+            name,
+            default,
+            default_if_empty,
+        });
+        Ok(())
+    }
+
+    /// Parses a default value up to, but not including, the closing `}`.
+    fn parse_default(&mut self) -> Result<Vec<ValuePart>> {
+        let mut parts = Parts::default();
+        loop {
+            match self.peek() {
+                None | Some('\n') => {
+                    return Err(self.error("expected `}` to close the default value"))
+                }
+                Some('}') => return Ok(parts.0),
+                Some('\\') => self.parse_escape(&mut parts)?,
+                Some('$') => self.parse_reference(&mut parts)?,
+                Some(c) => {
+                    self.bump();
+                    parts.push_char(c);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indexmap::indexmap;
+
+    use super::{parse, DotenvValue, ValuePart};
+
+    fn literal(value: &str) -> ValuePart {
+        ValuePart::Literal(value.to_string())
+    }
+
+    #[test]
+    fn parses_values() {
+        let entries = parse(
+            r#"
+# comment
+export A=plain # trailing comment
+B='single $A'
+C="double\n$A"
+D="multi
+line"
+E=
+A=ignored
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            entries,
+            indexmap! {
+                "A".to_string() => DotenvValue(vec![literal("plain")]),
+                "B".to_string() => DotenvValue(vec![literal("single $A")]),
+                "C".to_string() => DotenvValue(vec![
+                    literal("double\n"),
+                    ValuePart::Reference {
+                        name: "A".to_string(),
+                        default: None,
+                        default_if_empty: false,
+                    },
+                ]),
+                "D".to_string() => DotenvValue(vec![literal("multi\nline")]),
+                "E".to_string() => DotenvValue(vec![]),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_defaults() {
+        let entries = parse("A=${B:-fallback $C}").unwrap();
+        assert_eq!(
+            entries["A"],
+            DotenvValue(vec![ValuePart::Reference {
+                name: "B".to_string(),
+                default: Some(vec![
+                    literal("fallback "),
+                    ValuePart::Reference {
+                        name: "C".to_string(),
+                        default: None,
+                        default_if_empty: false,
+                    },
+                ]),
+                default_if_empty: true,
+            }])
+        );
+    }
+
+    #[test]
+    fn reports_parse_errors() {
+        assert!(parse("A=1\nB").is_err());
+        assert!(parse("A=\"unterminated").is_err());
+        assert!(parse("A=${B").is_err());
+        assert!(parse("A=two words").is_err());
+    }
+}
@@ -0,0 +1,399 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    io::{Cursor, Read},
+    sync::Arc,
+};
+
+use anyhow::{bail, Context, Result};
+use auto_hash_map::AutoMap;
+use flate2::read::GzDecoder;
+use tar::EntryType;
+use turbo_tasks::{primitives::StringVc, CompletionVc, ValueToString, ValueToStringVc};
+use zip::ZipArchive;
+
+use crate::{
+    util::join_path, DirectoryContent, DirectoryContentVc, DirectoryEntry, File, FileContent,
+    FileContentVc, FileMeta, FileMetaVc, FileSystem, FileSystemPathVc, FileSystemVc, LinkContent,
+    LinkContentVc, LinkType,
+};
+
+/// Maximum number of symlinks followed when reading a file, like `ELOOP`.
+const MAX_SYMLINK_DEPTH: usize = 40;
+
+/// A read-only [FileSystem] which exposes the entries of a zip or gzipped tar
+/// archive, e.g. a package from Yarn's cache or an `npm pack` tarball. The
+/// format is detected from the content of the archive.
+///
+/// The archive is read from another [FileSystem] and is indexed lazily on
+/// first access. Changes to the archive invalidate all reads. Use an
+/// [AttachedFileSystem](crate::attach::AttachedFileSystem) to mount the
+/// archive into another [FileSystem].
+#[turbo_tasks::value]
+pub struct ArchiveFileSystem {
+    name: String,
+    archive: FileSystemPathVc,
+}
+
+#[turbo_tasks::value_impl]
+impl ArchiveFileSystemVc {
+    #[turbo_tasks::function]
+    pub fn new(name: String, archive: FileSystemPathVc) -> Self {
+        ArchiveFileSystem { name, archive }.cell()
+    }
+}
+
+impl ArchiveFileSystem {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn archive(&self) -> FileSystemPathVc {
+        self.archive
+    }
+}
+
+/// The bytes of an archive, shared between clones of a [ZipArchive] and the
+/// entries of a tar archive.
+#[derive(Clone)]
+struct SharedBytes(Arc<Vec<u8>>);
+
+impl AsRef<[u8]> for SharedBytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+type SharedZipArchive = ZipArchive<Cursor<SharedBytes>>;
+
+/// The content of an archive, which entries are read from.
+enum ArchiveData {
+    Zip(SharedZipArchive),
+    /// The decompressed tar archive.
+    Tar(SharedBytes),
+}
+
+enum ArchiveEntry {
+    /// A file in a zip archive, by the name of its entry.
+    ZipFile(String),
+    /// A file in a tar archive, by the range of its content.
+    TarFile {
+        offset: usize,
+        len: usize,
+    },
+    Symlink(String),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum EntryKind {
+    File,
+    Directory,
+    Symlink,
+}
+
+/// The entries of an archive.
+#[turbo_tasks::value(serialization = "none", eq = "manual", cell = "new")]
+struct ArchiveIndex {
+    #[turbo_tasks(debug_ignore, trace_ignore)]
+    data: Option<ArchiveData>,
+    /// Files and symlinks in the archive, by path.
+    #[turbo_tasks(debug_ignore, trace_ignore)]
+    entries: HashMap<String, ArchiveEntry>,
+    /// The entries of each directory, by path. Directories don't need to be
+    /// stored in the archive.
+    #[turbo_tasks(debug_ignore, trace_ignore)]
+    directories: HashMap<String, BTreeMap<String, EntryKind>>,
+}
+
+impl ArchiveIndex {
+    fn empty() -> Self {
+        ArchiveIndex {
+            data: None,
+            entries: HashMap::new(),
+            directories: HashMap::new(),
+        }
+    }
+
+    fn new(data: ArchiveData) -> Self {
+        let mut index = ArchiveIndex::empty();
+        index.directories.insert(String::new(), BTreeMap::new());
+        index.data = Some(data);
+        index
+    }
+
+    fn from_zip(archive: SharedZipArchive) -> Self {
+        let mut index = ArchiveIndex::new(ArchiveData::Zip(archive.clone()));
+        for name in archive.file_names() {
+            if name.ends_with('/') {
+                index.add(name, EntryKind::Directory, None);
+            } else {
+                index.add(
+                    name,
+                    EntryKind::File,
+                    Some(ArchiveEntry::ZipFile(name.to_string())),
+                );
+            }
+        }
+        index
+    }
+
+    fn from_tar(bytes: SharedBytes) -> Result<Self> {
+        let mut index = ArchiveIndex::new(ArchiveData::Tar(bytes.clone()));
+        let mut archive = tar::Archive::new(Cursor::new(bytes.as_ref()));
+        // Hard links refer to an entry by its path in the archive.
+        let mut hard_links = Vec::new();
+        for entry in archive.entries()? {
+            let entry = entry?;
+            let name = String::from_utf8_lossy(&entry.path_bytes()).into_owned();
+            let header = entry.header();
+            match header.entry_type() {
+                EntryType::Regular | EntryType::Continuous => {
+                    let entry = ArchiveEntry::TarFile {
+                        offset: entry.raw_file_position() as usize,
+                        len: entry.size() as usize,
+                    };
+                    index.add(&name, EntryKind::File, Some(entry));
+                }
+                EntryType::Directory => index.add(&name, EntryKind::Directory, None),
+                EntryType::Symlink => {
+                    let Some(target) = entry.link_name_bytes() else {
+                        continue;
+                    };
+                    let target = String::from_utf8_lossy(&target).into_owned();
+                    index.add(
+                        &name,
+                        EntryKind::Symlink,
+                        Some(ArchiveEntry::Symlink(target)),
+                    );
+                }
+                EntryType::Link => {
+                    if let Some(target) = entry.link_name_bytes() {
+                        hard_links.push((name, String::from_utf8_lossy(&target).into_owned()));
+                    }
+                }
+                _ => {}
+            }
+        }
+        for (name, target) in hard_links {
+            if let Some(&ArchiveEntry::TarFile { offset, len }) =
+                index.entries.get(normalize_entry_name(&target))
+            {
+                index.add(
+                    &name,
+                    EntryKind::File,
+                    Some(ArchiveEntry::TarFile { offset, len }),
+                );
+            }
+        }
+        Ok(index)
+    }
+
+    /// Registers an entry and all its parent directories.
+    fn add(&mut self, name: &str, kind: EntryKind, entry: Option<ArchiveEntry>) {
+        let path = normalize_entry_name(name);
+        if path.is_empty() {
+            return;
+        }
+        match entry {
+            Some(entry) => {
+                self.entries.insert(path.to_string(), entry);
+            }
+            None => {
+                self.directories.entry(path.to_string()).or_default();
+            }
+        }
+        let mut child = path;
+        let mut child_kind = kind;
+        loop {
+            let (parent, child_name) = child.rsplit_once('/').unwrap_or(("", child));
+            let entries = self.directories.entry(parent.to_string()).or_default();
+            let known = entries.contains_key(child_name);
+            entries.insert(child_name.to_string(), child_kind);
+            if known || parent.is_empty() {
+                break;
+            }
+            child = parent;
+            child_kind = EntryKind::Directory;
+        }
+    }
+
+    /// Follows symlinks until a file or directory is reached. Returns [None]
+    /// for dangling links, links pointing out of the archive and cycles.
+    fn resolve_symlinks<'a>(&'a self, path: &str) -> Option<(String, Option<&'a ArchiveEntry>)> {
+        let mut path = path.to_string();
+        for _ in 0..MAX_SYMLINK_DEPTH {
+            match self.entries.get(&path) {
+                Some(ArchiveEntry::Symlink(target)) => {
+                    let parent = path.rsplit_once('/').map_or("", |(parent, _)| parent);
+                    if target.starts_with('/') {
+                        return None;
+                    }
+                    path = join_path(parent, target)?;
+                }
+                entry => return Some((path, entry)),
+            }
+        }
+        None
+    }
+
+    fn read(&self, path: &str) -> Result<FileContent> {
+        let (Some(data), Some((_, Some(entry)))) = (&self.data, self.resolve_symlinks(path)) else {
+            return Ok(FileContent::NotFound);
+        };
+        let content = match (data, entry) {
+            (ArchiveData::Zip(archive), ArchiveEntry::ZipFile(name)) => {
+                let mut archive = archive.clone();
+                let mut file = archive.by_name(name)?;
+                let mut content = Vec::with_capacity(file.size() as usize);
+                file.read_to_end(&mut content)?;
+                content
+            }
+            (ArchiveData::Tar(bytes), &ArchiveEntry::TarFile { offset, len }) => bytes
+                .as_ref()
+                .get(offset..offset + len)
+                .context("tar entry is out of bounds")?
+                .to_vec(),
+            _ => return Ok(FileContent::NotFound),
+        };
+        Ok(File::from(content).into())
+    }
+
+    /// Returns the metadata of the file or directory at `path`, following
+    /// symlinks.
+    fn metadata(&self, path: &str) -> Result<FileMeta> {
+        let Some((path, entry)) = self.resolve_symlinks(path) else {
+            bail!("path not found, can't read metadata");
+        };
+        let size = match (&self.data, entry) {
+            (_, None) if self.directories.contains_key(&path) => {
+                return Ok(FileMeta {
+                    is_dir: true,
+                    ..Default::default()
+                });
+            }
+            (Some(ArchiveData::Zip(archive)), Some(ArchiveEntry::ZipFile(name))) => {
+                archive.clone().by_name(name)?.size()
+            }
+            (_, Some(&ArchiveEntry::TarFile { len, .. })) => len as u64,
+            _ => bail!("path not found, can't read metadata"),
+        };
+        Ok(FileMeta {
+            size: Some(size),
+            ..Default::default()
+        })
+    }
+}
+
+/// Strips the `./` prefix and `/` suffix from the name of an entry.
+fn normalize_entry_name(name: &str) -> &str {
+    name.trim_start_matches("./").trim_end_matches('/')
+}
+
+#[turbo_tasks::function]
+async fn archive_index(archive: FileSystemPathVc) -> Result<ArchiveIndexVc> {
+    let content = archive.read().await?;
+    let FileContent::Content(file) = &*content else {
+        return Ok(ArchiveIndex::empty().cell());
+    };
+    let bytes = file.content().to_bytes()?.into_owned();
+    let archive_path = archive.to_string().await?;
+    let index = match bytes.as_slice() {
+        [b'P', b'K', 3, 4, ..] | [b'P', b'K', 5, 6, ..] => {
+            let zip = ZipArchive::new(Cursor::new(SharedBytes(Arc::new(bytes))))
+                .with_context(|| format!("unable to read zip archive {archive_path}"))?;
+            ArchiveIndex::from_zip(zip)
+        }
+        [0x1f, 0x8b, ..] => {
+            let mut tar = Vec::new();
+            GzDecoder::new(bytes.as_slice())
+                .read_to_end(&mut tar)
+                .with_context(|| format!("unable to decompress archive {archive_path}"))?;
+            ArchiveIndex::from_tar(SharedBytes(Arc::new(tar)))
+                .with_context(|| format!("unable to read tar archive {archive_path}"))?
+        }
+        _ => bail!("{archive_path} is neither a zip nor a gzipped tar archive"),
+    };
+    Ok(index.cell())
+}
+
+#[turbo_tasks::value_impl]
+impl FileSystem for ArchiveFileSystem {
+    #[turbo_tasks::function]
+    async fn read(&self, path: FileSystemPathVc) -> Result<FileContentVc> {
+        let index = archive_index(self.archive).await?;
+        Ok(index.read(&path.await?.path)?.cell())
+    }
+
+    #[turbo_tasks::function]
+    async fn read_link(&self, path: FileSystemPathVc) -> Result<LinkContentVc> {
+        let index = archive_index(self.archive).await?;
+        let path = &path.await?.path;
+        let Some(ArchiveEntry::Symlink(target)) = index.entries.get(path) else {
+            return Ok(LinkContent::NotFound.cell());
+        };
+        let mut link_type = LinkType::UNSET;
+        if target.starts_with('/') {
+            link_type |= LinkType::ABSOLUTE;
+        } else if let Some((target_path, None)) = index.resolve_symlinks(path) {
+            if index.directories.contains_key(&target_path) {
+                link_type |= LinkType::DIRECTORY;
+            }
+        }
+        Ok(LinkContent::Link {
+            target: target.clone(),
+            link_type,
+        }
+        .cell())
+    }
+
+    #[turbo_tasks::function]
+    async fn read_dir(&self, path: FileSystemPathVc) -> Result<DirectoryContentVc> {
+        let index = archive_index(self.archive).await?;
+        let Some(entries) = index.directories.get(&path.await?.path) else {
+            return Ok(DirectoryContent::NotFound.cell());
+        };
+        let entries = entries
+            .iter()
+            .map(|(name, kind)| {
+                let entry_path = path.join(name);
+                (
+                    name.clone(),
+                    match kind {
+                        EntryKind::File => DirectoryEntry::File(entry_path),
+                        EntryKind::Directory => DirectoryEntry::Directory(entry_path),
+                        EntryKind::Symlink => DirectoryEntry::Symlink(entry_path),
+                    },
+                )
+            })
+            .collect::<AutoMap<_, _>>();
+        Ok(DirectoryContentVc::new(entries))
+    }
+
+    #[turbo_tasks::function]
+    fn track(&self, _path: FileSystemPathVc) -> CompletionVc {
+        self.archive.track()
+    }
+
+    #[turbo_tasks::function]
+    fn write(&self, _path: FileSystemPathVc, _content: FileContentVc) -> Result<CompletionVc> {
+        bail!("Writing is not possible to an archive filesystem")
+    }
+
+    #[turbo_tasks::function]
+    fn write_link(&self, _path: FileSystemPathVc, _target: LinkContentVc) -> Result<CompletionVc> {
+        bail!("Writing is not possible to an archive filesystem")
+    }
+
+    #[turbo_tasks::function]
+    async fn metadata(&self, path: FileSystemPathVc) -> Result<FileMetaVc> {
+        let index = archive_index(self.archive).await?;
+        Ok(index.metadata(&path.await?.path)?.cell())
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ValueToString for ArchiveFileSystem {
+    #[turbo_tasks::function]
+    fn to_string(&self) -> StringVc {
+        StringVc::cell(self.name.clone())
+    }
+}
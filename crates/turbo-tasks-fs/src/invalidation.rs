@@ -89,3 +89,18 @@ impl InvalidationReasonKind for WatchStartKind {
         )
     }
 }
+
+/// Invalidation was caused by setting or clearing an entry of an
+/// [crate::overlay::OverlayFileSystem].
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct OverlayChange {
+    pub path: String,
+}
+
+impl InvalidationReason for OverlayChange {}
+
+impl Display for OverlayChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "overlay of {} changed", self.path)
+    }
+}
@@ -5,6 +5,7 @@
 #![feature(io_error_more)]
 #![feature(round_char_boundary)]
 
+pub mod archive;
 pub mod attach;
 pub mod embed;
 pub mod glob;
@@ -12,11 +13,13 @@ mod invalidation;
 mod invalidator_map;
 pub mod json;
 mod mutex_map;
+pub mod overlay;
 mod read_glob;
 mod retry;
 pub mod rope;
 pub mod source_context;
 pub mod util;
+mod watcher;
 
 use std::{
     borrow::Cow,
@@ -31,7 +34,6 @@ use std::{
         mpsc::{channel, RecvError, TryRecvError},
         Arc, Mutex,
     },
-    time::Duration,
 };
 
 use anyhow::{anyhow, bail, Context, Result};
@@ -42,7 +44,7 @@ use glob::GlobVc;
 use invalidator_map::InvalidatorMap;
 use jsonc_parser::{parse_to_serde_value, ParseOptions};
 use mime::Mime;
-use notify::{watcher, DebouncedEvent, RecommendedWatcher, RecursiveMode, Watcher};
+use notify::{DebouncedEvent, RecursiveMode};
 use read_glob::read_glob;
 pub use read_glob::{ReadGlobResult, ReadGlobResultVc};
 use serde::{Deserialize, Serialize};
@@ -61,6 +63,7 @@ use turbo_tasks::{
 };
 use turbo_tasks_hash::hash_xxh3_hash64;
 use util::{extract_disk_access, join_path, normalize_path, sys_to_unix, unix_to_sys};
+use watcher::FsWatcher;
 
 use self::{invalidation::WatchStart, json::UnparseableJson, mutex_map::MutexMap};
 use crate::{
@@ -87,7 +90,7 @@ pub trait FileSystem: ValueToString {
 
 #[derive(Default)]
 struct DiskWatcher {
-    watcher: Mutex<Option<RecommendedWatcher>>,
+    watcher: Mutex<Option<FsWatcher>>,
     /// Keeps track of which directories are currently watched. This is only
     /// used on a OS that doesn't support recursive watching.
     #[cfg(not(any(target_os = "macos", target_os = "windows")))]
@@ -119,7 +122,7 @@ impl DiskWatcher {
     #[cfg(not(any(target_os = "macos", target_os = "windows")))]
     fn start_watching(
         &self,
-        watcher: &mut std::sync::MutexGuard<Option<RecommendedWatcher>>,
+        watcher: &mut std::sync::MutexGuard<Option<FsWatcher>>,
         dir_path: &Path,
         root_path: &Path,
     ) -> Result<()> {
@@ -234,15 +237,16 @@ impl DiskFileSystem {
         // Create a channel to receive the events.
         let (tx, rx) = channel();
         // Create a watcher object, delivering debounced events.
-        // The notification back-end is selected based on the platform.
-        let mut watcher = watcher(tx, Duration::from_millis(1))?;
+        // The notification back-end is selected based on the platform, falling
+        // back to polling on filesystems which don't deliver native events.
+        let mut watcher = FsWatcher::new(&root_path, tx)?;
         // Add a path to be watched. All files and directories at that path and
         // below will be monitored for changes.
         #[cfg(any(target_os = "macos", target_os = "windows"))]
         watcher.watch(&root_path, RecursiveMode::Recursive)?;
         #[cfg(not(any(target_os = "macos", target_os = "windows")))]
         for dir_path in self.watcher.watching.iter() {
-            watcher.watch(&*dir_path, RecursiveMode::NonRecursive)?;
+            watcher.watch(&dir_path, RecursiveMode::NonRecursive)?;
         }
 
         // We need to invalidate all reads that happened before watching
@@ -1652,6 +1656,22 @@ pub struct FileMeta {
     #[serde(with = "mime_option_serde")]
     #[turbo_tasks(trace_ignore)]
     content_type: Option<Mime>,
+    is_dir: bool,
+    /// The size in bytes, which is only known for metadata read from a
+    /// [FileSystem].
+    size: Option<u64>,
+}
+
+impl FileMeta {
+    /// Returns true when the metadata describes a directory.
+    pub fn is_dir(&self) -> bool {
+        self.is_dir
+    }
+
+    /// Returns the size in bytes, when it was read from a [FileSystem].
+    pub fn size(&self) -> Option<u64> {
+        self.size
+    }
 }
 
 impl From<std::fs::Metadata> for FileMeta {
@@ -1661,6 +1681,8 @@ impl From<std::fs::Metadata> for FileMeta {
         Self {
             permissions,
             content_type: None,
+            is_dir: meta.is_dir(),
+            size: Some(meta.len()),
         }
     }
 }
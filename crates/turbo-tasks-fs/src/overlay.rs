@@ -0,0 +1,240 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::{bail, Result};
+use auto_hash_map::AutoMap;
+use turbo_tasks::{
+    mark_stateful, primitives::StringVc, CompletionVc, ValueToString, ValueToStringVc,
+};
+
+use crate::{
+    invalidation::OverlayChange, invalidator_map::InvalidatorMap, DirectoryContent,
+    DirectoryContentVc, DirectoryEntry, FileContent, FileContentVc, FileMetaVc, FileSystem,
+    FileSystemPathVc, FileSystemVc, LinkContent, LinkContentVc,
+};
+
+/// A [FileSystem] which layers transient content, e.g. unsaved editor
+/// buffers, over a base [FileSystem].
+///
+/// An overlay entry either replaces the content of a file or hides it with
+/// [FileContent::NotFound]. Directories containing overlay entries are listed
+/// with these entries merged in. Setting or clearing an entry invalidates the
+/// reads of that path and the listings of its parent directories only.
+#[turbo_tasks::value(cell = "new", eq = "manual", serialization = "none")]
+pub struct OverlayFileSystem {
+    name: String,
+    base: FileSystemVc,
+    #[turbo_tasks(debug_ignore, trace_ignore)]
+    overlays: Arc<Mutex<HashMap<String, FileContent>>>,
+    #[turbo_tasks(debug_ignore, trace_ignore)]
+    invalidator_map: Arc<InvalidatorMap>,
+    #[turbo_tasks(debug_ignore, trace_ignore)]
+    dir_invalidator_map: Arc<InvalidatorMap>,
+}
+
+#[turbo_tasks::value_impl]
+impl OverlayFileSystemVc {
+    #[turbo_tasks::function]
+    pub fn new(name: String, base: FileSystemVc) -> Self {
+        mark_stateful();
+        Self::cell(OverlayFileSystem {
+            name,
+            base,
+            overlays: Default::default(),
+            invalidator_map: Arc::new(InvalidatorMap::new()),
+            dir_invalidator_map: Arc::new(InvalidatorMap::new()),
+        })
+    }
+}
+
+impl OverlayFileSystem {
+    /// Overrides the content of the file at `path` (relative to the root).
+    pub fn set(&self, path: &str, content: FileContent) {
+        self.overlays
+            .lock()
+            .unwrap()
+            .insert(path.to_string(), content);
+        self.invalidate_path(path);
+    }
+
+    /// Removes the overlay entry at `path`, so that the content of the base
+    /// filesystem is visible again.
+    pub fn clear(&self, path: &str) {
+        if self.overlays.lock().unwrap().remove(path).is_some() {
+            self.invalidate_path(path);
+        }
+    }
+
+    /// Removes all overlay entries.
+    pub fn clear_all(&self) {
+        let paths = self
+            .overlays
+            .lock()
+            .unwrap()
+            .drain()
+            .map(|(path, _)| path)
+            .collect::<Vec<_>>();
+        for path in paths {
+            self.invalidate_path(&path);
+        }
+    }
+
+    /// Invalidates the tasks which read the file at `path` or listed one of
+    /// its parent directories.
+    fn invalidate_path(&self, path: &str) {
+        let reason = OverlayChange {
+            path: path.to_string(),
+        };
+        if let Some(invalidators) = self.invalidator_map.lock().unwrap().remove(path) {
+            invalidators
+                .into_iter()
+                .for_each(|i| i.invalidate_with_reason(reason.clone()));
+        }
+        let mut dir_invalidator_map = self.dir_invalidator_map.lock().unwrap();
+        let mut dir = path;
+        while !dir.is_empty() {
+            dir = dir.rsplit_once('/').map_or("", |(parent, _)| parent);
+            if let Some(invalidators) = dir_invalidator_map.remove(dir) {
+                invalidators
+                    .into_iter()
+                    .for_each(|i| i.invalidate_with_reason(reason.clone()));
+            }
+        }
+    }
+
+    /// Returns the overlay entry at `path` and registers the current task to
+    /// be invalidated when it changes. Has to be called within a turbo-tasks
+    /// function.
+    fn get_overlay(&self, path: &str) -> Option<FileContent> {
+        self.invalidator_map
+            .insert(path.to_string(), turbo_tasks::get_invalidator());
+        self.overlays.lock().unwrap().get(path).cloned()
+    }
+
+    fn base_path(&self, path: &str) -> FileSystemPathVc {
+        self.base.root().join(path)
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl FileSystem for OverlayFileSystem {
+    #[turbo_tasks::function]
+    async fn read(&self, fs_path: FileSystemPathVc) -> Result<FileContentVc> {
+        let path = &fs_path.await?.path;
+        Ok(match self.get_overlay(path) {
+            Some(content) => content.cell(),
+            None => self.base_path(path).read(),
+        })
+    }
+
+    #[turbo_tasks::function]
+    async fn read_link(&self, fs_path: FileSystemPathVc) -> Result<LinkContentVc> {
+        let path = &fs_path.await?.path;
+        Ok(match self.get_overlay(path) {
+            Some(_) => LinkContent::NotFound.cell(),
+            None => self.base_path(path).read_link(),
+        })
+    }
+
+    #[turbo_tasks::function]
+    async fn read_dir(&self, fs_path: FileSystemPathVc) -> Result<DirectoryContentVc> {
+        let path = &fs_path.await?.path;
+        self.dir_invalidator_map
+            .insert(path.clone(), turbo_tasks::get_invalidator());
+        let base_content = self.base_path(path).read_dir().await?;
+        let mut found = matches!(&*base_content, DirectoryContent::Entries(_));
+        let mut entries = AutoMap::new();
+        if let DirectoryContent::Entries(base_entries) = &*base_content {
+            for (name, entry) in base_entries.iter() {
+                let entry_path = fs_path.join(name);
+                let entry = match entry {
+                    DirectoryEntry::File(_) => DirectoryEntry::File(entry_path),
+                    DirectoryEntry::Directory(_) => DirectoryEntry::Directory(entry_path),
+                    DirectoryEntry::Symlink(_) => DirectoryEntry::Symlink(entry_path),
+                    DirectoryEntry::Other(_) => DirectoryEntry::Other(entry_path),
+                    DirectoryEntry::Error => DirectoryEntry::Error,
+                };
+                entries.insert(name.clone(), entry);
+            }
+        }
+        let prefix = if path.is_empty() {
+            String::new()
+        } else {
+            format!("{path}/")
+        };
+        for (overlay_path, content) in self.overlays.lock().unwrap().iter() {
+            let Some(rest) = overlay_path.strip_prefix(&prefix) else {
+                continue;
+            };
+            match (rest.split_once('/'), content) {
+                // Directories that only exist because of overlay entries
+                (Some((dir, _)), FileContent::Content(_)) => {
+                    found = true;
+                    entries
+                        .entry(dir.to_string())
+                        .or_insert_with(|| DirectoryEntry::Directory(fs_path.join(dir)));
+                }
+                (Some(_), FileContent::NotFound) => {}
+                (None, FileContent::Content(_)) => {
+                    found = true;
+                    entries.insert(rest.to_string(), DirectoryEntry::File(fs_path.join(rest)));
+                }
+                (None, FileContent::NotFound) => {
+                    entries.remove(&rest.to_string());
+                }
+            }
+        }
+        Ok(if found {
+            DirectoryContentVc::new(entries)
+        } else {
+            DirectoryContentVc::not_found()
+        })
+    }
+
+    #[turbo_tasks::function]
+    async fn track(&self, fs_path: FileSystemPathVc) -> Result<CompletionVc> {
+        let path = &fs_path.await?.path;
+        Ok(match self.get_overlay(path) {
+            Some(_) => CompletionVc::new(),
+            None => self.base_path(path).track(),
+        })
+    }
+
+    #[turbo_tasks::function]
+    async fn write(
+        &self,
+        fs_path: FileSystemPathVc,
+        content: FileContentVc,
+    ) -> Result<CompletionVc> {
+        Ok(self.base_path(&fs_path.await?.path).write(content))
+    }
+
+    #[turbo_tasks::function]
+    async fn write_link(
+        &self,
+        fs_path: FileSystemPathVc,
+        target: LinkContentVc,
+    ) -> Result<CompletionVc> {
+        Ok(self.base_path(&fs_path.await?.path).write_link(target))
+    }
+
+    #[turbo_tasks::function]
+    async fn metadata(&self, fs_path: FileSystemPathVc) -> Result<FileMetaVc> {
+        let path = &fs_path.await?.path;
+        Ok(match self.get_overlay(path) {
+            Some(FileContent::Content(file)) => file.meta().clone().cell(),
+            Some(FileContent::NotFound) => bail!("path not found, can't read metadata"),
+            None => self.base_path(path).metadata(),
+        })
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ValueToString for OverlayFileSystem {
+    #[turbo_tasks::function]
+    fn to_string(&self) -> StringVc {
+        StringVc::cell(self.name.clone())
+    }
+}
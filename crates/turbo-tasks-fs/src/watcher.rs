@@ -0,0 +1,225 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::Sender,
+        Arc, Mutex,
+    },
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use notify::{DebouncedEvent, RecommendedWatcher, RecursiveMode, Watcher};
+use turbo_watch_polling::should_poll;
+
+/// Interval in which the [PollWatcher] checks the watched directories.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Either the native watcher of the platform or a [PollWatcher].
+pub(crate) enum FsWatcher {
+    Native(RecommendedWatcher),
+    Polling(PollWatcher),
+}
+
+impl FsWatcher {
+    /// Creates a watcher for the filesystem at `root`, which polls when
+    /// [should_poll] says so.
+    pub fn new(root: &Path, tx: Sender<DebouncedEvent>) -> notify::Result<Self> {
+        Ok(if should_poll(root) {
+            FsWatcher::Polling(PollWatcher::new(tx, POLL_INTERVAL))
+        } else {
+            FsWatcher::Native(notify::watcher(tx, Duration::from_millis(1))?)
+        })
+    }
+
+    pub fn watch(&mut self, path: &Path, recursive_mode: RecursiveMode) -> notify::Result<()> {
+        match self {
+            FsWatcher::Native(watcher) => watcher.watch(path, recursive_mode),
+            FsWatcher::Polling(watcher) => watcher.watch(path, recursive_mode),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct EntryState {
+    is_dir: bool,
+    modified: Option<SystemTime>,
+    len: u64,
+}
+
+#[derive(Default)]
+struct PollState {
+    watches: HashMap<PathBuf, RecursiveMode>,
+    /// Incremented when `watches` changes, to detect scans which missed a
+    /// new watch.
+    generation: usize,
+    entries: HashMap<PathBuf, EntryState>,
+}
+
+/// A watcher which stats the watched directories and their entries in an
+/// interval, and reports the differences as [DebouncedEvent]s.
+pub(crate) struct PollWatcher {
+    state: Arc<Mutex<PollState>>,
+    stopped: Arc<AtomicBool>,
+}
+
+impl PollWatcher {
+    pub fn new(tx: Sender<DebouncedEvent>, interval: Duration) -> Self {
+        let state = Arc::new(Mutex::new(PollState::default()));
+        let stopped = Arc::new(AtomicBool::new(false));
+        {
+            let state = state.clone();
+            let stopped = stopped.clone();
+            thread::spawn(move || loop {
+                thread::sleep(interval);
+                if stopped.load(Ordering::Acquire) {
+                    break;
+                }
+                let (watches, generation) = {
+                    let state = state.lock().unwrap();
+                    (state.watches.clone(), state.generation)
+                };
+                // Scanning can take a while on slow filesystems, so it's done
+                // without blocking new watches.
+                let mut entries = HashMap::new();
+                for (path, recursive_mode) in watches {
+                    scan(&path, recursive_mode, &mut entries);
+                }
+                let mut state = state.lock().unwrap();
+                if state.generation != generation {
+                    // The entries of the new watch would be reported as
+                    // removed, the next scan includes them.
+                    continue;
+                }
+                let events = diff(&state.entries, &entries);
+                state.entries = entries;
+                drop(state);
+                for event in events {
+                    // The receiver has been dropped with the filesystem
+                    if tx.send(event).is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+        Self { state, stopped }
+    }
+
+    pub fn watch(&mut self, path: &Path, recursive_mode: RecursiveMode) -> notify::Result<()> {
+        if let Err(err) = fs::metadata(path) {
+            return Err(notify::Error::Io(err));
+        }
+        let mut entries = HashMap::new();
+        scan(path, recursive_mode, &mut entries);
+        let mut state = self.state.lock().unwrap();
+        state.entries.extend(entries);
+        state.watches.insert(path.to_path_buf(), recursive_mode);
+        state.generation += 1;
+        Ok(())
+    }
+}
+
+impl Drop for PollWatcher {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::Release);
+    }
+}
+
+fn entry_state(metadata: &fs::Metadata) -> EntryState {
+    EntryState {
+        is_dir: metadata.is_dir(),
+        modified: metadata.modified().ok(),
+        len: metadata.len(),
+    }
+}
+
+/// Collects the state of `path` and its entries.
+fn scan(path: &Path, recursive_mode: RecursiveMode, entries: &mut HashMap<PathBuf, EntryState>) {
+    let Ok(metadata) = fs::symlink_metadata(path) else {
+        return;
+    };
+    entries.insert(path.to_path_buf(), entry_state(&metadata));
+    if !metadata.is_dir() {
+        return;
+    }
+    let Ok(read_dir) = fs::read_dir(path) else {
+        return;
+    };
+    for entry in read_dir.flatten() {
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        let entry_path = entry.path();
+        if metadata.is_dir() && recursive_mode == RecursiveMode::Recursive {
+            scan(&entry_path, recursive_mode, entries);
+        } else {
+            entries.insert(entry_path, entry_state(&metadata));
+        }
+    }
+}
+
+fn diff(
+    old: &HashMap<PathBuf, EntryState>,
+    new: &HashMap<PathBuf, EntryState>,
+) -> Vec<DebouncedEvent> {
+    let mut events = Vec::new();
+    for (path, state) in new {
+        match old.get(path) {
+            None => events.push(DebouncedEvent::Create(path.clone())),
+            // Changes of directories are reported for their entries.
+            Some(old_state) if old_state != state && !(old_state.is_dir && state.is_dir) => {
+                events.push(DebouncedEvent::Write(path.clone()))
+            }
+            Some(_) => {}
+        }
+    }
+    for path in old.keys() {
+        if !new.contains_key(path) {
+            events.push(DebouncedEvent::Remove(path.clone()));
+        }
+    }
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::mpsc::{channel, Receiver},
+        time::Duration,
+    };
+
+    use notify::{DebouncedEvent, RecursiveMode};
+
+    use super::PollWatcher;
+
+    fn next_event(rx: &Receiver<DebouncedEvent>) -> DebouncedEvent {
+        rx.recv_timeout(Duration::from_secs(5)).unwrap()
+    }
+
+    #[test]
+    fn polls_for_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path().canonicalize().unwrap();
+        let file = dir.join("file.txt");
+        std::fs::write(&file, "a").unwrap();
+
+        let (tx, rx) = channel();
+        let mut watcher = PollWatcher::new(tx, Duration::from_millis(10));
+        watcher.watch(&dir, RecursiveMode::NonRecursive).unwrap();
+
+        std::fs::write(&file, "changed").unwrap();
+        assert!(matches!(next_event(&rx), DebouncedEvent::Write(path) if path == file));
+
+        let new_file = dir.join("new.txt");
+        std::fs::write(&new_file, "").unwrap();
+        assert!(matches!(next_event(&rx), DebouncedEvent::Create(path) if path == new_file));
+
+        std::fs::remove_file(&file).unwrap();
+        assert!(matches!(next_event(&rx), DebouncedEvent::Remove(path) if path == file));
+
+        drop(watcher);
+        assert!(rx.recv_timeout(Duration::from_secs(5)).is_err());
+    }
+}
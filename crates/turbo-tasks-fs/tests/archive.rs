@@ -0,0 +1,144 @@
+mod helpers;
+
+use std::{
+    fs,
+    io::{Cursor, Write},
+    path::Path,
+};
+
+use anyhow::Result;
+use flate2::{write::GzEncoder, Compression};
+use helpers::{read_dir_names, read_string, wait_for_content};
+use tar::EntryType;
+use turbo_tasks::TurboTasks;
+use turbo_tasks_fs::{
+    archive::ArchiveFileSystemVc, attach::AttachedFileSystemVc, DiskFileSystemVc, FileSystem,
+    FileSystemPathVc, LinkContent,
+};
+use turbo_tasks_memory::MemoryBackend;
+use zip::ZipWriter;
+
+fn write_tgz(path: &Path, index_js: &str) {
+    let mut builder = tar::Builder::new(GzEncoder::new(Vec::new(), Compression::fast()));
+    for (name, content) in [
+        ("package/package.json", "{}"),
+        ("package/lib/index.js", index_js),
+    ] {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(content.len() as u64);
+        header.set_mode(0o644);
+        builder
+            .append_data(&mut header, name, content.as_bytes())
+            .unwrap();
+    }
+    let mut header = tar::Header::new_gnu();
+    header.set_entry_type(EntryType::Symlink);
+    header.set_size(0);
+    builder
+        .append_link(&mut header, "package/index.js", "lib/index.js")
+        .unwrap();
+    let bytes = builder.into_inner().unwrap().finish().unwrap();
+    fs::write(path, bytes).unwrap();
+}
+
+fn write_zip(path: &Path) {
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    zip.add_directory("node_modules/", Default::default())
+        .unwrap();
+    zip.start_file("node_modules/foo/index.js", Default::default())
+        .unwrap();
+    zip.write_all(b"foo").unwrap();
+    fs::write(path, zip.finish().unwrap().into_inner()).unwrap();
+}
+
+fn mount(disk: DiskFileSystemVc, archive: &str) -> FileSystemPathVc {
+    let archive_fs = ArchiveFileSystemVc::new(archive.to_string(), disk.root().join(archive));
+    let mount_point = disk.root().join("mnt");
+    AttachedFileSystemVc::new(mount_point, archive_fs.into())
+        .root()
+        .join("mnt")
+}
+
+#[tokio::test]
+async fn reads_archives() {
+    turbo_tasks_fs::register();
+    let dir = tempfile::tempdir().unwrap();
+    write_tgz(&dir.path().join("pkg.tgz"), "tgz");
+    write_zip(&dir.path().join("pkg.zip"));
+    let root = dir.path().to_str().unwrap().to_string();
+    let tt = TurboTasks::new(MemoryBackend::default());
+    tt.run_once(async move {
+        let disk = DiskFileSystemVc::new("disk".to_string(), root);
+
+        let tgz = mount(disk, "pkg.tgz");
+        assert_eq!(
+            read_dir_names(tgz.join("package")).await?,
+            ["index.js", "lib", "package.json"]
+        );
+        assert_eq!(
+            read_string(tgz.join("package/lib/index.js"))
+                .await?
+                .as_deref(),
+            Some("tgz")
+        );
+        assert_eq!(
+            read_string(tgz.join("package/index.js")).await?.as_deref(),
+            Some("tgz")
+        );
+        assert!(matches!(
+            &*tgz.join("package/index.js").read_link().await?,
+            LinkContent::Link { target, .. } if target == "lib/index.js"
+        ));
+        assert_eq!(read_string(tgz.join("package/missing.js")).await?, None);
+
+        let index_js = tgz.join("package/index.js").metadata().await?;
+        assert!(!index_js.is_dir());
+        assert_eq!(index_js.size(), Some(3));
+        assert!(tgz.join("package/lib").metadata().await?.is_dir());
+
+        let zip = mount(disk, "pkg.zip");
+        assert_eq!(read_dir_names(zip.join("node_modules")).await?, ["foo"]);
+        assert_eq!(
+            read_string(zip.join("node_modules/foo/index.js"))
+                .await?
+                .as_deref(),
+            Some("foo")
+        );
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+async fn read_index_js(
+    tt: &TurboTasks<MemoryBackend>,
+    root: &str,
+    start_watching: bool,
+) -> Result<Option<String>> {
+    let root = root.to_string();
+    tt.run_once(async move {
+        let disk = DiskFileSystemVc::new("disk".to_string(), root);
+        if start_watching {
+            disk.await?.start_watching()?;
+        }
+        read_string(mount(disk, "pkg.tgz").join("package/index.js")).await
+    })
+    .await
+}
+
+#[tokio::test]
+async fn invalidates_on_archive_change() {
+    turbo_tasks_fs::register();
+    let dir = tempfile::tempdir().unwrap();
+    let archive_path = dir.path().join("pkg.tgz");
+    write_tgz(&archive_path, "before");
+    let root = dir.path().to_str().unwrap();
+    let tt = TurboTasks::new(MemoryBackend::default());
+    assert_eq!(
+        read_index_js(&tt, root, true).await.unwrap().as_deref(),
+        Some("before")
+    );
+
+    write_tgz(&archive_path, "after");
+    wait_for_content(|| read_index_js(&tt, root, false), "after").await;
+}
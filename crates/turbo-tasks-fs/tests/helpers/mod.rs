@@ -0,0 +1,45 @@
+// Every test only uses some of the helpers.
+#![allow(dead_code)]
+
+use std::{future::Future, time::Duration};
+
+use anyhow::Result;
+use turbo_tasks_fs::{DirectoryContent, FileContent, FileSystemPathVc};
+
+/// Reads a file as a string, or [None] when it doesn't exist.
+pub async fn read_string(path: FileSystemPathVc) -> Result<Option<String>> {
+    Ok(match &*path.read().await? {
+        FileContent::Content(file) => Some(file.content().to_str()?.to_string()),
+        FileContent::NotFound => None,
+    })
+}
+
+/// Lists the sorted names of the entries of a directory, or nothing when it
+/// doesn't exist.
+pub async fn read_dir_names(path: FileSystemPathVc) -> Result<Vec<String>> {
+    let DirectoryContent::Entries(entries) = &*path.read_dir().await? else {
+        return Ok(Vec::new());
+    };
+    let mut names = entries
+        .iter()
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+    names.sort();
+    Ok(names)
+}
+
+/// Calls `read` until it returns `expected`, as changes on disk are picked up
+/// asynchronously. Panics after 5 seconds.
+pub async fn wait_for_content<F, R>(mut read: F, expected: &str)
+where
+    F: FnMut() -> R,
+    R: Future<Output = Result<Option<String>>>,
+{
+    for _ in 0..50 {
+        if read().await.unwrap().as_deref() == Some(expected) {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    panic!("change to {expected:?} was not picked up");
+}
@@ -0,0 +1,108 @@
+#![feature(min_specialization)]
+
+mod helpers;
+
+use std::{fs, sync::Mutex};
+
+use anyhow::Result;
+use helpers::{read_dir_names, read_string};
+use turbo_tasks::{CompletionVc, TurboTasks};
+use turbo_tasks_fs::{
+    overlay::OverlayFileSystemVc, DiskFileSystemVc, File, FileContent, FileSystem, FileSystemPathVc,
+};
+use turbo_tasks_memory::MemoryBackend;
+
+fn register() {
+    turbo_tasks_fs::register();
+    include!(concat!(env!("OUT_DIR"), "/register_test_overlay.rs"));
+}
+
+/// The path of every execution of [track_read].
+static READS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+#[turbo_tasks::function]
+async fn track_read(path: FileSystemPathVc) -> Result<CompletionVc> {
+    let read = path.await?.path.clone();
+    READS.lock().unwrap().push(read);
+    path.read().await?;
+    Ok(CompletionVc::new())
+}
+
+fn read_count(path: &str) -> usize {
+    READS
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|read| *read == path)
+        .count()
+}
+
+#[tokio::test]
+async fn overlays_base_filesystem() {
+    register();
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("a.txt"), "disk").unwrap();
+    let root = dir.path().to_str().unwrap().to_string();
+    let tt = TurboTasks::new(MemoryBackend::default());
+    tt.run_once(async move {
+        let disk = DiskFileSystemVc::new("disk".to_string(), root);
+        let overlay = OverlayFileSystemVc::new("overlay".to_string(), disk.into());
+        let root = overlay.root();
+        let a = root.join("a.txt");
+        let c = root.join("b/c.txt");
+        assert_eq!(read_string(a).await?.as_deref(), Some("disk"));
+        assert_eq!(read_string(c).await?, None);
+        assert_eq!(read_dir_names(root).await?, ["a.txt"]);
+
+        overlay.await?.set("a.txt", File::from("buffer").into());
+        overlay.await?.set("b/c.txt", File::from("new").into());
+        assert_eq!(read_string(a).await?.as_deref(), Some("buffer"));
+        assert_eq!(read_string(c).await?.as_deref(), Some("new"));
+        assert_eq!(read_dir_names(root).await?, ["a.txt", "b"]);
+        assert_eq!(read_dir_names(root.join("b")).await?, ["c.txt"]);
+
+        overlay.await?.set("a.txt", FileContent::NotFound);
+        assert_eq!(read_string(a).await?, None);
+        assert_eq!(read_dir_names(root).await?, ["b"]);
+
+        overlay.await?.clear_all();
+        assert_eq!(read_string(a).await?.as_deref(), Some("disk"));
+        assert_eq!(read_string(c).await?, None);
+        assert_eq!(read_dir_names(root).await?, ["a.txt"]);
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn invalidates_only_readers_of_the_path() {
+    register();
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("a.txt"), "disk").unwrap();
+    fs::write(dir.path().join("d.txt"), "disk").unwrap();
+    let root = dir.path().to_str().unwrap().to_string();
+    let tt = TurboTasks::new(MemoryBackend::default());
+    tt.run_once(async move {
+        let disk = DiskFileSystemVc::new("disk".to_string(), root);
+        let overlay = OverlayFileSystemVc::new("overlay".to_string(), disk.into());
+        let a = overlay.root().join("a.txt");
+        let d = overlay.root().join("d.txt");
+        track_read(a).strongly_consistent().await?;
+        track_read(d).strongly_consistent().await?;
+        assert_eq!((read_count("a.txt"), read_count("d.txt")), (1, 1));
+
+        overlay.await?.set("a.txt", File::from("buffer").into());
+        track_read(a).strongly_consistent().await?;
+        track_read(d).strongly_consistent().await?;
+        assert_eq!((read_count("a.txt"), read_count("d.txt")), (2, 1));
+
+        overlay.await?.clear("a.txt");
+        track_read(a).strongly_consistent().await?;
+        track_read(d).strongly_consistent().await?;
+        assert_eq!((read_count("a.txt"), read_count("d.txt")), (3, 1));
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
@@ -0,0 +1,39 @@
+mod helpers;
+
+use anyhow::Result;
+use helpers::{read_string, wait_for_content};
+use turbo_tasks::TurboTasks;
+use turbo_tasks_fs::{DiskFileSystemVc, FileSystem};
+use turbo_tasks_memory::MemoryBackend;
+use turbo_watch_polling::POLL_ENV_VAR;
+
+async fn read_file(tt: &TurboTasks<MemoryBackend>, root: &str) -> Result<Option<String>> {
+    let root = root.to_string();
+    tt.run_once(async move {
+        let disk = DiskFileSystemVc::new("disk".to_string(), root);
+        // Watching is started once, later calls return early
+        disk.await?.start_watching()?;
+        read_string(disk.root().join("file.txt")).await
+    })
+    .await
+}
+
+#[tokio::test]
+async fn invalidates_when_polling() {
+    // Integration tests run in their own process, so this doesn't affect other
+    // tests
+    std::env::set_var(POLL_ENV_VAR, "1");
+    turbo_tasks_fs::register();
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("file.txt");
+    std::fs::write(&file, "before").unwrap();
+    let root = dir.path().to_str().unwrap();
+    let tt = TurboTasks::new(MemoryBackend::default());
+    assert_eq!(
+        read_file(&tt, root).await.unwrap().as_deref(),
+        Some("before")
+    );
+
+    std::fs::write(&file, "after").unwrap();
+    wait_for_content(|| read_file(&tt, root), "after").await;
+}
@@ -49,7 +49,7 @@ pub fn scope_stress(c: &mut Criterion) {
                                         rectangle(a, b).strongly_consistent().await?;
                                         Ok(NothingVc::new().into())
                                     });
-                                    tt.wait_task_completion(task, false).await
+                                    tt.wait_task_completion(task.id(), false).await
                                 }
                             })
                             .try_join()
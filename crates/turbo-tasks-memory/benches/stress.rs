@@ -43,7 +43,7 @@ pub fn fibonacci(c: &mut Criterion) {
                         (0..size).map(|i| fib(i, i)).try_join().await?;
                         Ok(NothingVc::new().into())
                     });
-                    tt.wait_task_completion(task, false).await.unwrap();
+                    tt.wait_task_completion(task.id(), false).await.unwrap();
                     tt
                 }
             })
@@ -0,0 +1,157 @@
+use std::{
+    collections::VecDeque,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+
+use dashmap::DashMap;
+use nohash_hasher::BuildNoHashHasher;
+use turbo_tasks::{InvalidationCause, TaskId};
+
+/// The number of invalidations that are kept per task.
+const MAX_CAUSES_PER_TASK: usize = 8;
+
+/// Records why tasks have been invalidated, so the chain of invalidations
+/// from a user-facing reason (e.g. a file change) to a recomputed task can be
+/// reconstructed. Disabled by default as it costs memory for every
+/// invalidated task.
+pub(crate) struct InvalidationTracing {
+    enabled: AtomicBool,
+    next_sequence: AtomicUsize,
+    /// The latest causes of invalidation per task, oldest first, each with a
+    /// sequence number to order them by recency.
+    causes: DashMap<TaskId, VecDeque<(usize, InvalidationCause)>, BuildNoHashHasher<TaskId>>,
+}
+
+/// The chain of invalidations that led to a task being recomputed.
+pub struct InvalidationTrace {
+    /// The invalidated task followed by the tasks that invalidated it, each
+    /// entry being invalidated by the next one. Contains the task id and its
+    /// description.
+    pub tasks: Vec<(TaskId, String)>,
+    /// The cause the chain started with. `None` when it's unknown, e.g. the
+    /// task was invalidated before tracing has been enabled or its
+    /// invalidation has been dropped from the history.
+    pub cause: Option<InvalidationCause>,
+}
+
+impl InvalidationTrace {
+    /// A user-facing description of the cause the chain started with.
+    pub fn cause_description(&self) -> String {
+        match &self.cause {
+            Some(InvalidationCause::Reason(reason)) => reason.to_string(),
+            Some(InvalidationCause::External) => "invalidated without a reason".to_string(),
+            Some(InvalidationCause::Dependency(_)) | None => "unknown".to_string(),
+        }
+    }
+}
+
+impl InvalidationTracing {
+    pub fn new() -> Self {
+        Self {
+            enabled: AtomicBool::new(false),
+            next_sequence: AtomicUsize::new(0),
+            causes: DashMap::default(),
+        }
+    }
+
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Release);
+        if !enabled {
+            self.clear();
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Acquire)
+    }
+
+    pub fn clear(&self) {
+        self.causes.clear();
+    }
+
+    pub fn record(&self, tasks: &[TaskId], cause: &InvalidationCause) {
+        if !self.is_enabled() {
+            return;
+        }
+        let sequence = self.next_sequence.fetch_add(1, Ordering::Relaxed);
+        for &task in tasks {
+            // A task that invalidates itself, e.g. by reading and writing the
+            // same state, would only produce a cycle.
+            if matches!(cause, InvalidationCause::Dependency(dependency) if *dependency == task) {
+                continue;
+            }
+            let mut causes = self.causes.entry(task).or_default();
+            if causes.len() == MAX_CAUSES_PER_TASK {
+                causes.pop_front();
+            }
+            causes.push_back((sequence, cause.clone()));
+        }
+    }
+
+    /// Returns the latest cause of `task` that has been recorded before
+    /// `before`.
+    fn cause_before(&self, task: TaskId, before: usize) -> Option<(usize, InvalidationCause)> {
+        self.causes.get(&task).and_then(|causes| {
+            causes
+                .iter()
+                .rev()
+                .find(|(sequence, _)| *sequence < before)
+                .cloned()
+        })
+    }
+
+    /// Follows the recorded causes from the latest invalidation of `task`
+    /// back to the cause the chain started with.
+    pub fn trace(&self, task: TaskId, describe: impl Fn(TaskId) -> String) -> InvalidationTrace {
+        self.trace_before(task, usize::MAX, describe)
+    }
+
+    /// Follows the recorded causes from the latest invalidation of `task`
+    /// before `before`. A dependency is followed to its latest invalidation
+    /// before the one of the dependent task, so later invalidations don't
+    /// change the chain.
+    fn trace_before(
+        &self,
+        task: TaskId,
+        before: usize,
+        describe: impl Fn(TaskId) -> String,
+    ) -> InvalidationTrace {
+        let mut tasks = vec![(task, describe(task))];
+        let mut current = (task, before);
+        let cause = loop {
+            let Some((sequence, cause)) = self.cause_before(current.0, current.1) else {
+                break None;
+            };
+            match cause {
+                InvalidationCause::Dependency(dependency) => {
+                    tasks.push((dependency, describe(dependency)));
+                    current = (dependency, sequence);
+                }
+                cause => break Some(cause),
+            }
+        };
+        InvalidationTrace { tasks, cause }
+    }
+
+    /// Returns the chains of all recorded invalidations, the most recent
+    /// first.
+    pub fn traces(&self, describe: impl Fn(TaskId) -> String) -> Vec<InvalidationTrace> {
+        let mut invalidations = self
+            .causes
+            .iter()
+            .flat_map(|entry| {
+                let task = *entry.key();
+                entry
+                    .value()
+                    .iter()
+                    .map(move |(sequence, _)| (*sequence, task))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        invalidations.sort_by(|a, b| b.cmp(a));
+        invalidations
+            .into_iter()
+            .map(|(sequence, task)| self.trace_before(task, sequence + 1, &describe))
+            .collect()
+    }
+}
@@ -11,15 +11,19 @@ mod cell;
 mod concurrent_priority_queue;
 mod count_hash_set;
 mod gc;
+mod invalidation_tracing;
 mod map_guard;
 mod memory_backend;
 mod memory_backend_with_pg;
 mod output;
 mod priority_pair;
+mod profile;
 pub mod scope;
 pub mod stats;
 mod task;
 pub mod viz;
 
+pub use invalidation_tracing::InvalidationTrace;
 pub use memory_backend::MemoryBackend;
 pub use memory_backend_with_pg::MemoryBackendWithPersistedGraph;
+pub use profile::Profile;
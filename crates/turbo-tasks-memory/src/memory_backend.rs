@@ -28,14 +28,16 @@ use turbo_tasks::{
     event::EventListener,
     primitives::RawVcSetVc,
     util::{IdFactory, NoMoveVec},
-    CellId, RawVc, TaskId, TraitTypeId, TurboTasksBackendApi, Unused,
+    CellId, InvalidationCause, RawVc, TaskId, TraitTypeId, TurboTasksBackendApi, Unused,
 };
 
 use crate::{
     cell::RecomputingCell,
     gc::GcQueue,
+    invalidation_tracing::{InvalidationTrace, InvalidationTracing},
     output::Output,
     priority_pair::PriorityPair,
+    profile::{Profile, ProfiledFuture, Profiler},
     scope::{TaskScope, TaskScopeId},
     task::{
         run_add_to_scope_queue, run_remove_from_scope_queue, Task, TaskDependency,
@@ -55,6 +57,8 @@ pub struct MemoryBackend {
     gc_queue: Option<GcQueue>,
     idle_gc_active: AtomicBool,
     scope_add_remove_priority: PriorityPair,
+    invalidation_tracing: InvalidationTracing,
+    profiler: Arc<Profiler>,
 }
 
 impl Default for MemoryBackend {
@@ -83,9 +87,57 @@ impl MemoryBackend {
             gc_queue: (memory_limit != usize::MAX).then(GcQueue::new),
             idle_gc_active: AtomicBool::new(false),
             scope_add_remove_priority: PriorityPair::new(),
+            invalidation_tracing: InvalidationTracing::new(),
+            profiler: Arc::new(Profiler::new()),
         }
     }
 
+    /// Enables or disables recording task executions for a [Profile].
+    pub fn set_profiling(&self, enabled: bool) {
+        self.profiler.set_enabled(enabled);
+    }
+
+    pub fn is_profiling_enabled(&self) -> bool {
+        self.profiler.is_enabled()
+    }
+
+    /// Takes the task executions that have been recorded since profiling has
+    /// been enabled or the last profile has been taken.
+    pub fn take_profile(&self) -> Profile {
+        self.profiler
+            .take(|task| self.with_task(task, |task| task.get_stats_type().to_string()))
+    }
+
+    /// Enables or disables recording why tasks are invalidated. Disabling it
+    /// drops all recorded invalidations.
+    pub fn set_invalidation_tracing(&self, enabled: bool) {
+        self.invalidation_tracing.set_enabled(enabled);
+    }
+
+    pub fn is_invalidation_tracing_enabled(&self) -> bool {
+        self.invalidation_tracing.is_enabled()
+    }
+
+    /// Drops all recorded invalidations.
+    pub fn clear_invalidation_traces(&self) {
+        self.invalidation_tracing.clear();
+    }
+
+    /// Returns the chain of invalidations that caused `task` to recompute.
+    /// Only available when invalidation tracing is enabled.
+    pub fn invalidation_trace(&self, task: TaskId) -> InvalidationTrace {
+        self.invalidation_tracing.trace(task, |task| {
+            self.with_task(task, |task| task.get_description())
+        })
+    }
+
+    /// Returns the chains of the latest invalidations of all invalidated
+    /// tasks, the most recent first.
+    pub fn invalidation_traces(&self) -> Vec<InvalidationTrace> {
+        self.invalidation_tracing
+            .traces(|task| self.with_task(task, |task| task.get_description()))
+    }
+
     fn connect_task_child(
         &self,
         parent: TaskId,
@@ -420,18 +472,26 @@ impl Backend for MemoryBackend {
         }
     }
 
+    fn record_invalidation_cause(&self, tasks: &[TaskId], cause: &InvalidationCause) {
+        self.invalidation_tracing.record(tasks, cause);
+    }
+
     fn get_task_description(&self, task: TaskId) -> String {
         self.with_task(task, |task| task.get_description())
     }
 
     type ExecutionScopeFuture<T: Future<Output = Result<()>> + Send + 'static> =
-        TaskLocalFuture<RefCell<AutoSet<TaskDependency>>, T>;
+        ProfiledFuture<TaskLocalFuture<RefCell<AutoSet<TaskDependency>>, T>>;
     fn execution_scope<T: Future<Output = Result<()>> + Send + 'static>(
         &self,
-        _task: TaskId,
+        task: TaskId,
         future: T,
     ) -> Self::ExecutionScopeFuture<T> {
-        DEPENDENCIES_TO_TRACK.scope(Default::default(), future)
+        ProfiledFuture::new(
+            task,
+            self.profiler.is_enabled().then(|| self.profiler.clone()),
+            DEPENDENCIES_TO_TRACK.scope(Default::default(), future),
+        )
     }
 
     fn try_start_task_execution(
@@ -442,6 +502,14 @@ impl Backend for MemoryBackend {
         self.with_task(task, |task| task.execute(self, turbo_tasks))
     }
 
+    fn try_unschedule_task(
+        &self,
+        task: TaskId,
+        turbo_tasks: &dyn TurboTasksBackendApi<MemoryBackend>,
+    ) -> bool {
+        self.with_task(task, |task| task.try_unschedule(self, turbo_tasks))
+    }
+
     fn task_execution_result(
         &self,
         task: TaskId,
@@ -461,6 +529,7 @@ impl Backend for MemoryBackend {
         stateful: bool,
         turbo_tasks: &dyn TurboTasksBackendApi<MemoryBackend>,
     ) -> bool {
+        self.profiler.record_execution(task_id, duration);
         let reexecute = self.with_task(task_id, |task| {
             task.execution_completed(duration, instant, stateful, self, turbo_tasks)
         });
@@ -656,6 +725,7 @@ impl Backend for MemoryBackend {
             self.lookup_and_connect_task(parent_task, &self.task_cache, &task_type, turbo_tasks)
         {
             // fast pass without creating a new task
+            self.profiler.record_call(task, parent_task, true);
             task
         } else {
             // It's important to avoid overallocating memory as this will go into the task
@@ -671,7 +741,7 @@ impl Backend for MemoryBackend {
                 task_type.clone(),
                 turbo_tasks.stats_type(),
             );
-            self.insert_and_connect_fresh_task(
+            let task = self.insert_and_connect_fresh_task(
                 parent_task,
                 &self.task_cache,
                 task_type,
@@ -679,7 +749,9 @@ impl Backend for MemoryBackend {
                 task,
                 false,
                 turbo_tasks,
-            )
+            );
+            self.profiler.record_call(task, parent_task, false);
+            task
         }
     }
 
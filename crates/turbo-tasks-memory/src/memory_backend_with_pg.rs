@@ -1634,11 +1634,11 @@ impl<'a, P: PersistedGraph> PersistedGraphApi for MemoryBackendPersistedGraphApi
         }
     }
 
-    fn lookup_task_type(&self, id: TaskId) -> &PersistentTaskType {
-        let task = self.backend.tasks.get(*id).unwrap();
+    fn lookup_task_type(&self, id: TaskId) -> Option<&PersistentTaskType> {
+        let task = self.backend.tasks.get(*id)?;
         match &task.task_type {
-            TaskType::Persistent(ty) => ty,
-            _ => panic!("lookup_task_type should only be used for PersistentTaskType"),
+            TaskType::Persistent(ty) => Some(ty),
+            _ => None,
         }
     }
 }
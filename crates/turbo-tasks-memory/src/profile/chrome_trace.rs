@@ -0,0 +1,80 @@
+use std::{io::Write, time::Duration};
+
+use anyhow::Result;
+use serde_json::{json, Value};
+
+use super::{Profile, ProfileEvent};
+
+const PID: u32 = 1;
+
+fn micros(duration: Duration) -> f64 {
+    duration.as_nanos() as f64 / 1000.0
+}
+
+impl Profile {
+    /// Writes the profile in the Chrome trace event format, which can be
+    /// opened in chrome://tracing, Perfetto or speedscope. Every thread gets a
+    /// track with a slice per poll of a task, and calls are annotated with
+    /// whether they were served from the task cache.
+    pub fn write_chrome_trace(&self, writer: impl Write) -> Result<()> {
+        let mut events = Vec::with_capacity(self.events.len() + self.threads.len() + 1);
+        events.push(json!({
+            "name": "process_name",
+            "ph": "M",
+            "pid": PID,
+            "args": { "name": "turbo-tasks" },
+        }));
+        for (thread, name) in &self.threads {
+            events.push(json!({
+                "name": "thread_name",
+                "ph": "M",
+                "pid": PID,
+                "tid": thread,
+                "args": { "name": name },
+            }));
+        }
+        for (function, event) in &self.events {
+            let function = &self.functions[*function];
+            match *event {
+                ProfileEvent::Poll {
+                    task,
+                    thread,
+                    start,
+                    duration,
+                } => events.push(json!({
+                    "name": function,
+                    "cat": "task",
+                    "ph": "X",
+                    "ts": micros(start),
+                    "dur": micros(duration),
+                    "pid": PID,
+                    "tid": thread,
+                    "args": { "task": *task },
+                })),
+                ProfileEvent::Call {
+                    task,
+                    parent,
+                    hit,
+                    thread,
+                    time,
+                } => events.push(json!({
+                    "name": if hit { "cache hit" } else { "cache miss" },
+                    "cat": "cache",
+                    "ph": "i",
+                    "s": "t",
+                    "ts": micros(time),
+                    "pid": PID,
+                    "tid": thread,
+                    "args": { "function": function, "task": *task, "parent": *parent },
+                })),
+                ProfileEvent::Execution { .. } => {}
+            }
+        }
+        let trace: Value = json!({
+            "traceEvents": events,
+            "displayTimeUnit": "ms",
+        });
+        serde_json::to_writer(writer, &trace)?;
+        Ok(())
+    }
+}
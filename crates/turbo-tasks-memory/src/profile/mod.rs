@@ -0,0 +1,225 @@
+mod chrome_trace;
+mod pprof;
+
+use std::{
+    cell::Cell,
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+use concurrent_queue::ConcurrentQueue;
+use pin_project_lite::pin_project;
+use turbo_tasks::TaskId;
+
+/// Records the execution of tasks, so it can be exported in formats that
+/// standard profilers can open. Disabled by default.
+pub(crate) struct Profiler {
+    enabled: AtomicBool,
+    start: Instant,
+    events: ConcurrentQueue<ProfileEvent>,
+}
+
+#[derive(Clone, Copy)]
+enum ProfileEvent {
+    /// A single poll of the future that executes a task.
+    Poll {
+        task: TaskId,
+        thread: usize,
+        start: Duration,
+        duration: Duration,
+    },
+    /// A task has finished an execution, which took `duration` in total.
+    Execution { task: TaskId, duration: Duration },
+    /// `parent` called `task`, which was either found in the task cache or
+    /// has been created.
+    Call {
+        task: TaskId,
+        parent: TaskId,
+        hit: bool,
+        thread: usize,
+        time: Duration,
+    },
+}
+
+static NEXT_THREAD_INDEX: AtomicUsize = AtomicUsize::new(0);
+static THREAD_NAMES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+thread_local! {
+    static THREAD_INDEX: Cell<Option<usize>> = Cell::new(None);
+}
+
+/// Returns a process-wide index of the current thread, which is used as the
+/// track of the events recorded on it.
+fn current_thread() -> usize {
+    THREAD_INDEX.with(|cell| {
+        if let Some(index) = cell.get() {
+            return index;
+        }
+        let index = NEXT_THREAD_INDEX.fetch_add(1, Ordering::Relaxed);
+        let thread = std::thread::current();
+        let name = thread.name().unwrap_or("unnamed");
+        let mut names = THREAD_NAMES.lock().unwrap();
+        if names.len() <= index {
+            names.resize(index + 1, String::new());
+        }
+        names[index] = format!("{name} #{index}");
+        cell.set(Some(index));
+        index
+    })
+}
+
+impl Profiler {
+    pub fn new() -> Self {
+        Self {
+            enabled: AtomicBool::new(false),
+            start: Instant::now(),
+            events: ConcurrentQueue::unbounded(),
+        }
+    }
+
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Release);
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Acquire)
+    }
+
+    fn push(&self, event: ProfileEvent) {
+        // The queue is unbounded and never closed.
+        let _ = self.events.push(event);
+    }
+
+    fn record_poll(&self, task: TaskId, start: Instant, duration: Duration) {
+        self.push(ProfileEvent::Poll {
+            task,
+            thread: current_thread(),
+            start: start.saturating_duration_since(self.start),
+            duration,
+        });
+    }
+
+    pub fn record_execution(&self, task: TaskId, duration: Duration) {
+        if self.is_enabled() {
+            self.push(ProfileEvent::Execution { task, duration });
+        }
+    }
+
+    pub fn record_call(&self, task: TaskId, parent: TaskId, hit: bool) {
+        if self.is_enabled() {
+            self.push(ProfileEvent::Call {
+                task,
+                parent,
+                hit,
+                thread: current_thread(),
+                time: self.start.elapsed(),
+            });
+        }
+    }
+
+    /// Takes all events recorded so far. `describe` returns the name of the
+    /// function a task executes.
+    pub fn take(&self, describe: impl Fn(TaskId) -> String) -> Profile {
+        let mut functions = Vec::new();
+        let mut function_by_name = HashMap::new();
+        let mut function_by_task = HashMap::new();
+        let mut function_of = |task: TaskId| {
+            *function_by_task.entry(task).or_insert_with(|| {
+                let name = describe(task);
+                *function_by_name.entry(name.clone()).or_insert_with(|| {
+                    functions.push(name);
+                    functions.len() - 1
+                })
+            })
+        };
+        let mut events = Vec::with_capacity(self.events.len());
+        let mut threads = Vec::new();
+        while let Ok(event) = self.events.pop() {
+            let function = match event {
+                ProfileEvent::Poll { task, thread, .. }
+                | ProfileEvent::Call { task, thread, .. } => {
+                    threads.push(thread);
+                    function_of(task)
+                }
+                ProfileEvent::Execution { task, .. } => function_of(task),
+            };
+            events.push((function, event));
+        }
+        threads.sort_unstable();
+        threads.dedup();
+        let thread_names = THREAD_NAMES.lock().unwrap();
+        let threads = threads
+            .into_iter()
+            .map(|thread| (thread, thread_names[thread].clone()))
+            .collect();
+        Profile {
+            functions,
+            threads,
+            events,
+            duration: self.start.elapsed(),
+        }
+    }
+}
+
+/// Task executions recorded by the [crate::MemoryBackend] while profiling was
+/// enabled.
+pub struct Profile {
+    /// Names of the functions tasks execute, indexed by the events.
+    functions: Vec<String>,
+    /// Index and name of every thread that events were recorded on.
+    threads: Vec<(usize, String)>,
+    /// Recorded events with the index of the function of their task.
+    events: Vec<(usize, ProfileEvent)>,
+    /// Time between the creation of the backend and taking the profile.
+    duration: Duration,
+}
+
+impl Profile {
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+pin_project! {
+    /// Records every poll of a task execution future in the [Profiler].
+    pub struct ProfiledFuture<F> {
+        task: TaskId,
+        profiler: Option<Arc<Profiler>>,
+        #[pin]
+        future: F,
+    }
+}
+
+impl<F> ProfiledFuture<F> {
+    pub(crate) fn new(task: TaskId, profiler: Option<Arc<Profiler>>, future: F) -> Self {
+        Self {
+            task,
+            profiler,
+            future,
+        }
+    }
+}
+
+impl<F: Future> Future for ProfiledFuture<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        match this.profiler {
+            Some(profiler) if profiler.is_enabled() => {
+                let start = Instant::now();
+                let result = this.future.poll(cx);
+                profiler.record_poll(*this.task, start, start.elapsed());
+                result
+            }
+            _ => this.future.poll(cx),
+        }
+    }
+}
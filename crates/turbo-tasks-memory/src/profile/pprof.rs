@@ -0,0 +1,148 @@
+use std::io::Write;
+
+use anyhow::Result;
+use prost::Message;
+
+use super::{Profile, ProfileEvent};
+
+// A subset of the messages of
+// https://github.com/google/pprof/blob/main/proto/profile.proto
+
+#[derive(Clone, PartialEq, Message)]
+struct PprofProfile {
+    #[prost(message, repeated, tag = "1")]
+    sample_type: Vec<ValueType>,
+    #[prost(message, repeated, tag = "2")]
+    sample: Vec<Sample>,
+    #[prost(message, repeated, tag = "4")]
+    location: Vec<Location>,
+    #[prost(message, repeated, tag = "5")]
+    function: Vec<Function>,
+    #[prost(string, repeated, tag = "6")]
+    string_table: Vec<String>,
+    #[prost(int64, tag = "10")]
+    duration_nanos: i64,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct ValueType {
+    #[prost(int64, tag = "1")]
+    r#type: i64,
+    #[prost(int64, tag = "2")]
+    unit: i64,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct Sample {
+    #[prost(uint64, repeated, tag = "1")]
+    location_id: Vec<u64>,
+    #[prost(int64, repeated, tag = "2")]
+    value: Vec<i64>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct Location {
+    #[prost(uint64, tag = "1")]
+    id: u64,
+    #[prost(message, repeated, tag = "4")]
+    line: Vec<Line>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct Line {
+    #[prost(uint64, tag = "1")]
+    function_id: u64,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct Function {
+    #[prost(uint64, tag = "1")]
+    id: u64,
+    #[prost(int64, tag = "2")]
+    name: i64,
+    #[prost(int64, tag = "3")]
+    system_name: i64,
+}
+
+/// Aggregated values of a single function.
+#[derive(Default, Clone, Copy)]
+struct FunctionStats {
+    executions: i64,
+    nanos: i64,
+    cache_hits: i64,
+    cache_misses: i64,
+}
+
+impl Profile {
+    /// Writes an uncompressed pprof profile that aggregates the executions,
+    /// the execution time and the cache hits and misses per function.
+    pub fn write_pprof(&self, mut writer: impl Write) -> Result<()> {
+        let mut stats = vec![FunctionStats::default(); self.functions.len()];
+        for (function, event) in &self.events {
+            let stats = &mut stats[*function];
+            match *event {
+                ProfileEvent::Execution { duration, .. } => {
+                    stats.executions += 1;
+                    stats.nanos += duration.as_nanos() as i64;
+                }
+                ProfileEvent::Call { hit: true, .. } => stats.cache_hits += 1,
+                ProfileEvent::Call { hit: false, .. } => stats.cache_misses += 1,
+                ProfileEvent::Poll { .. } => {}
+            }
+        }
+
+        let mut string_table = vec![String::new()];
+        let mut string = |s: &str| {
+            string_table.push(s.to_string());
+            string_table.len() as i64 - 1
+        };
+        let sample_type = [
+            ("executions", "count"),
+            ("cpu", "nanoseconds"),
+            ("cache_hits", "count"),
+            ("cache_misses", "count"),
+        ]
+        .into_iter()
+        .map(|(ty, unit)| ValueType {
+            r#type: string(ty),
+            unit: string(unit),
+        })
+        .collect();
+        let mut function = Vec::with_capacity(self.functions.len());
+        let mut location = Vec::with_capacity(self.functions.len());
+        let mut sample = Vec::with_capacity(self.functions.len());
+        for (index, (name, stats)) in self.functions.iter().zip(stats).enumerate() {
+            // Ids must not be zero.
+            let id = index as u64 + 1;
+            let name = string(name);
+            function.push(Function {
+                id,
+                name,
+                system_name: name,
+            });
+            location.push(Location {
+                id,
+                line: vec![Line { function_id: id }],
+            });
+            sample.push(Sample {
+                location_id: vec![id],
+                value: vec![
+                    stats.executions,
+                    stats.nanos,
+                    stats.cache_hits,
+                    stats.cache_misses,
+                ],
+            });
+        }
+        let profile = PprofProfile {
+            sample_type,
+            sample,
+            location,
+            function,
+            string_table,
+            duration_nanos: self.duration.as_nanos() as i64,
+        };
+        writer.write_all(&profile.encode_to_vec())?;
+        Ok(())
+    }
+}
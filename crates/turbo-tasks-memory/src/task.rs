@@ -780,6 +780,31 @@ impl Task {
         Some(TaskExecutionSpec { future })
     }
 
+    /// Moves a scheduled task back to Dirty when no active scope needs it.
+    /// Returns true if it was unscheduled.
+    pub(crate) fn try_unschedule(
+        &self,
+        backend: &MemoryBackend,
+        _turbo_tasks: &dyn TurboTasksBackendApi<MemoryBackend>,
+    ) -> bool {
+        let mut state = self.full_state_mut();
+        let Scheduled { ref mut event } = state.state_type else {
+            return false;
+        };
+        let event = event.take();
+        // This also adds the task to the dirty tasks of its scopes, so it's
+        // scheduled again when a scope becomes active
+        if self.scopes_dirty_or_active(false, &state.scopes, backend) {
+            state.state_type = Scheduled { event };
+            return false;
+        }
+        // Wake up readers so they can schedule the task again when they still
+        // need it
+        event.notify(usize::MAX);
+        state.state_type = Dirty { event };
+        true
+    }
+
     /// Tries to change the state to InProgress and returns true if it was
     /// possible.
     fn try_start_execution(
@@ -0,0 +1,82 @@
+use std::collections::HashSet;
+
+use indexmap::IndexMap;
+
+use super::escape_html;
+use crate::InvalidationTrace;
+
+/// The maximum number of chains that are listed per cause.
+const MAX_TRACES_PER_CAUSE: usize = 100;
+
+/// Renders the chains of invalidations grouped by the cause they started
+/// with. `traces` are expected to be ordered by recency.
+pub fn create_html(traces: &[InvalidationTrace], enabled: bool) -> String {
+    let mut out = String::new();
+    if !enabled {
+        out += r#"<p class="disclaimer">Invalidation tracing is disabled. Run with --trace-invalidations to enable it.</p>"#;
+    }
+    let mut by_cause: IndexMap<String, Vec<&InvalidationTrace>> = IndexMap::new();
+    for trace in traces {
+        by_cause
+            .entry(trace.cause_description())
+            .or_default()
+            .push(trace);
+    }
+    for (cause, traces) in by_cause {
+        // Chains share tasks, so each invalidated task is only counted once
+        let task_count = traces
+            .iter()
+            .flat_map(|trace| trace.tasks.iter().map(|(task, _)| *task))
+            .collect::<HashSet<_>>()
+            .len();
+        out += &format!(
+            "<details open><summary>{} <span class=\"count\">({} tasks, {} \
+             chains)</span></summary><ol>",
+            escape_html(&cause),
+            task_count,
+            traces.len()
+        );
+        for trace in traces.iter().take(MAX_TRACES_PER_CAUSE) {
+            out += "<li>";
+            for (i, (_, description)) in trace.tasks.iter().enumerate() {
+                if i > 0 {
+                    out += " <span class=\"arrow\">&larr;</span> ";
+                }
+                out += &escape_html(description);
+            }
+            out += " <span class=\"arrow\">&larr;</span> <em>";
+            out += &escape_html(&cause);
+            out += "</em></li>";
+        }
+        if traces.len() > MAX_TRACES_PER_CAUSE {
+            out += &format!(
+                "<li>... and {} more</li>",
+                traces.len() - MAX_TRACES_PER_CAUSE
+            );
+        }
+        out += "</ol></details>";
+    }
+    wrap_html(&out)
+}
+
+fn wrap_html(content: &str) -> String {
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+  <meta charset="utf-8">
+  <title>turbo-tasks invalidations</title>
+  <style>
+    body {{ margin: 0; padding: 0.8rem 1rem; font-family: monospace; }}
+    summary {{ cursor: pointer; font-weight: bold; padding: 0.4rem 0; }}
+    li {{ padding: 0.2rem 0; }}
+    .count, .arrow, .disclaimer {{ opacity: 0.6; }}
+    .disclaimer {{ font-style: italic; }}
+  </style>
+</head>
+<body>
+  {content}
+</body>
+</html>"#
+    )
+}
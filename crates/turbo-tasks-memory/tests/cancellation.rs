@@ -0,0 +1,67 @@
+#![feature(min_specialization)]
+
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Barrier,
+    },
+    time::Duration,
+};
+
+use turbo_tasks::{is_cancelled, CompletionVc, TurboTasks};
+use turbo_tasks_memory::MemoryBackend;
+use turbo_tasks_testing::register;
+
+register!();
+
+lazy_static::lazy_static! {
+    static ref STARTED: Barrier = Barrier::new(2);
+}
+
+static CHILD_RUNS: AtomicUsize = AtomicUsize::new(0);
+
+#[turbo_tasks::function]
+fn child() -> CompletionVc {
+    CHILD_RUNS.fetch_add(1, Ordering::SeqCst);
+    CompletionVc::new()
+}
+
+// A single thread, so the once task has finished when the child would start
+#[tokio::test]
+async fn cancel_once_task() {
+    lazy_static::initialize(&REGISTER);
+    let tt = TurboTasks::new(MemoryBackend::default());
+    let task = tt.spawn_once_task(async {
+        STARTED.wait();
+        while !is_cancelled() {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        // Reading the child schedules it after the cancellation
+        child().await?;
+        Ok(CompletionVc::new().into())
+    });
+
+    // The once task blocks the runtime, so it's cancelled from another thread
+    let handle = task.clone();
+    std::thread::spawn(move || {
+        STARTED.wait();
+        assert!(!handle.is_cancelled());
+        handle.cancel();
+    });
+
+    let result = tt.wait_task_completion(task.id(), false).await;
+    assert!(result.is_err());
+    while tt.get_in_progress_count() != 0 {
+        tokio::task::yield_now().await;
+    }
+    assert_eq!(CHILD_RUNS.load(Ordering::SeqCst), 0);
+
+    // The child still runs when it's needed by other work
+    tt.run_once(async {
+        child().await?;
+        Ok(())
+    })
+    .await
+    .unwrap();
+    assert_eq!(CHILD_RUNS.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,117 @@
+#![feature(min_specialization)]
+
+use std::{
+    fmt::{Display, Formatter},
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+use anyhow::Result;
+use turbo_tasks::{
+    get_invalidator, primitives::U32Vc, InvalidationCause, InvalidationReason, Invalidator,
+    NothingVc, TurboTasks,
+};
+use turbo_tasks_memory::{InvalidationTrace, MemoryBackend};
+use turbo_tasks_testing::register;
+
+register!();
+
+static VALUE: AtomicU32 = AtomicU32::new(0);
+static INVALIDATOR: Mutex<Option<Invalidator>> = Mutex::new(None);
+
+#[derive(PartialEq, Eq, Hash)]
+struct ValueChanged(u32);
+
+impl Display for ValueChanged {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "value changed to {}", self.0)
+    }
+}
+
+impl InvalidationReason for ValueChanged {}
+
+#[turbo_tasks::function]
+fn source() -> U32Vc {
+    *INVALIDATOR.lock().unwrap() = Some(get_invalidator());
+    U32Vc::cell(VALUE.load(Ordering::SeqCst))
+}
+
+#[turbo_tasks::function]
+async fn middle() -> Result<U32Vc> {
+    Ok(U32Vc::cell(*source().await? + 1))
+}
+
+#[turbo_tasks::function]
+async fn sink() -> Result<U32Vc> {
+    Ok(U32Vc::cell(*middle().await? * 2))
+}
+
+fn set_value(value: u32) {
+    VALUE.store(value, Ordering::SeqCst);
+    let invalidator = INVALIDATOR.lock().unwrap().take().unwrap();
+    invalidator.invalidate_with_reason(ValueChanged(value));
+}
+
+async fn wait_for_sink_trace(tt: &TurboTasks<MemoryBackend>, cause: &str) -> InvalidationTrace {
+    tokio::time::timeout(Duration::from_secs(10), async {
+        loop {
+            let traces = tt.backend().invalidation_traces();
+            if let Some(trace) = traces
+                .into_iter()
+                .find(|trace| trace.tasks.len() == 3 && trace.cause_description() == cause)
+            {
+                return trace;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("sink should be invalidated through middle and source")
+}
+
+#[tokio::test]
+async fn traces_invalidation_back_to_reason() {
+    lazy_static::initialize(&REGISTER);
+    let tt = TurboTasks::new(MemoryBackend::default());
+    tt.backend().set_invalidation_tracing(true);
+    let root = tt.spawn_root_task(|| {
+        Box::pin(async {
+            sink().await?;
+            Ok(NothingVc::new().into())
+        })
+    });
+    tt.wait_task_completion(root, true).await.unwrap();
+    assert!(tt.backend().invalidation_traces().is_empty());
+
+    set_value(1);
+    let trace = wait_for_sink_trace(&tt, "value changed to 1").await;
+
+    let descriptions = trace
+        .tasks
+        .iter()
+        .map(|(_, description)| description.as_str())
+        .collect::<Vec<_>>();
+    assert!(descriptions[0].contains("sink"), "{descriptions:?}");
+    assert!(descriptions[1].contains("middle"), "{descriptions:?}");
+    assert!(descriptions[2].contains("source"), "{descriptions:?}");
+    assert!(matches!(trace.cause, Some(InvalidationCause::Reason(_))));
+
+    // A later invalidation keeps the earlier chain intact
+    tt.wait_task_completion(root, true).await.unwrap();
+    set_value(2);
+    let latest = wait_for_sink_trace(&tt, "value changed to 2").await;
+    let earlier = wait_for_sink_trace(&tt, "value changed to 1").await;
+    assert_eq!(latest.tasks, earlier.tasks);
+    assert_eq!(
+        tt.backend()
+            .invalidation_trace(latest.tasks[0].0)
+            .cause_description(),
+        "value changed to 2"
+    );
+
+    tt.backend().set_invalidation_tracing(false);
+    assert!(tt.backend().invalidation_traces().is_empty());
+}
@@ -0,0 +1,65 @@
+#![feature(min_specialization)]
+
+use anyhow::Result;
+use turbo_tasks::{primitives::U32Vc, CompletionVc, TurboTasks};
+use turbo_tasks_memory::MemoryBackend;
+use turbo_tasks_testing::register;
+
+register!();
+
+#[turbo_tasks::function]
+fn leaf(value: u32) -> U32Vc {
+    U32Vc::cell(value)
+}
+
+#[turbo_tasks::function]
+async fn profiled() -> Result<CompletionVc> {
+    // The second call is served from the task cache.
+    leaf(1).await?;
+    leaf(1).await?;
+    Ok(CompletionVc::new())
+}
+
+#[tokio::test]
+async fn exports_profile() {
+    lazy_static::initialize(&REGISTER);
+    let tt = TurboTasks::new(MemoryBackend::default());
+    tt.backend().set_profiling(true);
+    tt.run_once(async {
+        profiled().await?;
+        Ok(())
+    })
+    .await
+    .unwrap();
+    let profile = tt.backend().take_profile();
+    assert!(!profile.is_empty());
+
+    let mut chrome_trace = Vec::new();
+    profile.write_chrome_trace(&mut chrome_trace).unwrap();
+    let chrome_trace: serde_json::Value = serde_json::from_slice(&chrome_trace).unwrap();
+    let events = chrome_trace["traceEvents"].as_array().unwrap();
+    let has_event = |ph: &str, name: &str, function: &str| {
+        events.iter().any(|event| {
+            event["ph"] == ph
+                && event["name"].as_str().unwrap().contains(name)
+                && (function.is_empty()
+                    || event["args"]["function"]
+                        .as_str()
+                        .unwrap()
+                        .contains(function))
+        })
+    };
+    assert!(has_event("M", "thread_name", ""));
+    assert!(has_event("X", "profiled", ""));
+    assert!(has_event("X", "leaf", ""));
+    assert!(has_event("i", "cache miss", "leaf"));
+    assert!(has_event("i", "cache hit", "leaf"));
+
+    let mut pprof = Vec::new();
+    profile.write_pprof(&mut pprof).unwrap();
+    let contains = |needle: &[u8]| pprof.windows(needle.len()).any(|w| w == needle);
+    assert!(contains(b"cache_hits"));
+    assert!(contains(b"leaf"));
+
+    assert!(tt.backend().take_profile().is_empty());
+}
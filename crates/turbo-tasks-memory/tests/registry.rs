@@ -0,0 +1,34 @@
+#![feature(min_specialization)]
+
+use turbo_tasks::{registry, Typed, ValueTypeId};
+use turbo_tasks_hash::hash_xxh3_hash64;
+use turbo_tasks_testing::{register, run};
+
+register!();
+
+#[turbo_tasks::value]
+struct Registered(u32);
+
+#[tokio::test]
+async fn stable_ids() {
+    run! {
+        let id = Registered::get_value_type_id();
+        let global_name = registry::get_value_type_global_name(id);
+        assert_eq!(*id, hash_xxh3_hash64(global_name) as usize);
+        assert_eq!(registry::get_value_type_id_by_global_name(global_name), Some(id));
+
+        let serialized = serde_json::to_string(&id)?;
+        assert_eq!(serialized, format!("{global_name:?}"));
+        assert_eq!(serde_json::from_str::<ValueTypeId>(&serialized)?, id);
+    }
+}
+
+#[tokio::test]
+async fn unknown_ids() {
+    run! {
+        let err = serde_json::from_str::<ValueTypeId>(r#""removed_crate@Removed""#).unwrap_err();
+        assert!(err.to_string().starts_with(
+            "removed_crate@Removed is not a registered ValueTypeId, it was probably renamed or removed"
+        ));
+    }
+}
@@ -0,0 +1,773 @@
+//! A [PersistedGraph] that stores the task graph in [sled], an embedded
+//! key-value database, so that it survives restarts of the process.
+//!
+//! Tasks are identified by a persisted id, which is assigned to a task type the
+//! first time it's seen and doesn't depend on the [TaskId] the task has in
+//! memory. Task ids in task types, task data and cell contents are mapped to
+//! persisted ids when serializing and back when deserializing.
+//!
+//! The graph is used by the `--cache` flag of node-file-trace, which is
+//! enabled with its `persistent_cache` feature. The turbopack dev server
+//! doesn't persist its graph yet, as it depends on a `MemoryBackend` without a
+//! persisted graph, so it still recomputes everything on restart.
+
+use std::{borrow::Cow, cell::RefCell, collections::HashSet, path::Path};
+
+use anyhow::{Context, Result};
+use dashmap::DashMap;
+use parking_lot::Mutex;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sled::{Db, Tree};
+use turbo_tasks::{
+    backend::PersistentTaskType,
+    persisted_graph::{
+        ActivateResult, DeactivateResult, PersistResult, PersistTaskState, PersistedGraph,
+        PersistedGraphApi, ReadTaskState, TaskCell, TaskData,
+    },
+    with_task_id_mapping, FunctionId, IdMapping, RawVc, TaskId, TaskInput, TraitTypeId,
+};
+
+type PersistedId = u64;
+
+const EMPTY: &[u8] = &[];
+
+/// The maximum number of cache entries a lookup of a partial task type loads
+/// into memory. Task types with more entries are looked up one by one.
+const MAX_PARTIAL_LOOKUP_ENTRIES: usize = 100;
+
+/// The part of a [PersistentTaskType] before its arguments.
+#[derive(Serialize, Deserialize)]
+enum TaskTypeHeader {
+    Native(FunctionId),
+    ResolveNative(FunctionId),
+    ResolveTrait(TraitTypeId, Cow<'static, str>),
+}
+
+/// Serializes a task type as its header followed by each of its arguments, so
+/// that the key of a partial task type (see [PersistentTaskType::partial]) is
+/// a prefix of the keys of all task types starting with its arguments.
+fn encode_task_type(task_type: &PersistentTaskType) -> postcard::Result<Vec<u8>> {
+    let (header, inputs) = match task_type {
+        PersistentTaskType::Native(id, inputs) => (TaskTypeHeader::Native(*id), inputs),
+        PersistentTaskType::ResolveNative(id, inputs) => {
+            (TaskTypeHeader::ResolveNative(*id), inputs)
+        }
+        PersistentTaskType::ResolveTrait(id, name, inputs) => {
+            (TaskTypeHeader::ResolveTrait(*id, name.clone()), inputs)
+        }
+    };
+    let mut bytes = postcard::to_allocvec(&header)?;
+    for input in inputs {
+        bytes.extend(postcard::to_allocvec(input)?);
+    }
+    Ok(bytes)
+}
+
+fn decode_task_type(bytes: &[u8]) -> postcard::Result<PersistentTaskType> {
+    let (header, mut bytes) = postcard::take_from_bytes::<TaskTypeHeader>(bytes)?;
+    let mut inputs = Vec::new();
+    while !bytes.is_empty() {
+        let (input, rest) = postcard::take_from_bytes::<TaskInput>(bytes)?;
+        inputs.push(input);
+        bytes = rest;
+    }
+    Ok(match header {
+        TaskTypeHeader::Native(id) => PersistentTaskType::Native(id, inputs),
+        TaskTypeHeader::ResolveNative(id) => PersistentTaskType::ResolveNative(id, inputs),
+        TaskTypeHeader::ResolveTrait(id, name) => {
+            PersistentTaskType::ResolveTrait(id, name, inputs)
+        }
+    })
+}
+
+/// The data stored for a persisted task.
+#[derive(Serialize, Deserialize)]
+struct StoredTask {
+    /// The serialized [TaskData].
+    data: Vec<u8>,
+    /// The children of the task, to update their active parents without
+    /// deserializing the task data.
+    children: Vec<PersistedId>,
+    /// The serialized dependencies of the task, which are the prefixes of the
+    /// task's keys in the `dependents` tree.
+    dependencies: Vec<Vec<u8>>,
+}
+
+pub struct SledPersistedGraph {
+    db: Db,
+    /// persisted id -> serialized [PersistentTaskType]
+    types: Tree,
+    /// serialized [PersistentTaskType] -> persisted id
+    cache: Tree,
+    /// persisted id -> [StoredTask]
+    tasks: Tree,
+    /// persisted id -> (), for stored tasks that need to be recomputed
+    dirty: Tree,
+    /// serialized [RawVc] + persisted id -> (), for tasks that depend on a
+    /// task output or cell
+    dependents: Tree,
+    /// persisted id -> number of active parents in the persisted graph
+    active_parents: Tree,
+    /// persisted id -> session, for tasks kept active by the memory graph
+    externally_active: Tree,
+    /// persisted id -> (), for stored tasks that are active and counted as
+    /// active parent of their children
+    active: Tree,
+    /// persisted id -> (), for tasks that still need to be activated
+    pending_activate: Tree,
+    /// persisted id -> (), for tasks that still need to be deactivated
+    pending_deactivate: Tree,
+    /// A new id for every time the database is opened, used to find external
+    /// keep alives that were not renewed.
+    session: u64,
+    persisted_ids: DashMap<TaskId, PersistedId>,
+    task_ids: DashMap<PersistedId, TaskId>,
+    /// Updates to the activeness span multiple keys and trees, so they must not
+    /// run concurrently.
+    active_lock: Mutex<()>,
+}
+
+impl SledPersistedGraph {
+    pub fn new(path: impl AsRef<Path>) -> Result<Self> {
+        let db = sled::open(path)?;
+        Ok(Self {
+            types: db.open_tree("types")?,
+            cache: db.open_tree("cache")?,
+            tasks: db.open_tree("tasks")?,
+            dirty: db.open_tree("dirty")?,
+            dependents: db.open_tree("dependents")?,
+            active_parents: db.open_tree("active_parents")?,
+            externally_active: db.open_tree("externally_active")?,
+            active: db.open_tree("active")?,
+            pending_activate: db.open_tree("pending_activate")?,
+            pending_deactivate: db.open_tree("pending_deactivate")?,
+            session: db.generate_id()?,
+            db,
+            persisted_ids: DashMap::new(),
+            task_ids: DashMap::new(),
+            active_lock: Mutex::new(()),
+        })
+    }
+
+    /// Runs `func` with a task id mapping between memory and persisted ids.
+    ///
+    /// Mapping an unknown id requires (de)serializing a task type, which can't
+    /// be done while a mapping is in use. Unknown ids are collected instead and
+    /// `func` is retried after they have been mapped. Returns `None` when an
+    /// id can't be mapped, e.g. because it belongs to a transient task.
+    fn with_mapping<T>(
+        &self,
+        api: &dyn PersistedGraphApi,
+        allocate: bool,
+        func: impl Fn() -> T,
+    ) -> Result<Option<T>> {
+        loop {
+            let mapping = CollectingIdMapping {
+                graph: self,
+                unknown_tasks: Default::default(),
+                unknown_ids: Default::default(),
+            };
+            let result = with_task_id_mapping(&mapping, &func);
+            let unknown_tasks = mapping.unknown_tasks.into_inner();
+            let unknown_ids = mapping.unknown_ids.into_inner();
+            if unknown_tasks.is_empty() && unknown_ids.is_empty() {
+                return Ok(Some(result));
+            }
+            for task in unknown_tasks {
+                if self.persisted_id(task, api, allocate)?.is_none() {
+                    return Ok(None);
+                }
+            }
+            for id in unknown_ids {
+                if self.task_id(id, api)?.is_none() {
+                    return Ok(None);
+                }
+            }
+        }
+    }
+
+    /// Serializes a value with task ids mapped to persisted ids. Returns `None`
+    /// when the value isn't serializable.
+    fn serialize<T: Serialize>(
+        &self,
+        api: &dyn PersistedGraphApi,
+        allocate: bool,
+        value: &T,
+    ) -> Result<Option<Vec<u8>>> {
+        Ok(self
+            .with_mapping(api, allocate, || postcard::to_allocvec(value))?
+            .and_then(|result| result.ok()))
+    }
+
+    /// Deserializes a value with persisted ids mapped to task ids. Returns
+    /// `None` when the value can't be deserialized anymore, e.g. because the
+    /// code has changed since it was stored.
+    fn deserialize<T: DeserializeOwned>(
+        &self,
+        api: &dyn PersistedGraphApi,
+        bytes: &[u8],
+    ) -> Result<Option<T>> {
+        Ok(self
+            .with_mapping(api, false, || postcard::from_bytes(bytes))?
+            .and_then(|result| result.ok()))
+    }
+
+    /// Serializes a task type with task ids mapped to persisted ids, see
+    /// [encode_task_type].
+    fn serialize_task_type(
+        &self,
+        api: &dyn PersistedGraphApi,
+        allocate: bool,
+        task_type: &PersistentTaskType,
+    ) -> Result<Option<Vec<u8>>> {
+        Ok(self
+            .with_mapping(api, allocate, || encode_task_type(task_type))?
+            .and_then(|result| result.ok()))
+    }
+
+    /// Returns the persisted id of a task. When `allocate` is set, a new id is
+    /// assigned to task types that haven't been seen before.
+    fn persisted_id(
+        &self,
+        task: TaskId,
+        api: &dyn PersistedGraphApi,
+        allocate: bool,
+    ) -> Result<Option<PersistedId>> {
+        if let Some(id) = self.persisted_ids.get(&task) {
+            return Ok(Some(*id));
+        }
+        let Some(task_type) = api.lookup_task_type(task) else {
+            return Ok(None);
+        };
+        let Some(key) = self.serialize_task_type(api, allocate, task_type)? else {
+            return Ok(None);
+        };
+        let id = if let Some(id) = self.cache.get(&key)? {
+            decode_u64(&id)?
+        } else if allocate {
+            let id = self.db.generate_id()?;
+            self.types.insert(id.to_be_bytes(), key.as_slice())?;
+            match self.cache.compare_and_swap(
+                &key,
+                None as Option<&[u8]>,
+                Some(&id.to_be_bytes()),
+            )? {
+                Ok(()) => id,
+                Err(err) => {
+                    // Another thread assigned an id to the task type concurrently
+                    self.types.remove(id.to_be_bytes())?;
+                    decode_u64(&err.current.unwrap())?
+                }
+            }
+        } else {
+            return Ok(None);
+        };
+        self.persisted_ids.insert(task, id);
+        self.task_ids.insert(id, task);
+        Ok(Some(id))
+    }
+
+    /// Returns the task id of a persisted id, creating the task in memory if
+    /// needed.
+    fn task_id(&self, id: PersistedId, api: &dyn PersistedGraphApi) -> Result<Option<TaskId>> {
+        if let Some(task) = self.task_ids.get(&id) {
+            return Ok(Some(*task));
+        }
+        let Some(key) = self.types.get(id.to_be_bytes())? else {
+            return Ok(None);
+        };
+        let Some(task_type) = self
+            .with_mapping(api, false, || decode_task_type(&key))?
+            .and_then(|result| result.ok())
+        else {
+            return Ok(None);
+        };
+        let task = api.get_or_create_task_type(task_type);
+        self.persisted_ids.insert(task, id);
+        self.task_ids.insert(id, task);
+        Ok(Some(task))
+    }
+
+    fn task_ids(
+        &self,
+        ids: impl IntoIterator<Item = PersistedId>,
+        api: &dyn PersistedGraphApi,
+    ) -> Result<Vec<TaskId>> {
+        let mut tasks = Vec::new();
+        for id in ids {
+            tasks.extend(self.task_id(id, api)?);
+        }
+        Ok(tasks)
+    }
+
+    fn stored_task(&self, id: PersistedId) -> Result<Option<StoredTask>> {
+        Ok(match self.tasks.get(id.to_be_bytes())? {
+            Some(bytes) => Some(postcard::from_bytes(&bytes)?),
+            None => None,
+        })
+    }
+
+    fn active_parents(&self, id: PersistedId) -> Result<u32> {
+        self.active_parents
+            .get(id.to_be_bytes())?
+            .map_or(Ok(0), |count| decode_u32(&count))
+    }
+
+    fn update_active_parents(&self, id: PersistedId, update: impl Fn(u32) -> u32) -> Result<u32> {
+        let count = self
+            .active_parents
+            .update_and_fetch(id.to_be_bytes(), |count| {
+                let Ok(decoded) = count.map_or(Ok(0), decode_u32) else {
+                    // Keep the corrupt count, so that it's reported below
+                    return count.map(<[u8]>::to_vec);
+                };
+                let count = update(decoded);
+                (count > 0).then(|| count.to_be_bytes().to_vec())
+            })?;
+        count.map_or(Ok(0), |count| decode_u32(&count))
+    }
+
+    /// Counts an active parent for each of `children`. Returns the children
+    /// that became active.
+    fn increment_active_parents(
+        &self,
+        children: impl IntoIterator<Item = PersistedId>,
+    ) -> Result<Vec<PersistedId>> {
+        let mut activated = Vec::new();
+        for child in children {
+            if self.update_active_parents(child, |count| count + 1)? == 1 {
+                activated.push(child);
+            }
+        }
+        Ok(activated)
+    }
+
+    /// Removes an active parent from each of `children`. Returns the children
+    /// that have no active parents anymore.
+    fn decrement_active_parents(
+        &self,
+        children: impl IntoIterator<Item = PersistedId>,
+    ) -> Result<Vec<PersistedId>> {
+        let mut deactivated = Vec::new();
+        for child in children {
+            if self.update_active_parents(child, |count| count.saturating_sub(1))? == 0 {
+                deactivated.push(child);
+            }
+        }
+        Ok(deactivated)
+    }
+
+    /// Records tasks as pending in `tree`, so the update can be continued after
+    /// a restart.
+    fn add_pending(
+        &self,
+        tree: &Tree,
+        ids: Vec<PersistedId>,
+        api: &dyn PersistedGraphApi,
+    ) -> Result<Vec<TaskId>> {
+        for id in ids.iter() {
+            tree.insert(id.to_be_bytes(), EMPTY)?;
+        }
+        self.task_ids(ids, api)
+    }
+
+    fn keys(tree: &Tree) -> impl Iterator<Item = Result<PersistedId>> {
+        tree.iter().keys().map(|key| decode_u64(&key?))
+    }
+}
+
+impl PersistedGraph for SledPersistedGraph {
+    fn read(
+        &self,
+        task: TaskId,
+        api: &dyn PersistedGraphApi,
+    ) -> Result<Option<(TaskData, ReadTaskState)>> {
+        let Some(id) = self.persisted_id(task, api, false)? else {
+            return Ok(None);
+        };
+        let Some(stored) = self.stored_task(id)? else {
+            return Ok(None);
+        };
+        let Some(data) = self.deserialize::<TaskData>(api, &stored.data)? else {
+            return Ok(None);
+        };
+        Ok(Some((
+            data,
+            ReadTaskState {
+                clean: !self.dirty.contains_key(id.to_be_bytes())?,
+                keeps_external_active: self.active_parents(id)? > 0,
+            },
+        )))
+    }
+
+    fn lookup(
+        &self,
+        partial_task_type: &PersistentTaskType,
+        api: &dyn PersistedGraphApi,
+    ) -> Result<bool> {
+        let Some(prefix) = self.serialize_task_type(api, false, partial_task_type)? else {
+            return Ok(false);
+        };
+        let ids = self
+            .cache
+            .scan_prefix(prefix)
+            .values()
+            .take(MAX_PARTIAL_LOOKUP_ENTRIES + 1)
+            .collect::<Result<Vec<_>, _>>()?;
+        if ids.len() > MAX_PARTIAL_LOOKUP_ENTRIES {
+            return Ok(false);
+        }
+        for id in ids {
+            let id = decode_u64(&id)?;
+            if self.tasks.contains_key(id.to_be_bytes())? {
+                self.task_id(id, api)?;
+            }
+        }
+        Ok(true)
+    }
+
+    fn lookup_one(
+        &self,
+        task_type: &PersistentTaskType,
+        api: &dyn PersistedGraphApi,
+    ) -> Result<Option<TaskId>> {
+        let Some(key) = self.serialize_task_type(api, false, task_type)? else {
+            return Ok(None);
+        };
+        let Some(id) = self.cache.get(key)? else {
+            return Ok(None);
+        };
+        let id = decode_u64(&id)?;
+        if !self.tasks.contains_key(id.to_be_bytes())? {
+            return Ok(None);
+        }
+        if let Some(task) = self.task_ids.get(&id) {
+            return Ok(Some(*task));
+        }
+        let task = api.get_or_create_task_type(task_type.clone());
+        self.persisted_ids.insert(task, id);
+        self.task_ids.insert(id, task);
+        Ok(Some(task))
+    }
+
+    fn is_persisted(&self, task: TaskId, api: &dyn PersistedGraphApi) -> Result<bool> {
+        Ok(match self.persisted_id(task, api, false)? {
+            Some(id) => self.tasks.contains_key(id.to_be_bytes())?,
+            None => false,
+        })
+    }
+
+    fn persist(
+        &self,
+        task: TaskId,
+        data: TaskData,
+        state: PersistTaskState,
+        api: &dyn PersistedGraphApi,
+    ) -> Result<Option<PersistResult>> {
+        let Some(id) = self.persisted_id(task, api, true)? else {
+            return Ok(None);
+        };
+        let mut children = Vec::new();
+        for &child in data.children.iter() {
+            let Some(child) = self.persisted_id(child, api, true)? else {
+                return Ok(None);
+            };
+            children.push(child);
+        }
+        let mut dependencies = Vec::new();
+        for dependency in data.dependencies.iter() {
+            let Some(dependency) = self.serialize(api, true, dependency)? else {
+                return Ok(None);
+            };
+            dependencies.push(dependency);
+        }
+        let mut cells = Vec::new();
+        for (index, cell) in data.cells {
+            // Cells that can't be serialized are recomputed when they are read
+            if self.serialize(api, true, &cell)?.is_some() {
+                cells.push((index, cell));
+            } else {
+                cells.push((index, TaskCell::NeedComputation));
+            }
+        }
+        let data = TaskData { cells, ..data };
+        let Some(data) = self.serialize(api, true, &data)? else {
+            return Ok(None);
+        };
+        let stored = StoredTask {
+            data,
+            children,
+            dependencies,
+        };
+
+        let _lock = self.active_lock.lock();
+        let key = id.to_be_bytes();
+        let old = self.stored_task(id)?;
+        if let Some(old) = &old {
+            for dependency in old.dependencies.iter() {
+                self.dependents.remove(dependent_key(dependency, id))?;
+            }
+        }
+        for dependency in stored.dependencies.iter() {
+            self.dependents
+                .insert(dependent_key(dependency, id), EMPTY)?;
+        }
+        self.tasks.insert(key, postcard::to_allocvec(&stored)?)?;
+        self.dirty.remove(key)?;
+        if state.externally_active {
+            self.externally_active
+                .insert(key, &self.session.to_be_bytes())?;
+        } else {
+            self.externally_active.remove(key)?;
+        }
+
+        let was_active = self.active.contains_key(key)?;
+        let is_active = state.externally_active || self.active_parents(id)? > 0;
+        let old_children: HashSet<_> = match old {
+            Some(old) if was_active => old.children.into_iter().collect(),
+            _ => HashSet::new(),
+        };
+        let new_children: HashSet<_> = if is_active {
+            stored.children.into_iter().collect()
+        } else {
+            HashSet::new()
+        };
+        let activated =
+            self.increment_active_parents(new_children.difference(&old_children).copied())?;
+        let deactivated =
+            self.decrement_active_parents(old_children.difference(&new_children).copied())?;
+        if is_active {
+            self.active.insert(key, EMPTY)?;
+        } else {
+            self.active.remove(key)?;
+        }
+
+        Ok(Some(PersistResult {
+            tasks_to_activate: self.add_pending(&self.pending_activate, activated, api)?,
+            tasks_to_deactivate: self.add_pending(&self.pending_deactivate, deactivated, api)?,
+        }))
+    }
+
+    fn activate_when_needed(
+        &self,
+        task: TaskId,
+        api: &dyn PersistedGraphApi,
+    ) -> Result<Option<ActivateResult>> {
+        let Some(id) = self.persisted_id(task, api, false)? else {
+            return Ok(None);
+        };
+        let _lock = self.active_lock.lock();
+        let key = id.to_be_bytes();
+        self.pending_activate.remove(key)?;
+        let keeps_external_active = self.active_parents(id)? > 0;
+        let Some(stored) = self.stored_task(id)? else {
+            // The task only exists in memory, which needs to keep it active for
+            // its active parents in the persisted graph
+            return Ok(Some(ActivateResult {
+                keeps_external_active,
+                external: true,
+                dirty: false,
+                more_tasks_to_activate: Vec::new(),
+            }));
+        };
+        let mut activated = Vec::new();
+        let needed = keeps_external_active || self.externally_active.contains_key(key)?;
+        if needed && self.active.insert(key, EMPTY)?.is_none() {
+            activated = self.increment_active_parents(stored.children)?;
+        }
+        Ok(Some(ActivateResult {
+            keeps_external_active,
+            external: false,
+            dirty: self.dirty.contains_key(key)?,
+            more_tasks_to_activate: self.add_pending(&self.pending_activate, activated, api)?,
+        }))
+    }
+
+    fn deactivate_when_needed(
+        &self,
+        task: TaskId,
+        api: &dyn PersistedGraphApi,
+    ) -> Result<Option<DeactivateResult>> {
+        let Some(id) = self.persisted_id(task, api, false)? else {
+            return Ok(None);
+        };
+        let _lock = self.active_lock.lock();
+        let key = id.to_be_bytes();
+        self.pending_deactivate.remove(key)?;
+        if self.active_parents(id)? > 0 {
+            return Ok(None);
+        }
+        let mut deactivated = Vec::new();
+        if !self.externally_active.contains_key(key)? && self.active.remove(key)?.is_some() {
+            if let Some(stored) = self.stored_task(id)? {
+                deactivated = self.decrement_active_parents(stored.children)?;
+            }
+        }
+        Ok(Some(DeactivateResult {
+            more_tasks_to_deactivate: self.add_pending(
+                &self.pending_deactivate,
+                deactivated,
+                api,
+            )?,
+        }))
+    }
+
+    fn set_externally_active(&self, task: TaskId, api: &dyn PersistedGraphApi) -> Result<bool> {
+        let Some(id) = self.persisted_id(task, api, false)? else {
+            return Ok(false);
+        };
+        let _lock = self.active_lock.lock();
+        let key = id.to_be_bytes();
+        self.externally_active
+            .insert(key, &self.session.to_be_bytes())?;
+        Ok(!self.active.contains_key(key)?)
+    }
+
+    fn unset_externally_active(&self, task: TaskId, api: &dyn PersistedGraphApi) -> Result<bool> {
+        let Some(id) = self.persisted_id(task, api, false)? else {
+            return Ok(false);
+        };
+        let _lock = self.active_lock.lock();
+        let key = id.to_be_bytes();
+        self.externally_active.remove(key)?;
+        Ok(self.active.contains_key(key)? && self.active_parents(id)? == 0)
+    }
+
+    fn remove_outdated_externally_active(
+        &self,
+        api: &dyn PersistedGraphApi,
+    ) -> Result<Vec<TaskId>> {
+        let _lock = self.active_lock.lock();
+        let mut deactivated = Vec::new();
+        for entry in self.externally_active.iter() {
+            let (key, session) = entry?;
+            if decode_u64(&session)? >= self.session {
+                continue;
+            }
+            self.externally_active.remove(&key)?;
+            let id = decode_u64(&key)?;
+            if self.active.contains_key(&key)? && self.active_parents(id)? == 0 {
+                deactivated.push(id);
+            }
+        }
+        self.add_pending(&self.pending_deactivate, deactivated, api)
+    }
+
+    fn make_dirty(&self, task: TaskId, api: &dyn PersistedGraphApi) -> Result<bool> {
+        let Some(id) = self.persisted_id(task, api, false)? else {
+            return Ok(false);
+        };
+        let key = id.to_be_bytes();
+        if !self.tasks.contains_key(key)? {
+            return Ok(false);
+        }
+        self.dirty.insert(key, EMPTY)?;
+        Ok(self.active.contains_key(key)?)
+    }
+
+    fn make_clean(&self, task: TaskId, api: &dyn PersistedGraphApi) -> Result<()> {
+        if let Some(id) = self.persisted_id(task, api, false)? {
+            self.dirty.remove(id.to_be_bytes())?;
+        }
+        Ok(())
+    }
+
+    fn make_dependent_dirty(&self, vc: RawVc, api: &dyn PersistedGraphApi) -> Result<Vec<TaskId>> {
+        let Some(prefix) = self.serialize(api, false, &vc)? else {
+            return Ok(Vec::new());
+        };
+        let mut active = Vec::new();
+        for key in self.dependents.scan_prefix(&prefix).keys() {
+            let key = key?;
+            let id = decode_u64(&key[prefix.len()..])?;
+            self.dirty.insert(id.to_be_bytes(), EMPTY)?;
+            if self.active.contains_key(id.to_be_bytes())? {
+                active.push(id);
+            }
+        }
+        self.task_ids(active, api)
+    }
+
+    fn get_active_external_tasks(&self, api: &dyn PersistedGraphApi) -> Result<Vec<TaskId>> {
+        let mut external = Vec::new();
+        for id in Self::keys(&self.active_parents) {
+            let id = id?;
+            if !self.tasks.contains_key(id.to_be_bytes())? {
+                external.push(id);
+            }
+        }
+        self.task_ids(external, api)
+    }
+
+    fn get_dirty_active_tasks(&self, api: &dyn PersistedGraphApi) -> Result<Vec<TaskId>> {
+        let mut dirty = Vec::new();
+        for id in Self::keys(&self.dirty) {
+            let id = id?;
+            if self.active.contains_key(id.to_be_bytes())? {
+                dirty.push(id);
+            }
+        }
+        self.task_ids(dirty, api)
+    }
+
+    fn get_pending_active_update(
+        &self,
+        api: &dyn PersistedGraphApi,
+    ) -> Result<(Vec<TaskId>, Vec<TaskId>)> {
+        let activate = Self::keys(&self.pending_activate).collect::<Result<Vec<_>>>()?;
+        let deactivate = Self::keys(&self.pending_deactivate).collect::<Result<Vec<_>>>()?;
+        Ok((
+            self.task_ids(activate, api)?,
+            self.task_ids(deactivate, api)?,
+        ))
+    }
+
+    fn stop(&self, _api: &dyn PersistedGraphApi) -> Result<()> {
+        self.db.flush()?;
+        Ok(())
+    }
+}
+
+/// Maps ids using the ids that are already known. Unknown ids are collected and
+/// replaced by placeholders.
+struct CollectingIdMapping<'a> {
+    graph: &'a SledPersistedGraph,
+    unknown_tasks: RefCell<Vec<TaskId>>,
+    unknown_ids: RefCell<Vec<PersistedId>>,
+}
+
+impl IdMapping<TaskId> for CollectingIdMapping<'_> {
+    fn forward(&self, task: TaskId) -> usize {
+        if let Some(id) = self.graph.persisted_ids.get(&task) {
+            return *id as usize;
+        }
+        self.unknown_tasks.borrow_mut().push(task);
+        0
+    }
+
+    fn backward(&self, id: usize) -> TaskId {
+        if let Some(task) = self.graph.task_ids.get(&(id as PersistedId)) {
+            return *task;
+        }
+        self.unknown_ids.borrow_mut().push(id as PersistedId);
+        TaskId::from(0)
+    }
+}
+
+fn dependent_key(dependency: &[u8], id: PersistedId) -> Vec<u8> {
+    let mut key = dependency.to_vec();
+    key.extend_from_slice(&id.to_be_bytes());
+    key
+}
+
+fn decode_u64(bytes: &[u8]) -> Result<u64> {
+    Ok(u64::from_be_bytes(
+        bytes.try_into().context("invalid u64 in database")?,
+    ))
+}
+
+fn decode_u32(bytes: &[u8]) -> Result<u32> {
+    Ok(u32::from_be_bytes(
+        bytes.try_into().context("invalid u32 in database")?,
+    ))
+}
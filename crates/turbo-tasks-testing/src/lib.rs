@@ -20,7 +20,8 @@ use turbo_tasks::{
     registry,
     test_helpers::with_turbo_tasks_for_testing,
     util::StaticOrArc,
-    CellId, InvalidationReason, RawVc, TaskId, TraitTypeId, TurboTasksApi, TurboTasksCallApi,
+    CellId, InvalidationReason, OnceTaskHandle, RawVc, TaskId, TraitTypeId, TurboTasksApi,
+    TurboTasksCallApi,
 };
 
 enum Task {
@@ -87,7 +88,7 @@ impl TurboTasksCallApi for VcStorage {
     fn run_once(
         &self,
         _future: std::pin::Pin<Box<dyn Future<Output = Result<()>> + Send + 'static>>,
-    ) -> TaskId {
+    ) -> OnceTaskHandle {
         unreachable!()
     }
 
@@ -95,7 +96,7 @@ impl TurboTasksCallApi for VcStorage {
         &self,
         _reason: StaticOrArc<dyn InvalidationReason>,
         _future: std::pin::Pin<Box<dyn Future<Output = Result<()>> + Send + 'static>>,
-    ) -> TaskId {
+    ) -> OnceTaskHandle {
         unreachable!()
     }
 
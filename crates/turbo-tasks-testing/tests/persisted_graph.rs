@@ -0,0 +1,244 @@
+#![feature(min_specialization)]
+
+use std::{
+    future::Future,
+    path::Path,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use anyhow::Result;
+use turbo_tasks::{
+    backend::PersistentTaskType,
+    persisted_graph::{PersistTaskState, PersistedGraph, PersistedGraphApi, TaskData},
+    primitives::U32Vc,
+    trace::TraceRawVcs,
+    RawVc, TaskId, TaskInput, TurboTasks,
+};
+use turbo_tasks_memory::MemoryBackendWithPersistedGraph;
+use turbo_tasks_sled::SledPersistedGraph;
+use turbo_tasks_testing::{register, retry::retry};
+
+register!();
+
+/// Opens the graph at `path`. The database of a previous session can be locked
+/// for a moment after it has been dropped, until sled's background threads
+/// have finished.
+fn open(path: &Path) -> Result<SledPersistedGraph> {
+    retry(
+        path,
+        |path| SledPersistedGraph::new(path),
+        5,
+        Duration::from_millis(10),
+    )
+}
+
+/// Runs `future` in a new turbo-tasks instance that persists to `path`, and
+/// waits until everything is persisted.
+async fn session<T: TraceRawVcs + Send + 'static>(
+    path: &Path,
+    future: impl Future<Output = Result<T>> + Send + 'static,
+) -> Result<T> {
+    lazy_static::initialize(&REGISTER);
+    let tt = TurboTasks::new(MemoryBackendWithPersistedGraph::new(open(path)?));
+    let result = tt.run_once(future).await?;
+    tt.wait_background_done().await;
+    tt.stop_and_wait().await;
+    Ok(result)
+}
+
+static SUM_COMPUTATIONS: AtomicUsize = AtomicUsize::new(0);
+static DOUBLE_COMPUTATIONS: AtomicUsize = AtomicUsize::new(0);
+
+#[turbo_tasks::function]
+async fn sum_of_doubles(a: u32, b: u32) -> Result<U32Vc> {
+    SUM_COMPUTATIONS.fetch_add(1, Ordering::SeqCst);
+    Ok(U32Vc::cell(*double(a).await? + *double(b).await?))
+}
+
+#[turbo_tasks::function]
+fn double(value: u32) -> U32Vc {
+    DOUBLE_COMPUTATIONS.fetch_add(1, Ordering::SeqCst);
+    U32Vc::cell(value * 2)
+}
+
+#[tokio::test]
+async fn restores_results_after_restart() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+
+    let result = session(dir.path(), async { Ok(*sum_of_doubles(1, 2).await?) }).await?;
+    assert_eq!(result, 6);
+    assert_eq!(SUM_COMPUTATIONS.load(Ordering::SeqCst), 1);
+    assert_eq!(DOUBLE_COMPUTATIONS.load(Ordering::SeqCst), 2);
+
+    let result = session(dir.path(), async { Ok(*sum_of_doubles(1, 2).await?) }).await?;
+    assert_eq!(result, 6);
+    assert_eq!(SUM_COMPUTATIONS.load(Ordering::SeqCst), 1);
+    assert_eq!(DOUBLE_COMPUTATIONS.load(Ordering::SeqCst), 2);
+
+    // Only the new task is computed, its children are restored
+    let result = session(dir.path(), async { Ok(*sum_of_doubles(2, 1).await?) }).await?;
+    assert_eq!(result, 6);
+    assert_eq!(SUM_COMPUTATIONS.load(Ordering::SeqCst), 2);
+    assert_eq!(DOUBLE_COMPUTATIONS.load(Ordering::SeqCst), 2);
+
+    Ok(())
+}
+
+#[turbo_tasks::value(serialization = "none")]
+struct Unserializable(u32);
+
+static UNSERIALIZABLE_COMPUTATIONS: AtomicUsize = AtomicUsize::new(0);
+
+#[turbo_tasks::function]
+fn unserializable(value: u32) -> UnserializableVc {
+    UNSERIALIZABLE_COMPUTATIONS.fetch_add(1, Ordering::SeqCst);
+    Unserializable(value).cell()
+}
+
+#[tokio::test]
+async fn recomputes_unserializable_cells_after_restart() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+
+    let result = session(dir.path(), async { Ok(unserializable(42).await?.0) }).await?;
+    assert_eq!(result, 42);
+    assert_eq!(UNSERIALIZABLE_COMPUTATIONS.load(Ordering::SeqCst), 1);
+
+    let result = session(dir.path(), async { Ok(unserializable(42).await?.0) }).await?;
+    assert_eq!(result, 42);
+    assert_eq!(UNSERIALIZABLE_COMPUTATIONS.load(Ordering::SeqCst), 2);
+
+    Ok(())
+}
+
+/// A [PersistedGraphApi] for a fixed set of task types, which are assigned the
+/// task ids 1, 2, 3, ... in order.
+struct TestApi {
+    task_types: Vec<PersistentTaskType>,
+}
+
+impl TestApi {
+    fn new(values: &[u32]) -> Self {
+        lazy_static::initialize(&REGISTER);
+        Self {
+            task_types: values
+                .iter()
+                .map(|&value| {
+                    PersistentTaskType::Native(*DOUBLE_FUNCTION_ID, vec![TaskInput::U32(value)])
+                })
+                .collect(),
+        }
+    }
+
+    fn task(&self, value: u32) -> TaskId {
+        self.get_or_create_task_type(PersistentTaskType::Native(
+            *DOUBLE_FUNCTION_ID,
+            vec![TaskInput::U32(value)],
+        ))
+    }
+}
+
+impl PersistedGraphApi for TestApi {
+    fn get_or_create_task_type(&self, ty: PersistentTaskType) -> TaskId {
+        let index = self
+            .task_types
+            .iter()
+            .position(|task_type| *task_type == ty)
+            .expect("unknown task type");
+        TaskId::from(index + 1)
+    }
+
+    fn lookup_task_type(&self, id: TaskId) -> Option<&PersistentTaskType> {
+        self.task_types.get(*id - 1)
+    }
+}
+
+fn persist(graph: &SledPersistedGraph, api: &TestApi, task: TaskId, dependencies: Vec<RawVc>) {
+    let data = TaskData {
+        children: Vec::new(),
+        dependencies,
+        cells: Vec::new(),
+        output: RawVc::TaskOutput(task),
+    };
+    let state = PersistTaskState {
+        externally_active: true,
+    };
+    assert!(graph.persist(task, data, state, api).unwrap().is_some());
+}
+
+fn is_clean(graph: &SledPersistedGraph, api: &TestApi, task: TaskId) -> bool {
+    graph.read(task, api).unwrap().unwrap().1.clean
+}
+
+#[test]
+fn keeps_dirty_flags_after_restart() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+
+    let api = TestApi::new(&[1, 2, 3]);
+    let graph = open(dir.path())?;
+    persist(&graph, &api, api.task(1), Vec::new());
+    persist(&graph, &api, api.task(2), Vec::new());
+    persist(
+        &graph,
+        &api,
+        api.task(3),
+        vec![RawVc::TaskOutput(api.task(2))],
+    );
+    assert!(graph.make_dirty(api.task(1), &api)?);
+    assert!(graph
+        .make_dependent_dirty(RawVc::TaskOutput(api.task(2)), &api)?
+        .contains(&api.task(3)));
+    graph.stop(&api)?;
+    drop(graph);
+
+    // Task ids are assigned differently in the new session
+    let api = TestApi::new(&[3, 2, 1]);
+    let graph = open(dir.path())?;
+    assert!(!is_clean(&graph, &api, api.task(1)));
+    assert!(is_clean(&graph, &api, api.task(2)));
+    assert!(!is_clean(&graph, &api, api.task(3)));
+    let mut dirty = graph.get_dirty_active_tasks(&api)?;
+    dirty.sort();
+    let mut expected = vec![api.task(1), api.task(3)];
+    expected.sort();
+    assert_eq!(dirty, expected);
+
+    Ok(())
+}
+
+#[test]
+fn looks_up_partial_task_types() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+
+    let api = TestApi::new(&[1, 2]);
+    let graph = open(dir.path())?;
+    persist(&graph, &api, api.task(1), Vec::new());
+    persist(&graph, &api, api.task(2), Vec::new());
+    graph.stop(&api)?;
+    drop(graph);
+
+    let api = TestApi::new(&[2, 1]);
+    let graph = open(dir.path())?;
+    assert!(graph.lookup(
+        &PersistentTaskType::Native(*DOUBLE_FUNCTION_ID, Vec::new()),
+        &api
+    )?);
+    assert!(graph.is_persisted(api.task(1), &api)?);
+    assert!(graph.is_persisted(api.task(2), &api)?);
+    assert_eq!(
+        graph.lookup_one(
+            &PersistentTaskType::Native(*DOUBLE_FUNCTION_ID, vec![TaskInput::U32(2)]),
+            &api
+        )?,
+        Some(api.task(2))
+    );
+    assert_eq!(
+        graph.lookup_one(
+            &PersistentTaskType::Native(*DOUBLE_FUNCTION_ID, vec![TaskInput::U32(3)]),
+            &api
+        )?,
+        None
+    );
+
+    Ok(())
+}
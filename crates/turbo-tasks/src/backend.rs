@@ -14,9 +14,9 @@ use serde::{Deserialize, Serialize};
 
 pub use crate::id::BackendJobId;
 use crate::{
-    event::EventListener, manager::TurboTasksBackendApi, primitives::RawVcSetVc, raw_vc::CellId,
-    registry, task_input::SharedReference, FunctionId, RawVc, ReadRef, TaskId, TaskIdProvider,
-    TaskInput, TraitRef, TraitTypeId, ValueTraitVc,
+    event::EventListener, invalidation::InvalidationCause, manager::TurboTasksBackendApi,
+    primitives::RawVcSetVc, raw_vc::CellId, registry, task_input::SharedReference, FunctionId,
+    RawVc, ReadRef, TaskId, TaskIdProvider, TaskInput, TraitRef, TraitTypeId, ValueTraitVc,
 };
 
 pub enum TaskType {
@@ -204,6 +204,10 @@ pub trait Backend: Sync + Send {
 
     fn invalidate_tasks(&self, tasks: Vec<TaskId>, turbo_tasks: &dyn TurboTasksBackendApi<Self>);
 
+    /// Called before `tasks` are invalidated with what caused the
+    /// invalidation, so the backend can explain why a task was recomputed.
+    fn record_invalidation_cause(&self, _tasks: &[TaskId], _cause: &InvalidationCause) {}
+
     fn get_task_description(&self, task: TaskId) -> String;
 
     type ExecutionScopeFuture<T: Future<Output = Result<()>> + Send + 'static>: Future<Output = Result<()>>
@@ -222,6 +226,18 @@ pub trait Backend: Sync + Send {
         turbo_tasks: &dyn TurboTasksBackendApi<Self>,
     ) -> Option<TaskExecutionSpec>;
 
+    /// Called instead of starting a scheduled task when the work that
+    /// scheduled it has been cancelled. Returns true when the task isn't needed
+    /// anymore and has been unscheduled, so it's only executed again on
+    /// demand. Returns false when the task should be executed anyway.
+    fn try_unschedule_task(
+        &self,
+        _task: TaskId,
+        _turbo_tasks: &dyn TurboTasksBackendApi<Self>,
+    ) -> bool {
+        false
+    }
+
     fn task_execution_result(
         &self,
         task: TaskId,
@@ -0,0 +1,186 @@
+use std::{
+    cell::RefCell,
+    collections::HashSet,
+    future::{poll_fn, Future},
+    pin::pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    task::Poll,
+};
+
+use anyhow::{anyhow, Result};
+
+use crate::{event::Event, TaskId};
+
+/// A handle to a task spawned by [TurboTasks::spawn_once_task], which can be
+/// used to cancel its work, e.g. when the result isn't needed anymore.
+///
+/// [TurboTasks::spawn_once_task]: crate::TurboTasks::spawn_once_task
+#[derive(Clone)]
+pub struct OnceTaskHandle {
+    id: TaskId,
+    cancellation: CancellationHandle,
+}
+
+impl OnceTaskHandle {
+    pub(crate) fn new(id: TaskId, cancellation: CancellationHandle) -> Self {
+        Self { id, cancellation }
+    }
+
+    pub fn id(&self) -> TaskId {
+        self.id
+    }
+
+    /// Cancels the work of the task. The task fails and tasks that have been
+    /// scheduled by it are not started, unless they are still needed by other
+    /// tasks. Blocking work can observe the cancellation via
+    /// [is_cancelled](crate::is_cancelled).
+    pub fn cancel(&self) {
+        self.cancellation.cancel();
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation.is_cancelled()
+    }
+
+    /// Returns a guard that cancels the task when it's dropped before being
+    /// disarmed.
+    pub(crate) fn cancel_on_drop(&self) -> CancelOnDrop {
+        CancelOnDrop(Some(self.cancellation.clone()))
+    }
+}
+
+/// Cancels a task when it's dropped before being disarmed, e.g. because the
+/// future waiting for the task has been dropped.
+pub(crate) struct CancelOnDrop(Option<CancellationHandle>);
+
+impl CancelOnDrop {
+    pub fn disarm(mut self) {
+        self.0 = None;
+    }
+}
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        if let Some(cancellation) = self.0.take() {
+            cancellation.cancel();
+        }
+    }
+}
+
+/// The cancellation of a once task.
+///
+/// The handle is inherited by all tasks that are scheduled while executing the
+/// once task. After cancellation, these tasks are not started when nothing else
+/// needs them anymore, and blocking work can observe the cancellation via
+/// [is_cancelled](crate::is_cancelled).
+#[derive(Clone)]
+pub(crate) struct CancellationHandle {
+    inner: Arc<CancellationHandleInner>,
+}
+
+struct CancellationHandleInner {
+    cancelled: AtomicBool,
+    event: Event,
+    /// Tasks that were not started because of the cancellation. Each task is
+    /// only skipped once, as it's needed when it's scheduled again.
+    skipped_tasks: Mutex<HashSet<TaskId>>,
+}
+
+impl Default for CancellationHandle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CancellationHandle {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(CancellationHandleInner {
+                cancelled: AtomicBool::new(false),
+                event: Event::new(|| "CancellationHandle::event".to_string()),
+                skipped_tasks: Mutex::new(HashSet::new()),
+            }),
+        }
+    }
+
+    pub fn cancel(&self) {
+        if !self.inner.cancelled.swap(true, Ordering::AcqRel) {
+            self.inner.event.notify(usize::MAX);
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Waits until the handle is cancelled.
+    pub async fn cancelled(&self) {
+        loop {
+            if self.is_cancelled() {
+                return;
+            }
+            let listener = self.inner.event.listen();
+            if self.is_cancelled() {
+                return;
+            }
+            listener.await;
+        }
+    }
+
+    /// Runs `future` until it completes or the handle is cancelled.
+    ///
+    /// The cancellation is checked after polling `future`, so work that
+    /// notices the cancellation stops at its next await point.
+    pub(crate) async fn run<T>(&self, future: impl Future<Output = Result<T>>) -> Result<T> {
+        let mut future = pin!(future);
+        let mut cancelled = pin!(self.cancelled());
+        poll_fn(|cx| {
+            if let Poll::Ready(result) = future.as_mut().poll(cx) {
+                return Poll::Ready(result);
+            }
+            if cancelled.as_mut().poll(cx).is_ready() {
+                return Poll::Ready(Err(anyhow!("the operation has been cancelled")));
+            }
+            Poll::Pending
+        })
+        .await
+    }
+
+    /// Returns true when starting `task` can be skipped because of the
+    /// cancellation and `skip` agrees to skip it.
+    pub(crate) fn try_skip(&self, task: TaskId, skip: impl FnOnce() -> bool) -> bool {
+        if !self.is_cancelled() {
+            return false;
+        }
+        let mut skipped_tasks = self.inner.skipped_tasks.lock().unwrap();
+        if skipped_tasks.contains(&task) || !skip() {
+            return false;
+        }
+        skipped_tasks.insert(task);
+        true
+    }
+}
+
+thread_local! {
+    /// The cancellation of the task that started the current blocking
+    /// operation.
+    static BLOCKING_CANCELLATION: RefCell<Option<CancellationHandle>> = RefCell::new(None);
+}
+
+/// Runs `func` with `cancellation` as the cancellation of the current thread.
+pub(crate) fn with_blocking_cancellation<T>(
+    cancellation: Option<CancellationHandle>,
+    func: impl FnOnce() -> T,
+) -> T {
+    let old = BLOCKING_CANCELLATION.with(|cell| cell.replace(cancellation));
+    let result = func();
+    BLOCKING_CANCELLATION.with(|cell| cell.replace(old));
+    result
+}
+
+pub(crate) fn blocking_cancellation() -> Option<CancellationHandle> {
+    BLOCKING_CANCELLATION.with(|cell| cell.borrow().clone())
+}
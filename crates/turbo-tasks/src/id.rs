@@ -91,7 +91,16 @@ macro_rules! make_serializable {
             where
                 E: serde::de::Error,
             {
-                $get_id(v).ok_or_else(|| E::unknown_variant(v, &[]))
+                $get_id(v).ok_or_else(|| {
+                    E::custom(format_args!(
+                        concat!(
+                            "{} is not a registered ",
+                            stringify!($ty),
+                            ", it was probably renamed or removed since it has been persisted"
+                        ),
+                        v
+                    ))
+                })
             }
         }
 
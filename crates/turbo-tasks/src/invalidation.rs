@@ -7,7 +7,7 @@ use std::{
 
 use indexmap::{map::Entry, IndexMap, IndexSet};
 
-use crate::{magic_any::HasherMut, util::StaticOrArc};
+use crate::{magic_any::HasherMut, util::StaticOrArc, TaskId};
 
 pub trait DynamicEqHash {
     fn as_any(&self) -> &dyn Any;
@@ -42,6 +42,18 @@ pub trait InvalidationReason: DynamicEqHash + Display + Send + Sync + 'static {
     }
 }
 
+/// What caused a task to be invalidated. Backends can record this to explain
+/// why a task has been recomputed.
+#[derive(Clone)]
+pub enum InvalidationCause {
+    /// The task was invalidated by the user with a reason, e.g. a file change.
+    Reason(StaticOrArc<dyn InvalidationReason>),
+    /// The task was invalidated by the user without a reason.
+    External,
+    /// The task read an output or a cell of the given task which has changed.
+    Dependency(TaskId),
+}
+
 /// Invalidation reason kind. This is used to merge multiple reasons of the same
 /// kind into a combined description.
 ///
@@ -34,6 +34,7 @@
 #![feature(never_type)]
 
 pub mod backend;
+mod cancellation;
 mod collectibles;
 mod completion;
 pub mod debug;
@@ -69,6 +70,7 @@ mod value;
 mod value_type;
 
 pub use anyhow::{Error, Result};
+pub use cancellation::OnceTaskHandle;
 pub use collectibles::CollectiblesSource;
 pub use completion::{Completion, CompletionVc, CompletionsVc};
 pub use display::{ValueToString, ValueToStringVc};
@@ -77,11 +79,12 @@ pub use id::{
     ValueTypeId,
 };
 pub use invalidation::{
-    DynamicEqHash, InvalidationReason, InvalidationReasonKind, InvalidationReasonSet,
+    DynamicEqHash, InvalidationCause, InvalidationReason, InvalidationReasonKind,
+    InvalidationReasonSet,
 };
 pub use join_iter_ext::{JoinIterExt, TryJoinIterExt};
 pub use manager::{
-    dynamic_call, emit, get_invalidator, mark_finished, mark_stateful, run_once,
+    dynamic_call, emit, get_invalidator, is_cancelled, mark_finished, mark_stateful, run_once,
     run_once_with_reason, spawn_blocking, spawn_thread, trait_call, turbo_tasks, Invalidator,
     StatsType, TaskIdProvider, TurboTasks, TurboTasksApi, TurboTasksBackendApi, TurboTasksCallApi,
     Unused, UpdateInfo,
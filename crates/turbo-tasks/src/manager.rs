@@ -25,10 +25,13 @@ use tracing::{instrument, trace_span, Instrument, Level};
 
 use crate::{
     backend::{Backend, CellContent, PersistentTaskType, TransientTaskType},
+    cancellation::{
+        blocking_cancellation, with_blocking_cancellation, CancellationHandle, OnceTaskHandle,
+    },
     event::{Event, EventListener},
     id::{BackendJobId, FunctionId, TraitTypeId},
     id_factory::IdFactory,
-    invalidation::InvalidationReasonSet,
+    invalidation::{InvalidationCause, InvalidationReasonSet},
     primitives::RawVcSetVc,
     raw_vc::{CellId, RawVc},
     registry,
@@ -52,12 +55,12 @@ pub trait TurboTasksCallApi: Sync + Send {
     fn run_once(
         &self,
         future: Pin<Box<dyn Future<Output = Result<()>> + Send + 'static>>,
-    ) -> TaskId;
+    ) -> OnceTaskHandle;
     fn run_once_with_reason(
         &self,
         reason: StaticOrArc<dyn InvalidationReason>,
         future: Pin<Box<dyn Future<Output = Result<()>> + Send + 'static>>,
-    ) -> TaskId;
+    ) -> OnceTaskHandle;
     fn run_once_process(
         &self,
         future: Pin<Box<dyn Future<Output = Result<()>> + Send + 'static>>,
@@ -299,6 +302,9 @@ task_local! {
     static CURRENT_TASK_ID: TaskId;
 
     static CURRENT_TASK_STATE: RefCell<CurrentTaskState>;
+
+    /// The cancellation of the work that scheduled the current task
+    static CURRENT_CANCELLATION: Option<CancellationHandle>;
 }
 
 impl<B: Backend + 'static> TurboTasks<B> {
@@ -354,33 +360,43 @@ impl<B: Backend + 'static> TurboTasks<B> {
     // TODO make sure that all dependencies settle before reading them
     /// Creates a new root task, that is only executed once.
     /// Dependencies will not invalidate the task.
+    ///
+    /// The returned handle can be used to cancel the work of the task.
     #[track_caller]
     pub fn spawn_once_task(
         &self,
         future: impl Future<Output = Result<RawVc>> + Send + 'static,
-    ) -> TaskId {
-        let id = self
-            .backend
-            .create_transient_task(TransientTaskType::Once(Box::pin(future)), self);
-        self.schedule(id);
-        id
+    ) -> OnceTaskHandle {
+        let cancellation = CancellationHandle::new();
+        let task_cancellation = cancellation.clone();
+        let id = self.backend.create_transient_task(
+            TransientTaskType::Once(Box::pin(async move { task_cancellation.run(future).await })),
+            self,
+        );
+        self.schedule_with_cancellation(id, Some(cancellation.clone()));
+        OnceTaskHandle::new(id, cancellation)
     }
 
+    /// Runs `future` in a once task and returns its result. Dropping the
+    /// returned future before it completes cancels the work, see
+    /// [OnceTaskHandle::cancel].
     pub async fn run_once<T: TraceRawVcs + Send + 'static>(
         &self,
         future: impl Future<Output = Result<T>> + Send + 'static,
     ) -> Result<T> {
         let (tx, rx) = tokio::sync::oneshot::channel();
-        let task_id = self.spawn_once_task(async move {
+        let task = self.spawn_once_task(async move {
             let result = future.await?;
             tx.send(result)
                 .map_err(|_| anyhow!("unable to send result"))?;
             Ok(CompletionVc::new().into())
         });
+        let cancel_on_drop = task.cancel_on_drop();
         // INVALIDATION: A Once task will never invalidate, therefore we don't need to
         // track a dependency
-        let raw_result = read_task_output_untracked(self, task_id, false).await?;
+        let raw_result = read_task_output_untracked(self, task.id(), false).await?;
         raw_result.into_read_untracked::<Completion>(self).await?;
+        cancel_on_drop.disarm();
 
         Ok(rx.await?)
     }
@@ -440,6 +456,19 @@ impl<B: Backend + 'static> TurboTasks<B> {
 
     #[track_caller]
     pub(crate) fn schedule(&self, task_id: TaskId) {
+        let cancellation = CURRENT_CANCELLATION
+            .try_with(|cancellation| cancellation.clone())
+            .ok()
+            .flatten();
+        self.schedule_with_cancellation(task_id, cancellation)
+    }
+
+    #[track_caller]
+    fn schedule_with_cancellation(
+        &self,
+        task_id: TaskId,
+        cancellation: Option<CancellationHandle>,
+    ) {
         self.begin_primary_job();
         self.scheduled_tasks.fetch_add(1, Ordering::AcqRel);
 
@@ -447,7 +476,9 @@ impl<B: Backend + 'static> TurboTasks<B> {
         let description = self.backend.get_task_description(task_id);
 
         let this = self.pin();
+        let task_cancellation = cancellation.clone();
         let future = async move {
+            let cancellation = task_cancellation;
             #[allow(clippy::blocks_in_if_conditions)]
             while CURRENT_TASK_STATE
                 .scope(Default::default(), async {
@@ -455,6 +486,14 @@ impl<B: Backend + 'static> TurboTasks<B> {
                         return false;
                     }
 
+                    if let Some(cancellation) = &cancellation {
+                        if cancellation.try_skip(task_id, || {
+                            this.backend.try_unschedule_task(task_id, &*this)
+                        }) {
+                            return false;
+                        }
+                    }
+
                     // Setup thread locals
                     let execution_future = CELL_COUNTERS.scope(Default::default(), async {
                         let execution = this.backend.try_start_task_execution(task_id, &*this)?;
@@ -500,7 +539,11 @@ impl<B: Backend + 'static> TurboTasks<B> {
         let future = TURBO_TASKS
             .scope(
                 self.pin(),
-                CURRENT_TASK_ID.scope(task_id, self.backend.execution_scope(task_id, future)),
+                CURRENT_TASK_ID.scope(
+                    task_id,
+                    CURRENT_CANCELLATION
+                        .scope(cancellation, self.backend.execution_scope(task_id, future)),
+                ),
             )
             .in_current_span();
 
@@ -801,6 +844,8 @@ impl<B: Backend + 'static> TurboTasks<B> {
             let tasks = take(tasks_to_notify);
             if !tasks.is_empty() {
                 let _guard = trace_span!("finish_current_task_state").entered();
+                self.backend
+                    .record_invalidation_cause(&tasks, &current_invalidation_cause());
                 self.backend.invalidate_tasks(tasks, self);
             }
             *stateful
@@ -832,7 +877,7 @@ impl<B: Backend + 'static> TurboTasksCallApi for TurboTasks<B> {
     fn run_once(
         &self,
         future: Pin<Box<dyn Future<Output = Result<()>> + Send + 'static>>,
-    ) -> TaskId {
+    ) -> OnceTaskHandle {
         self.spawn_once_task(async move {
             future.await?;
             Ok(CompletionVc::new().into())
@@ -844,7 +889,7 @@ impl<B: Backend + 'static> TurboTasksCallApi for TurboTasks<B> {
         &self,
         reason: StaticOrArc<dyn InvalidationReason>,
         future: Pin<Box<dyn Future<Output = Result<()>> + Send + 'static>>,
-    ) -> TaskId {
+    ) -> OnceTaskHandle {
         {
             let (_, reason_set) = &mut *self.aggregated_update.lock().unwrap();
             reason_set.insert(reason);
@@ -867,12 +912,15 @@ impl<B: Backend + 'static> TurboTasksCallApi for TurboTasks<B> {
             this.begin_primary_job();
             Ok(CompletionVc::new().into())
         })
+        .id()
     }
 }
 
 impl<B: Backend + 'static> TurboTasksApi for TurboTasks<B> {
     #[instrument(level = Level::INFO, skip_all, name = "invalidate")]
     fn invalidate(&self, task: TaskId) {
+        self.backend
+            .record_invalidation_cause(&[task], &InvalidationCause::External);
         self.backend.invalidate_task(task, self);
     }
 
@@ -880,8 +928,10 @@ impl<B: Backend + 'static> TurboTasksApi for TurboTasks<B> {
     fn invalidate_with_reason(&self, task: TaskId, reason: StaticOrArc<dyn InvalidationReason>) {
         {
             let (_, reason_set) = &mut *self.aggregated_update.lock().unwrap();
-            reason_set.insert(reason);
+            reason_set.insert(reason.clone());
         }
+        self.backend
+            .record_invalidation_cause(&[task], &InvalidationCause::Reason(reason));
         self.backend.invalidate_task(task, self);
     }
 
@@ -894,6 +944,8 @@ impl<B: Backend + 'static> TurboTasksApi for TurboTasks<B> {
             if tasks.is_empty() {
                 return;
             }
+            self.backend
+                .record_invalidation_cause(&tasks, &current_invalidation_cause());
             self.backend.invalidate_tasks(tasks, self);
         });
     }
@@ -1087,6 +1139,8 @@ impl<B: Backend + 'static> TurboTasksBackendApi<B> for TurboTasks<B> {
         });
         if result.is_err() {
             let _guard = trace_span!("schedule_notify_tasks", count = tasks.len()).entered();
+            self.backend
+                .record_invalidation_cause(tasks, &current_invalidation_cause());
             self.backend.invalidate_tasks(tasks.to_vec(), self);
         }
     }
@@ -1102,8 +1156,10 @@ impl<B: Backend + 'static> TurboTasksBackendApi<B> for TurboTasks<B> {
         });
         if result.is_err() {
             let _guard = trace_span!("schedule_notify_tasks_set", count = tasks.len()).entered();
+            let tasks = tasks.iter().copied().collect::<Vec<_>>();
             self.backend
-                .invalidate_tasks(tasks.iter().copied().collect(), self);
+                .record_invalidation_cause(&tasks, &current_invalidation_cause());
+            self.backend.invalidate_tasks(tasks, self);
         };
     }
 
@@ -1258,27 +1314,32 @@ impl<'de> Deserialize<'de> for Invalidator {
     }
 }
 
+/// Runs `future` in a once task and returns its result. Dropping the returned
+/// future before it completes cancels the work, see [OnceTaskHandle::cancel].
 pub async fn run_once<T: Send + 'static>(
     tt: Arc<dyn TurboTasksApi>,
     future: impl Future<Output = Result<T>> + Send + 'static,
 ) -> Result<T> {
     let (tx, rx) = tokio::sync::oneshot::channel();
 
-    let task_id = tt.run_once(Box::pin(async move {
+    let task = tt.run_once(Box::pin(async move {
         let result = future.await?;
         tx.send(result)
             .map_err(|_| anyhow!("unable to send result"))?;
         Ok(())
     }));
+    let cancel_on_drop = task.cancel_on_drop();
 
     // INVALIDATION: A Once task will never invalidate, therefore we don't need to
     // track a dependency
-    let raw_result = read_task_output_untracked(&*tt, task_id, false).await?;
+    let raw_result = read_task_output_untracked(&*tt, task.id(), false).await?;
     raw_result.into_read_untracked::<Completion>(&*tt).await?;
+    cancel_on_drop.disarm();
 
     Ok(rx.await?)
 }
 
+/// Like [run_once], but records `reason` as the reason of the update.
 pub async fn run_once_with_reason<T: Send + 'static>(
     tt: Arc<dyn TurboTasksApi>,
     reason: impl InvalidationReason,
@@ -1286,7 +1347,7 @@ pub async fn run_once_with_reason<T: Send + 'static>(
 ) -> Result<T> {
     let (tx, rx) = tokio::sync::oneshot::channel();
 
-    let task_id = tt.run_once_with_reason(
+    let task = tt.run_once_with_reason(
         (Arc::new(reason) as Arc<dyn InvalidationReason>).into(),
         Box::pin(async move {
             let result = future.await?;
@@ -1295,11 +1356,13 @@ pub async fn run_once_with_reason<T: Send + 'static>(
             Ok(())
         }),
     );
+    let cancel_on_drop = task.cancel_on_drop();
 
     // INVALIDATION: A Once task will never invalidate, therefore we don't need to
     // track a dependency
-    let raw_result = read_task_output_untracked(&*tt, task_id, false).await?;
+    let raw_result = read_task_output_untracked(&*tt, task.id(), false).await?;
     raw_result.into_read_untracked::<Completion>(&*tt).await?;
+    cancel_on_drop.disarm();
 
     Ok(rx.await?)
 }
@@ -1349,6 +1412,14 @@ pub fn spawn_detached(f: impl Future<Output = Result<()>> + Send + 'static) {
     tokio::spawn(turbo_tasks().detached(Box::pin(f.in_current_span())));
 }
 
+/// The cause of invalidations that are triggered from the current context:
+/// updates made by a task invalidate its dependents.
+fn current_invalidation_cause() -> InvalidationCause {
+    CURRENT_TASK_ID
+        .try_with(|id| InvalidationCause::Dependency(*id))
+        .unwrap_or(InvalidationCause::External)
+}
+
 pub fn current_task_for_testing() -> TaskId {
     CURRENT_TASK_ID.with(|id| *id)
 }
@@ -1390,12 +1461,30 @@ pub fn emit<T: ValueTraitVc>(collectible: T) {
     with_turbo_tasks(|tt| tt.emit_collectible(T::get_trait_type_id(), collectible.into()))
 }
 
+/// Returns true when the work that scheduled the current task has been
+/// cancelled. Also works within [spawn_blocking].
+///
+/// Long running operations can check this to stop early, as their result
+/// won't be used.
+pub fn is_cancelled() -> bool {
+    let cancellation = CURRENT_CANCELLATION
+        .try_with(|cancellation| cancellation.clone())
+        .ok()
+        .flatten()
+        .or_else(blocking_cancellation);
+    cancellation.map_or(false, |cancellation| cancellation.is_cancelled())
+}
+
 pub async fn spawn_blocking<T: Send + 'static>(func: impl FnOnce() -> T + Send + 'static) -> T {
     let span = trace_span!("blocking operation").or_current();
+    let cancellation = CURRENT_CANCELLATION
+        .try_with(|cancellation| cancellation.clone())
+        .ok()
+        .flatten();
     let (r, d) = tokio::task::spawn_blocking(|| {
         let _guard = span.entered();
         let start = Instant::now();
-        let r = func();
+        let r = with_blocking_cancellation(cancellation, func);
         (r, start.elapsed())
     })
     .await
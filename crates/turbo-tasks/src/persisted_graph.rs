@@ -199,7 +199,9 @@ pub trait PersistedGraph: Sync + Send {
 pub trait PersistedGraphApi {
     fn get_or_create_task_type(&self, ty: PersistentTaskType) -> TaskId;
 
-    fn lookup_task_type(&self, id: TaskId) -> &PersistentTaskType;
+    /// Returns the task type of a task, or `None` for transient tasks which
+    /// can't be persisted.
+    fn lookup_task_type(&self, id: TaskId) -> Option<&PersistentTaskType>;
 }
 
 /*
@@ -1,52 +1,70 @@
-use std::{fmt::Debug, hash::Hash, ops::Deref};
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    ops::Deref,
+};
 
 use dashmap::{mapref::entry::Entry, DashMap};
 use once_cell::sync::Lazy;
+use turbo_tasks_hash::hash_xxh3_hash64;
 
 use crate::{
     id::{FunctionId, TraitTypeId, ValueTypeId},
-    id_factory::IdFactory,
-    no_move_vec::NoMoveVec,
     NativeFunction, TraitType, ValueType,
 };
 
-static FUNCTION_ID_FACTORY: IdFactory<FunctionId> = IdFactory::new();
-static FUNCTIONS_BY_NAME: Lazy<DashMap<String, FunctionId>> = Lazy::new(DashMap::new);
+static FUNCTIONS_BY_NAME: Lazy<DashMap<&'static str, FunctionId>> = Lazy::new(DashMap::new);
 static FUNCTIONS_BY_VALUE: Lazy<DashMap<&'static NativeFunction, FunctionId>> =
     Lazy::new(DashMap::new);
-static FUNCTIONS: Lazy<NoMoveVec<(&'static NativeFunction, String)>> = Lazy::new(NoMoveVec::new);
+static FUNCTIONS: Lazy<DashMap<FunctionId, (&'static NativeFunction, &'static str)>> =
+    Lazy::new(DashMap::new);
 
-static VALUE_TYPE_ID_FACTORY: IdFactory<ValueTypeId> = IdFactory::new();
-static VALUE_TYPES_BY_NAME: Lazy<DashMap<String, ValueTypeId>> = Lazy::new(DashMap::new);
+static VALUE_TYPES_BY_NAME: Lazy<DashMap<&'static str, ValueTypeId>> = Lazy::new(DashMap::new);
 static VALUE_TYPES_BY_VALUE: Lazy<DashMap<&'static ValueType, ValueTypeId>> =
     Lazy::new(DashMap::new);
-static VALUE_TYPES: Lazy<NoMoveVec<(&'static ValueType, String)>> = Lazy::new(NoMoveVec::new);
+static VALUE_TYPES: Lazy<DashMap<ValueTypeId, (&'static ValueType, &'static str)>> =
+    Lazy::new(DashMap::new);
 
-static TRAIT_TYPE_ID_FACTORY: IdFactory<TraitTypeId> = IdFactory::new();
-static TRAIT_TYPES_BY_NAME: Lazy<DashMap<String, TraitTypeId>> = Lazy::new(DashMap::new);
+static TRAIT_TYPES_BY_NAME: Lazy<DashMap<&'static str, TraitTypeId>> = Lazy::new(DashMap::new);
 static TRAIT_TYPES_BY_VALUE: Lazy<DashMap<&'static TraitType, TraitTypeId>> =
     Lazy::new(DashMap::new);
-static TRAIT_TYPES: Lazy<NoMoveVec<(&'static TraitType, String)>> = Lazy::new(NoMoveVec::new);
+static TRAIT_TYPES: Lazy<DashMap<TraitTypeId, (&'static TraitType, &'static str)>> =
+    Lazy::new(DashMap::new);
+
+/// Derives the id of a registered thing from its global name, so that ids stay
+/// the same across builds and don't depend on the order of registration.
+fn stable_id<K: From<usize>>(global_name: &str) -> K {
+    (hash_xxh3_hash64(global_name) as usize).into()
+}
 
 fn register_thing<
-    K: From<usize> + Deref<Target = usize> + Sync + Send + Copy,
+    K: From<usize> + Deref<Target = usize> + Hash + Eq + Sync + Send + Copy,
     V: Clone + Hash + Ord + Eq + Sync + Send + Copy,
-    const INITIAL_CAPACITY_BITS: u32,
 >(
     global_name: &str,
     value: V,
-    id_factory: &IdFactory<K>,
-    store: &NoMoveVec<(V, String), INITIAL_CAPACITY_BITS>,
-    map_by_name: &DashMap<String, K>,
+    store: &DashMap<K, (V, &'static str)>,
+    map_by_name: &DashMap<&'static str, K>,
     map_by_value: &DashMap<V, K>,
 ) {
     if let Entry::Vacant(e) = map_by_value.entry(value) {
-        let new_id = id_factory.get();
-        // SAFETY: this is a fresh id
-        unsafe {
-            store.insert(*new_id, (value, global_name.to_string()));
+        let new_id = stable_id(global_name);
+        match store.entry(new_id) {
+            Entry::Occupied(existing) => {
+                panic!(
+                    "{} can't be registered as its id {} is already used by {}",
+                    global_name,
+                    *new_id,
+                    existing.get().1
+                );
+            }
+            Entry::Vacant(store_entry) => {
+                // Registered things live for the whole process
+                let global_name: &'static str = Box::leak(global_name.into());
+                store_entry.insert((value, global_name));
+                map_by_name.insert(global_name, new_id);
+            }
         }
-        map_by_name.insert(global_name.to_string(), new_id);
         e.insert(new_id);
     }
 }
@@ -65,11 +83,20 @@ fn get_thing_id<
     }
 }
 
+fn get_thing<K: Hash + Eq + Display, V: Copy>(
+    id: K,
+    store: &DashMap<K, (V, &'static str)>,
+) -> (V, &'static str) {
+    match store.get(&id) {
+        Some(entry) => *entry,
+        None => panic!("Use of unregistered {}", id),
+    }
+}
+
 pub fn register_function(global_name: &str, func: &'static NativeFunction) {
     register_thing(
         global_name,
         func,
-        &FUNCTION_ID_FACTORY,
         &FUNCTIONS,
         &FUNCTIONS_BY_NAME,
         &FUNCTIONS_BY_VALUE,
@@ -85,18 +112,17 @@ pub fn get_function_id_by_global_name(global_name: &str) -> Option<FunctionId> {
 }
 
 pub fn get_function(id: FunctionId) -> &'static NativeFunction {
-    FUNCTIONS.get(*id).unwrap().0
+    get_thing(id, &FUNCTIONS).0
 }
 
 pub fn get_function_global_name(id: FunctionId) -> &'static str {
-    &FUNCTIONS.get(*id).unwrap().1
+    get_thing(id, &FUNCTIONS).1
 }
 
 pub fn register_value_type(global_name: &str, ty: &'static ValueType) {
     register_thing(
         global_name,
         ty,
-        &VALUE_TYPE_ID_FACTORY,
         &VALUE_TYPES,
         &VALUE_TYPES_BY_NAME,
         &VALUE_TYPES_BY_VALUE,
@@ -112,18 +138,17 @@ pub fn get_value_type_id_by_global_name(global_name: &str) -> Option<ValueTypeId
 }
 
 pub fn get_value_type(id: ValueTypeId) -> &'static ValueType {
-    VALUE_TYPES.get(*id).unwrap().0
+    get_thing(id, &VALUE_TYPES).0
 }
 
 pub fn get_value_type_global_name(id: ValueTypeId) -> &'static str {
-    &VALUE_TYPES.get(*id).unwrap().1
+    get_thing(id, &VALUE_TYPES).1
 }
 
 pub fn register_trait_type(global_name: &str, ty: &'static TraitType) {
     register_thing(
         global_name,
         ty,
-        &TRAIT_TYPE_ID_FACTORY,
         &TRAIT_TYPES,
         &TRAIT_TYPES_BY_NAME,
         &TRAIT_TYPES_BY_VALUE,
@@ -139,9 +164,9 @@ pub fn get_trait_type_id_by_global_name(global_name: &str) -> Option<TraitTypeId
 }
 
 pub fn get_trait(id: TraitTypeId) -> &'static TraitType {
-    TRAIT_TYPES.get(*id).unwrap().0
+    get_thing(id, &TRAIT_TYPES).0
 }
 
 pub fn get_trait_type_global_name(id: TraitTypeId) -> &'static str {
-    &TRAIT_TYPES.get(*id).unwrap().1
+    get_thing(id, &TRAIT_TYPES).1
 }
@@ -0,0 +1,138 @@
+//! Detects paths on filesystems which don't deliver native file events, such
+//! as network mounts, Docker Desktop bind mounts and Windows drives in WSL2.
+//! File watchers fall back to polling for these paths.
+
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use once_cell::sync::Lazy;
+
+/// The environment variable which overrides the detection. Setting it to `1`
+/// polls all paths, setting it to `0` never polls.
+pub const POLL_ENV_VAR: &str = "TURBO_WATCH_POLL";
+
+/// Filesystems which don't deliver native file events.
+const POLLING_FS_TYPES: &[&str] = &[
+    "nfs",
+    "nfs4",
+    "cifs",
+    "smb3",
+    "smbfs",
+    "9p",
+    "drvfs",
+    "vboxsf",
+    "fakeowner",
+    "fuse.grpcfuse",
+    "fuse.osxfs",
+    "fuse.sshfs",
+];
+
+/// Returns true when changes to `path` need to be polled, either because
+/// [POLL_ENV_VAR] says so or because `path` is on a filesystem which doesn't
+/// deliver native file events.
+///
+/// The detection runs once per path and is logged once per mount, so watchers
+/// can call this for every path they watch.
+pub fn should_poll(path: &Path) -> bool {
+    static DETECTED: Lazy<Mutex<HashMap<PathBuf, bool>>> = Lazy::new(Default::default);
+    static LOGGED_MOUNTS: Lazy<Mutex<HashSet<PathBuf>>> = Lazy::new(Default::default);
+
+    match std::env::var(POLL_ENV_VAR).as_deref() {
+        Ok("1" | "true") => true,
+        Ok("0" | "false") => false,
+        _ => {
+            if let Some(poll) = DETECTED.lock().unwrap().get(path) {
+                return *poll;
+            }
+            let polling_mount = polling_mount(path);
+            if let Some(mount_point) = &polling_mount {
+                if LOGGED_MOUNTS.lock().unwrap().insert(mount_point.clone()) {
+                    tracing::info!(
+                        "{} doesn't support native file events, polling for changes",
+                        mount_point.display()
+                    );
+                }
+            }
+            let poll = polling_mount.is_some();
+            DETECTED.lock().unwrap().insert(path.to_path_buf(), poll);
+            poll
+        }
+    }
+}
+
+/// Returns the mount point of the filesystem containing `path` when it
+/// doesn't deliver native file events.
+fn polling_mount(path: &Path) -> Option<PathBuf> {
+    // Mounts rarely change while watching, so the mount table is read once.
+    static MOUNTS: Lazy<Option<String>> =
+        Lazy::new(|| std::fs::read_to_string("/proc/self/mounts").ok());
+
+    if !cfg!(target_os = "linux") {
+        return None;
+    }
+    let mounts = MOUNTS.as_deref()?;
+    // The path might not exist yet, so we check the closest existing ancestor.
+    let path = path
+        .ancestors()
+        .find_map(|path| path.canonicalize().ok())
+        .unwrap_or_else(|| path.to_path_buf());
+    let (mount_point, fs_type) = find_mount(mounts, &path)?;
+    POLLING_FS_TYPES.contains(&fs_type).then_some(mount_point)
+}
+
+/// Returns the closest mount point of `path` and the type of the filesystem
+/// mounted there, as listed in `/proc/self/mounts`.
+fn find_mount<'a>(mounts: &'a str, path: &Path) -> Option<(PathBuf, &'a str)> {
+    mounts
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split(' ');
+            let _device = fields.next()?;
+            // Spaces in mount points are escaped as octal.
+            let mount_point = PathBuf::from(fields.next()?.replace("\\040", " "));
+            let fs_type = fields.next()?;
+            Some((mount_point, fs_type))
+        })
+        .filter(|(mount_point, _)| path.starts_with(mount_point))
+        // Later mounts on the same mount point shadow earlier ones.
+        .max_by_key(|(mount_point, _)| mount_point.as_os_str().len())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::find_mount;
+
+    fn mount_fs_type<'a>(mounts: &'a str, path: &Path) -> Option<&'a str> {
+        find_mount(mounts, path).map(|(_, fs_type)| fs_type)
+    }
+
+    #[test]
+    fn finds_mount_fs_type() {
+        let mounts = "overlay / overlay rw 0 0\nserver:/export /mnt/data nfs4 rw 0 0\nC:\\134 \
+                      /mnt/c 9p rw 0 0\n/dev/sda1 /mnt/my\\040disk ext4 rw 0 0\n";
+        assert_eq!(mount_fs_type(mounts, Path::new("/app")), Some("overlay"));
+        assert_eq!(
+            mount_fs_type(mounts, Path::new("/mnt/data/project")),
+            Some("nfs4")
+        );
+        assert_eq!(mount_fs_type(mounts, Path::new("/mnt/c/Users")), Some("9p"));
+        assert_eq!(
+            mount_fs_type(mounts, Path::new("/mnt/my disk/a")),
+            Some("ext4")
+        );
+        assert_eq!(
+            find_mount(mounts, Path::new("/mnt/data/project")).map(|(mount_point, _)| mount_point),
+            Some(Path::new("/mnt/data").to_path_buf())
+        );
+        // A prefix of another mount point
+        assert_eq!(
+            mount_fs_type(mounts, Path::new("/mnt/cache")),
+            Some("overlay")
+        );
+    }
+}
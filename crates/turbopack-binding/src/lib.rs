@@ -78,6 +78,8 @@ pub mod turbopack {
     pub use turbopack_ecmascript_runtime as ecmascript_runtime;
     #[cfg(feature = "__turbopack_env")]
     pub use turbopack_env as env;
+    #[cfg(feature = "__turbopack_html")]
+    pub use turbopack_html as html;
     #[cfg(feature = "__turbopack_image")]
     pub use turbopack_image as image;
     #[cfg(feature = "__turbopack_json")]
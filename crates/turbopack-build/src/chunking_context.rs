@@ -134,38 +134,22 @@ impl BuildChunkingContextVc {
     ) -> Result<AssetVc> {
         let entry_chunk = module.as_root_chunk(self_vc.into());
 
-        let assets = self_vc
-            .get_evaluate_chunk_assets(entry_chunk, evaluatable_assets)
-            .await?;
+        let assets = self_vc.get_evaluate_chunk_assets(entry_chunk, evaluatable_assets);
 
-        let asset = self_vc.generate_evaluate_chunk(
-            entry_chunk,
-            AssetsVc::cell(assets),
-            evaluatable_assets,
-            Some(module),
-        );
+        let asset =
+            self_vc.generate_evaluate_chunk(entry_chunk, assets, evaluatable_assets, Some(module));
 
         Ok(asset)
     }
 
+    /// Returns the chunks of an evaluated chunk group, without the chunk that
+    /// evaluates the entries.
     #[turbo_tasks::function]
-    async fn generate_chunk(self, chunk: ChunkVc) -> Result<AssetVc> {
-        Ok(
-            if let Some(ecmascript_chunk) = EcmascriptChunkVc::resolve_from(chunk).await? {
-                EcmascriptBuildNodeChunkVc::new(self, ecmascript_chunk).into()
-            } else {
-                chunk.into()
-            },
-        )
-    }
-}
-
-impl BuildChunkingContextVc {
     async fn get_evaluate_chunk_assets(
         self,
         entry_chunk: ChunkVc,
         evaluatable_assets: EvaluatableAssetsVc,
-    ) -> Result<Vec<AssetVc>> {
+    ) -> Result<AssetsVc> {
         let evaluatable_assets_ref = evaluatable_assets.await?;
 
         let mut chunks: IndexSet<_> = evaluatable_assets_ref
@@ -186,11 +170,24 @@ impl BuildChunkingContextVc {
 
         let chunks = get_optimized_chunks(chunks.await?).await?;
 
-        Ok(chunks
-            .await?
-            .iter()
-            .map(|chunk| self.generate_chunk(*chunk))
-            .collect())
+        Ok(AssetsVc::cell(
+            chunks
+                .await?
+                .iter()
+                .map(|chunk| self.generate_chunk(*chunk))
+                .collect(),
+        ))
+    }
+
+    #[turbo_tasks::function]
+    async fn generate_chunk(self, chunk: ChunkVc) -> Result<AssetVc> {
+        Ok(
+            if let Some(ecmascript_chunk) = EcmascriptChunkVc::resolve_from(chunk).await? {
+                EcmascriptBuildNodeChunkVc::new(self, ecmascript_chunk).into()
+            } else {
+                chunk.into()
+            },
+        )
     }
 }
 
@@ -300,19 +297,33 @@ impl ChunkingContext for BuildChunkingContext {
         entry_chunk: ChunkVc,
         evaluatable_assets: EvaluatableAssetsVc,
     ) -> Result<AssetsVc> {
-        let mut assets = self_vc
-            .get_evaluate_chunk_assets(entry_chunk, evaluatable_assets)
-            .await?;
+        let other_assets = self_vc.get_evaluate_chunk_assets(entry_chunk, evaluatable_assets);
+
+        let mut assets = other_assets.await?.clone_value();
 
         assets.push(self_vc.generate_evaluate_chunk(
             entry_chunk,
-            AssetsVc::cell(assets.clone()),
+            other_assets,
             evaluatable_assets,
             None,
         ));
 
         Ok(AssetsVc::cell(assets))
     }
+
+    #[turbo_tasks::function]
+    fn evaluated_chunk_group_entry(
+        self_vc: BuildChunkingContextVc,
+        entry_chunk: ChunkVc,
+        evaluatable_assets: EvaluatableAssetsVc,
+    ) -> AssetVc {
+        self_vc.generate_evaluate_chunk(
+            entry_chunk,
+            self_vc.get_evaluate_chunk_assets(entry_chunk, evaluatable_assets),
+            evaluatable_assets,
+            None,
+        )
+    }
 }
 
 #[turbo_tasks::value_impl]
@@ -3,7 +3,7 @@ use indexmap::IndexSet;
 use turbo_tasks::{primitives::StringVc, ValueToString, ValueToStringVc};
 use turbopack_core::{
     asset::{Asset, AssetContentVc, AssetVc},
-    chunk::ChunkingContext,
+    chunk::{ChunkItemSizesVc, ChunkWithItemSizes, ChunkWithItemSizesVc, ChunkingContext},
     ident::AssetIdentVc,
     introspect::{Introspectable, IntrospectableChildrenVc, IntrospectableVc},
     reference::AssetReferencesVc,
@@ -105,6 +105,14 @@ impl GenerateSourceMap for EcmascriptBuildNodeChunk {
     }
 }
 
+#[turbo_tasks::value_impl]
+impl ChunkWithItemSizes for EcmascriptBuildNodeChunk {
+    #[turbo_tasks::function]
+    fn chunk_item_sizes(&self) -> ChunkItemSizesVc {
+        self.chunk.chunk_item_sizes()
+    }
+}
+
 #[turbo_tasks::function]
 fn introspectable_type() -> StringVc {
     StringVc::cell("ecmascript build node chunk".to_string())
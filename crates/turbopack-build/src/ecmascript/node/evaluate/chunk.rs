@@ -6,7 +6,7 @@ use turbo_tasks::{primitives::StringVc, Value, ValueToString, ValueToStringVc};
 use turbo_tasks_fs::File;
 use turbopack_core::{
     asset::{Asset, AssetContentVc, AssetVc, AssetsVc},
-    chunk::{ChunkVc, ChunkingContext, EvaluatableAssetsVc},
+    chunk::{ChunkVc, ChunkingContext, EvaluatableAssetsVc, EvaluateChunk, EvaluateChunkVc},
     code_builder::{CodeBuilder, CodeVc},
     ident::AssetIdentVc,
     reference::{AssetReferencesVc, SingleAssetReferenceVc},
@@ -238,6 +238,14 @@ impl Asset for EcmascriptBuildNodeEvaluateChunk {
     }
 }
 
+#[turbo_tasks::value_impl]
+impl EvaluateChunk for EcmascriptBuildNodeEvaluateChunk {
+    #[turbo_tasks::function]
+    fn evaluatable_assets(&self) -> EvaluatableAssetsVc {
+        self.evaluatable_assets
+    }
+}
+
 #[turbo_tasks::value_impl]
 impl GenerateSourceMap for EcmascriptBuildNodeEvaluateChunk {
     #[turbo_tasks::function]
@@ -20,7 +20,10 @@ use turbopack_core::issue::{
     PlainIssueProcessingPathItem, PlainIssueProcessingPathItemReadRef, PlainIssueSource,
 };
 
-use crate::source_context::format_source_context_lines;
+use crate::{
+    issue_rules::{IssueRules, SuppressedIssues},
+    source_context::format_source_context_lines,
+};
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub struct IssueSeverityCliOption(pub IssueSeverity);
@@ -223,6 +226,7 @@ pub struct LogOptions {
     pub show_all: bool,
     pub log_detail: bool,
     pub log_level: IssueSeverity,
+    pub issue_rules: IssueRules,
 }
 
 /// Tracks the state of currently seen issues.
@@ -244,7 +248,7 @@ pub struct LogOptions {
 /// Once an issue's count reaches zero, it's removed. If it is ever seen again,
 /// it is considered new and will be relogged.
 #[derive(Default)]
-struct SeenIssues {
+pub(crate) struct SeenIssues {
     /// Keeps track of all issue pulled from the source. Used so that we can
     /// decrement issues that are not pulled in the current synchronization.
     source_to_issue_ids: HashMap<RawVc, HashSet<u64>>,
@@ -257,13 +261,18 @@ struct SeenIssues {
 }
 
 impl SeenIssues {
-    fn new() -> Self {
+    pub(crate) fn new() -> Self {
         Default::default()
     }
 
+    /// Whether the issue is currently pulled by any source.
+    pub(crate) fn is_seen(&self, id: u64) -> bool {
+        self.issues_count.contains_key(&id)
+    }
+
     /// Synchronizes state between the issues previously pulled from this
     /// source, to the issues now pulled.
-    fn new_ids(&mut self, source: RawVc, issue_ids: HashSet<u64>) -> HashSet<u64> {
+    pub(crate) fn new_ids(&mut self, source: RawVc, issue_ids: HashSet<u64>) -> HashSet<u64> {
         let old = self.source_to_issue_ids.entry(source).or_default();
 
         // difference is the issues that were never counted before.
@@ -361,6 +370,7 @@ impl IssueReporter for ConsoleUi {
             show_all,
             log_detail,
             log_level,
+            ref issue_rules,
             ..
         } = self.options;
         let mut grouped_issues: GroupedIssues = HashMap::new();
@@ -383,12 +393,16 @@ impl IssueReporter for ConsoleUi {
             .new_ids(source.into_value(), issue_ids);
 
         let mut has_fatal = false;
+        let mut suppressed_issues = SuppressedIssues::default();
         for (plain_issue, id) in issues {
             if !new_ids.remove(&id) {
                 continue;
             }
 
-            let severity = plain_issue.severity;
+            let Some(severity) = issue_rules.severity(&plain_issue) else {
+                suppressed_issues.add(&plain_issue.category);
+                continue;
+            };
             if severity == IssueSeverity::Fatal {
                 has_fatal = true;
             }
@@ -529,11 +543,22 @@ impl IssueReporter for ConsoleUi {
             }
         }
 
+        if let Some(summary) = suppressed_issues.summary() {
+            println!(
+                "{} - {summary}",
+                IssueSeverity::Info.style(severity_to_style(IssueSeverity::Info)),
+            );
+        }
+
         Ok(BoolVc::cell(has_fatal))
     }
 }
 
-fn make_relative_to_cwd<'a>(path: &'a str, project_dir: &Path, cwd: &Path) -> Cow<'a, str> {
+pub(crate) fn make_relative_to_cwd<'a>(
+    path: &'a str,
+    project_dir: &Path,
+    cwd: &Path,
+) -> Cow<'a, str> {
     if let Some(path_in_project) = path.strip_prefix("[project]/") {
         let abs_path = if std::path::MAIN_SEPARATOR != '/' {
             project_dir.join(path_in_project.replace('/', std::path::MAIN_SEPARATOR_STR))
@@ -0,0 +1,542 @@
+//! Machine-readable issue reporters, for CI annotations and code scanning.
+
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    fs::OpenOptions,
+    io::{stdout, Write},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::{json, Value as JsonValue};
+use turbo_tasks::{
+    primitives::BoolVc, RawVc, ReadRef, TransientInstance, TransientValue, TryJoinIterExt,
+};
+use turbopack_core::issue::{
+    CapturedIssues, IssueReporter, IssueReporterVc, IssueSeverity, PlainIssue,
+};
+
+use crate::issue::{make_relative_to_cwd, LogOptions, SeenIssues};
+
+/// The format in which issues are reported.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Hash, clap::ValueEnum, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum IssueFormat {
+    /// Human-readable output, grouped by severity and category.
+    #[default]
+    Console,
+    /// One JSON object per issue and line. Every issue is written once, when
+    /// it first occurs.
+    Ndjson,
+    /// A SARIF 2.1.0 log of all current issues, which is rewritten whenever
+    /// they change.
+    Sarif,
+}
+
+impl IssueFormat {
+    /// The format of a [StructuredIssueReporter] which writes to `output`, or
+    /// `None` for [IssueFormat::Console]. A SARIF log is rewritten whenever
+    /// the issues change, so it can only be written to a file.
+    pub fn structured(self, output: Option<&Path>) -> Result<Option<StructuredIssueFormat>> {
+        Ok(match (self, output) {
+            (IssueFormat::Console, _) => None,
+            (IssueFormat::Ndjson, _) => Some(StructuredIssueFormat::Ndjson),
+            (IssueFormat::Sarif, Some(_)) => Some(StructuredIssueFormat::Sarif),
+            (IssueFormat::Sarif, None) => bail!("--issue-format sarif requires --issue-output"),
+        })
+    }
+}
+
+/// The machine-readable formats of a [StructuredIssueReporter].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StructuredIssueFormat {
+    Ndjson,
+    Sarif,
+}
+
+/// Options of a [StructuredIssueReporter].
+#[derive(Debug, Clone)]
+pub struct StructuredIssueOptions {
+    pub format: StructuredIssueFormat,
+    /// The file to write to. NDJSON is written to stdout if not set, while
+    /// SARIF requires a file.
+    pub output: Option<PathBuf>,
+    /// The name of the tool in SARIF logs.
+    pub tool_name: String,
+    pub log_options: LogOptions,
+}
+
+#[derive(Default)]
+struct ReporterState {
+    seen: SeenIssues,
+    /// The current issues by their id.
+    issues: HashMap<u64, StructuredIssue>,
+    /// Whether the output file has been truncated for this run.
+    started: bool,
+}
+
+impl ReporterState {
+    /// Writes the issues with the ids `new_issues` as NDJSON, or a SARIF log
+    /// of all current issues when they changed.
+    fn write(
+        &mut self,
+        options: &StructuredIssueOptions,
+        new_issues: &[u64],
+        removed_issues: bool,
+    ) -> Result<()> {
+        let content = match options.format {
+            StructuredIssueFormat::Ndjson => {
+                let mut content = String::new();
+                for id in new_issues {
+                    content.push_str(&serde_json::to_string(&self.issues[id])?);
+                    content.push('\n');
+                }
+                content
+            }
+            StructuredIssueFormat::Sarif
+                if !new_issues.is_empty() || removed_issues || !self.started =>
+            {
+                let mut issues = self.issues.values().collect::<Vec<_>>();
+                issues.sort_by(|a, b| (&a.context, &a.title).cmp(&(&b.context, &b.title)));
+                let mut content = serde_json::to_string_pretty(&sarif_log(
+                    &options.tool_name,
+                    issues.into_iter(),
+                ))?;
+                content.push('\n');
+                content
+            }
+            StructuredIssueFormat::Sarif => String::new(),
+        };
+        if content.is_empty() && self.started {
+            return Ok(());
+        }
+
+        match (&options.output, options.format) {
+            (Some(path), _) => {
+                // SARIF logs are rewritten as a whole, while NDJSON is appended to after the
+                // first report.
+                let append = self.started && options.format == StructuredIssueFormat::Ndjson;
+                OpenOptions::new()
+                    .write(true)
+                    .create(true)
+                    .append(append)
+                    .truncate(!append)
+                    .open(path)
+                    .and_then(|mut file| file.write_all(content.as_bytes()))
+                    .with_context(|| format!("unable to write issues to {}", path.display()))?;
+            }
+            (None, StructuredIssueFormat::Ndjson) => {
+                let mut stdout = stdout().lock();
+                stdout.write_all(content.as_bytes())?;
+                stdout.flush()?;
+            }
+            (None, StructuredIssueFormat::Sarif) => {
+                bail!("SARIF logs can only be written to a file")
+            }
+        }
+        self.started = true;
+        Ok(())
+    }
+}
+
+/// Reports issues as NDJSON or SARIF, deduplicating issues between peeks of
+/// the collected issues like the [crate::issue::ConsoleUi] does.
+#[turbo_tasks::value(shared, serialization = "none", eq = "manual")]
+#[derive(Clone)]
+pub struct StructuredIssueReporter {
+    #[turbo_tasks(trace_ignore, debug_ignore)]
+    options: Arc<StructuredIssueOptions>,
+
+    #[turbo_tasks(trace_ignore, debug_ignore)]
+    state: Arc<Mutex<ReporterState>>,
+}
+
+impl PartialEq for StructuredIssueReporter {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.options, &other.options)
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl StructuredIssueReporterVc {
+    #[turbo_tasks::function]
+    pub fn new(options: TransientInstance<StructuredIssueOptions>) -> Self {
+        StructuredIssueReporter {
+            options: Arc::new((*options).clone()),
+            state: Default::default(),
+        }
+        .cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl IssueReporter for StructuredIssueReporter {
+    #[turbo_tasks::function]
+    async fn report_issues(
+        &self,
+        issues: TransientInstance<ReadRef<CapturedIssues>>,
+        source: TransientValue<RawVc>,
+    ) -> Result<BoolVc> {
+        let issues = issues
+            .iter_with_shortest_path()
+            .map(|(issue, path)| async move {
+                let plain_issue = issue.into_plain(path);
+                let id = plain_issue.internal_hash(false).await?;
+                Ok((plain_issue.await?, *id))
+            })
+            .try_join()
+            .await?;
+        let options = &*self.options;
+        let issue_rules = &options.log_options.issue_rules;
+        let has_fatal = issues
+            .iter()
+            .any(|(issue, _)| issue_rules.severity(issue) == Some(IssueSeverity::Fatal));
+
+        let mut state = self.state.lock().unwrap();
+        let issue_ids = issues.iter().map(|(_, id)| *id).collect::<HashSet<_>>();
+        let mut new_ids = state.seen.new_ids(source.into_value(), issue_ids);
+
+        let mut new_issues = Vec::new();
+        for (issue, id) in &issues {
+            if !new_ids.remove(id) {
+                continue;
+            }
+            let Some(severity) = issue_rules.severity(issue) else {
+                continue;
+            };
+            if severity > options.log_options.log_level {
+                continue;
+            }
+            let mut structured_issue = StructuredIssue::new(issue, &options.log_options);
+            structured_issue.severity = severity;
+            state.issues.insert(*id, structured_issue);
+            new_issues.push(*id);
+        }
+        let issue_count = state.issues.len();
+        let ReporterState { seen, issues, .. } = &mut *state;
+        issues.retain(|id, _| seen.is_seen(*id));
+        let removed_issues = issue_count != issues.len();
+        state.write(options, &new_issues, removed_issues)?;
+
+        Ok(BoolVc::cell(has_fatal))
+    }
+}
+
+/// An issue as written to NDJSON. Lines and columns are one-based.
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+struct StructuredIssue {
+    #[serde(serialize_with = "serialize_severity")]
+    severity: IssueSeverity,
+    category: String,
+    context: String,
+    title: String,
+    description: String,
+    detail: String,
+    documentation_link: String,
+    source: Option<StructuredIssueSource>,
+    sub_issues: Vec<StructuredIssue>,
+}
+
+#[derive(Serialize, Clone, Debug)]
+struct StructuredIssueSource {
+    path: String,
+    start: StructuredSourcePos,
+    end: StructuredSourcePos,
+}
+
+#[derive(Serialize, Clone, Copy, Debug)]
+struct StructuredSourcePos {
+    line: usize,
+    column: usize,
+}
+
+impl StructuredIssue {
+    fn new(issue: &PlainIssue, options: &LogOptions) -> Self {
+        let relative = |path: &str| {
+            make_relative_to_cwd(path, &options.project_dir, &options.current_dir).into_owned()
+        };
+        StructuredIssue {
+            severity: issue.severity,
+            category: issue.category.clone(),
+            context: relative(&issue.context),
+            title: issue.title.clone(),
+            description: issue.description.clone(),
+            detail: issue.detail.clone(),
+            documentation_link: issue.documentation_link.clone(),
+            source: issue.source.as_ref().map(|source| StructuredIssueSource {
+                path: relative(&source.asset.ident),
+                start: StructuredSourcePos {
+                    line: source.start.line + 1,
+                    column: source.start.column + 1,
+                },
+                end: StructuredSourcePos {
+                    line: source.end.line + 1,
+                    column: source.end.column + 1,
+                },
+            }),
+            sub_issues: issue
+                .sub_issues
+                .iter()
+                .map(|issue| StructuredIssue::new(issue, options))
+                .collect(),
+        }
+    }
+}
+
+fn serialize_severity<S: Serializer>(
+    severity: &IssueSeverity,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(severity.as_str())
+}
+
+/// Creates a SARIF 2.1.0 log with a result for each issue. The categories of
+/// the issues are the rules.
+fn sarif_log<'a>(tool_name: &str, issues: impl Iterator<Item = &'a StructuredIssue>) -> JsonValue {
+    let mut rules = BTreeSet::new();
+    let results = issues
+        .map(|issue| {
+            rules.insert(issue.category.as_str());
+            let mut message = issue.title.clone();
+            if !issue.description.is_empty() {
+                message.push_str("\n\n");
+                message.push_str(&issue.description);
+            }
+            let location = match &issue.source {
+                Some(source) => json!({
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": path_to_uri(&source.path) },
+                        "region": {
+                            "startLine": source.start.line,
+                            "startColumn": source.start.column,
+                            "endLine": source.end.line,
+                            "endColumn": source.end.column,
+                        },
+                    },
+                }),
+                None => json!({
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": path_to_uri(&issue.context) },
+                    },
+                }),
+            };
+            json!({
+                "ruleId": issue.category,
+                "level": sarif_level(issue.severity),
+                "message": { "text": message },
+                "locations": [location],
+                "properties": {
+                    "severity": issue.severity.as_str(),
+                    "detail": issue.detail,
+                    "documentationLink": issue.documentation_link,
+                },
+            })
+        })
+        .collect::<Vec<_>>();
+    json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": tool_name,
+                    "rules": rules
+                        .into_iter()
+                        .map(|rule| json!({ "id": rule }))
+                        .collect::<Vec<_>>(),
+                },
+            },
+            "results": results,
+        }],
+    })
+}
+
+fn sarif_level(severity: IssueSeverity) -> &'static str {
+    match severity {
+        IssueSeverity::Bug | IssueSeverity::Fatal | IssueSeverity::Error => "error",
+        IssueSeverity::Warning => "warning",
+        IssueSeverity::Hint
+        | IssueSeverity::Note
+        | IssueSeverity::Suggestion
+        | IssueSeverity::Info => "note",
+    }
+}
+
+/// Converts a path to a relative URI reference, escaping the characters which
+/// occur in paths of virtual file systems.
+fn path_to_uri(path: &str) -> String {
+    path.replace('\\', "/")
+        .replace('%', "%25")
+        .replace(' ', "%20")
+        .replace('[', "%5B")
+        .replace(']', "%5D")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::issue_rules::IssueRules;
+
+    fn options(format: StructuredIssueFormat, output: &Path) -> StructuredIssueOptions {
+        StructuredIssueOptions {
+            format,
+            output: Some(output.to_path_buf()),
+            tool_name: "test".to_string(),
+            log_options: LogOptions {
+                current_dir: PathBuf::new(),
+                project_dir: PathBuf::new(),
+                show_all: false,
+                log_detail: false,
+                log_level: IssueSeverity::Info,
+                issue_rules: IssueRules::default(),
+            },
+        }
+    }
+
+    fn issue(title: &str, line: usize) -> StructuredIssue {
+        StructuredIssue {
+            severity: IssueSeverity::Error,
+            category: "parse".to_string(),
+            context: "[project]/src/index.js".to_string(),
+            title: title.to_string(),
+            description: String::new(),
+            detail: String::new(),
+            documentation_link: String::new(),
+            source: Some(StructuredIssueSource {
+                path: "[project]/src/index.js".to_string(),
+                start: StructuredSourcePos { line, column: 1 },
+                end: StructuredSourcePos { line, column: 5 },
+            }),
+            sub_issues: Vec::new(),
+        }
+    }
+
+    fn read_lines(path: &Path) -> Vec<JsonValue> {
+        fs::read_to_string(path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn writes_ndjson_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let output = dir.path().join("issues.ndjson");
+        fs::write(&output, "from a previous run\n").unwrap();
+        let options = options(StructuredIssueFormat::Ndjson, &output);
+        let mut state = ReporterState::default();
+
+        state.issues.insert(1, issue("first", 1));
+        state.issues.insert(2, issue("second", 2));
+        state.write(&options, &[1, 2], false).unwrap();
+        let lines = read_lines(&output);
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines[0],
+            json!({
+                "severity": "error",
+                "category": "parse",
+                "context": "[project]/src/index.js",
+                "title": "first",
+                "description": "",
+                "detail": "",
+                "documentationLink": "",
+                "source": {
+                    "path": "[project]/src/index.js",
+                    "start": { "line": 1, "column": 1 },
+                    "end": { "line": 1, "column": 5 },
+                },
+                "subIssues": [],
+            })
+        );
+        assert_eq!(lines[1]["title"], "second");
+
+        // Later reports only append the new issues.
+        state.issues.insert(3, issue("third", 3));
+        state.write(&options, &[3], false).unwrap();
+        let titles = read_lines(&output)
+            .iter()
+            .map(|line| line["title"].as_str().unwrap().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(titles, ["first", "second", "third"]);
+    }
+
+    #[test]
+    fn writes_sarif_logs() {
+        let dir = tempfile::tempdir().unwrap();
+        let output = dir.path().join("issues.sarif");
+        let options = options(StructuredIssueFormat::Sarif, &output);
+        let mut state = ReporterState::default();
+
+        state.issues.insert(1, issue("unexpected token", 3));
+        state.write(&options, &[1], false).unwrap();
+        let log: JsonValue = serde_json::from_str(&fs::read_to_string(&output).unwrap()).unwrap();
+        assert_eq!(
+            log,
+            json!({
+                "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+                "version": "2.1.0",
+                "runs": [{
+                    "tool": {
+                        "driver": {
+                            "name": "test",
+                            "rules": [{ "id": "parse" }],
+                        },
+                    },
+                    "results": [{
+                        "ruleId": "parse",
+                        "level": "error",
+                        "message": { "text": "unexpected token" },
+                        "locations": [{
+                            "physicalLocation": {
+                                "artifactLocation": { "uri": "%5Bproject%5D/src/index.js" },
+                                "region": {
+                                    "startLine": 3,
+                                    "startColumn": 1,
+                                    "endLine": 3,
+                                    "endColumn": 5,
+                                },
+                            },
+                        }],
+                        "properties": {
+                            "severity": "error",
+                            "detail": "",
+                            "documentationLink": "",
+                        },
+                    }],
+                }],
+            })
+        );
+
+        // The log is rewritten as a whole when issues are removed.
+        state.issues.clear();
+        state.write(&options, &[], true).unwrap();
+        let log: JsonValue = serde_json::from_str(&fs::read_to_string(&output).unwrap()).unwrap();
+        assert_eq!(log["runs"][0]["results"], json!([]));
+    }
+
+    #[test]
+    fn requires_a_file_for_sarif() {
+        let output = Path::new("issues.sarif");
+        assert_eq!(IssueFormat::Console.structured(Some(output)).unwrap(), None);
+        assert_eq!(
+            IssueFormat::Ndjson.structured(None).unwrap(),
+            Some(StructuredIssueFormat::Ndjson)
+        );
+        assert_eq!(
+            IssueFormat::Sarif.structured(Some(output)).unwrap(),
+            Some(StructuredIssueFormat::Sarif)
+        );
+        assert!(IssueFormat::Sarif.structured(None).is_err());
+    }
+}
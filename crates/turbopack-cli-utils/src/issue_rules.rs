@@ -0,0 +1,411 @@
+//! Rules to ignore issues or to change their severity, e.g. to hide known
+//! warnings from third-party packages or to treat resolve errors as fatal.
+
+use std::{collections::BTreeMap, str::FromStr};
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use turbo_tasks::{primitives::Regex, trace::TraceRawVcs};
+use turbo_tasks_fs::glob::Glob;
+use turbopack_core::issue::{IssueSeverity, PlainIssue};
+
+use crate::issue::IssueSeverityCliOption;
+
+/// What happens to an issue matched by an [IssueRule].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs)]
+pub enum IssueRuleAction {
+    /// The issue is not reported.
+    Ignore,
+    /// The issue is reported with this severity instead.
+    Severity(IssueSeverity),
+}
+
+impl FromStr for IssueRuleAction {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if s == "ignore" {
+            Ok(IssueRuleAction::Ignore)
+        } else {
+            Ok(IssueRuleAction::Severity(
+                IssueSeverityCliOption::from_str(s)
+                    .with_context(|| anyhow!("expected `ignore` or a severity, got `{s}`"))?
+                    .0,
+            ))
+        }
+    }
+}
+
+/// Matches issues by category, title and context path. An issue matches when
+/// all of the provided matchers match.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs)]
+pub struct IssueRule {
+    /// The exact category, e.g. `resolve`.
+    category: Option<String>,
+    /// A regular expression that is searched for in the title.
+    title: Option<Regex>,
+    /// A glob that matches the context path relative to the project directory,
+    /// e.g. `**/node_modules/**`.
+    path: Option<Glob>,
+    action: IssueRuleAction,
+}
+
+/// The format of a rule in an issue rules file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct IssueRuleConfig {
+    category: Option<String>,
+    title: Option<String>,
+    path: Option<String>,
+    severity: String,
+}
+
+impl IssueRule {
+    fn new(
+        category: Option<String>,
+        title: Option<&str>,
+        path: Option<&str>,
+        action: IssueRuleAction,
+    ) -> Result<Self> {
+        Ok(IssueRule {
+            category,
+            title: title
+                .map(|title| {
+                    regex::Regex::new(title)
+                        .map(Regex)
+                        .with_context(|| anyhow!("invalid title regex `{title}`"))
+                })
+                .transpose()?,
+            path: path.map(Glob::parse).transpose()?,
+            action,
+        })
+    }
+
+    fn matches(&self, category: &str, title: &str, context: &str) -> bool {
+        if let Some(expected) = &self.category {
+            if expected != category {
+                return false;
+            }
+        }
+        if let Some(regex) = &self.title {
+            if !regex.is_match(title) {
+                return false;
+            }
+        }
+        if let Some(path) = &self.path {
+            let context = context.strip_prefix("[project]/").unwrap_or(context);
+            if !path.execute(context) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+const KEYS: [&str; 4] = ["category", "title", "path", "severity"];
+
+/// Splits a rule into its `key=value` pairs. Only commas which are followed
+/// by a key separate pairs, so values like `{a,b}` globs may contain commas.
+fn split_pairs(s: &str) -> Vec<&str> {
+    let mut pairs = Vec::new();
+    let mut start = 0;
+    for (index, _) in s.match_indices(',') {
+        let rest = &s[index + 1..];
+        let is_key = KEYS.iter().any(|key| {
+            rest.trim_start()
+                .strip_prefix(key)
+                .map_or(false, |rest| rest.starts_with('='))
+        });
+        if is_key {
+            pairs.push(&s[start..index]);
+            start = index + 1;
+        }
+    }
+    pairs.push(&s[start..]);
+    pairs
+}
+
+/// Parses a rule in the form of `category=resolve,path=**/node_modules/**,
+/// severity=ignore`, where `severity` is either `ignore` or the severity to
+/// report matching issues with.
+impl FromStr for IssueRule {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut category = None;
+        let mut title = None;
+        let mut path = None;
+        let mut action = None;
+        for part in split_pairs(s) {
+            let Some((key, value)) = part.split_once('=') else {
+                bail!("invalid issue rule `{s}`, expected comma-separated `key=value` pairs");
+            };
+            match key.trim() {
+                "category" => category = Some(value.to_string()),
+                "title" => title = Some(value),
+                "path" => path = Some(value),
+                "severity" => action = Some(value.parse()?),
+                key => bail!(
+                    "unknown key `{key}` in issue rule `{s}`, expected `category`, `title`, \
+                     `path` or `severity`"
+                ),
+            }
+        }
+        let Some(action) = action else {
+            bail!("issue rule `{s}` is missing a `severity`");
+        };
+        IssueRule::new(category, title, path, action)
+    }
+}
+
+/// A list of [IssueRule]s. The first rule matching an issue decides what
+/// happens to it.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs)]
+pub struct IssueRules(Vec<IssueRule>);
+
+impl IssueRules {
+    pub fn new(rules: Vec<IssueRule>) -> Self {
+        IssueRules(rules)
+    }
+
+    /// Parses an issue rules file, which is a JSON list of rules like
+    /// `{ "category": "resolve", "path": "**/node_modules/**", "severity":
+    /// "ignore" }`.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let configs: Vec<IssueRuleConfig> = serde_json::from_str(json)?;
+        configs
+            .into_iter()
+            .map(|config| {
+                IssueRule::new(
+                    config.category,
+                    config.title.as_deref(),
+                    config.path.as_deref(),
+                    config.severity.parse()?,
+                )
+            })
+            .collect::<Result<Vec<_>>>()
+            .map(IssueRules)
+    }
+
+    pub fn extend(&mut self, rules: IssueRules) {
+        self.0.extend(rules.0);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the severity to report the issue with, or `None` when the issue
+    /// is ignored.
+    pub fn severity(&self, issue: &PlainIssue) -> Option<IssueSeverity> {
+        self.severity_of(
+            &issue.category,
+            &issue.title,
+            &issue.context,
+            issue.severity,
+        )
+    }
+
+    fn severity_of(
+        &self,
+        category: &str,
+        title: &str,
+        context: &str,
+        severity: IssueSeverity,
+    ) -> Option<IssueSeverity> {
+        match self
+            .0
+            .iter()
+            .find(|rule| rule.matches(category, title, context))
+        {
+            Some(IssueRule {
+                action: IssueRuleAction::Ignore,
+                ..
+            }) => None,
+            Some(IssueRule {
+                action: IssueRuleAction::Severity(severity),
+                ..
+            }) => Some(*severity),
+            None => Some(severity),
+        }
+    }
+}
+
+/// Counts the issues which are ignored by [IssueRules] per category, to
+/// report how many issues were suppressed.
+#[derive(Debug, Default)]
+pub(crate) struct SuppressedIssues(BTreeMap<String, usize>);
+
+impl SuppressedIssues {
+    pub(crate) fn add(&mut self, category: &str) {
+        *self.0.entry(category.to_string()).or_default() += 1;
+    }
+
+    /// A summary like `3 issues suppressed by issue rules: 2 [resolve], 1
+    /// [parse]`, or `None` when no issue was suppressed.
+    pub(crate) fn summary(&self) -> Option<String> {
+        if self.0.is_empty() {
+            return None;
+        }
+        let count = self.0.values().sum::<usize>();
+        let categories = self
+            .0
+            .iter()
+            .map(|(category, count)| format!("{count} [{category}]"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        Some(format!(
+            "{count} {} suppressed by issue rules: {categories}",
+            if count == 1 { "issue" } else { "issues" }
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(rules: &[&str]) -> IssueRules {
+        IssueRules::new(rules.iter().map(|rule| rule.parse().unwrap()).collect())
+    }
+
+    #[test]
+    fn parses_rules() {
+        let rule: IssueRule = "category=resolve, path=**/node_modules/**,severity=ignore"
+            .parse()
+            .unwrap();
+        assert_eq!(rule.category.as_deref(), Some("resolve"));
+        assert!(rule.title.is_none());
+        assert!(rule.path.is_some());
+        assert_eq!(rule.action, IssueRuleAction::Ignore);
+
+        let rule: IssueRule = "title=^Module not found,severity=warning".parse().unwrap();
+        assert_eq!(
+            rule.action,
+            IssueRuleAction::Severity(IssueSeverity::Warning)
+        );
+
+        assert!("category=resolve".parse::<IssueRule>().is_err());
+        assert!("category=resolve,severity=loud"
+            .parse::<IssueRule>()
+            .is_err());
+        assert!("kind=resolve,severity=ignore".parse::<IssueRule>().is_err());
+        assert!("title=(,severity=ignore".parse::<IssueRule>().is_err());
+    }
+
+    #[test]
+    fn keeps_commas_in_values() {
+        assert_eq!(
+            split_pairs("path={src,lib}/**,title=a{1,2},severity=ignore"),
+            ["path={src,lib}/**", "title=a{1,2}", "severity=ignore"]
+        );
+        let rules = rules(&["path={src,lib}/**,severity=ignore"]);
+        let severity = |context| rules.severity_of("parse", "", context, IssueSeverity::Error);
+        assert_eq!(severity("[project]/src/index.js"), None);
+        assert_eq!(severity("[project]/lib/index.js"), None);
+        assert_eq!(
+            severity("[project]/test/index.js"),
+            Some(IssueSeverity::Error)
+        );
+    }
+
+    #[test]
+    fn parses_config_files() {
+        let rules = IssueRules::from_json(
+            r#"[
+                { "category": "resolve", "path": "**/node_modules/**", "severity": "ignore" },
+                { "title": "deprecated", "severity": "info" }
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(
+            rules,
+            IssueRules::new(vec![
+                "category=resolve,path=**/node_modules/**,severity=ignore"
+                    .parse()
+                    .unwrap(),
+                "title=deprecated,severity=info".parse().unwrap(),
+            ])
+        );
+
+        assert!(IssueRules::from_json(r#"[{ "category": "resolve" }]"#).is_err());
+        assert!(IssueRules::from_json(r#"[{ "kind": "resolve", "severity": "ignore" }]"#).is_err());
+    }
+
+    #[test]
+    fn first_matching_rule_applies() {
+        let rules = rules(&[
+            "category=resolve,path=**/node_modules/**,severity=ignore",
+            "category=resolve,severity=fatal",
+            "title=deprecated,severity=info",
+        ]);
+        let severity = |category, title, context| {
+            rules.severity_of(category, title, context, IssueSeverity::Warning)
+        };
+        assert_eq!(
+            severity(
+                "resolve",
+                "Module not found",
+                "[project]/node_modules/a/index.js"
+            ),
+            None
+        );
+        assert_eq!(
+            severity("resolve", "Module not found", "[project]/src/index.js"),
+            Some(IssueSeverity::Fatal)
+        );
+        assert_eq!(
+            severity("parse", "`foo` is deprecated", "[project]/src/index.js"),
+            Some(IssueSeverity::Info)
+        );
+        assert_eq!(
+            severity("parse", "Unexpected token", "[project]/src/index.js"),
+            Some(IssueSeverity::Warning)
+        );
+    }
+
+    #[test]
+    fn matches_paths_relative_to_the_project() {
+        let rules = rules(&[
+            "path=src/*.js,severity=ignore",
+            "title=^Module,severity=info",
+        ]);
+        let severity =
+            |title, context| rules.severity_of("parse", title, context, IssueSeverity::Error);
+        assert_eq!(severity("", "[project]/src/index.js"), None);
+        assert_eq!(
+            severity("", "[project]/src/nested/index.js"),
+            Some(IssueSeverity::Error)
+        );
+        assert_eq!(
+            severity("", "[project]/other/src/index.js"),
+            Some(IssueSeverity::Error)
+        );
+        // The title regex is searched for, and anchors apply to the whole title.
+        assert_eq!(
+            severity("Module not found", "[project]/lib/index.js"),
+            Some(IssueSeverity::Info)
+        );
+        assert_eq!(
+            severity("Error: Module not found", "[project]/lib/index.js"),
+            Some(IssueSeverity::Error)
+        );
+    }
+
+    #[test]
+    fn summarizes_suppressed_issues() {
+        let mut suppressed = SuppressedIssues::default();
+        assert_eq!(suppressed.summary(), None);
+        suppressed.add("resolve");
+        assert_eq!(
+            suppressed.summary().as_deref(),
+            Some("1 issue suppressed by issue rules: 1 [resolve]")
+        );
+        suppressed.add("parse");
+        suppressed.add("resolve");
+        assert_eq!(
+            suppressed.summary().as_deref(),
+            Some("3 issues suppressed by issue rules: 1 [parse], 2 [resolve]")
+        );
+    }
+}
@@ -5,6 +5,8 @@
 
 pub mod exit;
 pub mod issue;
+pub mod issue_format;
+pub mod issue_rules;
 pub mod raw_trace;
 pub mod runtime_entry;
 pub mod source_context;
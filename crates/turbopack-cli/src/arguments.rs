@@ -4,7 +4,9 @@ use std::{
 };
 
 use clap::{Args, Parser};
-use turbopack_cli_utils::issue::IssueSeverityCliOption;
+use turbopack_cli_utils::{
+    issue::IssueSeverityCliOption, issue_format::IssueFormat, issue_rules::IssueRule,
+};
 
 #[derive(Debug, Parser)]
 #[clap(author, version, about, long_about = None)]
@@ -46,6 +48,31 @@ pub struct CommonArguments {
     #[clap(long)]
     pub log_detail: bool,
 
+    /// The format to report issues in. `ndjson` and `sarif` are meant for CI
+    /// annotations and code scanning.
+    #[clap(long, value_enum, default_value_t = IssueFormat::Console)]
+    pub issue_format: IssueFormat,
+
+    /// The file to write `ndjson` and `sarif` issues to. Required with these
+    /// formats, as the dev server logs to stdout.
+    #[clap(long, value_parser)]
+    pub issue_output: Option<PathBuf>,
+
+    /// Ignores issues or changes their severity, e.g.
+    /// `--issue-rule category=resolve,path=**/node_modules/**,severity=ignore`.
+    /// Issues are matched by `category`, a `title` regex and a context `path`
+    /// glob, and `severity` is either `ignore` or the severity to report them
+    /// with. Values may contain commas, like `path={src,lib}/**`. Can be
+    /// repeated, and the first matching rule applies.
+    #[clap(long = "issue-rule", value_parser)]
+    pub issue_rules: Vec<IssueRule>,
+
+    /// A JSON file with a list of issue rules, e.g.
+    /// `[{ "category": "resolve", "path": "**/node_modules/**", "severity":
+    /// "ignore" }]`. These apply after the rules passed by `--issue-rule`.
+    #[clap(long, value_parser)]
+    pub issue_rules_config: Option<PathBuf>,
+
     /// Whether to enable full task stats recording in Turbo Engine.
     #[clap(long)]
     pub full_stats: bool,
@@ -54,6 +81,16 @@ pub struct CommonArguments {
     /// MB.
     #[clap(long)]
     pub memory_limit: Option<usize>,
+
+    /// Record task executions and write them as Chrome trace events to the
+    /// provided file on exit.
+    #[clap(long, value_parser, env = "TURBO_TASKS_CHROME_TRACE")]
+    pub chrome_trace: Option<PathBuf>,
+
+    /// Record task executions and write them as pprof profile aggregated per
+    /// function to the provided file on exit.
+    #[clap(long, value_parser, env = "TURBO_TASKS_PPROF")]
+    pub pprof: Option<PathBuf>,
 }
 
 #[derive(Debug, Args)]
@@ -73,6 +110,70 @@ pub struct DevArguments {
     #[clap(short = 'H', long, value_parser, default_value = "0.0.0.0")]
     pub hostname: IpAddr,
 
+    /// An entry point of the application, relative to the project directory.
+    /// Can be repeated. HTML pages are served with the scripts, stylesheets
+    /// and assets they reference bundled. Defaults to `index.html` if it
+    /// exists, and to `src/index` otherwise.
+    #[clap(long = "entry", value_parser)]
+    pub entries: Vec<String>,
+
+    /// Forwards requests to a path prefix to another server, e.g.
+    /// `--proxy /api=http://localhost:8080`. Can be repeated.
+    #[clap(long = "proxy", value_parser)]
+    pub proxies: Vec<String>,
+
+    /// A JSON file with a list of proxies, e.g.
+    /// `[{ "prefix": "/api", "target": "http://localhost:8080", "ws": true }]`.
+    /// Besides `prefix` and `target`, a proxy supports `stripPrefix`,
+    /// `changeOrigin`, `requestHeaders` and `responseHeaders` as lists of
+    /// `[name, value]` pairs, and `ws` to pass websocket upgrades through.
+    #[clap(long, value_parser)]
+    pub proxy_config: Option<PathBuf>,
+
+    /// Serve the application over HTTPS. Unless `--https-key` and
+    /// `--https-cert` are provided, a self-signed certificate is generated
+    /// and cached in `.turbopack/certificates` of the project directory.
+    #[clap(long)]
+    pub experimental_https: bool,
+
+    /// The PEM encoded private key to serve HTTPS with.
+    #[clap(long, value_parser, requires = "experimental_https")]
+    pub https_key: Option<PathBuf>,
+
+    /// The PEM encoded certificate chain to serve HTTPS with.
+    #[clap(long, value_parser, requires = "experimental_https")]
+    pub https_cert: Option<PathBuf>,
+
+    /// An additional host name or IP address the self-signed certificate is
+    /// valid for, e.g. to open the application from another device on the
+    /// network. Can be repeated.
+    #[clap(
+        long = "https-hostname",
+        value_parser,
+        requires = "experimental_https",
+        conflicts_with_all = ["https_key", "https_cert"]
+    )]
+    pub https_hostnames: Vec<String>,
+
+    /// Environment variables starting with this prefix are inlined into
+    /// client code as `process.env.NAME` and `import.meta.env.NAME`, e.g.
+    /// `--public-env-prefix PUBLIC_`. References to the other variables of
+    /// the dotenv files in client code are reported. Can be repeated.
+    #[clap(long = "public-env-prefix", value_parser)]
+    pub public_env_prefixes: Vec<String>,
+
+    /// Selects the dotenv files to load, e.g. `--mode staging` loads
+    /// `.env.staging.local`, `.env.local`, `.env.staging` and `.env`.
+    /// Defaults to `NODE_ENV`, or `development` when it's not set.
+    #[clap(long, value_parser)]
+    pub mode: Option<String>,
+
+    /// Also write the bundle analysis, which is served at
+    /// `/__turbopack_analyze__`, as `stats.json` and `treemap.html` into this
+    /// directory, and update them when the application changes.
+    #[clap(long, value_parser)]
+    pub bundle_analysis: Option<PathBuf>,
+
     /// Compile all, instead of only compiling referenced assets when their
     /// parent asset is requested
     #[clap(long)]
@@ -82,6 +183,11 @@ pub struct DevArguments {
     #[clap(long)]
     pub no_open: bool,
 
+    /// Record why tasks are recomputed. The chains of invalidations can be
+    /// inspected at /__turbo_tasks__/invalidations.
+    #[clap(long)]
+    pub trace_invalidations: bool,
+
     // ==
     // = Inherited options from next-dev, need revisit later.
     // ==
@@ -0,0 +1,120 @@
+use std::{
+    fs,
+    io::Write,
+    net::IpAddr,
+    path::Path,
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+use anyhow::{bail, Context, Result};
+use owo_colors::OwoColorize;
+use turbopack_dev_server::tls::{self_signed_certificate, server_config, ServerConfig};
+
+/// How long a generated certificate is valid.
+const VALIDITY: Duration = Duration::from_secs(365 * 24 * 60 * 60);
+
+/// How long a generated certificate is reused before a new one is generated,
+/// leaving a margin until it expires.
+const MAX_AGE: Duration = Duration::from_secs(300 * 24 * 60 * 60);
+
+/// Creates the TLS configuration to serve HTTPS with. Uses the provided key
+/// and certificate files, or a self-signed certificate cached in
+/// `.turbopack/certificates` of the project directory, which is also valid
+/// for `extra_names`.
+pub fn https_config(
+    project_dir: &Path,
+    hostname: IpAddr,
+    extra_names: &[String],
+    key: Option<&Path>,
+    cert: Option<&Path>,
+) -> Result<Arc<ServerConfig>> {
+    let (cert_pem, key_pem) = match (cert, key) {
+        (Some(cert), Some(key)) => (read(cert)?, read(key)?),
+        (None, None) => self_signed(
+            &project_dir.join(".turbopack/certificates"),
+            hostname,
+            extra_names,
+        )?,
+        _ => bail!("--https-key and --https-cert must be provided together"),
+    };
+    server_config(&cert_pem, &key_pem)
+}
+
+fn read(path: &Path) -> Result<Vec<u8>> {
+    fs::read(path).with_context(|| format!("unable to read {}", path.display()))
+}
+
+fn self_signed(dir: &Path, hostname: IpAddr, extra_names: &[String]) -> Result<(Vec<u8>, Vec<u8>)> {
+    let mut names = vec![
+        "localhost".to_string(),
+        "127.0.0.1".to_string(),
+        "::1".to_string(),
+    ];
+    if !hostname.is_unspecified() && !hostname.is_loopback() {
+        names.push(hostname.to_string());
+    }
+    for name in extra_names {
+        if !names.contains(name) {
+            names.push(name.clone());
+        }
+    }
+    // The cached certificate is keyed by the names it's valid for. Colons
+    // aren't allowed in file names on Windows.
+    let name = if names.len() == 3 {
+        "localhost".to_string()
+    } else {
+        names[3..].join("_").replace(':', "-")
+    };
+    let cert_path = dir.join(format!("{name}.pem"));
+    let key_path = dir.join(format!("{name}-key.pem"));
+
+    if let (Ok(cert_pem), Ok(key_pem), true) = (
+        fs::read(&cert_path),
+        fs::read(&key_path),
+        is_fresh(&cert_path),
+    ) {
+        return Ok((cert_pem, key_pem));
+    }
+
+    let certificate = self_signed_certificate(&names, VALIDITY)?;
+    fs::create_dir_all(dir).with_context(|| format!("unable to create {}", dir.display()))?;
+    write(&cert_path, &certificate.cert_pem)?;
+    write_private(&key_path, &certificate.private_key_pem)?;
+    println!(
+        "{} - generated a self-signed certificate for {} at {}",
+        "info ".cyan(),
+        names.join(", "),
+        cert_path.display()
+    );
+    Ok((
+        certificate.cert_pem.into_bytes(),
+        certificate.private_key_pem.into_bytes(),
+    ))
+}
+
+/// Whether the certificate at `path` was generated recently enough to be
+/// reused.
+fn is_fresh(path: &Path) -> bool {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .map_or(false, |age| age < MAX_AGE)
+}
+
+fn write(path: &Path, content: &str) -> Result<()> {
+    fs::write(path, content).with_context(|| format!("unable to write {}", path.display()))
+}
+
+/// Writes the private key so that only the current user can read it.
+fn write_private(path: &Path, content: &str) -> Result<()> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options
+        .open(path)
+        .and_then(|mut file| file.write_all(content.as_bytes()))
+        .with_context(|| format!("unable to write {}", path.display()))
+}
@@ -1,28 +1,35 @@
 use std::{
     collections::HashSet,
     env::current_dir,
+    fs::File,
     future::{join, Future},
-    io::{stdout, Write},
+    io::{stdout, BufWriter, Write},
     net::{IpAddr, SocketAddr},
-    path::{PathBuf, MAIN_SEPARATOR},
+    path::{Path, PathBuf, MAIN_SEPARATOR},
     sync::Arc,
     time::{Duration, Instant},
 };
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use dunce::canonicalize;
 use owo_colors::OwoColorize;
 use turbo_tasks::{
     primitives::StringVc,
     util::{FormatBytes, FormatDuration},
-    StatsType, TransientInstance, TurboTasks, TurboTasksBackendApi, UpdateInfo, Value,
+    NothingVc, StatsType, TransientInstance, TurboTasks, TurboTasksBackendApi, UpdateInfo, Value,
 };
-use turbo_tasks_fs::{DiskFileSystemVc, FileSystem, FileSystemVc};
+use turbo_tasks_fs::{DiskFileSystemVc, FileSystem, FileSystemPathVc, FileSystemVc};
 use turbo_tasks_malloc::TurboMalloc;
 use turbo_tasks_memory::MemoryBackend;
 use turbopack::evaluate_context::node_build_environment;
-use turbopack_cli_utils::issue::{ConsoleUiVc, LogOptions};
+use turbopack_cli_utils::{
+    exit::ExitGuard,
+    issue::{ConsoleUiVc, LogOptions},
+    issue_format::{IssueFormat, StructuredIssueOptions, StructuredIssueReporterVc},
+    issue_rules::IssueRules,
+};
 use turbopack_core::{
+    asset::AssetsSetVc,
     environment::ServerAddr,
     issue::{IssueReporterVc, IssueSeverity},
     resolve::{parse::RequestVc, pattern::QueryMapVc},
@@ -30,20 +37,29 @@ use turbopack_core::{
 };
 use turbopack_dev::DevChunkingContextVc;
 use turbopack_dev_server::{
+    analyze::{write_bundle_analysis, BundleAnalysisSourceVc, BundleEntrypointsVc},
     introspect::IntrospectionSource,
     source::{
-        combined::CombinedContentSourceVc, router::PrefixedRouterContentSourceVc,
-        source_maps::SourceMapContentSourceVc, static_assets::StaticAssetsContentSourceVc,
+        combined::CombinedContentSourceVc,
+        proxy::{ProxyContentSourceVc, ProxyOptions},
+        router::PrefixedRouterContentSourceVc,
+        source_maps::SourceMapContentSourceVc,
+        static_assets::StaticAssetsContentSourceVc,
         ContentSourceVc,
     },
+    tls::ServerConfig,
     DevServer, DevServerBuilder,
 };
 use turbopack_env::dotenv::load_env;
 use turbopack_node::execution_context::ExecutionContextVc;
 
-use self::web_entry_source::create_web_entry_source;
+use self::{
+    https::https_config,
+    web_entry_source::{create_web_entry_pages, create_web_entry_source},
+};
 use crate::arguments::DevArguments;
 
+pub(crate) mod https;
 pub(crate) mod turbo_tasks_viz;
 pub(crate) mod web_entry_source;
 
@@ -58,15 +74,21 @@ pub struct TurbopackDevServerBuilder {
     project_dir: String,
     root_dir: String,
     entry_requests: Vec<EntryRequest>,
+    proxies: Vec<ProxyOptions>,
     eager_compile: bool,
     hostname: Option<IpAddr>,
     issue_reporter: Option<Box<dyn IssueReporterProvider>>,
     port: Option<u16>,
+    https: Option<Arc<ServerConfig>>,
     browserslist_query: String,
+    public_env_prefixes: Vec<String>,
+    mode: Option<String>,
     log_level: IssueSeverity,
     show_all: bool,
     log_detail: bool,
+    issue_rules: IssueRules,
     allow_retry: bool,
+    bundle_analysis_dir: Option<String>,
 }
 
 impl TurbopackDevServerBuilder {
@@ -80,17 +102,23 @@ impl TurbopackDevServerBuilder {
             project_dir,
             root_dir,
             entry_requests: vec![],
+            proxies: vec![],
             eager_compile: false,
             hostname: None,
             issue_reporter: None,
             port: None,
+            https: None,
             browserslist_query: "last 1 Chrome versions, last 1 Firefox versions, last 1 Safari \
                                  versions, last 1 Edge versions"
                 .to_owned(),
+            public_env_prefixes: vec![],
+            mode: None,
             log_level: IssueSeverity::Warning,
             show_all: false,
             log_detail: false,
+            issue_rules: IssueRules::default(),
             allow_retry: false,
+            bundle_analysis_dir: None,
         }
     }
 
@@ -99,6 +127,12 @@ impl TurbopackDevServerBuilder {
         self
     }
 
+    /// Forwards requests matching `proxy` to another server.
+    pub fn proxy(mut self, proxy: ProxyOptions) -> TurbopackDevServerBuilder {
+        self.proxies.push(proxy);
+        self
+    }
+
     pub fn eager_compile(mut self, eager_compile: bool) -> TurbopackDevServerBuilder {
         self.eager_compile = eager_compile;
         self
@@ -114,11 +148,31 @@ impl TurbopackDevServerBuilder {
         self
     }
 
+    /// Serves HTTPS instead of HTTP.
+    pub fn https(mut self, config: Arc<ServerConfig>) -> TurbopackDevServerBuilder {
+        self.https = Some(config);
+        self
+    }
+
     pub fn browserslist_query(mut self, browserslist_query: String) -> TurbopackDevServerBuilder {
         self.browserslist_query = browserslist_query;
         self
     }
 
+    /// Inlines the environment variables starting with `prefix` into client
+    /// code.
+    pub fn public_env_prefix(mut self, prefix: String) -> TurbopackDevServerBuilder {
+        self.public_env_prefixes.push(prefix);
+        self
+    }
+
+    /// Selects the dotenv files to load, e.g. `.env.staging` for `staging`.
+    /// Defaults to `NODE_ENV`.
+    pub fn mode(mut self, mode: String) -> TurbopackDevServerBuilder {
+        self.mode = Some(mode);
+        self
+    }
+
     pub fn log_level(mut self, log_level: IssueSeverity) -> TurbopackDevServerBuilder {
         self.log_level = log_level;
         self
@@ -139,6 +193,21 @@ impl TurbopackDevServerBuilder {
         self
     }
 
+    /// Ignores issues or changes their severity, both in the console and in
+    /// the issues sent to HMR clients.
+    pub fn issue_rules(mut self, issue_rules: IssueRules) -> TurbopackDevServerBuilder {
+        self.issue_rules = issue_rules;
+        self
+    }
+
+    /// Writes the bundle analysis, which is served at `/__turbopack_analyze__`,
+    /// as `stats.json` and `treemap.html` into the directory, and keeps it up
+    /// to date.
+    pub fn bundle_analysis_dir(mut self, dir: String) -> TurbopackDevServerBuilder {
+        self.bundle_analysis_dir = Some(dir);
+        self
+    }
+
     pub fn issue_reporter(
         mut self,
         issue_reporter: Box<dyn IssueReporterProvider>,
@@ -155,7 +224,10 @@ impl TurbopackDevServerBuilder {
         loop {
             let current_port = port + attempts;
             let addr = SocketAddr::new(host, current_port);
-            let listen_result = DevServer::listen(addr);
+            let listen_result = match &self.https {
+                Some(config) => DevServer::listen_tls(addr, config.clone()),
+                None => DevServer::listen(addr),
+            };
 
             if let Err(e) = &listen_result {
                 if self.allow_retry && attempts < max_attempts {
@@ -191,7 +263,9 @@ impl TurbopackDevServerBuilder {
         let port = self.port.context("port must be set")?;
         let host = self.hostname.context("hostname must be set")?;
 
-        let server = self.find_port(host, port, 10)?;
+        let server = self
+            .find_port(host, port, 10)?
+            .issue_rules(self.issue_rules.clone());
 
         let turbo_tasks = self.turbo_tasks;
         let project_dir = self.project_dir;
@@ -200,28 +274,59 @@ impl TurbopackDevServerBuilder {
         let show_all = self.show_all;
         let log_detail = self.log_detail;
         let browserslist_query = self.browserslist_query;
+        let public_env_prefixes = self.public_env_prefixes;
+        let mode = self.mode;
         let log_args = Arc::new(LogOptions {
             current_dir: current_dir().unwrap(),
             project_dir: PathBuf::from(project_dir.clone()),
             show_all,
             log_detail,
             log_level: self.log_level,
+            issue_rules: self.issue_rules,
         });
         let entry_requests = Arc::new(self.entry_requests);
+        let proxies = Arc::new(self.proxies);
         let tasks = turbo_tasks.clone();
         let issue_provider = self.issue_reporter.unwrap_or_else(|| {
             // Initialize a ConsoleUi reporter if no custom reporter was provided
             Box::new(move || ConsoleUiVc::new(log_args.clone().into()).into())
         });
 
+        if let Some(bundle_analysis_dir) = self.bundle_analysis_dir {
+            let root_dir = root_dir.clone();
+            let project_dir = project_dir.clone();
+            let entry_requests = entry_requests.clone();
+            let browserslist_query = browserslist_query.clone();
+            let public_env_prefixes = public_env_prefixes.clone();
+            let mode = mode.clone();
+            turbo_tasks.spawn_root_task(move || {
+                let pages = web_entry_pages(
+                    root_dir.clone(),
+                    project_dir.clone(),
+                    entry_requests.clone().into(),
+                    browserslist_query.clone(),
+                    public_env_prefixes.clone(),
+                    mode.clone(),
+                );
+                let dir = bundle_analysis_fs(&bundle_analysis_dir).root();
+                Box::pin(async move {
+                    write_bundle_analysis(BundleEntrypointsVc::from_pages(pages), dir).await?;
+                    Ok(NothingVc::new().into())
+                })
+            });
+        }
+
         let source = move || {
             source(
                 root_dir.clone(),
                 project_dir.clone(),
                 entry_requests.clone().into(),
+                proxies.clone().into(),
                 eager_compile,
                 turbo_tasks.clone().into(),
                 browserslist_query.clone(),
+                public_env_prefixes.clone(),
+                mode.clone(),
             )
         };
 
@@ -244,26 +349,35 @@ async fn output_fs(project_dir: &str) -> Result<FileSystemVc> {
     Ok(disk_fs.into())
 }
 
-#[allow(clippy::too_many_arguments)]
 #[turbo_tasks::function]
-async fn source(
+fn bundle_analysis_fs(dir: &str) -> FileSystemVc {
+    DiskFileSystemVc::new("bundle analysis".to_string(), dir.to_string()).into()
+}
+
+#[turbo_tasks::function]
+fn project_path(root_dir: &str, project_dir: &str) -> FileSystemPathVc {
+    let fs = project_fs(root_dir);
+    let project_relative = project_dir.strip_prefix(root_dir).unwrap();
+    let project_relative = project_relative
+        .strip_prefix(MAIN_SEPARATOR)
+        .unwrap_or(project_relative)
+        .replace(MAIN_SEPARATOR, "/");
+    fs.root().join(&project_relative)
+}
+
+#[turbo_tasks::function]
+async fn web_entry_pages(
     root_dir: String,
     project_dir: String,
     entry_requests: TransientInstance<Vec<EntryRequest>>,
-    eager_compile: bool,
-    turbo_tasks: TransientInstance<TurboTasks<MemoryBackend>>,
     browserslist_query: String,
-) -> Result<ContentSourceVc> {
+    public_env_prefixes: Vec<String>,
+    mode: Option<String>,
+) -> Result<AssetsSetVc> {
     let output_fs = output_fs(&project_dir);
-    let fs = project_fs(&root_dir);
-    let project_relative = project_dir.strip_prefix(&root_dir).unwrap();
-    let project_relative = project_relative
-        .strip_prefix(MAIN_SEPARATOR)
-        .unwrap_or(project_relative)
-        .replace(MAIN_SEPARATOR, "/");
-    let project_path = fs.root().join(&project_relative);
+    let project_path = project_path(&root_dir, &project_dir);
 
-    let env = load_env(project_path);
+    let env = load_env(project_path, mode);
     let build_output_root = output_fs.root().join(".turbopack/build");
 
     let build_chunking_context = DevChunkingContextVc::builder(
@@ -289,15 +403,44 @@ async fn source(
         })
         .collect();
 
-    let web_source = create_web_entry_source(
+    Ok(create_web_entry_pages(
         project_path,
         execution_context,
         entry_requests,
         server_root,
         env,
-        eager_compile,
         &browserslist_query,
+        public_env_prefixes,
+    ))
+}
+
+#[allow(clippy::too_many_arguments)]
+#[turbo_tasks::function]
+async fn source(
+    root_dir: String,
+    project_dir: String,
+    entry_requests: TransientInstance<Vec<EntryRequest>>,
+    proxies: TransientInstance<Vec<ProxyOptions>>,
+    eager_compile: bool,
+    turbo_tasks: TransientInstance<TurboTasks<MemoryBackend>>,
+    browserslist_query: String,
+    public_env_prefixes: Vec<String>,
+    mode: Option<String>,
+) -> Result<ContentSourceVc> {
+    let project_path = project_path(&root_dir, &project_dir);
+    let env = load_env(project_path, mode.clone());
+    let server_root = ServerFileSystemVc::new().as_file_system().root();
+    let pages = web_entry_pages(
+        root_dir,
+        project_dir,
+        entry_requests,
+        browserslist_query,
+        public_env_prefixes,
+        mode,
     );
+    let web_source = create_web_entry_source(pages, server_root, env, eager_compile);
+    let bundle_analysis =
+        BundleAnalysisSourceVc::new(BundleEntrypointsVc::from_pages(pages)).into();
     let viz = turbo_tasks_viz::TurboTasksSource {
         turbo_tasks: turbo_tasks.into(),
     }
@@ -305,7 +448,13 @@ async fn source(
     .into();
     let static_source =
         StaticAssetsContentSourceVc::new(String::new(), project_path.join("public")).into();
-    let main_source = CombinedContentSourceVc::new(vec![static_source, web_source]);
+    let main_source = CombinedContentSourceVc::new(
+        proxies
+            .iter()
+            .map(|proxy| ProxyContentSourceVc::new(proxy.clone()).into())
+            .chain([static_source, web_source])
+            .collect(),
+    );
     let introspect = IntrospectionSource {
         roots: HashSet::from([main_source.into()]),
     }
@@ -319,6 +468,7 @@ async fn source(
             ("__turbopack__".to_string(), introspect),
             ("__turbo_tasks__".to_string(), viz),
             ("__turbopack_sourcemap__".to_string(), source_maps),
+            ("__turbopack_analyze__".to_string(), bundle_analysis),
         ],
         main_source,
     )
@@ -372,22 +522,137 @@ pub async fn start_server(args: &DevArguments) -> Result<()> {
         false => StatsType::Essential,
     };
     tt.set_stats_type(stats_type);
+    tt.backend()
+        .set_invalidation_tracing(args.trace_invalidations);
+    let _profile_guard = ProfileWriter::new(
+        tt.clone(),
+        args.common.chrome_trace.clone(),
+        args.common.pprof.clone(),
+    )
+    .map(ExitGuard::new)
+    .transpose()?;
 
     let tt_clone = tt.clone();
 
-    #[allow(unused_mut)]
-    let mut server = TurbopackDevServerBuilder::new(tt, dir, root_dir)
-        .entry_request(EntryRequest::Relative("src/index".into()))
+    let entries = if !args.entries.is_empty() {
+        args.entries.clone()
+    } else if Path::new(&dir).join("index.html").is_file() {
+        vec!["index.html".to_string()]
+    } else {
+        vec!["src/index".to_string()]
+    };
+
+    let mut proxies = args
+        .proxies
+        .iter()
+        .map(|proxy| ProxyOptions::parse(proxy))
+        .collect::<Result<Vec<_>>>()?;
+    if let Some(proxy_config) = &args.proxy_config {
+        let config = std::fs::read_to_string(proxy_config)
+            .with_context(|| format!("unable to read proxy config {}", proxy_config.display()))?;
+        let config: Vec<ProxyOptions> = serde_json::from_str(&config)
+            .with_context(|| format!("unable to parse proxy config {}", proxy_config.display()))?;
+        proxies.extend(config);
+    }
+
+    let https = if args.experimental_https {
+        Some(https_config(
+            Path::new(&dir),
+            args.hostname,
+            &args.https_hostnames,
+            args.https_key.as_deref(),
+            args.https_cert.as_deref(),
+        )?)
+    } else {
+        None
+    };
+
+    let log_level = args
+        .common
+        .log_level
+        .map_or_else(|| IssueSeverity::Warning, |l| l.0);
+    let mut issue_rules = IssueRules::new(args.common.issue_rules.clone());
+    if let Some(issue_rules_config) = &args.common.issue_rules_config {
+        let config = std::fs::read_to_string(issue_rules_config).with_context(|| {
+            format!(
+                "unable to read issue rules config {}",
+                issue_rules_config.display()
+            )
+        })?;
+        issue_rules.extend(IssueRules::from_json(&config).with_context(|| {
+            format!(
+                "unable to parse issue rules config {}",
+                issue_rules_config.display()
+            )
+        })?);
+    }
+    if args.common.issue_format != IssueFormat::Console && args.common.issue_output.is_none() {
+        // The dev server logs to stdout, which would interleave with the issues.
+        bail!("--issue-format requires --issue-output for the dev server");
+    }
+    let structured_issue_format = args
+        .common
+        .issue_format
+        .structured(args.common.issue_output.as_deref())?;
+    let structured_issue_options = structured_issue_format.map(|format| {
+        Arc::new(StructuredIssueOptions {
+            format,
+            output: args.common.issue_output.clone(),
+            tool_name: "turbopack".to_string(),
+            log_options: LogOptions {
+                current_dir: current_dir().unwrap(),
+                project_dir: PathBuf::from(&dir),
+                show_all: args.common.show_all,
+                log_detail: args.common.log_detail,
+                log_level,
+                issue_rules: issue_rules.clone(),
+            },
+        })
+    });
+
+    let mut server = TurbopackDevServerBuilder::new(tt, dir, root_dir);
+    for entry in entries {
+        server = server.entry_request(EntryRequest::Relative(entry));
+    }
+    for proxy in proxies {
+        server = server.proxy(proxy);
+    }
+    for prefix in &args.public_env_prefixes {
+        server = server.public_env_prefix(prefix.clone());
+    }
+    if let Some(mode) = &args.mode {
+        server = server.mode(mode.clone());
+    }
+    if let Some(https) = https {
+        server = server.https(https);
+    }
+    if let Some(dir) = &args.bundle_analysis {
+        std::fs::create_dir_all(dir).with_context(|| {
+            format!(
+                "unable to create bundle analysis directory {}",
+                dir.display()
+            )
+        })?;
+        let dir = canonicalize(dir)
+            .context("bundle analysis directory can't be found")?
+            .to_str()
+            .context("bundle analysis directory contains invalid characters")?
+            .to_string();
+        server = server.bundle_analysis_dir(dir);
+    }
+    server = server
         .eager_compile(args.eager_compile)
         .hostname(args.hostname)
         .port(args.port)
         .log_detail(args.common.log_detail)
         .show_all(args.common.show_all)
-        .log_level(
-            args.common
-                .log_level
-                .map_or_else(|| IssueSeverity::Warning, |l| l.0),
-        );
+        .log_level(log_level)
+        .issue_rules(issue_rules);
+    if let Some(options) = structured_issue_options {
+        server = server.issue_reporter(Box::new(move || {
+            StructuredIssueReporterVc::new(options.clone().into()).into()
+        }));
+    }
 
     #[cfg(feature = "serializable")]
     {
@@ -397,7 +662,16 @@ pub async fn start_server(args: &DevArguments) -> Result<()> {
     let server = server.build().await?;
 
     {
-        let index_uri = ServerAddr::new(server.addr).to_string()?;
+        let index_uri = if args.experimental_https {
+            let server_addr = ServerAddr::new(server.addr);
+            let hostname = server_addr.hostname().context("expected a hostname")?;
+            match server.addr.port() {
+                443 => format!("https://{hostname}"),
+                port => format!("https://{hostname}:{port}"),
+            }
+        } else {
+            ServerAddr::new(server.addr).to_string()?
+        };
         println!(
             "{} - started server on {}, url: {}",
             "ready".green(),
@@ -495,6 +769,65 @@ pub async fn start_server(args: &DevArguments) -> Result<()> {
     Ok(())
 }
 
+/// Writes the recorded task executions to the requested files when dropped.
+struct ProfileWriter {
+    turbo_tasks: Arc<TurboTasks<MemoryBackend>>,
+    chrome_trace: Option<PathBuf>,
+    pprof: Option<PathBuf>,
+}
+
+impl ProfileWriter {
+    /// Enables profiling when any output file is requested.
+    fn new(
+        turbo_tasks: Arc<TurboTasks<MemoryBackend>>,
+        chrome_trace: Option<PathBuf>,
+        pprof: Option<PathBuf>,
+    ) -> Option<Self> {
+        if chrome_trace.is_none() && pprof.is_none() {
+            return None;
+        }
+        turbo_tasks.backend().set_profiling(true);
+        Some(Self {
+            turbo_tasks,
+            chrome_trace,
+            pprof,
+        })
+    }
+
+    fn write(&self) -> Result<()> {
+        let profile = self.turbo_tasks.backend().take_profile();
+        if let Some(path) = &self.chrome_trace {
+            profile.write_chrome_trace(BufWriter::new(
+                File::create(path).with_context(|| format!("creating {}", path.display()))?,
+            ))?;
+            println!(
+                "{} - chrome trace written to {}",
+                "event".purple(),
+                path.display()
+            );
+        }
+        if let Some(path) = &self.pprof {
+            profile.write_pprof(BufWriter::new(
+                File::create(path).with_context(|| format!("creating {}", path.display()))?,
+            ))?;
+            println!(
+                "{} - pprof profile written to {}",
+                "event".purple(),
+                path.display()
+            );
+        }
+        Ok(())
+    }
+}
+
+impl Drop for ProfileWriter {
+    fn drop(&mut self) {
+        if let Err(err) = self.write() {
+            eprintln!("{} - failed to write the profile: {:?}", "error".red(), err);
+        }
+    }
+}
+
 #[cfg(feature = "profile")]
 // When profiling, exits the process when no new updates have been received for
 // a given timeout and there are no more tasks in progress.
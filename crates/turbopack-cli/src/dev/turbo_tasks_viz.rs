@@ -50,6 +50,11 @@ impl ContentSource for TurboTasksSource {
                 RouteType::Exact,
                 self_vc.into(),
             ),
+            RouteTreeVc::new_route(
+                vec![BaseSegment::Static("invalidations".to_string())],
+                RouteType::Exact,
+                self_vc.into(),
+            ),
             RouteTreeVc::new_route(
                 vec![BaseSegment::Static("reset".to_string())],
                 RouteType::Exact,
@@ -129,11 +134,17 @@ impl GetContentSourceContent for TurboTasksSource {
                 let table = viz::table::create_table(tree, tt.stats_type());
                 viz::table::wrap_html(&table)
             }
+            "invalidations" => {
+                let b = tt.backend();
+                let traces = b.invalidation_traces();
+                viz::invalidations::create_html(&traces, b.is_invalidation_tracing_enabled())
+            }
             "reset" => {
                 let b = tt.backend();
                 b.with_all_cached_tasks(|task| {
                     b.with_task(task, |task| task.reset_stats());
                 });
+                b.clear_invalidation_traces();
                 "Done".to_string()
             }
             _ => bail!("Unknown path: {}", path),
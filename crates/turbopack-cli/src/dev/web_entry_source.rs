@@ -17,6 +17,7 @@ use turbopack::{
 };
 use turbopack_cli_utils::runtime_entry::{RuntimeEntriesVc, RuntimeEntry};
 use turbopack_core::{
+    asset::{Asset, AssetsSetVc},
     chunk::{ChunkableAssetVc, ChunkingContextVc},
     compile_time_defines,
     compile_time_info::{CompileTimeDefinesVc, CompileTimeInfo, CompileTimeInfoVc},
@@ -27,6 +28,7 @@ use turbopack_core::{
         options::{ImportMap, ImportMapVc, ImportMapping},
         origin::PlainResolveOriginVc,
         parse::RequestVc,
+        pnp::find_pnp_root,
     },
     source_asset::SourceAssetVc,
 };
@@ -40,6 +42,8 @@ use turbopack_ecmascript_plugins::transform::{
     styled_components::{StyledComponentsTransformConfig, StyledComponentsTransformer},
     styled_jsx::StyledJsxTransformer,
 };
+use turbopack_env::ClientEnvVc;
+use turbopack_html::{HtmlEntryAssetVc, HtmlModuleAssetVc};
 use turbopack_node::execution_context::ExecutionContextVc;
 
 use crate::embed_js::embed_file_path;
@@ -76,6 +80,7 @@ async fn get_client_resolve_options_context(
     let next_client_import_map = get_client_import_map(project_path);
     let module_options_context = ResolveOptionsContext {
         enable_node_modules: Some(project_path.root().resolve().await?),
+        enable_pnp: find_pnp_root(project_path).await?,
         custom_conditions: vec!["development".to_string()],
         import_map: Some(next_client_import_map),
         browser: true,
@@ -185,9 +190,18 @@ pub fn client_defines() -> CompileTimeDefinesVc {
     .cell()
 }
 
+/// The compile time info for client code. The environment variables starting
+/// with one of the `public_env_prefixes` are inlined, and references to the
+/// other variables of the dotenv files are reported.
 #[turbo_tasks::function]
-pub fn get_client_compile_time_info(browserslist_query: &str) -> CompileTimeInfoVc {
-    CompileTimeInfo::builder(EnvironmentVc::new(Value::new(
+pub async fn get_client_compile_time_info(
+    browserslist_query: &str,
+    env: ProcessEnvVc,
+    public_env_prefixes: Vec<String>,
+) -> Result<CompileTimeInfoVc> {
+    let client_env = ClientEnvVc::new(client_defines(), env, public_env_prefixes).await?;
+
+    Ok(CompileTimeInfo::builder(EnvironmentVc::new(Value::new(
         ExecutionEnvironment::Browser(
             BrowserEnvironment {
                 dom: true,
@@ -198,8 +212,9 @@ pub fn get_client_compile_time_info(browserslist_query: &str) -> CompileTimeInfo
             .into(),
         ),
     )))
-    .defines(client_defines())
-    .cell()
+    .defines(client_env.defines)
+    .free_var_references(client_env.free_var_references)
+    .cell())
 }
 
 #[turbo_tasks::function]
@@ -246,17 +261,20 @@ pub async fn get_client_runtime_entries(
     Ok(RuntimeEntriesVc::cell(runtime_entries))
 }
 
+/// Creates the HTML pages for the entries. Each page references the chunks of
+/// its entries.
 #[turbo_tasks::function]
-pub async fn create_web_entry_source(
+pub async fn create_web_entry_pages(
     project_path: FileSystemPathVc,
     execution_context: ExecutionContextVc,
     entry_requests: Vec<RequestVc>,
     server_root: FileSystemPathVc,
-    _env: ProcessEnvVc,
-    eager_compile: bool,
+    env: ProcessEnvVc,
     browserslist_query: &str,
-) -> Result<ContentSourceVc> {
-    let compile_time_info = get_client_compile_time_info(browserslist_query);
+    public_env_prefixes: Vec<String>,
+) -> Result<AssetsSetVc> {
+    let compile_time_info =
+        get_client_compile_time_info(browserslist_query, env, public_env_prefixes);
     let context = get_client_asset_context(project_path, execution_context, compile_time_info);
     let chunking_context =
         get_client_chunking_context(project_path, server_root, compile_time_info.environment());
@@ -279,38 +297,62 @@ pub async fn create_web_entry_source(
         .try_join()
         .await?;
 
-    let entries: Vec<_> = entries
-        .into_iter()
-        .flatten()
-        .map(|module| async move {
-            if let Some(ecmascript) = EcmascriptModuleAssetVc::resolve_from(module).await? {
-                Ok((
-                    ecmascript.into(),
+    let mut pages = Vec::new();
+    let mut chunk_entries = Vec::new();
+    for module in entries.into_iter().flatten() {
+        if let Some(html) = HtmlModuleAssetVc::resolve_from(module).await? {
+            let path = module.ident().path().await?;
+            let page_path = project_path
+                .await?
+                .get_path_to(&path)
+                .map(str::to_string)
+                .ok_or_else(|| anyhow!("HTML entry {} is outside of the project", path.path))?;
+            pages.push(
+                HtmlEntryAssetVc::new(
+                    server_root.join(&page_path),
+                    html,
                     chunking_context,
-                    Some(runtime_entries.with_entry(ecmascript.into())),
-                ))
-            } else if let Some(chunkable) = ChunkableAssetVc::resolve_from(module).await? {
-                // TODO this is missing runtime code, so it's probably broken and we should also
-                // add an ecmascript chunk with the runtime code
-                Ok((chunkable, chunking_context, None))
-            } else {
-                // TODO convert into a serve-able asset
-                Err(anyhow!(
-                    "Entry module is not chunkable, so it can't be used to bootstrap the \
-                     application"
-                ))
-            }
-        })
-        .try_join()
-        .await?;
+                    Some(runtime_entries),
+                )
+                .into(),
+            );
+        } else if let Some(ecmascript) = EcmascriptModuleAssetVc::resolve_from(module).await? {
+            chunk_entries.push((
+                ecmascript.into(),
+                chunking_context,
+                Some(runtime_entries.with_entry(ecmascript.into())),
+            ));
+        } else if let Some(chunkable) = ChunkableAssetVc::resolve_from(module).await? {
+            // TODO this is missing runtime code, so it's probably broken and we should also
+            // add an ecmascript chunk with the runtime code
+            chunk_entries.push((chunkable, chunking_context, None));
+        } else {
+            // TODO convert into a serve-able asset
+            return Err(anyhow!(
+                "Entry module is not chunkable, so it can't be used to bootstrap the application"
+            ));
+        }
+    }
 
-    let entry_asset = DevHtmlAssetVc::new(server_root.join("index.html"), entries).into();
+    // Entries which are not HTML pages are served by a generated index.html.
+    if !chunk_entries.is_empty() || pages.is_empty() {
+        pages.push(DevHtmlAssetVc::new(server_root.join("index.html"), chunk_entries).into());
+    }
+
+    Ok(AssetsSetVc::cell(pages.into_iter().collect()))
+}
 
-    let graph = if eager_compile {
-        AssetGraphContentSourceVc::new_eager(server_root, entry_asset)
+#[turbo_tasks::function]
+pub fn create_web_entry_source(
+    pages: AssetsSetVc,
+    server_root: FileSystemPathVc,
+    _env: ProcessEnvVc,
+    eager_compile: bool,
+) -> ContentSourceVc {
+    if eager_compile {
+        AssetGraphContentSourceVc::new_eager_multiple(server_root, pages)
     } else {
-        AssetGraphContentSourceVc::new_lazy(server_root, entry_asset)
+        AssetGraphContentSourceVc::new_lazy_multiple(server_root, pages)
     }
-    .into();
-    Ok(graph)
+    .into()
 }
@@ -81,8 +81,10 @@ impl AvailableAssetsVc {
     }
 }
 
+/// The assets that are placed into the chunk group of `root`, i.e. the assets
+/// reachable from `root` without crossing into a separate chunk group.
 #[turbo_tasks::function]
-async fn chunkable_assets_set(root: AssetVc) -> Result<AssetsSetVc> {
+pub async fn chunkable_assets_set(root: AssetVc) -> Result<AssetsSetVc> {
     let assets = AdjacencyMap::new()
         .skip_duplicates()
         .visit(once(root), |&asset: &AssetVc| async move {
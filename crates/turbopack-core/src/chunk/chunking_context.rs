@@ -56,4 +56,12 @@ pub trait ChunkingContext {
         entry: ChunkVc,
         evaluatable_assets: EvaluatableAssetsVc,
     ) -> AssetsVc;
+
+    /// Returns the chunk of [ChunkingContext::evaluated_chunk_group] that
+    /// evaluates the entries. Loading it loads the whole chunk group.
+    fn evaluated_chunk_group_entry(
+        &self,
+        entry: ChunkVc,
+        evaluatable_assets: EvaluatableAssetsVc,
+    ) -> AssetVc;
 }
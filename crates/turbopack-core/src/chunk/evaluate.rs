@@ -30,6 +30,14 @@ impl EvaluatableAssetVc {
     }
 }
 
+/// A chunk which evaluates entries once the other chunks of its chunk group
+/// are loaded.
+#[turbo_tasks::value_trait]
+pub trait EvaluateChunk: Asset {
+    /// The entries which are evaluated by the chunk.
+    fn evaluatable_assets(&self) -> EvaluatableAssetsVc;
+}
+
 #[turbo_tasks::value(transparent)]
 pub struct EvaluatableAssets(Vec<EvaluatableAssetVc>);
 
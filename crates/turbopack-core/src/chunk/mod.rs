@@ -31,7 +31,10 @@ use self::availability_info::AvailabilityInfo;
 pub use self::{
     chunking_context::{ChunkingContext, ChunkingContextVc},
     data::{ChunkData, ChunkDataOption, ChunkDataOptionVc, ChunkDataVc, ChunksData, ChunksDataVc},
-    evaluate::{EvaluatableAsset, EvaluatableAssetVc, EvaluatableAssets, EvaluatableAssetsVc},
+    evaluate::{
+        EvaluatableAsset, EvaluatableAssetVc, EvaluatableAssets, EvaluatableAssetsVc,
+        EvaluateChunk, EvaluateChunkVc,
+    },
 };
 use crate::{
     asset::{Asset, AssetVc, AssetsVc},
@@ -148,6 +151,36 @@ pub trait OutputChunk: Asset {
     fn runtime_info(&self) -> OutputChunkRuntimeInfoVc;
 }
 
+/// A chunk item placed in a chunk, with the size of the code emitted for it.
+#[turbo_tasks::value(shared)]
+pub struct ChunkItemSize {
+    pub chunk_item: ChunkItemVc,
+    /// The size of the emitted code in bytes.
+    pub size: usize,
+}
+
+#[turbo_tasks::value(transparent)]
+pub struct ChunkItemSizes(Vec<ChunkItemSize>);
+
+/// A chunk which can list the chunk items it contains, e.g. for bundle
+/// analysis.
+#[turbo_tasks::value_trait]
+pub trait ChunkWithItemSizes {
+    fn chunk_item_sizes(&self) -> ChunkItemSizesVc;
+}
+
+/// A chunk item which loads another chunk group when it's evaluated, like the
+/// loader of a dynamic `import()`, e.g. to tell chunk groups apart for bundle
+/// analysis.
+#[turbo_tasks::value_trait]
+pub trait ChunkGroupLoader {
+    /// The asset the loaded chunk group is created for.
+    fn loaded_asset(&self) -> AssetVc;
+    /// The chunks which are loaded first. Other chunks of the chunk group
+    /// can be loaded by these.
+    fn loaded_chunks(&self) -> AssetsVc;
+}
+
 /// Specifies how a chunk interacts with other chunks when building a chunk
 /// group
 #[derive(
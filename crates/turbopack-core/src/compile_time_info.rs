@@ -125,6 +125,8 @@ pub enum FreeVarReference {
         export: Option<String>,
     },
     Value(CompileTimeDefineValue),
+    /// Reports an error with this message where the free var is referenced.
+    Error(String),
 }
 
 impl From<bool> for FreeVarReference {
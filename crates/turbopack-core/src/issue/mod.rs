@@ -433,6 +433,29 @@ pub struct IssueSource {
 
 #[turbo_tasks::value_impl]
 impl IssueSourceVc {
+    /// Creates an [IssueSource] spanning from (`start_line`, `start_column`) to
+    /// (`end_line`, `end_column`), all 0-indexed.
+    #[turbo_tasks::function]
+    pub fn from_line_col(
+        asset: AssetVc,
+        start_line: usize,
+        start_column: usize,
+        end_line: usize,
+        end_column: usize,
+    ) -> Self {
+        Self::cell(IssueSource {
+            asset,
+            start: SourcePos {
+                line: start_line,
+                column: start_column,
+            },
+            end: SourcePos {
+                line: end_line,
+                column: end_column,
+            },
+        })
+    }
+
     #[turbo_tasks::function]
     pub async fn from_byte_offset(asset: AssetVc, start: usize, end: usize) -> Result<Self> {
         fn find_line_and_column(lines: &[FileLine], offset: usize) -> SourcePos {
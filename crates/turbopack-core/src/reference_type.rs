@@ -75,6 +75,7 @@ pub enum EntryReferenceSubType {
     AppRoute,
     AppClientComponent,
     Runtime,
+    Worker,
     Custom(u8),
     Undefined,
 }
@@ -0,0 +1,218 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+use serde_json::{Map as JsonMap, Value as JsonValue};
+use turbo_tasks::primitives::StringVc;
+use turbo_tasks_fs::{FileContent, FileJsonContent, FileSystemPathVc};
+
+use super::options::{LockedVersions, LockedVersionsVc};
+use crate::issue::{Issue, IssueVc};
+
+#[turbo_tasks::value_impl]
+impl LockedVersionsVc {
+    #[turbo_tasks::function]
+    pub fn empty() -> Self {
+        LockedVersions::default().cell()
+    }
+
+    /// Reads the locked versions from a npm `package-lock.json` (or
+    /// `npm-shrinkwrap.json`) file. Lockfile versions 1, 2 and 3 are
+    /// supported.
+    #[turbo_tasks::function]
+    pub async fn from_package_lock(path: FileSystemPathVc) -> Result<Self> {
+        let read = path.read_json().await?;
+        let json = match &*read {
+            FileJsonContent::Content(json) => json,
+            FileJsonContent::NotFound => return Ok(Self::empty()),
+            FileJsonContent::Unparseable(e) => {
+                let mut message = "lockfile is not parseable: invalid JSON: ".to_string();
+                if let FileContent::Content(content) = &*path.read().await? {
+                    let text = content.content().to_str()?;
+                    e.write_with_content(&mut message, &text)?;
+                } else {
+                    write!(message, "{}", e)?;
+                }
+                LockfileIssue {
+                    error_message: message,
+                    path,
+                }
+                .cell()
+                .as_issue()
+                .emit();
+                return Ok(Self::empty());
+            }
+        };
+        let mut locked_versions = LockedVersions {
+            root: Some(path.parent().resolve().await?),
+            ..Default::default()
+        };
+        read_lockfile(json, &mut locked_versions);
+        Ok(locked_versions.cell())
+    }
+}
+
+fn read_lockfile(json: &JsonValue, locked_versions: &mut LockedVersions) {
+    if let Some(packages) = json.get("packages").and_then(JsonValue::as_object) {
+        read_packages(packages, locked_versions);
+    } else if let Some(dependencies) = json.get("dependencies").and_then(JsonValue::as_object) {
+        read_dependencies(dependencies, "", locked_versions);
+    }
+}
+
+fn version_of(entry: &JsonValue) -> Option<&str> {
+    entry.get("version").and_then(JsonValue::as_str)
+}
+
+/// Reads the `packages` of lockfile version 2 and 3, which are keyed by their
+/// install location, e.g. `node_modules/a/node_modules/b`.
+fn read_packages(packages: &JsonMap<String, JsonValue>, locked_versions: &mut LockedVersions) {
+    for (location, entry) in packages {
+        // Links to workspace packages don't have a version in the registry.
+        if entry.get("link").and_then(JsonValue::as_bool) == Some(true) {
+            continue;
+        }
+        let Some(version) = version_of(entry) else {
+            continue;
+        };
+        if location.starts_with("node_modules/") || location.contains("/node_modules/") {
+            locked_versions
+                .locations
+                .insert(location.clone(), version.to_string());
+        }
+    }
+}
+
+/// Reads the nested `dependencies` of lockfile version 1, which are keyed by
+/// package name and installed into the `node_modules` of their parent.
+fn read_dependencies(
+    dependencies: &JsonMap<String, JsonValue>,
+    parent: &str,
+    locked_versions: &mut LockedVersions,
+) {
+    for (name, entry) in dependencies {
+        let Some(version) = version_of(entry) else {
+            continue;
+        };
+        let location = format!("{parent}node_modules/{name}");
+        if let Some(nested) = entry.get("dependencies").and_then(JsonValue::as_object) {
+            read_dependencies(nested, &format!("{location}/"), locked_versions);
+        }
+        locked_versions
+            .locations
+            .insert(location, version.to_string());
+    }
+}
+
+#[turbo_tasks::value(shared)]
+pub struct LockfileIssue {
+    pub path: FileSystemPathVc,
+    pub error_message: String,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for LockfileIssue {
+    #[turbo_tasks::function]
+    fn title(&self) -> StringVc {
+        StringVc::cell("Error parsing lockfile".to_string())
+    }
+
+    #[turbo_tasks::function]
+    fn category(&self) -> StringVc {
+        StringVc::cell("parse".to_string())
+    }
+
+    #[turbo_tasks::function]
+    fn context(&self) -> FileSystemPathVc {
+        self.path
+    }
+
+    #[turbo_tasks::function]
+    fn description(&self) -> StringVc {
+        StringVc::cell(self.error_message.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn locked_versions(json: JsonValue) -> LockedVersions {
+        let mut locked_versions = LockedVersions::default();
+        read_lockfile(&json, &mut locked_versions);
+        locked_versions
+    }
+
+    #[test]
+    fn resolves_through_ancestor_node_modules() {
+        let locked = locked_versions(json!({
+            "lockfileVersion": 3,
+            "packages": {
+                "": { "name": "root" },
+                "node_modules/a": { "version": "1.0.0" },
+                "node_modules/a/node_modules/b": { "version": "1.0.0" },
+                "node_modules/a/node_modules/c": { "version": "2.0.0" },
+                "node_modules/c": { "version": "1.0.0" }
+            }
+        }));
+        // `c` is hoisted into `a/node_modules`, next to `b`.
+        assert_eq!(
+            locked.get("node_modules/a/node_modules/b", "c"),
+            Some("2.0.0")
+        );
+        assert_eq!(locked.get("node_modules/a", "c"), Some("2.0.0"));
+        assert_eq!(locked.get("", "c"), Some("1.0.0"));
+        assert_eq!(locked.get("src/components", "c"), Some("1.0.0"));
+        assert_eq!(locked.get("", "b"), None);
+    }
+
+    #[test]
+    fn resolves_workspace_dependencies() {
+        let locked = locked_versions(json!({
+            "lockfileVersion": 3,
+            "packages": {
+                "": { "name": "root", "workspaces": ["packages/*"] },
+                "node_modules/x": { "resolved": "packages/x", "link": true },
+                "node_modules/y": { "version": "1.0.0" },
+                "packages/x": { "name": "x", "version": "0.1.0" },
+                "packages/x/node_modules/y": { "version": "2.0.0" }
+            }
+        }));
+        assert_eq!(locked.get("packages/x/src", "y"), Some("2.0.0"));
+        assert_eq!(locked.get("packages/z", "y"), Some("1.0.0"));
+        assert_eq!(locked.get("", "x"), None);
+    }
+
+    #[test]
+    fn locates_packages() {
+        let locked = locked_versions(json!({
+            "lockfileVersion": 1,
+            "dependencies": {
+                "a": {
+                    "version": "1.0.0",
+                    "dependencies": {
+                        "b": { "version": "2.0.0" },
+                        "@scope/c": { "version": "1.0.0" }
+                    }
+                },
+                "b": { "version": "1.0.0" },
+                "d": {
+                    "version": "1.0.0",
+                    "dependencies": { "b": { "version": "2.0.0" } }
+                }
+            }
+        }));
+        assert_eq!(
+            locked.location_of("@scope/c", "1.0.0"),
+            Some("node_modules/a/node_modules/@scope/c")
+        );
+        assert_eq!(
+            locked.location_of("b", "2.0.0"),
+            Some("node_modules/a/node_modules/b")
+        );
+        assert_eq!(locked.location_of("b", "3.0.0"), None);
+        assert_eq!(locked.get("node_modules/d", "b"), Some("2.0.0"));
+        assert_eq!(locked.get("node_modules/d/lib", "b"), Some("2.0.0"));
+    }
+}
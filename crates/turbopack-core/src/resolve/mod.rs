@@ -18,8 +18,8 @@ use turbo_tasks_fs::{
 
 use self::{
     options::{
-        resolve_modules_options, ImportMapResult, ResolveInPackage, ResolveIntoPackage,
-        ResolveModules, ResolveModulesOptionsVc, ResolveOptionsVc,
+        resolve_modules_options, ImportMapResult, LockedVersions, ResolveInPackage,
+        ResolveIntoPackage, ResolveModules, ResolveModulesOptionsVc, ResolveOptionsVc,
     },
     parse::{Request, RequestVc},
     pattern::QueryMapVc,
@@ -40,12 +40,14 @@ use crate::{
 };
 
 mod alias_map;
+pub mod lockfile;
 pub mod node;
 pub mod options;
 pub mod origin;
 pub mod parse;
 pub mod pattern;
 pub mod plugin;
+pub mod pnp;
 pub(crate) mod remap;
 
 pub use alias_map::{
@@ -507,6 +509,54 @@ struct FindPackageResult {
     references: Vec<AssetReferenceVc>,
 }
 
+/// Returns the name and version of the package in the registry filesystem
+/// that contains `context`, or `None` when `context` is outside of the
+/// registry.
+async fn registry_package(
+    registry: FileSystemPathVc,
+    context: FileSystemPathVc,
+) -> Result<Option<(String, String)>> {
+    let registry = registry.await?;
+    let context = context.await?;
+    let Some(path) = registry.get_path_to(&context) else {
+        return Ok(None);
+    };
+    let mut segments = path.split('/');
+    let name = match segments.next() {
+        Some(scope) if scope.starts_with('@') => match segments.next() {
+            Some(name) => format!("{scope}/{name}"),
+            None => return Ok(None),
+        },
+        Some(name) if !name.is_empty() => name.to_string(),
+        _ => return Ok(None),
+    };
+    Ok(segments.next().map(|version| (name, version.to_string())))
+}
+
+/// Returns the directory `context` is requested from, relative to the
+/// lockfile. Packages in the registry are requested from their install
+/// location.
+async fn registry_importer(
+    registry: FileSystemPathVc,
+    locked_versions: &LockedVersions,
+    context: FileSystemPathVc,
+) -> Result<String> {
+    if let Some((name, version)) = registry_package(registry, context).await? {
+        return Ok(locked_versions
+            .location_of(&name, &version)
+            .unwrap_or_default()
+            .to_string());
+    }
+    let Some(root) = locked_versions.root else {
+        return Ok(String::new());
+    };
+    Ok(root
+        .await?
+        .get_path_to(&*context.await?)
+        .unwrap_or_default()
+        .to_string())
+}
+
 #[turbo_tasks::function]
 async fn find_package(
     context: FileSystemPathVc,
@@ -547,7 +597,25 @@ async fn find_package(
                     packages.push(package_dir.resolve().await?);
                 }
             }
-            ResolveModules::Registry(_, _) => todo!(),
+            ResolveModules::Registry(registry, locked_versions) => {
+                let locked_versions = locked_versions.await?;
+                let importer = registry_importer(*registry, &locked_versions, context).await?;
+                if let Some(version) = locked_versions.get(&importer, &package_name) {
+                    let package_dir = registry.join(&format!("{package_name}/{version}"));
+                    if dir_exists(package_dir, &mut references).await?.is_some() {
+                        packages.push(package_dir.resolve().await?);
+                    }
+                }
+            }
+            ResolveModules::PnP(root) => {
+                if let Some(package_dir) =
+                    pnp::find_pnp_package(*root, context, &package_name).await?
+                {
+                    if dir_exists(package_dir, &mut references).await?.is_some() {
+                        packages.push(package_dir.resolve().await?);
+                    }
+                }
+            }
         }
     }
     Ok(FindPackageResultVc::cell(FindPackageResult {
@@ -782,6 +850,7 @@ async fn resolve_internal(
         }
         Request::Relative {
             path,
+            query,
             force_in_context,
         } => {
             let mut patterns = vec![path.clone()];
@@ -803,7 +872,12 @@ async fn resolve_internal(
                 ));
             }
 
-            merge_results(results)
+            let result = merge_results(results);
+            if query.await?.is_empty() {
+                result
+            } else {
+                with_query(result, *query).await?
+            }
         }
         Request::Module {
             module,
@@ -911,6 +985,27 @@ async fn resolve_internal(
     Ok(result)
 }
 
+/// Attaches `query` to all source assets in the `result`, so that module rules
+/// can match on it (e.g. `./file.wasm?url`).
+async fn with_query(result: ResolveResultVc, query: StringVc) -> Result<ResolveResultVc> {
+    Ok(result
+        .await?
+        .map(
+            |asset| async move {
+                Ok(
+                    if let Some(source) = SourceAssetVc::resolve_from(asset).await? {
+                        SourceAssetVc::new_with_query(source.await?.path, query).into()
+                    } else {
+                        asset
+                    },
+                )
+            },
+            |reference| async move { Ok(reference) },
+        )
+        .await?
+        .cell())
+}
+
 async fn resolve_into_folder(
     package_path: FileSystemPathVc,
     options: ResolveOptionsVc,
@@ -14,9 +14,57 @@ use super::{
 };
 use crate::resolve::{parse::RequestVc, plugin::ResolvePluginVc};
 
+/// The exact versions of packages, as locked by a lockfile.
 #[turbo_tasks::value(shared)]
-#[derive(Hash, Debug)]
-pub struct LockedVersions {}
+#[derive(Hash, Debug, Default)]
+pub struct LockedVersions {
+    /// The directory of the lockfile, which install locations are relative
+    /// to.
+    pub root: Option<FileSystemPathVc>,
+    /// Versions of packages by their install location, e.g.
+    /// `node_modules/a/node_modules/b` or `packages/x/node_modules/y`.
+    pub locations: BTreeMap<String, String>,
+}
+
+impl LockedVersions {
+    /// Returns the install location of the package `name` with `version`.
+    /// The registry filesystem only has a single directory per version, so
+    /// when the version is installed at multiple locations, the least nested
+    /// one is used.
+    pub fn location_of(&self, name: &str, version: &str) -> Option<&str> {
+        let suffix = format!("node_modules/{name}");
+        self.locations
+            .iter()
+            .filter(|(location, locked)| *locked == version && location.ends_with(&suffix))
+            .map(|(location, _)| location.as_str())
+            .min_by_key(|location| location.matches('/').count())
+    }
+
+    /// Returns the locked version of `package_name` when it's requested from
+    /// the directory `importer`, relative to the lockfile. Like Node.js, the
+    /// `node_modules` directories of `importer` and all of its ancestors are
+    /// searched.
+    pub fn get(&self, importer: &str, package_name: &str) -> Option<&str> {
+        let mut dir = importer.trim_matches('/');
+        loop {
+            // `node_modules/node_modules` is never searched.
+            if dir != "node_modules" && !dir.ends_with("/node_modules") {
+                let location = if dir.is_empty() {
+                    format!("node_modules/{package_name}")
+                } else {
+                    format!("{dir}/node_modules/{package_name}")
+                };
+                if let Some(version) = self.locations.get(&location) {
+                    return Some(version);
+                }
+            }
+            if dir.is_empty() {
+                return None;
+            }
+            dir = dir.rsplit_once('/').map_or("", |(parent, _)| parent);
+        }
+    }
+}
 
 /// A location where to resolve modules.
 #[derive(
@@ -32,6 +80,9 @@ pub enum ResolveModules {
     /// registry filesystem is assumed to have structure like
     /// @scope/module/version/<path-in-package>
     Registry(FileSystemPathVc, LockedVersionsVc),
+    /// lookup packages in the Yarn Plug'n'Play manifest of the project in
+    /// that directory. Packages may be located inside of zip archives.
+    PnP(FileSystemPathVc),
 }
 
 #[derive(TraceRawVcs, Hash, PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
@@ -15,6 +15,9 @@ pub enum Request {
     },
     Relative {
         path: Pattern,
+        /// The query string of the request (e.g. `url` for `./file.wasm?url`),
+        /// without the leading `?`. Empty if there is none.
+        query: StringVc,
         force_in_context: bool,
     },
     Module {
@@ -94,8 +97,10 @@ impl Request {
                 } else if r.starts_with('#') {
                     Request::PackageInternal { path: request }
                 } else if r.starts_with("./") || r.starts_with("../") || r == "." || r == ".." {
+                    let (path, query) = r.split_once('?').unwrap_or((r, ""));
                     Request::Relative {
-                        path: request,
+                        path: path.to_string().into(),
+                        query: StringVc::cell(query.to_string()),
                         force_in_context: false,
                     }
                 } else {
@@ -212,6 +217,7 @@ impl RequestVc {
     pub fn relative(request: Value<Pattern>, force_in_context: bool) -> Self {
         Self::cell(Request::Relative {
             path: request.into_value(),
+            query: StringVc::empty(),
             force_in_context,
         })
     }
@@ -285,12 +291,19 @@ impl ValueToString for Request {
             }
             Request::Relative {
                 path,
+                query,
                 force_in_context,
             } => {
+                let query = query.await?;
+                let query = if query.is_empty() {
+                    String::new()
+                } else {
+                    format!("?{query}")
+                };
                 if *force_in_context {
-                    format!("relative-in-context {path}")
+                    format!("relative-in-context {path}{query}")
                 } else {
-                    format!("relative {path}")
+                    format!("relative {path}{query}")
                 }
             }
             Request::Module {
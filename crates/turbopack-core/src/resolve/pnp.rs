@@ -0,0 +1,401 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use serde_json::Value as JsonValue;
+use turbo_tasks::primitives::{StringVc, StringsVc};
+use turbo_tasks_fs::{
+    archive::ArchiveFileSystemVc, FileContent, FileSystem, FileSystemPathVc, FileSystemVc,
+};
+
+use super::{find_context_file, FindContextFileResult};
+use crate::issue::{Issue, IssueVc};
+
+/// The Plug'n'Play manifest of a Yarn project, as written to `.pnp.data.json`
+/// or embedded into `.pnp.cjs`. Packages are identified by their locator,
+/// `name@reference`, which is empty for the top-level package.
+#[turbo_tasks::value(shared)]
+#[derive(Debug, Default)]
+pub struct PnpManifest {
+    /// The location of each package, relative to the project root, e.g.
+    /// `./.yarn/cache/foo-npm-1.0.0-abcdef.zip/node_modules/foo/`.
+    pub locations: BTreeMap<String, String>,
+    /// The locator of the package at each location, to find the package
+    /// containing a path without scanning all locations.
+    pub issuers: BTreeMap<String, String>,
+    /// The dependencies of each package, by the requested name. `None` for
+    /// peer dependencies that are not provided.
+    pub dependencies: BTreeMap<String, BTreeMap<String, Option<String>>>,
+    /// Dependencies which are available to every package when they are not
+    /// declared, unless the package is in the exclusion list.
+    pub fallback_pool: BTreeMap<String, Option<String>>,
+    /// Locators of packages which must not use the fallback.
+    pub fallback_exclusion_list: Vec<String>,
+    pub enable_top_level_fallback: bool,
+}
+
+const TOP_LEVEL: &str = "";
+
+/// The files Yarn writes the manifest to, in the order they are read.
+#[turbo_tasks::function]
+fn pnp_manifest_files() -> StringsVc {
+    StringsVc::cell(vec![".pnp.data.json".to_string(), ".pnp.cjs".to_string()])
+}
+
+/// Finds the root of the Plug'n'Play project containing `context`, i.e. the
+/// closest directory with a `.pnp.cjs` or `.pnp.data.json` manifest.
+pub async fn find_pnp_root(context: FileSystemPathVc) -> Result<Option<FileSystemPathVc>> {
+    Ok(
+        match &*find_context_file(context, pnp_manifest_files()).await? {
+            FindContextFileResult::Found(manifest, _) => Some(manifest.parent().resolve().await?),
+            FindContextFileResult::NotFound(_) => None,
+        },
+    )
+}
+
+fn locator(name: Option<&str>, reference: Option<&str>) -> String {
+    match (name, reference) {
+        (Some(name), Some(reference)) => format!("{name}@{reference}"),
+        _ => TOP_LEVEL.to_string(),
+    }
+}
+
+/// Reads a dependency, which is either a reference, a `[name, reference]`
+/// pair for aliased packages, or `null` for missing peer dependencies.
+fn read_dependency(name: &str, value: &JsonValue) -> Option<String> {
+    match value {
+        JsonValue::String(reference) => Some(locator(Some(name), Some(reference))),
+        JsonValue::Array(alias) => match alias.as_slice() {
+            [JsonValue::String(name), JsonValue::String(reference)] => {
+                Some(locator(Some(name), Some(reference)))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn read_dependencies(value: Option<&JsonValue>) -> BTreeMap<String, Option<String>> {
+    value
+        .and_then(JsonValue::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| match entry.as_array()?.as_slice() {
+            [JsonValue::String(name), dependency] => {
+                Some((name.clone(), read_dependency(name, dependency)))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Reads `[key, value]` pairs, which the manifest uses instead of objects.
+fn read_entries(value: Option<&JsonValue>) -> impl Iterator<Item = (Option<&str>, &JsonValue)> {
+    value
+        .and_then(JsonValue::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| match entry.as_array()?.as_slice() {
+            [key, value] => Some((key.as_str(), value)),
+            _ => None,
+        })
+}
+
+impl PnpManifest {
+    fn from_json(json: &JsonValue) -> Self {
+        let mut manifest = PnpManifest {
+            enable_top_level_fallback: json
+                .get("enableTopLevelFallback")
+                .and_then(JsonValue::as_bool)
+                .unwrap_or(false),
+            fallback_pool: read_dependencies(json.get("fallbackPool")),
+            ..Default::default()
+        };
+        for (name, references) in read_entries(json.get("packageRegistryData")) {
+            for (reference, package) in read_entries(Some(references)) {
+                let Some(location) = package.get("packageLocation").and_then(JsonValue::as_str)
+                else {
+                    continue;
+                };
+                let locator = locator(name, reference);
+                manifest.dependencies.insert(
+                    locator.clone(),
+                    read_dependencies(package.get("packageDependencies")),
+                );
+                manifest
+                    .issuers
+                    .insert(location.to_string(), locator.clone());
+                manifest.locations.insert(locator, location.to_string());
+            }
+        }
+        for (name, references) in read_entries(json.get("fallbackExclusionList")) {
+            for reference in references.as_array().into_iter().flatten() {
+                manifest
+                    .fallback_exclusion_list
+                    .push(locator(name, reference.as_str()));
+            }
+        }
+        manifest
+    }
+
+    /// Returns the locator of the package which contains `location`, which is
+    /// relative to the project root and ends with a `/`.
+    fn find_issuer(&self, location: &str) -> Option<&str> {
+        // Package locations end with a `/`, so the closest package is found by
+        // looking up the parent directories of `location`, innermost first.
+        location
+            .rmatch_indices('/')
+            .find_map(|(index, _)| self.issuers.get(&location[..=index]))
+            .map(|locator| locator.as_str())
+    }
+
+    /// Returns the location of the package `name` as required from the package
+    /// `issuer`, following the fallbacks of Plug'n'Play.
+    fn resolve(&self, issuer: &str, name: &str) -> Option<&str> {
+        let dependency = self
+            .dependencies
+            .get(issuer)
+            .and_then(|dependencies| dependencies.get(name))
+            .or_else(|| {
+                if !self.enable_top_level_fallback
+                    || self.fallback_exclusion_list.iter().any(|l| l == issuer)
+                {
+                    return None;
+                }
+                self.dependencies
+                    .get(TOP_LEVEL)
+                    .and_then(|dependencies| dependencies.get(name))
+                    .or_else(|| self.fallback_pool.get(name))
+            })?;
+        self.locations
+            .get(dependency.as_deref()?)
+            .map(|location| location.as_str())
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl PnpManifestVc {
+    /// Reads the manifest of the project in `root` from `.pnp.data.json`, or
+    /// from the state embedded into `.pnp.cjs`.
+    #[turbo_tasks::function]
+    pub async fn read(root: FileSystemPathVc) -> Result<Self> {
+        let data_path = root.join(".pnp.data.json");
+        let cjs_path = root.join(".pnp.cjs");
+        let (path, data) = if let FileContent::Content(file) = &*data_path.read().await? {
+            (data_path, file.content().to_str()?.to_string())
+        } else if let FileContent::Content(file) = &*cjs_path.read().await? {
+            let Some(data) = extract_runtime_state(&file.content().to_str()?) else {
+                return Ok(Self::emit_issue(
+                    cjs_path,
+                    "unable to find RAW_RUNTIME_STATE".to_string(),
+                ));
+            };
+            (cjs_path, data)
+        } else {
+            return Ok(PnpManifest::default().cell());
+        };
+        match serde_json::from_str(&data) {
+            Ok(json) => Ok(PnpManifest::from_json(&json).cell()),
+            Err(e) => Ok(Self::emit_issue(path, format!("invalid JSON: {e}"))),
+        }
+    }
+}
+
+impl PnpManifestVc {
+    fn emit_issue(path: FileSystemPathVc, error_message: String) -> Self {
+        PnpManifestIssue {
+            path,
+            error_message,
+        }
+        .cell()
+        .as_issue()
+        .emit();
+        PnpManifest::default().cell()
+    }
+}
+
+/// Extracts the JSON manifest that is embedded as a string literal into
+/// `.pnp.cjs`, e.g. `const RAW_RUNTIME_STATE =\n'{\\\n  "__info": ...}';`.
+fn extract_runtime_state(source: &str) -> Option<String> {
+    let (_, rest) = source.split_once("RAW_RUNTIME_STATE")?;
+    let rest = rest.trim_start().strip_prefix('=')?.trim_start();
+    let mut chars = rest.chars();
+    let quote = chars.next().filter(|c| matches!(c, '\'' | '"'))?;
+    let mut data = String::new();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next()? {
+                // Line continuation
+                '\n' => {}
+                'n' => data.push('\n'),
+                'r' => data.push('\r'),
+                't' => data.push('\t'),
+                'u' => {
+                    let code: String = chars.by_ref().take(4).collect();
+                    data.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+                }
+                c => data.push(c),
+            },
+            c if c == quote => return Some(data),
+            c => data.push(c),
+        }
+    }
+    None
+}
+
+/// Returns the location of `context` relative to the project root, as used by
+/// the package locations in the manifest. Contexts inside of zip archives are
+/// located by the name of the [ArchiveFileSystem].
+///
+/// [ArchiveFileSystem]: turbo_tasks_fs::archive::ArchiveFileSystem
+async fn pnp_location(root: FileSystemPathVc, context: FileSystemPathVc) -> Result<Option<String>> {
+    let context_value = context.await?;
+    let location =
+        if let Some(archive) = ArchiveFileSystemVc::resolve_from(context_value.fs).await? {
+            format!("{}/{}", archive.await?.name(), context_value.path)
+        } else if let Some(path) = root.await?.get_path_to(&context_value) {
+            format!("./{path}")
+        } else {
+            return Ok(None);
+        };
+    Ok(Some(if location.ends_with('/') {
+        location
+    } else {
+        format!("{location}/")
+    }))
+}
+
+/// Resolves Yarn's `__virtual__/<hash>/<depth>/` segments, which go `depth`
+/// directories up from the directory containing `__virtual__`.
+fn resolve_virtual(location: &str) -> String {
+    let mut segments: Vec<&str> = Vec::new();
+    let mut rest = location.split('/');
+    while let Some(segment) = rest.next() {
+        if segment == "__virtual__" {
+            let _hash = rest.next();
+            let depth = rest
+                .next()
+                .and_then(|depth| depth.parse::<usize>().ok())
+                .unwrap_or_default();
+            for _ in 0..depth {
+                segments.pop();
+            }
+        } else {
+            segments.push(segment);
+        }
+    }
+    segments.join("/")
+}
+
+/// Returns the directory of a package location. Packages inside of zip
+/// archives are read through an [ArchiveFileSystem] named after the (possibly
+/// virtual) location of the archive, so that the issuer of requests from
+/// inside the archive can be found again.
+///
+/// [ArchiveFileSystem]: turbo_tasks_fs::archive::ArchiveFileSystem
+fn package_path(root: FileSystemPathVc, location: &str) -> FileSystemPathVc {
+    let location = location.trim_end_matches('/');
+    if let Some(index) = location.find(".zip/") {
+        let (archive, inner) = location.split_at(index + ".zip".len());
+        let archive_path = root.join(resolve_virtual(archive).trim_start_matches("./"));
+        let fs: FileSystemVc = ArchiveFileSystemVc::new(archive.to_string(), archive_path).into();
+        fs.root().join(inner.trim_start_matches('/'))
+    } else {
+        root.join(resolve_virtual(location).trim_start_matches("./"))
+    }
+}
+
+/// Finds the directory of the package `package_name` as required from
+/// `context`, using the Plug'n'Play manifest of the project in `root`.
+pub(super) async fn find_pnp_package(
+    root: FileSystemPathVc,
+    context: FileSystemPathVc,
+    package_name: &str,
+) -> Result<Option<FileSystemPathVc>> {
+    let manifest = PnpManifestVc::read(root).await?;
+    let Some(location) = pnp_location(root, context).await? else {
+        return Ok(None);
+    };
+    let issuer = manifest.find_issuer(&location).unwrap_or(TOP_LEVEL);
+    Ok(manifest
+        .resolve(issuer, package_name)
+        .map(|location| package_path(root, location)))
+}
+
+#[turbo_tasks::value(shared)]
+pub struct PnpManifestIssue {
+    pub path: FileSystemPathVc,
+    pub error_message: String,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for PnpManifestIssue {
+    #[turbo_tasks::function]
+    fn title(&self) -> StringVc {
+        StringVc::cell("Error parsing Plug'n'Play manifest".to_string())
+    }
+
+    #[turbo_tasks::function]
+    fn category(&self) -> StringVc {
+        StringVc::cell("parse".to_string())
+    }
+
+    #[turbo_tasks::function]
+    fn context(&self) -> FileSystemPathVc {
+        self.path
+    }
+
+    #[turbo_tasks::function]
+    fn description(&self) -> StringVc {
+        StringVc::cell(self.error_message.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_runtime_state() {
+        let source = "const RAW_RUNTIME_STATE =\n'{\\\n  \"a\": \"it\\'s\"\\\n}';\n";
+        assert_eq!(
+            extract_runtime_state(source).as_deref(),
+            Some("{  \"a\": \"it's\"}")
+        );
+    }
+
+    #[test]
+    fn finds_closest_issuer() {
+        let manifest = PnpManifest::from_json(&serde_json::json!({
+            "packageRegistryData": [
+                [null, [[null, { "packageLocation": "./" }]]],
+                ["a", [["workspace:packages/a", { "packageLocation": "./packages/a/" }]]],
+                ["foo", [["npm:1.0.0", {
+                    "packageLocation": "./.yarn/cache/foo.zip/node_modules/foo/"
+                }]]]
+            ]
+        }));
+        assert_eq!(manifest.find_issuer("./src/"), Some(TOP_LEVEL));
+        assert_eq!(
+            manifest.find_issuer("./packages/a/src/"),
+            Some("a@workspace:packages/a")
+        );
+        assert_eq!(manifest.find_issuer("./packages/ab/"), Some(TOP_LEVEL));
+        assert_eq!(
+            manifest.find_issuer("./.yarn/cache/foo.zip/node_modules/foo/lib/"),
+            Some("foo@npm:1.0.0")
+        );
+        assert_eq!(manifest.find_issuer("/elsewhere/"), None);
+    }
+
+    #[test]
+    fn resolves_virtual_locations() {
+        assert_eq!(
+            resolve_virtual("./.yarn/__virtual__/foo-virtual-123/0/cache/foo.zip"),
+            "./.yarn/cache/foo.zip"
+        );
+        assert_eq!(
+            resolve_virtual("./.yarn/__virtual__/foo-virtual-123/1/packages/foo"),
+            "./packages/foo"
+        );
+    }
+}
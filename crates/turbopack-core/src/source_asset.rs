@@ -1,9 +1,10 @@
 use anyhow::Result;
+use turbo_tasks::{primitives::StringVc, Value};
 use turbo_tasks_fs::{FileContent, FileSystemEntryType, FileSystemPathVc, LinkContent};
 
 use crate::{
     asset::{Asset, AssetContent, AssetContentVc, AssetVc},
-    ident::AssetIdentVc,
+    ident::{AssetIdent, AssetIdentVc},
     reference::AssetReferencesVc,
 };
 
@@ -12,13 +13,24 @@ use crate::{
 #[turbo_tasks::value]
 pub struct SourceAsset {
     pub path: FileSystemPathVc,
+    /// The query string the asset was requested with (e.g. `?url`), without
+    /// the leading `?`.
+    pub query: Option<StringVc>,
 }
 
 #[turbo_tasks::value_impl]
 impl SourceAssetVc {
     #[turbo_tasks::function]
     pub fn new(path: FileSystemPathVc) -> Self {
-        Self::cell(SourceAsset { path })
+        Self::cell(SourceAsset { path, query: None })
+    }
+
+    #[turbo_tasks::function]
+    pub fn new_with_query(path: FileSystemPathVc, query: StringVc) -> Self {
+        Self::cell(SourceAsset {
+            path,
+            query: Some(query),
+        })
     }
 }
 
@@ -26,7 +38,17 @@ impl SourceAssetVc {
 impl Asset for SourceAsset {
     #[turbo_tasks::function]
     fn ident(&self) -> AssetIdentVc {
-        AssetIdentVc::from_path(self.path)
+        match self.query {
+            Some(query) => AssetIdentVc::new(Value::new(AssetIdent {
+                path: self.path,
+                query: Some(query),
+                fragment: None,
+                assets: Vec::new(),
+                modifiers: Vec::new(),
+                part: None,
+            })),
+            None => AssetIdentVc::from_path(self.path),
+        }
     }
 
     #[turbo_tasks::function]
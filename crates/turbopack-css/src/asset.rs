@@ -22,6 +22,7 @@ use turbopack_core::{
         origin::{ResolveOrigin, ResolveOriginVc},
         PrimaryResolveResult,
     },
+    source_map::{GenerateSourceMap, GenerateSourceMapVc},
 };
 
 use crate::{
@@ -280,12 +281,27 @@ impl CssChunkItem for ModuleChunkItem {
 
             code_gen.emit(&stylesheet)?;
 
-            let srcmap = ParseResultSourceMap::new(source_map.clone(), srcmap).cell();
+            // When the source was generated from another file, like a stylesheet
+            // transformed by a loader, its map is composed into the map of the
+            // generated CSS so that it points to that file. A generated source
+            // without a map can't be mapped to that file, so no map is emitted
+            // rather than one that presents the generated source as the original.
+            let srcmap = ParseResultSourceMap::new(source_map.clone(), srcmap);
+            let srcmap = match GenerateSourceMapVc::resolve_from(self.module.await?.source).await? {
+                Some(source) => {
+                    let original_source_map = source.generate_source_map();
+                    original_source_map
+                        .await?
+                        .is_some()
+                        .then(|| srcmap.with_original_source_map(original_source_map).cell())
+                }
+                None => Some(srcmap.cell()),
+            };
 
             Ok(CssChunkItemContent {
                 inner_code: code_string.into(),
                 imports,
-                source_map: Some(srcmap),
+                source_map: srcmap,
             }
             .into())
         } else {
@@ -12,10 +12,10 @@ use turbopack_core::{
     asset::{Asset, AssetContentVc, AssetVc, AssetsVc},
     chunk::{
         availability_info::AvailabilityInfo, chunk_content, chunk_content_split, Chunk,
-        ChunkContentResult, ChunkGroupReferenceVc, ChunkItem, ChunkItemVc, ChunkVc,
-        ChunkableAssetVc, ChunkingContext, ChunkingContextVc, ChunksVc, FromChunkableAsset,
-        ModuleId, ModuleIdVc, ModuleIdsVc, OutputChunk, OutputChunkRuntimeInfo,
-        OutputChunkRuntimeInfoVc, OutputChunkVc,
+        ChunkContentResult, ChunkGroupReferenceVc, ChunkItem, ChunkItemSize, ChunkItemSizesVc,
+        ChunkItemVc, ChunkVc, ChunkWithItemSizes, ChunkWithItemSizesVc, ChunkableAssetVc,
+        ChunkingContext, ChunkingContextVc, ChunksVc, FromChunkableAsset, ModuleId, ModuleIdVc,
+        ModuleIdsVc, OutputChunk, OutputChunkRuntimeInfo, OutputChunkRuntimeInfoVc, OutputChunkVc,
     },
     code_builder::{CodeBuilder, CodeVc},
     ident::{AssetIdent, AssetIdentVc},
@@ -515,6 +515,31 @@ impl FromChunkableAsset for CssChunkItemVc {
     }
 }
 
+#[turbo_tasks::value_impl]
+impl ChunkWithItemSizes for CssChunk {
+    #[turbo_tasks::function]
+    async fn chunk_item_sizes(&self) -> Result<ChunkItemSizesVc> {
+        let content = css_chunk_content(
+            self.context,
+            self.main_entries,
+            Value::new(self.availability_info),
+        )
+        .await?;
+        let sizes = content
+            .chunk_items
+            .iter()
+            .map(|&chunk_item| async move {
+                Ok(ChunkItemSize {
+                    chunk_item: chunk_item.into(),
+                    size: chunk_item.content().await?.inner_code.len(),
+                })
+            })
+            .try_join()
+            .await?;
+        Ok(ChunkItemSizesVc::cell(sizes))
+    }
+}
+
 #[turbo_tasks::function]
 fn introspectable_type() -> StringVc {
     StringVc::cell("css chunk".to_string())
@@ -5,7 +5,10 @@ use turbo_tasks::{primitives::StringVc, ValueToString};
 use turbo_tasks_fs::File;
 use turbopack_core::{
     asset::{Asset, AssetContentVc, AssetVc},
-    chunk::{Chunk, ChunkItem, ChunkVc, ChunkingContext, ChunkingContextVc},
+    chunk::{
+        Chunk, ChunkItem, ChunkItemSize, ChunkItemSizesVc, ChunkVc, ChunkWithItemSizes,
+        ChunkWithItemSizesVc, ChunkingContext, ChunkingContextVc,
+    },
     code_builder::{CodeBuilder, CodeVc},
     ident::AssetIdentVc,
     introspect::{Introspectable, IntrospectableVc},
@@ -127,6 +130,17 @@ impl GenerateSourceMap for SingleItemCssChunk {
     }
 }
 
+#[turbo_tasks::value_impl]
+impl ChunkWithItemSizes for SingleItemCssChunk {
+    #[turbo_tasks::function]
+    async fn chunk_item_sizes(&self) -> Result<ChunkItemSizesVc> {
+        Ok(ChunkItemSizesVc::cell(vec![ChunkItemSize {
+            chunk_item: self.item.into(),
+            size: self.item.content().await?.inner_code.len(),
+        }]))
+    }
+}
+
 #[turbo_tasks::function]
 fn introspectable_type() -> StringVc {
     StringVc::cell("single asset css chunk".to_string())
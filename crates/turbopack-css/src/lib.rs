@@ -17,7 +17,10 @@ pub(crate) mod util;
 use anyhow::Result;
 pub use asset::CssModuleAssetVc;
 pub use module_asset::ModuleCssModuleAssetVc;
-pub use transform::{CssInputTransform, CssInputTransformsVc};
+pub use transform::{
+    CssInputTransform, CssInputTransformsVc, CssTransformPlugin, CssTransformPluginVc,
+    CustomCssTransformer, TransformContext,
+};
 
 use crate::references::import::ImportAssetReferenceVc;
 
@@ -66,11 +66,17 @@ pub struct ParseResultSourceMap {
     /// SourceMap.
     #[turbo_tasks(debug_ignore, trace_ignore)]
     mappings: Vec<(BytePos, LineCol)>,
+
+    /// The source map of the parsed source, when the source was itself
+    /// generated from another file. It is composed into the generated map.
+    original_source_map: OptionSourceMapVc,
 }
 
 impl PartialEq for ParseResultSourceMap {
     fn eq(&self, other: &Self) -> bool {
-        Arc::ptr_eq(&self.source_map, &other.source_map) && self.mappings == other.mappings
+        Arc::ptr_eq(&self.source_map, &other.source_map)
+            && self.mappings == other.mappings
+            && self.original_source_map == other.original_source_map
     }
 }
 
@@ -79,6 +85,16 @@ impl ParseResultSourceMap {
         ParseResultSourceMap {
             source_map,
             mappings,
+            original_source_map: OptionSourceMapVc::cell(None),
+        }
+    }
+
+    /// Maps the generated code back to the file the parsed source was
+    /// generated from, using the source map of the parsed source.
+    pub fn with_original_source_map(self, original_source_map: OptionSourceMapVc) -> Self {
+        ParseResultSourceMap {
+            original_source_map,
+            ..self
         }
     }
 }
@@ -86,15 +102,25 @@ impl ParseResultSourceMap {
 #[turbo_tasks::value_impl]
 impl GenerateSourceMap for ParseResultSourceMap {
     #[turbo_tasks::function]
-    fn generate_source_map(&self) -> OptionSourceMapVc {
+    async fn generate_source_map(&self) -> Result<OptionSourceMapVc> {
+        let original_source_map = match *self.original_source_map.await? {
+            Some(map) => Some(map.await?),
+            None => None,
+        };
+        // Only a regular map can be composed. A sectioned one is never generated
+        // for a single source.
+        let original_source_map = match original_source_map.as_deref() {
+            Some(turbopack_core::source_map::SourceMap::Regular(map)) => Some(&****map),
+            _ => None,
+        };
         let map = self.source_map.build_source_map_with_config(
             &self.mappings,
-            None,
+            original_source_map,
             InlineSourcesContentConfig {},
         );
-        OptionSourceMapVc::cell(Some(
+        Ok(OptionSourceMapVc::cell(Some(
             turbopack_core::source_map::SourceMap::new_regular(map).cell(),
-        ))
+        )))
     }
 }
 
@@ -0,0 +1,120 @@
+use swc_core::css::{
+    ast::{
+        ComplexSelector, ComplexSelectorChildren, CompoundSelector, ForgivingComplexSelector,
+        PseudoClassSelector, PseudoClassSelectorChildren, SelectorList, SubclassSelector,
+    },
+    visit::{VisitMut, VisitMutWith},
+};
+
+/// Lowers `:is()` pseudo-classes by expanding them into a selector list, e.g.
+/// `:is(h1, h2).title a` into `h1.title a, h2.title a`.
+///
+/// Only `:is()` arguments that are compound selectors (without combinators)
+/// are expanded, as other arguments can't be expanded without changing which
+/// elements match. Selectors with such arguments are left untouched.
+pub struct IsPseudoClassLowering;
+
+impl VisitMut for IsPseudoClassLowering {
+    fn visit_mut_selector_list(&mut self, list: &mut SelectorList) {
+        list.visit_mut_children_with(self);
+
+        list.children = list
+            .children
+            .drain(..)
+            .flat_map(|selector| expand_complex_selector(selector).into_iter())
+            .collect();
+    }
+}
+
+fn expand_complex_selector(selector: ComplexSelector) -> Vec<ComplexSelector> {
+    for (i, child) in selector.children.iter().enumerate() {
+        let ComplexSelectorChildren::CompoundSelector(compound) = child else {
+            continue;
+        };
+        let Some(expanded) = expand_compound_selector(compound) else {
+            continue;
+        };
+        return expanded
+            .into_iter()
+            .flat_map(|compound| {
+                let mut selector = selector.clone();
+                selector.children[i] = ComplexSelectorChildren::CompoundSelector(compound);
+                expand_complex_selector(selector)
+            })
+            .collect();
+    }
+    vec![selector]
+}
+
+/// Expands the first `:is()` of a compound selector into one compound selector
+/// per argument. Returns `None` when there is nothing to expand.
+fn expand_compound_selector(compound: &CompoundSelector) -> Option<Vec<CompoundSelector>> {
+    compound
+        .subclass_selectors
+        .iter()
+        .enumerate()
+        .find_map(|(i, subclass)| {
+            let SubclassSelector::PseudoClass(pseudo) = subclass else {
+                return None;
+            };
+            if !pseudo.name.value.as_ref().eq_ignore_ascii_case("is") {
+                return None;
+            }
+            is_arguments(pseudo)?
+                .into_iter()
+                .map(|argument| merge_compound_selectors(compound, i, argument))
+                .collect()
+        })
+}
+
+/// Returns the arguments of an `:is()` pseudo-class when they are all compound
+/// selectors.
+fn is_arguments(pseudo: &PseudoClassSelector) -> Option<Vec<&CompoundSelector>> {
+    let [argument] = pseudo.children.as_deref()? else {
+        return None;
+    };
+    let selectors: Vec<&ComplexSelector> = match argument {
+        PseudoClassSelectorChildren::SelectorList(list) => list.children.iter().collect(),
+        PseudoClassSelectorChildren::ForgivingSelectorList(list) => list
+            .children
+            .iter()
+            .map(|selector| match selector {
+                ForgivingComplexSelector::ComplexSelector(selector) => Some(selector),
+                ForgivingComplexSelector::ListOfComponentValues(_) => None,
+            })
+            .collect::<Option<_>>()?,
+        _ => return None,
+    };
+    selectors
+        .into_iter()
+        .map(|selector| match selector.children.as_slice() {
+            [ComplexSelectorChildren::CompoundSelector(compound)]
+                if compound.nesting_selector.is_none() =>
+            {
+                Some(compound)
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Replaces the subclass selector at `index` of `compound` with the selectors
+/// of `argument`. Returns `None` when both have a type selector.
+fn merge_compound_selectors(
+    compound: &CompoundSelector,
+    index: usize,
+    argument: &CompoundSelector,
+) -> Option<CompoundSelector> {
+    let type_selector = match (&compound.type_selector, &argument.type_selector) {
+        (Some(_), Some(_)) => return None,
+        (type_selector, None) | (None, type_selector) => type_selector.clone(),
+    };
+    let mut subclass_selectors = compound.subclass_selectors.clone();
+    subclass_selectors.splice(index..=index, argument.subclass_selectors.iter().cloned());
+    Some(CompoundSelector {
+        span: compound.span,
+        nesting_selector: compound.nesting_selector.clone(),
+        type_selector,
+        subclass_selectors,
+    })
+}
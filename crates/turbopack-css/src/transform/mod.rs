@@ -1,22 +1,80 @@
-use std::sync::Arc;
+mod is_pseudo_class;
+
+use std::{fmt::Debug, sync::Arc};
 
 use anyhow::Result;
+use async_trait::async_trait;
 use swc_core::{
     common::SourceMap,
-    css::{ast::Stylesheet, visit::VisitMutWith},
+    css::{
+        ast::Stylesheet,
+        compat::{
+            compiler::{Compiler, Config},
+            feature::Features,
+        },
+        prefixer::{
+            options::{Options, Targets},
+            prefixer,
+        },
+        visit::VisitMutWith,
+    },
+    ecma::preset_env::Versions,
 };
+use turbopack_core::environment::EnvironmentVc;
+
+use self::is_pseudo_class::IsPseudoClassLowering;
 
 #[turbo_tasks::value(serialization = "auto_for_input")]
 #[derive(PartialOrd, Ord, Hash, Debug, Copy, Clone)]
 pub enum CssInputTransform {
     Nested,
-    Custom,
+    /// Adds vendor prefixes and lowers modern syntax (colors, media query
+    /// ranges, `:is()` and `:not()` selectors) for the browsers targeted by
+    /// the environment's browserslist query.
+    PresetEnv(EnvironmentVc),
+    Custom(CssTransformPluginVc),
 }
 
 #[turbo_tasks::value(transparent, serialization = "auto_for_input")]
 #[derive(Debug, PartialOrd, Ord, Hash, Clone)]
 pub struct CssInputTransforms(Vec<CssInputTransform>);
 
+/// The CustomCssTransformer trait allows you to implement your own custom
+/// transformer to run over all CSS files imported in the graph.
+#[async_trait]
+pub trait CustomCssTransformer: Debug {
+    async fn transform(
+        &self,
+        stylesheet: &mut Stylesheet,
+        ctx: &TransformContext<'_>,
+    ) -> Result<()>;
+}
+
+/// A wrapper around a CustomCssTransformer instance, allowing it to operate
+/// with the turbo_task caching requirements.
+#[turbo_tasks::value(
+    transparent,
+    serialization = "none",
+    eq = "manual",
+    into = "new",
+    cell = "new"
+)]
+#[derive(Debug)]
+pub struct CssTransformPlugin(
+    #[turbo_tasks(trace_ignore)] Box<dyn CustomCssTransformer + Send + Sync>,
+);
+
+#[async_trait]
+impl CustomCssTransformer for CssTransformPlugin {
+    async fn transform(
+        &self,
+        stylesheet: &mut Stylesheet,
+        ctx: &TransformContext<'_>,
+    ) -> Result<()> {
+        self.0.transform(stylesheet, ctx).await
+    }
+}
+
 pub struct TransformContext<'a> {
     pub source_map: &'a Arc<SourceMap>,
 }
@@ -25,18 +83,201 @@ impl CssInputTransform {
     pub async fn apply(
         &self,
         stylesheet: &mut Stylesheet,
-        &TransformContext { source_map: _ }: &TransformContext<'_>,
+        ctx: &TransformContext<'_>,
     ) -> Result<()> {
         match *self {
             CssInputTransform::Nested => {
-                stylesheet.visit_mut_with(&mut swc_core::css::compat::compiler::Compiler::new(
-                    swc_core::css::compat::compiler::Config {
-                        process: swc_core::css::compat::feature::Features::NESTING,
-                    },
-                ));
+                stylesheet.visit_mut_with(&mut Compiler::new(Config {
+                    process: Features::NESTING,
+                }));
+            }
+            CssInputTransform::PresetEnv(env) => {
+                let versions = *env.runtime_versions().await?;
+                let support = css_feature_support(env).await?;
+
+                stylesheet.visit_mut_with(&mut Compiler::new(Config {
+                    process: compat_features(&support),
+                }));
+                if !support.is_pseudo_class {
+                    stylesheet.visit_mut_with(&mut IsPseudoClassLowering);
+                }
+                stylesheet.visit_mut_with(&mut prefixer(Options {
+                    env: Some(Targets::Versions(versions)),
+                }));
+            }
+            CssInputTransform::Custom(plugin) => {
+                plugin.await?.transform(stylesheet, ctx).await?;
             }
-            CssInputTransform::Custom => todo!(),
         }
         Ok(())
     }
 }
+
+/// Browserslist queries for the browsers that support the syntax lowered by
+/// the preset env transform, answered by the caniuse data of browserslist.
+///
+/// See https://caniuse.com/css-media-range-syntax
+const MEDIA_QUERY_RANGES: &str = "supports css-media-range-syntax";
+/// See https://caniuse.com/css-rrggbbaa
+const COLOR_HEX_ALPHA: &str = "supports css-rrggbbaa";
+/// See https://caniuse.com/css-not-sel-list
+const SELECTOR_NOT_LIST: &str = "supports css-not-sel-list";
+/// caniuse counts the prefixed `:-webkit-any()` and `:matches()` as partial
+/// support of `:is()`, which the `supports` query doesn't tell apart from full
+/// support, so the browsers shipping `:is()` are listed instead.
+///
+/// See https://caniuse.com/css-matches-pseudo and
+/// https://developer.mozilla.org/en-US/docs/Web/CSS/:is#browser_compatibility
+const IS_PSEUDO_CLASS: &str = "chrome >= 88, edge >= 88, firefox >= 78, safari >= 14, ios_saf >= \
+                               14, samsung >= 15, opera >= 75";
+
+/// Whether all browsers targeted by an environment support the syntax lowered
+/// by the preset env transform.
+#[turbo_tasks::value]
+struct CssFeatureSupport {
+    media_query_ranges: bool,
+    color_hex_alpha: bool,
+    selector_not_list: bool,
+    is_pseudo_class: bool,
+}
+
+/// Resolves the feature support of the browsers targeted by `env` once, so
+/// it's shared by all stylesheets of the environment.
+#[turbo_tasks::function]
+async fn css_feature_support(env: EnvironmentVc) -> Result<CssFeatureSupportVc> {
+    let versions = *env.runtime_versions().await?;
+    Ok(CssFeatureSupport {
+        media_query_ranges: supports(&versions, MEDIA_QUERY_RANGES)?,
+        color_hex_alpha: supports(&versions, COLOR_HEX_ALPHA)?,
+        selector_not_list: supports(&versions, SELECTOR_NOT_LIST)?,
+        is_pseudo_class: supports(&versions, IS_PSEUDO_CLASS)?,
+    }
+    .cell())
+}
+
+/// Returns the first version of each browser matched by a browserslist
+/// query.
+fn first_versions(query: &str) -> Result<Versions> {
+    Versions::parse_versions(browserslist::resolve([query], &browserslist::Opts::new())?)
+}
+
+/// Returns whether all browsers in `versions` support a feature. Browsers
+/// without support data are assumed to not support it, while server runtimes
+/// are ignored as they never render CSS.
+fn supports(versions: &Versions, query: &str) -> Result<bool> {
+    let support = first_versions(query)?;
+    Ok(versions.iter().all(|(browser, version)| {
+        let Some(version) = version else {
+            return true;
+        };
+        if matches!(browser, "node" | "deno" | "bun") {
+            return true;
+        }
+        // The Android browser has aligned its versioning with Chrome.
+        let first_supported = support[browser].or(if browser == "android" {
+            support.chrome
+        } else {
+            None
+        });
+        first_supported.map_or(false, |first_supported| *version >= first_supported)
+    }))
+}
+
+/// Returns the syntax lowering features needed for browsers with `support`.
+fn compat_features(support: &CssFeatureSupport) -> Features {
+    // No browser supports custom media queries yet. caniuse has no data for
+    // `hwb()` colors and the CSS Color 4 syntax of `rgb()` and `hsl()`, so they
+    // are always lowered, which every browser supports.
+    let mut features = Features::CUSTOM_MEDIA
+        | Features::COLOR_HWB
+        | Features::COLOR_SPACE_SEPARATED_PARAMETERS
+        | Features::COLOR_ALPHA_PARAMETER
+        | Features::COLOR_LEGACY_RGB_AND_HSL;
+    if !support.media_query_ranges {
+        features |= Features::MEDIA_QUERY_RANGES;
+    }
+    if !support.color_hex_alpha {
+        features |= Features::COLOR_HEX_ALPHA;
+    }
+    if !support.selector_not_list {
+        features |= Features::SELECTOR_NOT;
+    }
+    features
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use swc_core::{
+        common::{FileName, SourceMap},
+        css::{ast::Stylesheet, parser::parse_file},
+        ecma::preset_env::Versions,
+    };
+    use turbo_tasks::TurboTasks;
+    use turbo_tasks_memory::MemoryBackend;
+
+    use super::{
+        supports, CssInputTransform, CssTransformPluginVc, CustomCssTransformer, TransformContext,
+        COLOR_HEX_ALPHA, IS_PSEUDO_CLASS,
+    };
+
+    #[derive(Debug)]
+    struct RemoveRules;
+
+    #[async_trait]
+    impl CustomCssTransformer for RemoveRules {
+        async fn transform(
+            &self,
+            stylesheet: &mut Stylesheet,
+            _ctx: &TransformContext<'_>,
+        ) -> Result<()> {
+            stylesheet.rules.clear();
+            Ok(())
+        }
+    }
+
+    fn versions(query: &str) -> Versions {
+        Versions::parse_versions(
+            browserslist::resolve([query], &browserslist::Opts::new()).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn derives_support_from_browserslist() {
+        assert!(supports(&versions("chrome 100, safari 15"), COLOR_HEX_ALPHA).unwrap());
+        assert!(!supports(&versions("chrome 100, ie 11"), COLOR_HEX_ALPHA).unwrap());
+        assert!(!supports(&versions("chrome 50"), COLOR_HEX_ALPHA).unwrap());
+        assert!(supports(&versions("node 16"), COLOR_HEX_ALPHA).unwrap());
+        assert!(supports(&versions("chrome 88, safari 14"), IS_PSEUDO_CLASS).unwrap());
+        assert!(!supports(&versions("chrome 60"), IS_PSEUDO_CLASS).unwrap());
+    }
+
+    #[tokio::test]
+    async fn applies_custom_transforms() {
+        crate::register();
+        let tt = TurboTasks::new(MemoryBackend::default());
+        tt.run_once(async move {
+            let source_map: std::sync::Arc<SourceMap> = Default::default();
+            let fm = source_map.new_source_file(FileName::Anon, "a { color: red }".to_string());
+            let mut stylesheet =
+                parse_file::<Stylesheet>(&fm, Default::default(), &mut Vec::new()).unwrap();
+            assert_eq!(stylesheet.rules.len(), 1);
+
+            let plugin = CssTransformPluginVc::cell(Box::new(RemoveRules));
+            CssInputTransform::Custom(plugin)
+                .apply(
+                    &mut stylesheet,
+                    &TransformContext {
+                        source_map: &source_map,
+                    },
+                )
+                .await?;
+            assert!(stylesheet.rules.is_empty());
+            Ok(())
+        })
+        .await
+        .unwrap();
+    }
+}
@@ -0,0 +1,543 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use anyhow::Result;
+use indexmap::{IndexMap, IndexSet};
+use serde::Serialize;
+use turbo_tasks::{
+    primitives::StringVc, CompletionVc, CompletionsVc, TryJoinIterExt, Value, ValueToString,
+};
+use turbo_tasks_fs::{File, FileContent, FileContentVc, FileSystemPathVc};
+use turbopack_core::{
+    asset::{Asset, AssetContent, AssetContentVc, AssetVc, AssetsSetVc, AssetsVc},
+    chunk::{
+        ChunkGroupLoader, ChunkGroupLoaderVc, ChunkItem, ChunkWithItemSizes, ChunkWithItemSizesVc,
+        EvaluateChunkVc,
+    },
+    ident::AssetIdentVc,
+    introspect::{Introspectable, IntrospectableVc},
+    reference::AssetReference,
+};
+
+use crate::source::{
+    route_tree::{BaseSegment, RouteTreeVc, RouteTreesVc, RouteType},
+    ContentSource, ContentSourceContentVc, ContentSourceData, ContentSourceVc,
+    GetContentSourceContent, GetContentSourceContentVc,
+};
+
+/// The entrypoints of a bundle: the name of each one and the chunks it loads.
+#[turbo_tasks::value(transparent)]
+pub struct BundleEntrypoints(Vec<(String, AssetsVc)>);
+
+#[turbo_tasks::value_impl]
+impl BundleEntrypointsVc {
+    /// Creates an entrypoint for each page, named after its path, which loads
+    /// the chunks the page references.
+    #[turbo_tasks::function]
+    pub async fn from_pages(pages: AssetsSetVc) -> Result<Self> {
+        let entrypoints = pages
+            .await?
+            .iter()
+            .map(|&page| async move {
+                Ok((
+                    page.ident().path().await?.path.clone(),
+                    AssetsVc::cell(referenced_assets(page).await?),
+                ))
+            })
+            .try_join()
+            .await?;
+        Ok(Self::cell(entrypoints))
+    }
+}
+
+/// Serves a bundle analysis of `entrypoints`: a `stats.json` in the format of
+/// webpack stats, and a treemap of the modules in each chunk at the root.
+#[turbo_tasks::value(shared)]
+pub struct BundleAnalysisSource {
+    pub entrypoints: BundleEntrypointsVc,
+}
+
+#[turbo_tasks::value_impl]
+impl BundleAnalysisSourceVc {
+    #[turbo_tasks::function]
+    pub fn new(entrypoints: BundleEntrypointsVc) -> Self {
+        BundleAnalysisSource { entrypoints }.cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ContentSource for BundleAnalysisSource {
+    #[turbo_tasks::function]
+    fn get_routes(&self) -> RouteTreeVc {
+        let item = |stats_json| {
+            BundleAnalysisSourceItem {
+                entrypoints: self.entrypoints,
+                stats_json,
+            }
+            .cell()
+            .into()
+        };
+        RouteTreesVc::cell(vec![
+            RouteTreeVc::new_route(Vec::new(), RouteType::Exact, item(false)),
+            RouteTreeVc::new_route(
+                vec![BaseSegment::Static("stats.json".to_string())],
+                RouteType::Exact,
+                item(true),
+            ),
+        ])
+        .merge()
+    }
+}
+
+#[turbo_tasks::value]
+struct BundleAnalysisSourceItem {
+    entrypoints: BundleEntrypointsVc,
+    /// Whether to serve the stats instead of the treemap.
+    stats_json: bool,
+}
+
+#[turbo_tasks::value_impl]
+impl GetContentSourceContent for BundleAnalysisSourceItem {
+    #[turbo_tasks::function]
+    fn get(&self, _path: &str, _data: Value<ContentSourceData>) -> ContentSourceContentVc {
+        let content = if self.stats_json {
+            stats_json(self.entrypoints)
+        } else {
+            treemap_html(self.entrypoints)
+        };
+        ContentSourceContentVc::static_content(AssetContent::File(content).cell().into())
+    }
+}
+
+/// Writes the `stats.json` and the `treemap.html` of `entrypoints` into `dir`.
+#[turbo_tasks::function]
+pub fn write_bundle_analysis(
+    entrypoints: BundleEntrypointsVc,
+    dir: FileSystemPathVc,
+) -> CompletionVc {
+    CompletionsVc::all(vec![
+        dir.join("stats.json").write(stats_json(entrypoints)),
+        dir.join("treemap.html").write(treemap_html(entrypoints)),
+    ])
+}
+
+#[turbo_tasks::function]
+async fn stats_json(entrypoints: BundleEntrypointsVc) -> Result<FileContentVc> {
+    let stats = bundle_stats(entrypoints).await?;
+    Ok(File::from(stats.as_str())
+        .with_content_type(mime::APPLICATION_JSON)
+        .into())
+}
+
+#[turbo_tasks::function]
+async fn treemap_html(entrypoints: BundleEntrypointsVc) -> Result<FileContentVc> {
+    let stats = bundle_stats(entrypoints).await?;
+    // The stats are embedded into a script tag, so they must not be able to close
+    // it.
+    let html = include_str!("treemap.html").replace(
+        "/* STATS */ null",
+        &stats.replace("</", "<\\/").replace("<!--", "<\\!--"),
+    );
+    Ok(File::from(html)
+        .with_content_type(mime::TEXT_HTML_UTF_8)
+        .into())
+}
+
+#[turbo_tasks::value_impl]
+impl Introspectable for BundleAnalysisSource {
+    #[turbo_tasks::function]
+    fn ty(&self) -> StringVc {
+        StringVc::cell("bundle analysis source".to_string())
+    }
+
+    #[turbo_tasks::function]
+    fn details(&self) -> StringVc {
+        StringVc::cell("serves stats.json and a treemap of the bundled modules".to_string())
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Stats {
+    public_path: &'static str,
+    assets: Vec<StatsAsset>,
+    chunks: Vec<StatsChunk>,
+    modules: Vec<StatsModule>,
+    entrypoints: IndexMap<String, StatsChunkGroup>,
+    named_chunk_groups: IndexMap<String, StatsChunkGroup>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StatsAsset {
+    name: String,
+    size: usize,
+    chunks: Vec<usize>,
+    chunk_names: Vec<String>,
+    emitted: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StatsChunk {
+    id: usize,
+    names: Vec<String>,
+    files: Vec<String>,
+    size: usize,
+    /// Whether the chunk evaluates the entries of its chunk group.
+    entry: bool,
+    initial: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StatsModule {
+    id: String,
+    identifier: String,
+    name: String,
+    /// The size of the original source. Unknown for generated modules.
+    size: Option<usize>,
+    /// The size of the code emitted into all chunks.
+    emitted_size: usize,
+    chunks: Vec<usize>,
+    /// The size of the code emitted into each of `chunks`.
+    emitted_sizes: Vec<usize>,
+    depth: Option<usize>,
+    issuer: Option<String>,
+    issuer_name: Option<String>,
+    /// The import chain from an entry module to the direct issuer.
+    issuer_path: Vec<StatsIssuer>,
+    reasons: Vec<StatsReason>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StatsIssuer {
+    identifier: String,
+    name: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StatsReason {
+    module_identifier: String,
+    module_name: String,
+    #[serde(rename = "type")]
+    ty: &'static str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StatsChunkGroup {
+    name: String,
+    chunks: Vec<usize>,
+    assets: Vec<StatsChunkGroupAsset>,
+}
+
+#[derive(Serialize)]
+struct StatsChunkGroupAsset {
+    name: String,
+    size: usize,
+}
+
+struct ChunkGroup {
+    name: String,
+    initial: bool,
+    assets: Vec<AssetVc>,
+}
+
+struct Module {
+    identifier: String,
+    name: String,
+    size: Option<usize>,
+    chunks: Vec<usize>,
+    /// The size of the code emitted into each of `chunks`.
+    emitted_sizes: Vec<usize>,
+    references: Vec<String>,
+}
+
+/// Walks the chunk groups of `entrypoints` and the async chunk groups they
+/// load, and returns webpack compatible stats of the chunks and of the modules
+/// in them.
+#[turbo_tasks::function]
+pub async fn bundle_stats(entrypoints: BundleEntrypointsVc) -> Result<StringVc> {
+    let entrypoints = entrypoints.await?;
+    let mut queue = VecDeque::with_capacity(entrypoints.len());
+    let mut output_fs = None;
+    for (name, roots) in entrypoints.iter() {
+        let roots = roots.await?;
+        if let Some(root) = roots.first() {
+            output_fs.get_or_insert(root.ident().path().await?.fs.resolve().await?);
+        }
+        queue.push_back((name.clone(), true, roots.to_vec()));
+    }
+
+    let mut groups = Vec::new();
+    let mut group_names = HashSet::new();
+    while let Some((name, initial, roots)) = queue.pop_front() {
+        if !group_names.insert(name.clone()) {
+            continue;
+        }
+        let mut assets = IndexSet::new();
+        // The chunks of the async chunk groups loaded by this one.
+        let mut loaded = HashSet::new();
+        let mut asset_queue = VecDeque::from(roots);
+        while let Some(asset) = asset_queue.pop_front() {
+            let asset = asset.resolve().await?;
+            let path = asset.ident().path().await?;
+            // Only output assets are part of a chunk group, and source maps belong to the
+            // chunk they map.
+            if Some(path.fs.resolve().await?) != output_fs
+                || path.path.ends_with(".map")
+                || loaded.contains(&asset)
+                || !assets.insert(asset)
+            {
+                continue;
+            }
+            if let Some(chunk) = ChunkWithItemSizesVc::resolve_from(asset).await? {
+                for item in chunk.chunk_item_sizes().await?.iter() {
+                    let Some(loader) = ChunkGroupLoaderVc::resolve_from(item.chunk_item).await? else {
+                        continue;
+                    };
+                    let loaded_chunks = loader.loaded_chunks().await?;
+                    for &chunk in loaded_chunks.iter() {
+                        loaded.insert(chunk.resolve().await?);
+                    }
+                    queue.push_back((
+                        loader.loaded_asset().ident().path().await?.path.clone(),
+                        false,
+                        loaded_chunks.to_vec(),
+                    ));
+                }
+            }
+            asset_queue.extend(referenced_assets(asset).await?);
+        }
+        groups.push(ChunkGroup {
+            name,
+            initial,
+            assets: assets.into_iter().collect(),
+        });
+    }
+
+    let mut chunk_ids = IndexMap::new();
+    let mut chunks = Vec::new();
+    let mut assets = Vec::new();
+    let mut modules: IndexMap<String, Module> = IndexMap::new();
+    for group in &groups {
+        for &asset in &group.assets {
+            if chunk_ids.contains_key(&asset) {
+                continue;
+            }
+            let id = chunks.len();
+            chunk_ids.insert(asset, id);
+            let name = asset.ident().path().await?.path.clone();
+            let size = content_size(asset.content()).await?.unwrap_or_default();
+            if let Some(chunk) = ChunkWithItemSizesVc::resolve_from(asset).await? {
+                for item in chunk.chunk_item_sizes().await?.iter() {
+                    let ident = item.chunk_item.asset_ident();
+                    let identifier = ident.to_string().await?.to_string();
+                    if let Some(module) = modules.get_mut(&identifier) {
+                        module.chunks.push(id);
+                        module.emitted_sizes.push(item.size);
+                        continue;
+                    }
+                    let module = Module {
+                        name: format!("./{}", ident.path().await?.path),
+                        size: original_size(ident).await?,
+                        chunks: vec![id],
+                        emitted_sizes: vec![item.size],
+                        references: item
+                            .chunk_item
+                            .references()
+                            .await?
+                            .iter()
+                            .map(|reference| async move {
+                                reference
+                                    .resolve_reference()
+                                    .primary_assets()
+                                    .await?
+                                    .iter()
+                                    .map(|asset| async move {
+                                        Ok(asset.ident().to_string().await?.to_string())
+                                    })
+                                    .try_join()
+                                    .await
+                            })
+                            .try_join()
+                            .await?
+                            .into_iter()
+                            .flatten()
+                            .collect(),
+                        identifier: identifier.clone(),
+                    };
+                    modules.insert(identifier, module);
+                }
+            }
+            chunks.push(StatsChunk {
+                id,
+                names: Vec::new(),
+                files: vec![name.clone()],
+                size,
+                entry: EvaluateChunkVc::resolve_from(asset).await?.is_some(),
+                initial: group.initial,
+            });
+            assets.push(StatsAsset {
+                name,
+                size,
+                chunks: vec![id],
+                chunk_names: Vec::new(),
+                emitted: true,
+            });
+        }
+    }
+
+    // The import chain of a module is the shortest path from a module which isn't
+    // imported by any other module.
+    let mut importers: HashMap<&str, Vec<&str>> = HashMap::new();
+    for module in modules.values() {
+        for reference in &module.references {
+            if let Some((reference, _)) = modules.get_key_value(reference) {
+                importers
+                    .entry(reference.as_str())
+                    .or_default()
+                    .push(module.identifier.as_str());
+            }
+        }
+    }
+    let mut issuers: HashMap<&str, Option<&str>> = HashMap::new();
+    let mut module_queue = VecDeque::with_capacity(modules.len());
+    for module in modules.keys() {
+        if !importers.contains_key(module.as_str()) {
+            issuers.insert(module, None);
+            module_queue.push_back(module.as_str());
+        }
+    }
+    while let Some(module) = module_queue.pop_front() {
+        for reference in &modules[module].references {
+            if let Some((reference, _)) = modules.get_key_value(reference) {
+                if !issuers.contains_key(reference.as_str()) {
+                    issuers.insert(reference, Some(module));
+                    module_queue.push_back(reference);
+                }
+            }
+        }
+    }
+
+    let issuer_path = |module: &str| {
+        let mut path = Vec::new();
+        let mut current = issuers.get(module).copied().flatten();
+        while let Some(issuer) = current {
+            path.push(StatsIssuer {
+                identifier: issuer.to_string(),
+                name: modules[issuer].name.clone(),
+            });
+            current = issuers.get(issuer).copied().flatten();
+        }
+        path.reverse();
+        path
+    };
+    let stats_modules = modules
+        .values()
+        .map(|module| {
+            let issuer_path = issuer_path(&module.identifier);
+            StatsModule {
+                id: module.identifier.clone(),
+                identifier: module.identifier.clone(),
+                name: module.name.clone(),
+                size: module.size,
+                emitted_size: module.emitted_sizes.iter().sum(),
+                chunks: module.chunks.clone(),
+                emitted_sizes: module.emitted_sizes.clone(),
+                depth: issuers
+                    .contains_key(module.identifier.as_str())
+                    .then_some(issuer_path.len()),
+                issuer: issuer_path.last().map(|issuer| issuer.identifier.clone()),
+                issuer_name: issuer_path.last().map(|issuer| issuer.name.clone()),
+                issuer_path,
+                reasons: importers
+                    .get(module.identifier.as_str())
+                    .into_iter()
+                    .flatten()
+                    .map(|&importer| StatsReason {
+                        module_identifier: importer.to_string(),
+                        module_name: modules[importer].name.clone(),
+                        ty: "import",
+                    })
+                    .collect(),
+            }
+        })
+        .collect();
+
+    let mut entrypoints = IndexMap::new();
+    let mut named_chunk_groups = IndexMap::new();
+    for group in groups {
+        let stats_group = StatsChunkGroup {
+            chunks: group.assets.iter().map(|asset| chunk_ids[asset]).collect(),
+            assets: group
+                .assets
+                .iter()
+                .map(|asset| {
+                    let asset = &assets[chunk_ids[asset]];
+                    StatsChunkGroupAsset {
+                        name: asset.name.clone(),
+                        size: asset.size,
+                    }
+                })
+                .collect(),
+            name: group.name.clone(),
+        };
+        if group.initial {
+            entrypoints.insert(group.name, stats_group);
+        } else {
+            named_chunk_groups.insert(group.name, stats_group);
+        }
+    }
+
+    let stats = Stats {
+        public_path: "/",
+        assets,
+        chunks,
+        modules: stats_modules,
+        entrypoints,
+        named_chunk_groups,
+    };
+    Ok(StringVc::cell(serde_json::to_string(&stats)?))
+}
+
+async fn referenced_assets(asset: AssetVc) -> Result<Vec<AssetVc>> {
+    Ok(asset
+        .references()
+        .await?
+        .iter()
+        .map(|reference| async move {
+            Ok(reference
+                .resolve_reference()
+                .primary_assets()
+                .await?
+                .iter()
+                .copied()
+                .collect::<Vec<_>>())
+        })
+        .try_join()
+        .await?
+        .into_iter()
+        .flatten()
+        .collect())
+}
+
+async fn content_size(content: AssetContentVc) -> Result<Option<usize>> {
+    Ok(match &*content.await? {
+        AssetContent::File(file) => match &*file.await? {
+            FileContent::Content(file) => Some(file.content().len()),
+            FileContent::NotFound => None,
+        },
+        AssetContent::Redirect { .. } => None,
+    })
+}
+
+/// The size of the file a module was created from.
+async fn original_size(ident: AssetIdentVc) -> Result<Option<usize>> {
+    Ok(match &*ident.path().read().await? {
+        FileContent::Content(file) => Some(file.content().len()),
+        FileContent::NotFound => None,
+    })
+}
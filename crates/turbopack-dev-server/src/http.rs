@@ -191,7 +191,7 @@ pub async fn process_request_with_content_source(
             for (name, value) in &proxy_result.headers {
                 headers.append(
                     HeaderName::from_bytes(name.as_bytes())?,
-                    hyper::header::HeaderValue::from_str(value)?,
+                    hyper::header::HeaderValue::from_bytes(value.as_bytes())?,
                 );
             }
 
@@ -4,11 +4,13 @@
 #![feature(iter_intersperse)]
 #![feature(str_split_remainder)]
 
+pub mod analyze;
 pub mod html;
 mod http;
 pub mod introspect;
 mod invalidation;
 pub mod source;
+pub mod tls;
 pub mod update;
 
 use std::{
@@ -34,15 +36,23 @@ use turbo_tasks::{
     run_once_with_reason, trace::TraceRawVcs, util::FormatDuration, CollectiblesSource, RawVc,
     TransientInstance, TransientValue, TurboTasksApi,
 };
+use turbopack_cli_utils::issue_rules::IssueRules;
 use turbopack_core::{
     error::PrettyPrintError,
     issue::{IssueReporter, IssueReporterVc, IssueVc},
 };
 
-use self::{source::ContentSourceVc, update::UpdateServer};
+use self::{
+    source::ContentSourceVc,
+    tls::{Incoming, ServerConfig},
+    update::UpdateServer,
+};
 use crate::{
     invalidation::{ServerRequest, ServerRequestSideEffects},
-    source::ContentSourceSideEffect,
+    source::{
+        proxy::{find_websocket_proxy, proxy_websocket},
+        ContentSourceSideEffect,
+    },
 };
 
 pub trait SourceProvider: Send + Clone + 'static {
@@ -64,7 +74,8 @@ pub struct DevServerBuilder {
     #[turbo_tasks(trace_ignore)]
     pub addr: SocketAddr,
     #[turbo_tasks(trace_ignore)]
-    server: Builder<AddrIncoming>,
+    server: Builder<Incoming>,
+    issue_rules: IssueRules,
 }
 
 #[derive(TraceRawVcs)]
@@ -100,6 +111,29 @@ async fn handle_issues<T: Into<RawVc> + CollectiblesSource + Copy>(
 
 impl DevServer {
     pub fn listen(addr: SocketAddr) -> Result<DevServerBuilder, anyhow::Error> {
+        let incoming = Self::bind(addr)?;
+        Ok(DevServerBuilder {
+            addr: incoming.local_addr(),
+            server: Server::builder(Incoming::plain(incoming)),
+            issue_rules: IssueRules::default(),
+        })
+    }
+
+    /// Like [DevServer::listen], but serves HTTPS with a configuration created
+    /// by [tls::server_config].
+    pub fn listen_tls(
+        addr: SocketAddr,
+        config: Arc<ServerConfig>,
+    ) -> Result<DevServerBuilder, anyhow::Error> {
+        let incoming = Self::bind(addr)?;
+        Ok(DevServerBuilder {
+            addr: incoming.local_addr(),
+            server: Server::builder(Incoming::tls(incoming, config)),
+            issue_rules: IssueRules::default(),
+        })
+    }
+
+    fn bind(addr: SocketAddr) -> Result<AddrIncoming> {
         // This is annoying. The hyper::Server doesn't allow us to know which port was
         // bound (until we build it with a request handler) when using the standard
         // `server::try_bind` approach. This is important when binding the `0` port,
@@ -125,15 +159,23 @@ impl DevServer {
         socket.listen(128).context("not able to listen on socket")?;
 
         let listener: TcpListener = socket.into();
-        let addr = listener
-            .local_addr()
-            .context("not able to get bound address")?;
-        let server = Server::from_tcp(listener).context("Not able to start server")?;
-        Ok(DevServerBuilder { addr, server })
+        listener
+            .set_nonblocking(true)
+            .context("not able to configure socket")?;
+        let listener =
+            tokio::net::TcpListener::from_std(listener).context("Not able to start server")?;
+        AddrIncoming::from_listener(listener).context("Not able to start server")
     }
 }
 
 impl DevServerBuilder {
+    /// Applies `issue_rules` to the issues sent to HMR clients. Issues
+    /// reported to the issue reporter are filtered by the reporter itself.
+    pub fn issue_rules(mut self, issue_rules: IssueRules) -> Self {
+        self.issue_rules = issue_rules;
+        self
+    }
+
     pub fn serve(
         self,
         turbo_tasks: Arc<dyn TurboTasksApi>,
@@ -143,10 +185,12 @@ impl DevServerBuilder {
         let ongoing_side_effects = Arc::new(Mutex::new(VecDeque::<
             Arc<tokio::sync::Mutex<Option<JoinHandle<Result<()>>>>>,
         >::with_capacity(16)));
+        let issue_rules = Arc::new(self.issue_rules);
         let make_svc = make_service_fn(move |_| {
             let tt = turbo_tasks.clone();
             let source_provider = source_provider.clone();
             let get_issue_reporter = get_issue_reporter.clone();
+            let issue_rules = issue_rules.clone();
             let ongoing_side_effects = ongoing_side_effects.clone();
             async move {
                 let handler = move |request: Request<hyper::Body>| {
@@ -154,6 +198,7 @@ impl DevServerBuilder {
                     let start = Instant::now();
                     let tt = tt.clone();
                     let get_issue_reporter = get_issue_reporter.clone();
+                    let issue_rules = issue_rules.clone();
                     let ongoing_side_effects = ongoing_side_effects.clone();
                     let source_provider = source_provider.clone();
                     let future = async move {
@@ -197,18 +242,26 @@ impl DevServerBuilder {
                             let issue_reporter = get_issue_reporter();
 
                             if hyper_tungstenite::is_upgrade_request(&request) {
-                                let uri = request.uri();
-                                let path = uri.path();
+                                let path = request.uri().path().to_string();
 
                                 if path == "/turbopack-hmr" {
                                     let (response, websocket) =
                                         hyper_tungstenite::upgrade(request, None)?;
-                                    let update_server =
-                                        UpdateServer::new(source_provider, issue_reporter);
+                                    let update_server = UpdateServer::new(
+                                        source_provider,
+                                        issue_reporter,
+                                        issue_rules,
+                                    );
                                     update_server.run(&*tt, websocket);
                                     return Ok(response);
                                 }
 
+                                let source = source_provider.get_source();
+                                if let Some(proxy) = *find_websocket_proxy(source, &path).await? {
+                                    let proxy = proxy.await?;
+                                    return proxy_websocket(&proxy.options, request).await;
+                                }
+
                                 println!("[404] {} (WebSocket)", path);
                                 if path == "/_next/webpack-hmr" {
                                     // Special-case requests to webpack-hmr as these are made by
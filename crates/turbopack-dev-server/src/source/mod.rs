@@ -4,6 +4,7 @@ pub mod conditional;
 pub mod headers;
 pub mod issue_context;
 pub mod lazy_instantiated;
+pub mod proxy;
 pub mod query;
 pub mod request;
 pub(crate) mod resolve;
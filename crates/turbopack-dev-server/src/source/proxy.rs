@@ -0,0 +1,408 @@
+use anyhow::{anyhow, bail, Context, Result};
+use futures::StreamExt;
+use hyper::{
+    client::HttpConnector,
+    header::{HeaderName, HeaderValue, HOST},
+    Client, Request, Response, StatusCode, Uri,
+};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use turbo_tasks::{primitives::StringVc, trace::TraceRawVcs, util::SharedError, Value};
+use turbo_tasks_bytes::Bytes;
+use turbopack_core::introspect::{Introspectable, IntrospectableVc};
+
+use super::{
+    route_tree::{BaseSegment, RouteTreeVc, RouteType},
+    Body, ContentSource, ContentSourceContent, ContentSourceContentVc, ContentSourceData,
+    ContentSourceDataVary, ContentSourceDataVaryVc, ContentSourceVc, GetContentSourceContent,
+    GetContentSourceContentVc, ProxyResult,
+};
+
+/// Headers which only apply to a single connection and are not forwarded.
+const HOP_BY_HOP_HEADERS: &[&str] = &[
+    "connection",
+    "keep-alive",
+    "proxy-authenticate",
+    "proxy-authorization",
+    "proxy-connection",
+    "te",
+    "trailer",
+    "transfer-encoding",
+    "upgrade",
+];
+
+static CLIENT: Lazy<Client<HttpConnector>> = Lazy::new(Client::new);
+
+/// Options of a [ProxyContentSource].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ProxyOptions {
+    /// Requests to this path and below are proxied, e.g. `/api`.
+    pub prefix: String,
+    /// The server to proxy to, e.g. `http://localhost:8080`. A path of the
+    /// target is prepended to the request path.
+    pub target: String,
+    /// Removes the prefix from the path before proxying.
+    #[serde(default)]
+    pub strip_prefix: bool,
+    /// Sets the `Host` header to the host of the target instead of passing the
+    /// host of the dev server.
+    #[serde(default)]
+    pub change_origin: bool,
+    /// Headers which are set on proxied requests. Headers with an empty value
+    /// are removed.
+    #[serde(default)]
+    pub request_headers: Vec<(String, String)>,
+    /// Headers which are set on proxied responses. Headers with an empty value
+    /// are removed.
+    #[serde(default)]
+    pub response_headers: Vec<(String, String)>,
+    /// Passes websocket upgrade requests through to the target.
+    #[serde(default)]
+    pub ws: bool,
+}
+
+impl ProxyOptions {
+    /// Parses the `<prefix>=<target>` shorthand.
+    pub fn parse(value: &str) -> Result<Self> {
+        let Some((prefix, target)) = value.split_once('=') else {
+            bail!("expected `<prefix>=<target>`, got `{value}`");
+        };
+        Ok(ProxyOptions {
+            prefix: prefix.to_string(),
+            target: target.to_string(),
+            ..Default::default()
+        })
+    }
+
+    fn prefix_segments(&self) -> impl Iterator<Item = &str> {
+        self.prefix.split('/').filter(|s| !s.is_empty())
+    }
+
+    /// Whether requests to `path` are proxied.
+    pub fn matches(&self, path: &str) -> bool {
+        let mut segments = path.split('/').filter(|s| !s.is_empty());
+        self.prefix_segments()
+            .all(|prefix| segments.next() == Some(prefix))
+    }
+
+    /// Slices the prefix off a matching `path`, keeping the rest of the path
+    /// as it was requested, including empty segments and trailing slashes.
+    fn strip_prefix_from<'a>(&self, path: &'a str) -> &'a str {
+        let mut rest = path;
+        for prefix in self.prefix_segments() {
+            rest = rest.trim_start_matches('/');
+            rest = rest.strip_prefix(prefix).unwrap_or(rest);
+        }
+        rest
+    }
+
+    /// The URI on the target for a request to `path_and_query`.
+    fn target_uri(&self, path_and_query: &str) -> Result<Uri> {
+        let target = Uri::try_from(&self.target)
+            .with_context(|| format!("invalid proxy target {}", self.target))?;
+        if target.scheme_str() != Some("http") {
+            bail!(
+                "proxy target {} is not supported, only http:// targets can be proxied to",
+                self.target
+            );
+        }
+        let (path, query) = match path_and_query.split_once('?') {
+            Some((path, query)) => (path, Some(query)),
+            None => (path_and_query, None),
+        };
+        let path = if self.strip_prefix {
+            self.strip_prefix_from(path)
+        } else {
+            path
+        };
+        let path = if path.is_empty() { "/" } else { path };
+        let mut uri = format!("{}{}", target.path().trim_end_matches('/'), path);
+        if let Some(query) = query {
+            uri.push('?');
+            uri.push_str(query);
+        }
+        Ok(Uri::builder()
+            .scheme("http")
+            .authority(
+                target
+                    .authority()
+                    .with_context(|| format!("proxy target {} has no host", self.target))?
+                    .clone(),
+            )
+            .path_and_query(uri)
+            .build()?)
+    }
+
+    /// Applies the header rewrites to the headers of a proxied request.
+    fn rewrite_request_headers(&self, headers: &mut hyper::HeaderMap, uri: &Uri) -> Result<()> {
+        if self.change_origin {
+            if let Some(authority) = uri.authority() {
+                headers.insert(
+                    HOST,
+                    HeaderValue::from_bytes(authority.as_str().as_bytes())?,
+                );
+            }
+        }
+        apply_headers(headers, &self.request_headers)
+    }
+}
+
+fn apply_headers(headers: &mut hyper::HeaderMap, rewrites: &[(String, String)]) -> Result<()> {
+    for (name, value) in rewrites {
+        let name = HeaderName::try_from(name.as_str())?;
+        if value.is_empty() {
+            headers.remove(name);
+        } else {
+            headers.insert(name, HeaderValue::from_bytes(value.as_bytes())?);
+        }
+    }
+    Ok(())
+}
+
+/// Forwards requests with a path prefix to another HTTP server, e.g. to an API
+/// backend running on another port.
+#[turbo_tasks::value(shared)]
+pub struct ProxyContentSource {
+    pub options: ProxyOptions,
+}
+
+impl ProxyContentSourceVc {
+    pub fn new(options: ProxyOptions) -> Self {
+        ProxyContentSource { options }.cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ContentSource for ProxyContentSource {
+    #[turbo_tasks::function]
+    async fn get_routes(self_vc: ProxyContentSourceVc) -> Result<RouteTreeVc> {
+        let this = self_vc.await?;
+        Ok(RouteTreeVc::new_route(
+            BaseSegment::from_static_pathname(&this.options.prefix).collect(),
+            RouteType::CatchAll,
+            self_vc.into(),
+        ))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl GetContentSourceContent for ProxyContentSource {
+    #[turbo_tasks::function]
+    fn vary(&self) -> ContentSourceDataVaryVc {
+        ContentSourceDataVary {
+            method: true,
+            url: true,
+            raw_headers: true,
+            body: true,
+            // Responses of the target are never cached.
+            cache_buster: true,
+            ..Default::default()
+        }
+        .cell()
+    }
+
+    #[turbo_tasks::function]
+    async fn get(
+        &self,
+        _path: &str,
+        data: Value<ContentSourceData>,
+    ) -> Result<ContentSourceContentVc> {
+        let ContentSourceData {
+            method: Some(method),
+            url: Some(url),
+            raw_headers: Some(raw_headers),
+            body: Some(body),
+            ..
+        } = &*data
+        else {
+            return Err(anyhow!("missing data for proxy request"));
+        };
+
+        let uri = self.options.target_uri(url)?;
+        let body = body.await?;
+        let mut request = Request::builder()
+            .method(method.as_str())
+            .uri(uri.clone())
+            .body(hyper::Body::wrap_stream(body.read()))?;
+        let headers = request.headers_mut();
+        for (name, value) in raw_headers {
+            if !HOP_BY_HOP_HEADERS.contains(&name.as_str()) {
+                headers.append(
+                    HeaderName::try_from(name.as_str())?,
+                    HeaderValue::from_bytes(value.as_bytes())?,
+                );
+            }
+        }
+        self.options.rewrite_request_headers(headers, &uri)?;
+
+        let result = match CLIENT.request(request).await {
+            Ok(mut response) => {
+                apply_headers(response.headers_mut(), &self.options.response_headers)?;
+                let headers = response
+                    .headers()
+                    .iter()
+                    .filter(|(name, _)| !HOP_BY_HOP_HEADERS.contains(&name.as_str()))
+                    // Values aren't necessarily ASCII, e.g. UTF-8 file names.
+                    .map(|(name, value)| {
+                        (
+                            name.to_string(),
+                            String::from_utf8_lossy(value.as_bytes()).into_owned(),
+                        )
+                    })
+                    .collect();
+                ProxyResult {
+                    status: response.status().as_u16(),
+                    headers,
+                    body: Body::from_stream(response.into_body().map(|chunk| {
+                        chunk
+                            .map(Bytes::from)
+                            .map_err(|err| SharedError::new(anyhow!(err)))
+                    })),
+                }
+            }
+            Err(err) => ProxyResult {
+                status: StatusCode::BAD_GATEWAY.as_u16(),
+                headers: vec![(
+                    "content-type".to_string(),
+                    "text/plain; charset=utf-8".to_string(),
+                )],
+                body: format!(
+                    "Error occurred while proxying {url} to {}: {err}",
+                    self.options.target
+                )
+                .into(),
+            },
+        };
+        Ok(ContentSourceContent::HttpProxy(result.cell()).cell())
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Introspectable for ProxyContentSource {
+    #[turbo_tasks::function]
+    fn ty(&self) -> StringVc {
+        StringVc::cell("proxy content source".to_string())
+    }
+
+    #[turbo_tasks::function]
+    fn title(&self) -> StringVc {
+        StringVc::cell(format!(
+            "{} -> {}",
+            self.options.prefix, self.options.target
+        ))
+    }
+}
+
+#[turbo_tasks::value(transparent)]
+pub struct OptionProxyContentSource(Option<ProxyContentSourceVc>);
+
+/// Finds the proxy within `source` which websocket upgrade requests to `path`
+/// are passed through to.
+#[turbo_tasks::function]
+pub async fn find_websocket_proxy(
+    source: ContentSourceVc,
+    path: &str,
+) -> Result<OptionProxyContentSourceVc> {
+    if let Some(proxy) = ProxyContentSourceVc::resolve_from(source).await? {
+        let options = &proxy.await?.options;
+        if options.ws && options.matches(path) {
+            return Ok(OptionProxyContentSourceVc::cell(Some(proxy)));
+        }
+    }
+    for &child in source.get_children().await?.iter() {
+        if let Some(proxy) = *find_websocket_proxy(child, path).await? {
+            return Ok(OptionProxyContentSourceVc::cell(Some(proxy)));
+        }
+    }
+    Ok(OptionProxyContentSourceVc::cell(None))
+}
+
+/// Passes a websocket upgrade request through to the target of a proxy and
+/// connects both sides once the target accepted the upgrade.
+pub(crate) async fn proxy_websocket(
+    options: &ProxyOptions,
+    mut request: Request<hyper::Body>,
+) -> Result<Response<hyper::Body>> {
+    let path_and_query = request
+        .uri()
+        .path_and_query()
+        .map_or("/", |path_and_query| path_and_query.as_str());
+    let uri = options.target_uri(path_and_query)?;
+    let mut upstream_request = Request::builder()
+        .method(request.method())
+        .uri(uri.clone())
+        .body(hyper::Body::empty())?;
+    // The upgrade headers are kept, as they are what asks the target to upgrade.
+    *upstream_request.headers_mut() = request.headers().clone();
+    options.rewrite_request_headers(upstream_request.headers_mut(), &uri)?;
+
+    let mut upstream_response = CLIENT.request(upstream_request).await?;
+    if upstream_response.status() != StatusCode::SWITCHING_PROTOCOLS {
+        return Ok(upstream_response);
+    }
+
+    let mut response = Response::builder().status(StatusCode::SWITCHING_PROTOCOLS);
+    *response.headers_mut().expect("headers must be defined") = upstream_response.headers().clone();
+    apply_headers(
+        response.headers_mut().expect("headers must be defined"),
+        &options.response_headers,
+    )?;
+
+    let upgraded = hyper::upgrade::on(&mut request);
+    let upstream_upgraded = hyper::upgrade::on(&mut upstream_response);
+    tokio::spawn(async move {
+        let (mut upgraded, mut upstream_upgraded) =
+            futures::try_join!(upgraded, upstream_upgraded)?;
+        tokio::io::copy_bidirectional(&mut upgraded, &mut upstream_upgraded).await?;
+        anyhow::Ok(())
+    });
+
+    Ok(response.body(hyper::Body::empty())?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn target_uri() {
+        let options = ProxyOptions::parse("/api=http://localhost:8080/backend/").unwrap();
+        assert!(options.matches("/api"));
+        assert!(options.matches("/api/users"));
+        assert!(!options.matches("/apis"));
+        assert_eq!(
+            options.target_uri("/api/users?id=1").unwrap(),
+            "http://localhost:8080/backend/api/users?id=1"
+        );
+
+        let options = ProxyOptions {
+            strip_prefix: true,
+            ..options
+        };
+        assert_eq!(
+            options.target_uri("/api/users?id=1").unwrap(),
+            "http://localhost:8080/backend/users?id=1"
+        );
+        assert_eq!(
+            options.target_uri("/api/users/").unwrap(),
+            "http://localhost:8080/backend/users/"
+        );
+        assert_eq!(
+            options.target_uri("/api//users//1").unwrap(),
+            "http://localhost:8080/backend//users//1"
+        );
+        assert_eq!(
+            options.target_uri("/api/").unwrap(),
+            "http://localhost:8080/backend/"
+        );
+        assert_eq!(
+            options.target_uri("/api").unwrap(),
+            "http://localhost:8080/backend/"
+        );
+        assert!(ProxyOptions::parse("/api=https://example.com")
+            .unwrap()
+            .target_uri("/api")
+            .is_err());
+    }
+}
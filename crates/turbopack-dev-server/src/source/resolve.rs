@@ -154,8 +154,13 @@ async fn request_to_data(
             request
                 .headers
                 .iter()
-                .map(|(name, value)| Ok((name.to_string(), value.to_str()?.to_string())))
-                .collect::<Result<Vec<_>>>()?,
+                .map(|(name, value)| {
+                    (
+                        name.to_string(),
+                        String::from_utf8_lossy(value.as_bytes()).into_owned(),
+                    )
+                })
+                .collect(),
         );
     }
     if let Some(filter) = vary.query.as_ref() {
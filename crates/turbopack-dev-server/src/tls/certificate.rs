@@ -0,0 +1,52 @@
+//! Generation of self-signed certificates for local development.
+
+use std::{net::IpAddr, time::Duration};
+
+use anyhow::{Context, Result};
+use rcgen::{
+    Certificate, CertificateParams, DistinguishedName, DnType, ExtendedKeyUsagePurpose, SanType,
+    PKCS_ECDSA_P256_SHA256,
+};
+use time::OffsetDateTime;
+
+/// A PEM encoded certificate and its private key.
+#[derive(Debug, Clone)]
+pub struct SelfSignedCertificate {
+    pub cert_pem: String,
+    pub private_key_pem: String,
+}
+
+/// Generates a self-signed certificate which is valid for `names`, which are
+/// host names or IP addresses, from now on for `validity`.
+pub fn self_signed_certificate(
+    names: &[String],
+    validity: Duration,
+) -> Result<SelfSignedCertificate> {
+    let now = OffsetDateTime::now_utc();
+    let mut params = CertificateParams::default();
+    params.alg = &PKCS_ECDSA_P256_SHA256;
+    // Allow for clocks which are slightly behind.
+    params.not_before = now - Duration::from_secs(24 * 60 * 60);
+    params.not_after = now + validity;
+    params.distinguished_name = DistinguishedName::new();
+    params
+        .distinguished_name
+        .push(DnType::CommonName, "turbopack dev server");
+    params.subject_alt_names = names
+        .iter()
+        .map(|name| match name.parse::<IpAddr>() {
+            Ok(ip) => SanType::IpAddress(ip),
+            Err(_) => SanType::DnsName(name.clone()),
+        })
+        .collect();
+    params.extended_key_usages = vec![ExtendedKeyUsagePurpose::ServerAuth];
+
+    let certificate =
+        Certificate::from_params(params).context("unable to generate a certificate")?;
+    Ok(SelfSignedCertificate {
+        cert_pem: certificate
+            .serialize_pem()
+            .context("unable to serialize the certificate")?,
+        private_key_pem: certificate.serialize_private_key_pem(),
+    })
+}
@@ -0,0 +1,133 @@
+mod certificate;
+
+use std::{
+    fmt, io,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use anyhow::{bail, Context as _, Result};
+use futures::{future::ready, stream, Stream, StreamExt};
+use hyper::server::{
+    accept::Accept,
+    conn::{AddrIncoming, AddrStream},
+};
+use tokio::io::{AsyncRead, AsyncWrite};
+pub use tokio_rustls::rustls::ServerConfig;
+use tokio_rustls::{
+    rustls::{Certificate, PrivateKey},
+    TlsAcceptor,
+};
+
+pub use self::certificate::{self_signed_certificate, SelfSignedCertificate};
+
+/// How long a client may take to complete the TLS handshake.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How many TLS handshakes are performed concurrently.
+const MAX_CONCURRENT_HANDSHAKES: usize = 64;
+
+/// Creates the TLS configuration of a dev server from a PEM encoded
+/// certificate chain and private key. The server offers HTTP/2 and HTTP/1.1.
+pub fn server_config(cert_chain_pem: &[u8], private_key_pem: &[u8]) -> Result<Arc<ServerConfig>> {
+    let cert_chain = rustls_pemfile::certs(&mut &*cert_chain_pem)
+        .context("unable to parse the certificate")?
+        .into_iter()
+        .map(Certificate)
+        .collect::<Vec<_>>();
+    if cert_chain.is_empty() {
+        bail!("the certificate file does not contain a certificate");
+    }
+    let private_key = rustls_pemfile::read_all(&mut &*private_key_pem)
+        .context("unable to parse the private key")?
+        .into_iter()
+        .find_map(|item| match item {
+            rustls_pemfile::Item::RSAKey(key)
+            | rustls_pemfile::Item::PKCS8Key(key)
+            | rustls_pemfile::Item::ECKey(key) => Some(PrivateKey(key)),
+            _ => None,
+        })
+        .context("the key file does not contain a private key")?;
+    let mut config = ServerConfig::builder()
+        .with_safe_defaults()
+        .with_no_client_auth()
+        .with_single_cert(cert_chain, private_key)
+        .context("invalid certificate or private key")?;
+    config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+    Ok(Arc::new(config))
+}
+
+/// A connection accepted by the dev server.
+pub trait Connection: AsyncRead + AsyncWrite + Send + Unpin {}
+
+impl<T: AsyncRead + AsyncWrite + Send + Unpin> Connection for T {}
+
+type ConnectionStream = Pin<Box<dyn Stream<Item = io::Result<Box<dyn Connection>>> + Send>>;
+
+/// The connections accepted by a dev server, either plain or over TLS.
+pub struct Incoming(ConnectionStream);
+
+impl fmt::Debug for Incoming {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Incoming").finish_non_exhaustive()
+    }
+}
+
+impl Incoming {
+    pub(crate) fn plain(mut incoming: AddrIncoming) -> Self {
+        Incoming(Box::pin(
+            stream::poll_fn(move |cx| Pin::new(&mut incoming).poll_accept(cx)).map(|connection| {
+                connection.map(|connection: AddrStream| Box::new(connection) as Box<dyn Connection>)
+            }),
+        ))
+    }
+
+    /// Accepts TLS connections. Connections which fail the handshake are
+    /// dropped, e.g. when the browser does not trust the certificate yet.
+    pub(crate) fn tls(mut incoming: AddrIncoming, config: Arc<ServerConfig>) -> Self {
+        let acceptor = TlsAcceptor::from(config);
+        Incoming(Box::pin(
+            stream::poll_fn(move |cx| Pin::new(&mut incoming).poll_accept(cx))
+                .map(move |connection| {
+                    let acceptor = acceptor.clone();
+                    async move {
+                        let connection = match connection {
+                            Ok(connection) => connection,
+                            Err(err) => return Some(Err(err)),
+                        };
+                        match tokio::time::timeout(HANDSHAKE_TIMEOUT, acceptor.accept(connection))
+                            .await
+                        {
+                            Ok(Ok(connection)) => {
+                                Some(Ok(Box::new(connection) as Box<dyn Connection>))
+                            }
+                            Ok(Err(err)) => {
+                                tracing::debug!("TLS handshake failed: {err}");
+                                None
+                            }
+                            Err(_) => {
+                                tracing::debug!("TLS handshake timed out");
+                                None
+                            }
+                        }
+                    }
+                })
+                .buffer_unordered(MAX_CONCURRENT_HANDSHAKES)
+                .filter_map(ready),
+        ))
+    }
+}
+
+impl Accept for Incoming {
+    type Conn = Box<dyn Connection>;
+    type Error = io::Error;
+
+    fn poll_accept(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Self::Conn, Self::Error>>> {
+        self.0.as_mut().poll_next(cx)
+    }
+}
@@ -0,0 +1,469 @@
+//! Errors which occur in the browser and are reported over the HMR socket.
+//!
+//! The stack frames of an error point into the chunks served by the dev
+//! server. They are traced through the source maps of these chunks into the
+//! original sources, so the error can be reported as an issue with a code
+//! frame.
+
+use std::fmt::{Display, Write};
+
+use anyhow::{bail, Result};
+use hyper::{HeaderMap, Uri};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use sourcemap::DecodedMap;
+use turbo_tasks::{
+    primitives::StringVc, CompletionVc, TransientInstance, Value, ValueToString, ValueToStringVc,
+};
+use turbo_tasks_fs::{
+    DirectoryContentVc, File, FileContentVc, FileMetaVc, FileSystem, FileSystemPathVc,
+    FileSystemVc, LinkContentVc,
+};
+use turbopack_core::{
+    asset::AssetContentVc,
+    issue::{Issue, IssueSeverity, IssueSeverityVc, IssueSourceVc, IssueVc, OptionIssueSourceVc},
+    server_fs::ServerFileSystemVc,
+    source_map::{GenerateSourceMap, GenerateSourceMapVc, SourceMapVc, Token},
+    virtual_asset::VirtualAssetVc,
+    PROJECT_FILESYSTEM_NAME, SOURCE_MAP_ROOT_NAME,
+};
+use turbopack_ecmascript::magic_identifier::unmangle_identifiers;
+
+use super::protocol::ClientError;
+use crate::source::{
+    request::SourceRequest,
+    resolve::{resolve_source_request, ResolveSourceRequestResult},
+    Body, ContentSourceVc,
+};
+
+/// How an error which is reported by the browser occurred.
+#[turbo_tasks::value(serialization = "auto_for_input")]
+#[derive(Debug, Clone, Copy, Hash, PartialOrd, Ord)]
+pub enum ClientErrorKind {
+    /// An uncaught exception or unhandled promise rejection.
+    RuntimeError,
+    /// An exception while applying an HMR update.
+    HmrApplyFailure,
+}
+
+/// Emits an issue for an error reported by the browser, with its stack traced
+/// into the original sources of the chunks served by `source`.
+///
+/// Identical errors share this task, so an error which occurs repeatedly is
+/// traced and reported once.
+#[turbo_tasks::function]
+pub async fn emit_client_error(
+    source: ContentSourceVc,
+    kind: Value<ClientErrorKind>,
+    error: Value<ClientError>,
+) -> Result<CompletionVc> {
+    let error = &*error;
+    let magic = |content| format!("{{{}}}", content);
+
+    let mut stack = String::new();
+    let mut context = None;
+    let mut issue_source = None;
+    let frames = parse_stack(error.stack.as_deref().unwrap_or_default());
+    for frame in &frames {
+        let frame = StackFrame {
+            name: frame
+                .name
+                .as_deref()
+                .map(|name| unmangle_identifiers(name, magic).into_owned()),
+            ..frame.clone()
+        };
+        match trace_frame(source, &frame).await? {
+            Some(traced) => {
+                write!(
+                    stack,
+                    "\n    at {} [{}]",
+                    traced.frame,
+                    StackFrame {
+                        name: None,
+                        ..frame
+                    }
+                )?;
+                if issue_source.is_none() {
+                    if let Some((path, content)) = traced.original {
+                        let offset =
+                            byte_offset(&content, traced.frame.line - 1, traced.frame.column - 1);
+                        let asset =
+                            VirtualAssetVc::new(path, AssetContentVc::from(File::from(content)));
+                        context = Some(path);
+                        issue_source = Some(IssueSourceVc::from_byte_offset(
+                            asset.into(),
+                            offset,
+                            offset,
+                        ));
+                    }
+                }
+            }
+            None => write!(stack, "\n    at {frame}")?,
+        }
+    }
+
+    let context = match context {
+        Some(context) => context,
+        None => {
+            let root = ServerFileSystemVc::new().root();
+            match frames.first().and_then(|frame| url_path(&frame.file)) {
+                Some(path) => root.join(path.trim_start_matches('/')),
+                None => root,
+            }
+        }
+    };
+    ClientErrorIssue {
+        context,
+        kind: kind.into_value(),
+        title: format!(
+            "{}: {}",
+            error.name,
+            unmangle_identifiers(&error.message, magic)
+        ),
+        stack: stack.trim_start_matches('\n').to_string(),
+        source: issue_source,
+    }
+    .cell()
+    .as_issue()
+    .emit();
+
+    Ok(CompletionVc::immutable())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct StackFrame {
+    name: Option<String>,
+    file: String,
+    line: usize,
+    column: usize,
+}
+
+impl Display for StackFrame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.name.as_deref() {
+            None | Some("<anonymous>") => write!(f, "{}:{}:{}", self.file, self.line, self.column),
+            Some(name) => write!(f, "{} ({}:{}:{})", name, self.file, self.line, self.column),
+        }
+    }
+}
+
+/// Parses a stack as formatted by V8 (`at name (file:line:column)`), or by
+/// SpiderMonkey and JavaScriptCore (`name@file:line:column`). Lines in other
+/// formats are skipped.
+fn parse_stack(stack: &str) -> Vec<StackFrame> {
+    static V8_FRAME: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"^\s*at (?:(.+?) \()?(.+):(\d+):(\d+)\)?$").unwrap());
+    static GECKO_FRAME: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"^\s*(?:(.*?)@)?(.+):(\d+):(\d+)$").unwrap());
+
+    stack
+        .lines()
+        .filter_map(|line| {
+            let captures = V8_FRAME
+                .captures(line)
+                .or_else(|| GECKO_FRAME.captures(line))?;
+            Some(StackFrame {
+                name: captures
+                    .get(1)
+                    .map(|name| name.as_str().to_string())
+                    .filter(|name| !name.is_empty()),
+                file: captures[2].to_string(),
+                line: captures[3].parse().ok()?,
+                column: captures[4].parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+/// The byte offset of a zero-based line and column in `content`.
+fn byte_offset(content: &str, line: usize, column: usize) -> usize {
+    let line_start = content
+        .split_inclusive('\n')
+        .take(line)
+        .map(str::len)
+        .sum::<usize>();
+    (line_start + column).min(content.len())
+}
+
+/// The path of a URL, if it is an absolute URL or a path.
+fn url_path(url: &str) -> Option<&str> {
+    if url.starts_with('/') {
+        return Some(url);
+    }
+    let (_, rest) = url.split_once("://")?;
+    rest.find('/').map(|index| &rest[index..])
+}
+
+struct TracedFrame {
+    frame: StackFrame,
+    /// The path and content of the original source, if it's part of the
+    /// project.
+    original: Option<(FileSystemPathVc, String)>,
+}
+
+/// Traces a stack frame through the source map of the chunk it points to.
+async fn trace_frame(source: ContentSourceVc, frame: &StackFrame) -> Result<Option<TracedFrame>> {
+    let Some(path) = url_path(&frame.file) else {
+        return Ok(None);
+    };
+    let Ok(uri) = Uri::try_from(path) else {
+        return Ok(None);
+    };
+    let request = SourceRequest {
+        method: "GET".to_string(),
+        uri,
+        headers: HeaderMap::new(),
+        body: Body::new(vec![]),
+    };
+    let ResolveSourceRequestResult::Static(content, _) =
+        &*resolve_source_request(source, TransientInstance::new(request)).await?
+    else {
+        return Ok(None);
+    };
+    let Some(generate_source_map) = GenerateSourceMapVc::resolve_from(content.await?.content).await?
+    else {
+        return Ok(None);
+    };
+    let Some(map) = *generate_source_map.generate_source_map().await? else {
+        return Ok(None);
+    };
+    let token = map
+        .lookup_token(frame.line.saturating_sub(1), frame.column.saturating_sub(1))
+        .await?;
+    let Some(Token::Original(token)) = &*token else {
+        return Ok(None);
+    };
+
+    let original_file = token
+        .original_file
+        .strip_prefix(&format!("/{SOURCE_MAP_ROOT_NAME}/"))
+        .unwrap_or(&token.original_file);
+    let project_path = original_file
+        .strip_prefix(&format!("[{PROJECT_FILESYSTEM_NAME}]/"))
+        .filter(|path| !path.contains("/node_modules/"));
+    let traced = StackFrame {
+        name: token.name.clone().or_else(|| frame.name.clone()),
+        file: project_path.unwrap_or(original_file).to_string(),
+        line: token.original_line + 1,
+        column: token.original_column + 1,
+    };
+    let original = match project_path {
+        Some(project_path) => original_source_content(map, &token.original_file)
+            .await?
+            .map(|content| {
+                let path = OriginalSourceFileSystemVc::new(PROJECT_FILESYSTEM_NAME.to_string())
+                    .as_file_system()
+                    .root()
+                    .join(project_path);
+                (path, content)
+            }),
+        None => None,
+    };
+    Ok(Some(TracedFrame {
+        frame: traced,
+        original,
+    }))
+}
+
+/// Looks up the content of an original source, which is embedded in the
+/// source map.
+async fn original_source_content(map: SourceMapVc, file: &str) -> Result<Option<String>> {
+    let rope = map.to_rope().await?;
+    let Ok(map) = sourcemap::decode_slice(&rope.to_bytes()?) else {
+        return Ok(None);
+    };
+    Ok(find_source_content(&map, file))
+}
+
+fn find_source_content(map: &DecodedMap, file: &str) -> Option<String> {
+    match map {
+        DecodedMap::Regular(map) => map
+            .sources()
+            .position(|source| source == file)
+            .and_then(|index| map.get_source_contents(index as u32))
+            .map(ToString::to_string),
+        DecodedMap::Index(index) => index.sections().find_map(|section| {
+            section
+                .get_sourcemap()
+                .and_then(|map| find_source_content(map, file))
+        }),
+        _ => None,
+    }
+}
+
+#[turbo_tasks::value(shared)]
+struct ClientErrorIssue {
+    context: FileSystemPathVc,
+    kind: ClientErrorKind,
+    title: String,
+    stack: String,
+    source: Option<IssueSourceVc>,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for ClientErrorIssue {
+    #[turbo_tasks::function]
+    fn severity(&self) -> IssueSeverityVc {
+        IssueSeverity::Error.into()
+    }
+
+    #[turbo_tasks::function]
+    fn context(&self) -> FileSystemPathVc {
+        self.context
+    }
+
+    #[turbo_tasks::function]
+    fn category(&self) -> StringVc {
+        StringVc::cell(
+            match self.kind {
+                ClientErrorKind::RuntimeError => "browser runtime",
+                ClientErrorKind::HmrApplyFailure => "hmr",
+            }
+            .to_string(),
+        )
+    }
+
+    #[turbo_tasks::function]
+    fn title(&self) -> StringVc {
+        StringVc::cell(self.title.clone())
+    }
+
+    #[turbo_tasks::function]
+    fn description(&self) -> StringVc {
+        StringVc::cell(self.stack.clone())
+    }
+
+    #[turbo_tasks::function]
+    fn source(&self) -> OptionIssueSourceVc {
+        OptionIssueSourceVc::cell(self.source)
+    }
+}
+
+/// A marker file system for the original sources of a source map. It's named
+/// like the file system the sources were read from, so issues in these sources
+/// are displayed like any other issue.
+#[turbo_tasks::value]
+struct OriginalSourceFileSystem {
+    name: String,
+}
+
+#[turbo_tasks::value_impl]
+impl OriginalSourceFileSystemVc {
+    #[turbo_tasks::function]
+    fn new(name: String) -> Self {
+        Self::cell(OriginalSourceFileSystem { name })
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl FileSystem for OriginalSourceFileSystem {
+    #[turbo_tasks::function]
+    fn read(&self, _fs_path: FileSystemPathVc) -> Result<FileContentVc> {
+        bail!("Reading is not possible from the marker filesystem for original sources")
+    }
+
+    #[turbo_tasks::function]
+    fn read_link(&self, _fs_path: FileSystemPathVc) -> Result<LinkContentVc> {
+        bail!("Reading is not possible from the marker filesystem for original sources")
+    }
+
+    #[turbo_tasks::function]
+    fn read_dir(&self, _fs_path: FileSystemPathVc) -> Result<DirectoryContentVc> {
+        bail!("Reading is not possible from the marker filesystem for original sources")
+    }
+
+    #[turbo_tasks::function]
+    fn track(&self, _fs_path: FileSystemPathVc) -> Result<CompletionVc> {
+        bail!("Tracking is not possible to the marker filesystem for original sources")
+    }
+
+    #[turbo_tasks::function]
+    fn write(&self, _fs_path: FileSystemPathVc, _content: FileContentVc) -> Result<CompletionVc> {
+        bail!("Writing is not possible to the marker filesystem for original sources")
+    }
+
+    #[turbo_tasks::function]
+    fn write_link(
+        &self,
+        _fs_path: FileSystemPathVc,
+        _target: LinkContentVc,
+    ) -> Result<CompletionVc> {
+        bail!("Writing is not possible to the marker filesystem for original sources")
+    }
+
+    #[turbo_tasks::function]
+    fn metadata(&self, _fs_path: FileSystemPathVc) -> Result<FileMetaVc> {
+        bail!("Reading is not possible from the marker filesystem for original sources")
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ValueToString for OriginalSourceFileSystem {
+    #[turbo_tasks::function]
+    fn to_string(&self) -> StringVc {
+        StringVc::cell(self.name.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_stacks() {
+        let frame = |name: Option<&str>, file: &str, line, column| StackFrame {
+            name: name.map(ToString::to_string),
+            file: file.to_string(),
+            line,
+            column,
+        };
+        assert_eq!(
+            parse_stack(
+                "TypeError: x is undefined\n    at render \
+                 (http://localhost:3000/_chunks/index.js:12:5)\n    at \
+                 http://localhost:3000/_chunks/index.js:20:1"
+            ),
+            vec![
+                frame(
+                    Some("render"),
+                    "http://localhost:3000/_chunks/index.js",
+                    12,
+                    5
+                ),
+                frame(None, "http://localhost:3000/_chunks/index.js", 20, 1),
+            ]
+        );
+        assert_eq!(
+            parse_stack(
+                "render@http://localhost:3000/_chunks/index.js:12:5\n\
+                 @http://localhost:3000/_chunks/index.js:20:1"
+            ),
+            vec![
+                frame(
+                    Some("render"),
+                    "http://localhost:3000/_chunks/index.js",
+                    12,
+                    5
+                ),
+                frame(None, "http://localhost:3000/_chunks/index.js", 20, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn extracts_url_paths() {
+        assert_eq!(
+            url_path("https://localhost:3000/_chunks/index.js"),
+            Some("/_chunks/index.js")
+        );
+        assert_eq!(url_path("/_chunks/index.js"), Some("/_chunks/index.js"));
+        assert_eq!(url_path("native"), None);
+    }
+
+    #[test]
+    fn computes_byte_offsets() {
+        assert_eq!(byte_offset("ab\ncd\n", 0, 1), 1);
+        assert_eq!(byte_offset("ab\ncd\n", 1, 1), 4);
+        assert_eq!(byte_offset("ab", 3, 0), 2);
+    }
+}
@@ -1,3 +1,4 @@
+pub mod client_error;
 pub mod protocol;
 pub mod server;
 pub mod stream;
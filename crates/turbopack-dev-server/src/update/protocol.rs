@@ -2,7 +2,10 @@ use std::{collections::BTreeMap, fmt::Display, ops::Deref, path::PathBuf};
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use turbopack_cli_utils::issue::{format_issue, LogOptions};
+use turbopack_cli_utils::{
+    issue::{format_issue, LogOptions},
+    issue_rules::IssueRules,
+};
 use turbopack_core::{
     issue::{IssueSeverity, PlainIssue},
     source_pos::SourcePos,
@@ -38,6 +41,28 @@ pub enum ClientMessage {
         #[serde(flatten)]
         resource: ResourceIdentifier,
     },
+    /// An uncaught exception or unhandled promise rejection in the browser.
+    RuntimeError {
+        #[serde(flatten)]
+        error: ClientError,
+    },
+    /// An update to `resource` which failed to apply in the browser. The
+    /// server answers with a restart instruction.
+    HmrApplyFailure {
+        resource: ResourceIdentifier,
+        #[serde(flatten)]
+        error: ClientError,
+    },
+}
+
+/// An error which occurred in the browser, with the stack as formatted by the
+/// browser.
+#[turbo_tasks::value(serialization = "auto_for_input")]
+#[derive(Debug, Clone, Hash, PartialOrd, Ord)]
+pub struct ClientError {
+    pub name: String,
+    pub message: String,
+    pub stack: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -176,6 +201,7 @@ impl<'a> From<&'a PlainIssue> for Issue<'a> {
                     show_all: true,
                     log_detail: true,
                     log_level: IssueSeverity::Info,
+                    issue_rules: IssueRules::default(),
                 },
             ),
         }
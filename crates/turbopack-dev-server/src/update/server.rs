@@ -1,5 +1,7 @@
 use std::{
+    collections::HashSet,
     pin::Pin,
+    sync::Arc,
     task::{Context, Poll},
 };
 
@@ -7,37 +9,56 @@ use anyhow::{Context as _, Error, Result};
 use futures::{prelude::*, ready, stream::FusedStream, SinkExt};
 use hyper::{upgrade::Upgraded, HeaderMap, Uri};
 use hyper_tungstenite::{tungstenite::Message, HyperWebsocket, WebSocketStream};
+use parking_lot::Mutex;
 use pin_project_lite::pin_project;
 use tokio::select;
 use tokio_stream::StreamMap;
 use tracing::{instrument, Level};
-use turbo_tasks::{TransientInstance, TurboTasksApi};
+use turbo_tasks::{turbo_tasks, TransientInstance, TurboTasksApi, Value};
 use turbo_tasks_fs::json::parse_json_with_source_context;
+use turbopack_cli_utils::issue_rules::IssueRules;
 use turbopack_core::{error::PrettyPrintError, issue::IssueReporterVc, version::Update};
 
 use super::{
-    protocol::{ClientMessage, ClientUpdateInstruction, Issue, ResourceIdentifier},
+    client_error::{emit_client_error, ClientErrorKind},
+    protocol::{
+        ClientError, ClientMessage, ClientUpdateInstruction, Issue, ResourceIdentifier,
+        EMPTY_ISSUES,
+    },
     stream::UpdateStream,
 };
 use crate::{
+    handle_issues,
     source::{request::SourceRequest, resolve::resolve_source_request, Body},
     update::stream::UpdateStreamItem,
     SourceProvider,
 };
 
+/// The maximum number of client errors of a connection which are reported at
+/// the same time. Further errors are dropped until a report finishes.
+const MAX_IN_FLIGHT_CLIENT_ERRORS: usize = 8;
+
 /// A server that listens for updates and sends them to connected clients.
 pub(crate) struct UpdateServer<P: SourceProvider> {
     source_provider: P,
-    #[allow(dead_code)]
     issue_reporter: IssueReporterVc,
+    issue_rules: Arc<IssueRules>,
+    /// The client errors which are currently being reported.
+    in_flight_client_errors: Arc<Mutex<HashSet<(ClientErrorKind, ClientError)>>>,
 }
 
 impl<P: SourceProvider + Clone + Send + Sync> UpdateServer<P> {
     /// Create a new update server with the given websocket and content source.
-    pub fn new(source_provider: P, issue_reporter: IssueReporterVc) -> Self {
+    pub fn new(
+        source_provider: P,
+        issue_reporter: IssueReporterVc,
+        issue_rules: Arc<IssueRules>,
+    ) -> Self {
         Self {
             source_provider,
             issue_reporter,
+            issue_rules,
+            in_flight_client_errors: Default::default(),
         }
     }
 
@@ -89,6 +110,16 @@ impl<P: SourceProvider + Clone + Send + Sync> UpdateServer<P> {
                         Some(ClientMessage::Unsubscribe { resource }) => {
                             streams.remove(&resource);
                         }
+                        Some(ClientMessage::RuntimeError { error }) => {
+                            self.report_client_error(ClientErrorKind::RuntimeError, error);
+                        }
+                        Some(ClientMessage::HmrApplyFailure { resource, error }) => {
+                            self.report_client_error(ClientErrorKind::HmrApplyFailure, error);
+                            // The application is in an unknown state after a failed update.
+                            client
+                                .send(ClientUpdateInstruction::restart(&resource, EMPTY_ISSUES))
+                                .await?;
+                        }
                         None => {
                             // WebSocket was closed, stop sending updates
                             break;
@@ -98,7 +129,14 @@ impl<P: SourceProvider + Clone + Send + Sync> UpdateServer<P> {
                 Some((resource, update)) = streams.next() => {
                     match update {
                         Ok(update) => {
-                            Self::send_update(&mut client, &mut streams, resource, &update).await?;
+                            Self::send_update(
+                                &mut client,
+                                &mut streams,
+                                resource,
+                                &update,
+                                &self.issue_rules,
+                            )
+                            .await?;
                         }
                         Err(err) => {
                             eprintln!("Failed to get update for {resource}: {}", PrettyPrintError(&err));
@@ -112,11 +150,46 @@ impl<P: SourceProvider + Clone + Send + Sync> UpdateServer<P> {
         Ok(())
     }
 
+    /// Reports an error which occurred in the browser through the issue
+    /// reporter. Tracing the error can take a while, so it's reported in the
+    /// background instead of blocking updates to the client.
+    ///
+    /// Identical errors share the task emitting their issue, so they're
+    /// reported once. Errors which are already being reported, or which exceed
+    /// [MAX_IN_FLIGHT_CLIENT_ERRORS], are dropped.
+    fn report_client_error(&self, kind: ClientErrorKind, error: ClientError) {
+        let key = (kind, error);
+        {
+            let mut in_flight = self.in_flight_client_errors.lock();
+            if in_flight.len() >= MAX_IN_FLIGHT_CLIENT_ERRORS || !in_flight.insert(key.clone()) {
+                return;
+            }
+        }
+        let in_flight_client_errors = self.in_flight_client_errors.clone();
+        let source_provider = self.source_provider.clone();
+        let issue_reporter = self.issue_reporter;
+        turbo_tasks().run_once_process(Box::pin(async move {
+            let emitted = emit_client_error(
+                source_provider.get_source(),
+                Value::new(key.0),
+                Value::new(key.1.clone()),
+            );
+            let result =
+                handle_issues(emitted, "browser", "report client error", issue_reporter).await;
+            in_flight_client_errors.lock().remove(&key);
+            if let Err(err) = result {
+                eprintln!("Failed to report client error: {}", PrettyPrintError(&err));
+            }
+            Ok(())
+        }));
+    }
+
     async fn send_update(
         client: &mut UpdateClient,
         streams: &mut StreamMap<ResourceIdentifier, UpdateStream>,
         resource: ResourceIdentifier,
         item: &UpdateStreamItem,
+        issue_rules: &IssueRules,
     ) -> Result<()> {
         match item {
             UpdateStreamItem::NotFound => {
@@ -130,7 +203,13 @@ impl<P: SourceProvider + Clone + Send + Sync> UpdateServer<P> {
             UpdateStreamItem::Found { update, issues } => {
                 let issues = issues
                     .iter()
-                    .map(|p| (&**p).into())
+                    .filter_map(|p| {
+                        let severity = issue_rules.severity(p)?;
+                        Some(Issue {
+                            severity,
+                            ..(&**p).into()
+                        })
+                    })
                     .collect::<Vec<Issue<'_>>>();
                 match &**update {
                     Update::Partial(partial) => {
@@ -0,0 +1,129 @@
+#![feature(min_specialization)]
+
+use std::{
+    net::{Ipv4Addr, SocketAddr},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use anyhow::Result;
+use futures::SinkExt;
+use hyper::{
+    header::{CONNECTION, UPGRADE},
+    Body, Client, Request, StatusCode,
+};
+use hyper_tungstenite::{
+    tungstenite::{protocol::Role, Message},
+    WebSocketStream,
+};
+use turbo_tasks::{
+    primitives::BoolVc, RawVc, ReadRef, TransientInstance, TransientValue, TryJoinIterExt,
+    TurboTasks,
+};
+use turbo_tasks_memory::MemoryBackend;
+use turbopack_core::issue::{CapturedIssues, Issue, IssueReporter, IssueReporterVc};
+use turbopack_dev_server::{source::NoContentSourceVc, DevServer};
+
+fn register() {
+    turbopack_dev_server::register();
+    include!(concat!(env!("OUT_DIR"), "/register_test_client_error.rs"));
+}
+
+/// The source and the issue titles of every report.
+static REPORTS: Mutex<Vec<(RawVc, Vec<String>)>> = Mutex::new(Vec::new());
+
+#[turbo_tasks::value]
+struct RecordingIssueReporter;
+
+#[turbo_tasks::value_impl]
+impl RecordingIssueReporterVc {
+    #[turbo_tasks::function]
+    fn new() -> Self {
+        Self::cell(RecordingIssueReporter)
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl IssueReporter for RecordingIssueReporter {
+    #[turbo_tasks::function]
+    async fn report_issues(
+        &self,
+        issues: TransientInstance<ReadRef<CapturedIssues>>,
+        source: TransientValue<RawVc>,
+    ) -> Result<BoolVc> {
+        let titles = issues
+            .iter_with_shortest_path()
+            .map(|(issue, _)| async move { Ok(issue.title().await?.clone_value()) })
+            .try_join()
+            .await?;
+        REPORTS.lock().unwrap().push((source.into_value(), titles));
+        Ok(BoolVc::cell(false))
+    }
+}
+
+#[tokio::test]
+async fn reports_client_errors_by_content() {
+    register();
+
+    let tt = TurboTasks::new(MemoryBackend::default());
+    let server = DevServer::listen(SocketAddr::from((Ipv4Addr::LOCALHOST, 0)))
+        .unwrap()
+        .serve(
+            tt,
+            || NoContentSourceVc::new().into(),
+            Arc::new(|| -> IssueReporterVc { RecordingIssueReporterVc::new().into() }),
+        );
+    let addr = server.addr;
+    tokio::spawn(server.future);
+
+    let response = Client::new()
+        .request(
+            Request::get(format!("http://{addr}/turbopack-hmr"))
+                .header(CONNECTION, "upgrade")
+                .header(UPGRADE, "websocket")
+                .header("sec-websocket-version", "13")
+                .header("sec-websocket-key", "dGhlIHNhbXBsZSBub25jZQ==")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::SWITCHING_PROTOCOLS);
+    let upgraded = hyper::upgrade::on(response).await.unwrap();
+    let mut socket = WebSocketStream::from_raw_socket(upgraded, Role::Client, None).await;
+
+    // The same error is reported twice, e.g. when a button handler throws on
+    // every click, next to a different error.
+    let error = |message| {
+        format!(r#"{{"type":"runtimeError","name":"Error","message":"{message}","stack":null}}"#)
+    };
+    for message in ["boom", "boom", "bang"] {
+        socket.send(Message::text(error(message))).await.unwrap();
+    }
+
+    for _ in 0..100 {
+        let reports = REPORTS.lock().unwrap().clone();
+        let sources = |title: &str| {
+            reports
+                .iter()
+                .filter(|(_, titles)| titles == &[title])
+                .map(|(source, _)| *source)
+                .collect::<Vec<_>>()
+        };
+        let booms = sources("Error: boom");
+        let bangs = sources("Error: bang");
+        if !booms.is_empty() && !bangs.is_empty() {
+            // Identical errors share a source, so the issue reporter can tell
+            // them apart from other errors without releasing them.
+            assert!(booms.iter().all(|source| *source == booms[0]));
+            assert_ne!(booms[0], bangs[0]);
+            assert!(reports.iter().all(|(_, titles)| !titles.is_empty()));
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    panic!(
+        "the errors were not reported: {:?}",
+        REPORTS.lock().unwrap()
+    );
+}
@@ -0,0 +1,134 @@
+#![feature(min_specialization)]
+
+use std::{
+    net::{Ipv4Addr, SocketAddr},
+    path::PathBuf,
+    sync::Arc,
+    time::Duration,
+};
+
+use hyper::{
+    client::conn::Builder,
+    header::{CONNECTION, UPGRADE},
+    Body, Request, StatusCode,
+};
+use tokio::net::TcpStream;
+use tokio_rustls::{
+    client::TlsStream,
+    rustls::{Certificate, ClientConfig, RootCertStore, ServerName},
+    TlsConnector,
+};
+use turbo_tasks::TurboTasks;
+use turbo_tasks_memory::MemoryBackend;
+use turbopack_cli_utils::{
+    issue::{ConsoleUiVc, LogOptions},
+    issue_rules::IssueRules,
+};
+use turbopack_core::issue::IssueSeverity;
+use turbopack_dev_server::{
+    source::NoContentSourceVc,
+    tls::{self_signed_certificate, server_config},
+    DevServer,
+};
+
+async fn connect(
+    addr: SocketAddr,
+    config: &ClientConfig,
+    alpn_protocol: &[u8],
+) -> TlsStream<TcpStream> {
+    let mut config = config.clone();
+    config.alpn_protocols = vec![alpn_protocol.to_vec()];
+    let stream = TlsConnector::from(Arc::new(config))
+        .connect(
+            ServerName::try_from("localhost").unwrap(),
+            TcpStream::connect(addr).await.unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(stream.get_ref().1.alpn_protocol(), Some(alpn_protocol));
+    stream
+}
+
+#[tokio::test]
+async fn serves_https() {
+    turbopack_dev_server::register();
+
+    let certificate = self_signed_certificate(
+        &["localhost".to_string(), "127.0.0.1".to_string()],
+        Duration::from_secs(24 * 60 * 60),
+    )
+    .unwrap();
+    let config = server_config(
+        certificate.cert_pem.as_bytes(),
+        certificate.private_key_pem.as_bytes(),
+    )
+    .unwrap();
+
+    let tt = TurboTasks::new(MemoryBackend::default());
+    let log_options = Arc::new(LogOptions {
+        current_dir: PathBuf::new(),
+        project_dir: PathBuf::new(),
+        show_all: false,
+        log_detail: false,
+        log_level: IssueSeverity::Error,
+        issue_rules: IssueRules::default(),
+    });
+    let server = DevServer::listen_tls(SocketAddr::from((Ipv4Addr::LOCALHOST, 0)), config)
+        .unwrap()
+        .serve(
+            tt,
+            || NoContentSourceVc::new().into(),
+            Arc::new(move || ConsoleUiVc::new(log_options.clone().into()).into()),
+        );
+    let addr = server.addr;
+    tokio::spawn(server.future);
+
+    // Only the generated certificate is trusted.
+    let mut roots = RootCertStore::empty();
+    for cert in rustls_pemfile::certs(&mut certificate.cert_pem.as_bytes()).unwrap() {
+        roots.add(&Certificate(cert)).unwrap();
+    }
+    let client_config = ClientConfig::builder()
+        .with_safe_defaults()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+
+    let (mut sender, connection) = Builder::new()
+        .http2_only(true)
+        .handshake::<_, Body>(connect(addr, &client_config, b"h2").await)
+        .await
+        .unwrap();
+    tokio::spawn(connection);
+    let response = sender
+        .send_request(
+            Request::get("https://localhost/index.html")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.version(), hyper::Version::HTTP_2);
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    // The HMR websocket is served over HTTP/1.1.
+    let (mut sender, connection) = Builder::new()
+        .handshake::<_, Body>(connect(addr, &client_config, b"http/1.1").await)
+        .await
+        .unwrap();
+    tokio::spawn(connection.without_shutdown());
+    let response = sender
+        .send_request(
+            Request::get("/turbopack-hmr")
+                .header("host", "localhost")
+                .header(CONNECTION, "upgrade")
+                .header(UPGRADE, "websocket")
+                .header("sec-websocket-version", "13")
+                .header("sec-websocket-key", "dGhlIHNhbXBsZSBub25jZQ==")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.version(), hyper::Version::HTTP_11);
+    assert_eq!(response.status(), StatusCode::SWITCHING_PROTOCOLS);
+}
@@ -0,0 +1,202 @@
+#![feature(min_specialization)]
+
+use std::{
+    convert::Infallible,
+    net::{Ipv4Addr, SocketAddr},
+    path::PathBuf,
+    sync::Arc,
+};
+
+use anyhow::Result;
+use hyper::{
+    header::{CONNECTION, UPGRADE},
+    service::{make_service_fn, service_fn},
+    Body, Client, Request, Response, Server, StatusCode,
+};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use turbo_tasks::TurboTasks;
+use turbo_tasks_memory::MemoryBackend;
+use turbopack_cli_utils::{
+    issue::{ConsoleUiVc, LogOptions},
+    issue_rules::IssueRules,
+};
+use turbopack_core::issue::IssueSeverity;
+use turbopack_dev_server::{
+    source::{
+        combined::CombinedContentSourceVc,
+        proxy::{ProxyContentSourceVc, ProxyOptions},
+        ContentSourceVc, NoContentSourceVc,
+    },
+    DevServer,
+};
+
+fn register() {
+    turbopack_dev_server::register();
+    include!(concat!(env!("OUT_DIR"), "/register_test_proxy.rs"));
+}
+
+#[turbo_tasks::function]
+fn source(target: String) -> ContentSourceVc {
+    let proxy = ProxyContentSourceVc::new(ProxyOptions {
+        prefix: "/api".to_string(),
+        target,
+        strip_prefix: true,
+        change_origin: true,
+        request_headers: vec![
+            ("x-proxied".to_string(), "1".to_string()),
+            ("cookie".to_string(), String::new()),
+        ],
+        response_headers: vec![("x-backend".to_string(), "rewritten".to_string())],
+        ws: true,
+    });
+    CombinedContentSourceVc::new(vec![proxy.into(), NoContentSourceVc::new().into()]).into()
+}
+
+/// A stand-in for a backend, which describes the requests it receives and
+/// echoes websocket messages.
+async fn backend(request: Request<Body>) -> Result<Response<Body>, Infallible> {
+    if request.headers().contains_key(UPGRADE) {
+        let upgraded = hyper::upgrade::on(request);
+        tokio::spawn(async move {
+            let mut upgraded = upgraded.await.unwrap();
+            let mut buf = [0; 4];
+            upgraded.read_exact(&mut buf).await.unwrap();
+            upgraded.write_all(&buf).await.unwrap();
+        });
+        return Ok(Response::builder()
+            .status(StatusCode::SWITCHING_PROTOCOLS)
+            .header(CONNECTION, "upgrade")
+            .header(UPGRADE, "websocket")
+            .body(Body::empty())
+            .unwrap());
+    }
+    let header = |name| {
+        request
+            .headers()
+            .get(name)
+            .map_or("-".to_string(), |value| {
+                String::from_utf8_lossy(value.as_bytes()).into_owned()
+            })
+    };
+    let description = format!(
+        "{} {} host={} x-proxied={} cookie={} x-name={}",
+        request.method(),
+        request.uri(),
+        header("host"),
+        header("x-proxied"),
+        header("cookie"),
+        header("x-name"),
+    );
+    let body = hyper::body::to_bytes(request.into_body()).await.unwrap();
+    Ok(Response::builder()
+        .header("x-backend", "original")
+        .header("x-file", "naïve.txt".as_bytes())
+        .body(Body::from(format!(
+            "{description} body={}",
+            String::from_utf8_lossy(&body)
+        )))
+        .unwrap())
+}
+
+async fn send(request: Request<Body>) -> (StatusCode, Option<String>, String) {
+    let response = Client::new().request(request).await.unwrap();
+    let status = response.status();
+    let x_backend = response
+        .headers()
+        .get("x-backend")
+        .map(|value| value.to_str().unwrap().to_string());
+    if status == StatusCode::OK {
+        assert_eq!(
+            response.headers().get("x-file").unwrap().as_bytes(),
+            "naïve.txt".as_bytes()
+        );
+    }
+    let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    (status, x_backend, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[tokio::test]
+async fn proxies_requests() {
+    register();
+
+    let backend = Server::bind(&SocketAddr::from((Ipv4Addr::LOCALHOST, 0))).serve(make_service_fn(
+        |_| async { Ok::<_, Infallible>(service_fn(backend)) },
+    ));
+    let backend_addr = backend.local_addr();
+    tokio::spawn(backend);
+
+    let tt = TurboTasks::new(MemoryBackend::default());
+    let target = format!("http://{backend_addr}");
+    let log_options = Arc::new(LogOptions {
+        current_dir: PathBuf::new(),
+        project_dir: PathBuf::new(),
+        show_all: false,
+        log_detail: false,
+        log_level: IssueSeverity::Error,
+        issue_rules: IssueRules::default(),
+    });
+    let server = DevServer::listen(SocketAddr::from((Ipv4Addr::LOCALHOST, 0)))
+        .unwrap()
+        .serve(
+            tt,
+            move || source(target.clone()),
+            Arc::new(move || ConsoleUiVc::new(log_options.clone().into()).into()),
+        );
+    let addr = server.addr;
+    tokio::spawn(server.future);
+
+    let (status, x_backend, body) = send(
+        Request::get(format!("http://{addr}/api/users?id=1"))
+            .header("cookie", "secret")
+            .header("x-name", "José".as_bytes())
+            .body(Body::empty())
+            .unwrap(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(x_backend.as_deref(), Some("rewritten"));
+    assert_eq!(
+        body,
+        format!("GET /users?id=1 host={backend_addr} x-proxied=1 cookie=- x-name=José body=")
+    );
+
+    let (status, _, body) = send(
+        Request::post(format!("http://{addr}/api"))
+            .body(Body::from("payload"))
+            .unwrap(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        body,
+        format!("POST / host={backend_addr} x-proxied=1 cookie=- x-name=- body=payload")
+    );
+
+    let (status, x_backend, _) = send(
+        Request::get(format!("http://{addr}/apis"))
+            .body(Body::empty())
+            .unwrap(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(x_backend, None);
+
+    let response = Client::new()
+        .request(
+            Request::get(format!("http://{addr}/api/socket"))
+                .header(CONNECTION, "upgrade")
+                .header(UPGRADE, "websocket")
+                .header("sec-websocket-version", "13")
+                .header("sec-websocket-key", "dGhlIHNhbXBsZSBub25jZQ==")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::SWITCHING_PROTOCOLS);
+    let mut upgraded = hyper::upgrade::on(response).await.unwrap();
+    upgraded.write_all(b"ping").await.unwrap();
+    let mut buf = [0; 4];
+    upgraded.read_exact(&mut buf).await.unwrap();
+    assert_eq!(&buf, b"ping");
+}
@@ -139,6 +139,46 @@ impl DevChunkingContextVc {
         this.into_value().cell()
     }
 
+    /// Returns the chunks of an evaluated chunk group, without the chunks that
+    /// register the chunk list and evaluate the entries.
+    #[turbo_tasks::function]
+    async fn evaluated_chunk_group_assets(
+        self_vc: DevChunkingContextVc,
+        entry_chunk: ChunkVc,
+        evaluatable_assets: EvaluatableAssetsVc,
+    ) -> Result<AssetsVc> {
+        let evaluatable_assets_ref = evaluatable_assets.await?;
+
+        let mut entry_assets: IndexSet<_> = evaluatable_assets_ref
+            .iter()
+            .map({
+                move |evaluatable_asset| async move {
+                    evaluatable_asset
+                        .as_root_chunk(self_vc.into())
+                        .resolve()
+                        .await
+                }
+            })
+            .try_join()
+            .await?
+            .into_iter()
+            .collect();
+
+        entry_assets.insert(entry_chunk.resolve().await?);
+
+        let parallel_chunks = get_parallel_chunks(entry_assets).await?;
+
+        let optimized_chunks = get_optimized_chunks(parallel_chunks).await?;
+
+        Ok(AssetsVc::cell(
+            optimized_chunks
+                .await?
+                .iter()
+                .map(|chunk| self_vc.generate_chunk(*chunk))
+                .collect(),
+        ))
+    }
+
     #[turbo_tasks::function]
     fn generate_evaluate_chunk(
         self_vc: DevChunkingContextVc,
@@ -296,36 +336,9 @@ impl ChunkingContext for DevChunkingContext {
         entry_chunk: ChunkVc,
         evaluatable_assets: EvaluatableAssetsVc,
     ) -> Result<AssetsVc> {
-        let evaluatable_assets_ref = evaluatable_assets.await?;
+        let other_assets = self_vc.evaluated_chunk_group_assets(entry_chunk, evaluatable_assets);
 
-        let mut entry_assets: IndexSet<_> = evaluatable_assets_ref
-            .iter()
-            .map({
-                move |evaluatable_asset| async move {
-                    evaluatable_asset
-                        .as_root_chunk(self_vc.into())
-                        .resolve()
-                        .await
-                }
-            })
-            .try_join()
-            .await?
-            .into_iter()
-            .collect();
-
-        entry_assets.insert(entry_chunk.resolve().await?);
-
-        let parallel_chunks = get_parallel_chunks(entry_assets).await?;
-
-        let optimized_chunks = get_optimized_chunks(parallel_chunks).await?;
-
-        let mut assets: Vec<AssetVc> = optimized_chunks
-            .await?
-            .iter()
-            .map(|chunk| self_vc.generate_chunk(*chunk))
-            .collect();
-
-        let other_assets = AssetsVc::cell(assets.clone());
+        let mut assets = other_assets.await?.clone_value();
 
         assets.push(self_vc.generate_chunk_list_register_chunk(
             entry_chunk,
@@ -337,6 +350,19 @@ impl ChunkingContext for DevChunkingContext {
 
         Ok(AssetsVc::cell(assets))
     }
+
+    #[turbo_tasks::function]
+    fn evaluated_chunk_group_entry(
+        self_vc: DevChunkingContextVc,
+        entry_chunk: ChunkVc,
+        evaluatable_assets: EvaluatableAssetsVc,
+    ) -> AssetVc {
+        self_vc.generate_evaluate_chunk(
+            entry_chunk,
+            self_vc.evaluated_chunk_group_assets(entry_chunk, evaluatable_assets),
+            evaluatable_assets,
+        )
+    }
 }
 
 #[turbo_tasks::value_impl]
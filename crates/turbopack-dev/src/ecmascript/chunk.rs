@@ -4,8 +4,8 @@ use turbo_tasks::{primitives::StringVc, ValueToString, ValueToStringVc};
 use turbopack_core::{
     asset::{Asset, AssetContentVc, AssetVc},
     chunk::{
-        ChunkingContext, OutputChunk, OutputChunkRuntimeInfo, OutputChunkRuntimeInfoVc,
-        OutputChunkVc,
+        ChunkItemSizesVc, ChunkWithItemSizes, ChunkWithItemSizesVc, ChunkingContext, OutputChunk,
+        OutputChunkRuntimeInfo, OutputChunkRuntimeInfoVc, OutputChunkVc,
     },
     ident::AssetIdentVc,
     introspect::{Introspectable, IntrospectableChildrenVc, IntrospectableVc},
@@ -125,6 +125,14 @@ impl GenerateSourceMap for EcmascriptDevChunk {
     }
 }
 
+#[turbo_tasks::value_impl]
+impl ChunkWithItemSizes for EcmascriptDevChunk {
+    #[turbo_tasks::function]
+    fn chunk_item_sizes(&self) -> ChunkItemSizesVc {
+        self.chunk.chunk_item_sizes()
+    }
+}
+
 #[turbo_tasks::function]
 fn introspectable_type() -> StringVc {
     StringVc::cell("dev ecmascript chunk".to_string())
@@ -8,7 +8,8 @@ use turbo_tasks_fs::File;
 use turbopack_core::{
     asset::{Asset, AssetContentVc, AssetVc, AssetsVc},
     chunk::{
-        ChunkDataVc, ChunkVc, ChunkingContext, ChunksDataVc, EvaluatableAssetsVc, ModuleIdReadRef,
+        ChunkDataVc, ChunkVc, ChunkingContext, ChunksDataVc, EvaluatableAssetsVc, EvaluateChunk,
+        EvaluateChunkVc, ModuleIdReadRef,
     },
     code_builder::{CodeBuilder, CodeVc},
     ident::AssetIdentVc,
@@ -223,6 +224,14 @@ impl Asset for EcmascriptDevEvaluateChunk {
     }
 }
 
+#[turbo_tasks::value_impl]
+impl EvaluateChunk for EcmascriptDevEvaluateChunk {
+    #[turbo_tasks::function]
+    fn evaluatable_assets(&self) -> EvaluatableAssetsVc {
+        self.evaluatable_assets
+    }
+}
+
 #[turbo_tasks::value_impl]
 impl GenerateSourceMap for EcmascriptDevEvaluateChunk {
     #[turbo_tasks::function]
@@ -43,6 +43,9 @@ interface TurbopackNodeBuildContext {
   m: Module;
   c: ModuleCache;
   l: LoadChunk;
+  w: LoadWebAssembly;
+  u: LoadWebAssemblyModule;
+  a: AsyncModule;
   g: typeof globalThis;
   __dirname: string;
 }
@@ -130,6 +133,30 @@ function loadChunkAsync(source: SourceInfo, chunkPath: string): Promise<void> {
   });
 }
 
+function readWebAssemblyAsset(wasmPath: string) {
+  const fs = require("fs");
+  // Static assets are referenced by their server-relative URL, which maps to a
+  // path relative to the output root.
+  return fs.readFileSync(path.resolve(RUNTIME_ROOT, wasmPath.slice(1)));
+}
+
+async function loadWebAssembly(
+  wasmPath: string,
+  imports: WebAssemblyImports
+) {
+  const { WebAssembly } = globalThis as any;
+  const { instance } = await WebAssembly.instantiate(
+    readWebAssemblyAsset(wasmPath),
+    imports
+  );
+  return instance.exports;
+}
+
+async function loadWebAssemblyModule(wasmPath: string) {
+  const { WebAssembly } = globalThis as any;
+  return WebAssembly.compile(readWebAssemblyAsset(wasmPath));
+}
+
 function instantiateModule(id: ModuleId, source: SourceInfo): Module {
   const moduleFactory = moduleFactories[id];
   if (typeof moduleFactory !== "function") {
@@ -188,6 +215,9 @@ function instantiateModule(id: ModuleId, source: SourceInfo): Module {
       m: module,
       c: moduleCache,
       l: loadChunkAsync.bind(null, { type: SourceType.Parent, parentId: id }),
+      w: loadWebAssembly,
+      u: loadWebAssemblyModule,
+      a: asyncModule.bind(null, module),
       g: globalThis,
       __dirname: module.id.replace(/(^|\/)[\/]+$/, ""),
     });
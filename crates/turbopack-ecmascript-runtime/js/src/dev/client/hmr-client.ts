@@ -37,6 +37,53 @@ export function connect({ assetPrefix }: ClientOptions) {
       subscribeToChunkUpdate(chunkPath, callback);
     }
   }
+
+  // Report uncaught errors to the server, which prints them source mapped.
+  if (typeof window !== "undefined") {
+    window.addEventListener("error", (event) => {
+      reportRuntimeError(event.error ?? event.message);
+    });
+    window.addEventListener("unhandledrejection", (event) => {
+      reportRuntimeError(event.reason);
+    });
+  }
+}
+
+// Errors which were already reported, by message and stack, so an error which
+// is thrown repeatedly (e.g. in every animation frame) is reported once.
+const reportedErrors: Set<string> = new Set();
+const MAX_REPORTED_ERRORS = 100;
+// At most this many errors are reported per interval.
+const ERROR_REPORT_INTERVAL_MS = 1000;
+const MAX_ERROR_REPORTS_PER_INTERVAL = 10;
+let errorReportIntervalStart = 0;
+let errorReportsInInterval = 0;
+
+function reportRuntimeError(error: unknown) {
+  const clientError = toClientError(error);
+  const key = `${clientError.message}\n${clientError.stack ?? ""}`;
+  if (reportedErrors.has(key)) {
+    return;
+  }
+
+  const now = Date.now();
+  if (now - errorReportIntervalStart >= ERROR_REPORT_INTERVAL_MS) {
+    errorReportIntervalStart = now;
+    errorReportsInInterval = 0;
+  }
+  if (errorReportsInInterval >= MAX_ERROR_REPORTS_PER_INTERVAL) {
+    return;
+  }
+  errorReportsInInterval++;
+
+  if (reportedErrors.size >= MAX_REPORTED_ERRORS) {
+    reportedErrors.clear();
+  }
+  reportedErrors.add(key);
+  sendJSON({
+    type: "runtimeError",
+    ...clientError,
+  });
 }
 
 type UpdateCallbackSet = {
@@ -46,8 +93,15 @@ type UpdateCallbackSet = {
 
 const updateCallbackSets: Map<ResourceKey, UpdateCallbackSet> = new Map();
 
-function sendJSON(message: ClientMessage) {
-  sendMessage(JSON.stringify(message));
+function sendJSON(message: ClientMessage): boolean {
+  return sendMessage(JSON.stringify(message));
+}
+
+function toClientError(error: unknown): ClientError {
+  if (error instanceof Error) {
+    return { name: error.name, message: error.message, stack: error.stack };
+  }
+  return { name: "Error", message: String(error) };
 }
 
 type ResourceKey = string;
@@ -581,6 +635,14 @@ function triggerUpdate(msg: ServerMessage) {
       `An error occurred during the update of resource \`${msg.resource.path}\``,
       err
     );
-    location.reload();
+    // The server reports the error and answers with a restart instruction.
+    const reported = sendJSON({
+      type: "hmrApplyFailure",
+      resource: msg.resource,
+      ...toClientError(err),
+    });
+    if (!reported) {
+      location.reload();
+    }
   }
 }
@@ -30,9 +30,13 @@ export function addEventListener(cb: (event: WebsocketEvent) => void) {
   eventCallbacks.push(cb);
 }
 
-export function sendMessage(data: any) {
-  if (!source || source.readyState !== source.OPEN) return;
-  return source.send(data);
+/**
+ * Sends a message to the server. Returns whether the socket was connected.
+ */
+export function sendMessage(data: any): boolean {
+  if (!source || source.readyState !== source.OPEN) return false;
+  source.send(data);
+  return true;
 }
 
 export type HMROptions = {
@@ -98,7 +98,26 @@ type ClientMessageUnsubscribe = {
   type: "unsubscribe";
 } & ResourceIdentifier;
 
-type ClientMessage = ClientMessageSubscribe | ClientMessageUnsubscribe;
+type ClientError = {
+  name: string;
+  message: string;
+  stack?: string;
+};
+
+type ClientMessageRuntimeError = {
+  type: "runtimeError";
+} & ClientError;
+
+type ClientMessageHmrApplyFailure = {
+  type: "hmrApplyFailure";
+  resource: ResourceIdentifier;
+} & ClientError;
+
+type ClientMessage =
+  | ClientMessageSubscribe
+  | ClientMessageUnsubscribe
+  | ClientMessageRuntimeError
+  | ClientMessageHmrApplyFailure;
 
 type IssueSeverity =
   | "bug"
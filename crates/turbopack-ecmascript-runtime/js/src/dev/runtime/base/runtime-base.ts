@@ -44,6 +44,9 @@ interface TurbopackDevBaseContext {
   m: Module;
   c: ModuleCache;
   l: LoadChunk;
+  w: LoadWebAssembly;
+  u: LoadWebAssemblyModule;
+  a: AsyncModule;
   g: typeof globalThis;
   k: RefreshContext;
   __dirname: string;
@@ -110,6 +113,15 @@ interface RuntimeBackend {
   registerChunk: (chunkPath: ChunkPath, params?: DevRuntimeParams) => void;
   loadChunk: (chunkPath: ChunkPath, source: SourceInfo) => Promise<void>;
   reloadChunk?: (chunkPath: ChunkPath) => Promise<void>;
+  loadWebAssembly: (
+    source: SourceInfo,
+    wasmPath: string,
+    imports: WebAssemblyImports
+  ) => Promise<WebAssemblyExports>;
+  loadWebAssemblyModule: (
+    source: SourceInfo,
+    wasmPath: string
+  ) => Promise<any>;
   unloadChunk?: (chunkPath: ChunkPath) => void;
 
   restart: () => void;
@@ -343,6 +355,15 @@ function instantiateModule(id: ModuleId, source: SourceInfo): Module {
           m: module,
           c: moduleCache,
           l: loadChunk.bind(null, { type: SourceType.Parent, parentId: id }),
+          w: loadWebAssembly.bind(null, {
+            type: SourceType.Parent,
+            parentId: id,
+          }),
+          u: loadWebAssemblyModule.bind(null, {
+            type: SourceType.Parent,
+            parentId: id,
+          }),
+          a: asyncModule.bind(null, module),
           g: globalThis,
           k: refresh,
           __dirname: module.id.replace(/(^|\/)\/+$/, ""),
@@ -363,6 +384,21 @@ function instantiateModule(id: ModuleId, source: SourceInfo): Module {
   return module;
 }
 
+function loadWebAssembly(
+  source: SourceInfo,
+  wasmPath: string,
+  imports: WebAssemblyImports
+): Promise<WebAssemblyExports> {
+  return BACKEND.loadWebAssembly(source, wasmPath, imports);
+}
+
+function loadWebAssemblyModule(
+  source: SourceInfo,
+  wasmPath: string
+): Promise<any> {
+  return BACKEND.loadWebAssemblyModule(source, wasmPath);
+}
+
 /**
  * NOTE(alexkirsz) Webpack has a "module execution" interception hook that
  * Next.js' React Refresh runtime hooks into to add module context to the
@@ -16,6 +16,11 @@ type ChunkResolver = {
 
 let BACKEND: RuntimeBackend;
 
+/**
+ * Only available in web workers.
+ */
+declare function importScripts(...urls: string[]): void;
+
 function augmentContext(context: TurbopackDevBaseContext): TurbopackDevContext {
   return context;
 }
@@ -132,9 +137,26 @@ function commonJsRequireContext(
       });
     },
 
+    async loadWebAssembly(_source, wasmPath, imports) {
+      const { instance } = await WebAssembly.instantiateStreaming(
+        fetch(wasmPath),
+        imports
+      );
+      return instance.exports;
+    },
+
+    loadWebAssemblyModule(_source, wasmPath) {
+      return WebAssembly.compileStreaming(fetch(wasmPath));
+    },
+
     restart: () => self.location.reload(),
   };
 
+  /**
+   * Workers don't have a DOM, so chunks can't be loaded with `<script>` tags.
+   */
+  const IS_WORKER = typeof document === "undefined";
+
   /**
    * Maps chunk paths to the corresponding resolver.
    */
@@ -177,6 +199,13 @@ function commonJsRequireContext(
       return resolver.promise;
     }
 
+    if (IS_WORKER) {
+      // Workers only load their entry chunk, so we need to load all other chunks
+      // ourselves, including the ones referenced from runtime code.
+      loadChunkInWorker(chunkPath, resolver);
+      return resolver.promise;
+    }
+
     if (source.type === SourceType.Runtime) {
       // We don't need to load chunks references from runtime code, as they're already
       // present in the DOM.
@@ -199,7 +228,7 @@ function commonJsRequireContext(
       link.rel = "stylesheet";
       link.href = `/${chunkPath}`;
       link.onerror = () => {
-        resolver.reject();
+        resolver.reject(new Error(`Failed to load CSS chunk ${chunkPath}`));
       };
       link.onload = () => {
         // CSS chunks do not register themselves, and as such must be marked as
@@ -214,7 +243,7 @@ function commonJsRequireContext(
       // which happens in `registerChunk`. Hence the absence of `resolve()` in
       // this branch.
       script.onerror = () => {
-        resolver.reject();
+        resolver.reject(new Error(`Failed to load chunk ${chunkPath}`));
       };
       document.body.appendChild(script);
     } else {
@@ -223,6 +252,40 @@ function commonJsRequireContext(
 
     return resolver.promise;
   }
+
+  /**
+   * Loads the given chunk from within a web worker.
+   */
+  function loadChunkInWorker(chunkPath: ChunkPath, resolver: ChunkResolver) {
+    if (chunkPath.endsWith(".css")) {
+      // Styles have no effect in workers, so CSS chunks are marked as loaded
+      // instantly.
+      resolver.resolve();
+      return;
+    }
+
+    if (!chunkPath.endsWith(".js")) {
+      throw new Error(`can't infer type of chunk from path ${chunkPath}`);
+    }
+
+    const url = `/${chunkPath}`;
+    try {
+      // Classic workers can load scripts synchronously. The chunk will mark
+      // itself as loaded in `registerChunk`.
+      importScripts(url);
+    } catch (_) {
+      // `importScripts` throws in module workers, so we fetch and evaluate the
+      // chunk in the global scope instead.
+      fetch(url)
+        .then((res) => res.text())
+        .then((code) => {
+          (0, eval)(`${code}\n\n//# sourceURL=${location.origin}${url}`);
+        })
+        .catch((error) => {
+          resolver.reject(error);
+        });
+    }
+  }
 })();
 
 function _eval({ code, url, map }: EcmascriptModuleEntry): ModuleFactory {
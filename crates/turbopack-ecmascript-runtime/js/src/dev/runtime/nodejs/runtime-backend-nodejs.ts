@@ -92,18 +92,25 @@ let BACKEND: RuntimeBackend;
       loadChunk(chunkPath, source);
     },
 
+    async loadWebAssembly(source, wasmPath, imports) {
+      const wasm = readWebAssemblyAsset(source, wasmPath);
+      const { WebAssembly } = globalThis as any;
+      const { instance } = await WebAssembly.instantiate(wasm, imports);
+      return instance.exports;
+    },
+
+    async loadWebAssemblyModule(source, wasmPath) {
+      const wasm = readWebAssemblyAsset(source, wasmPath);
+      const { WebAssembly } = globalThis as any;
+      return WebAssembly.compile(wasm);
+    },
+
     restart: () => {
       throw new Error("restart not implemented for the Node.js backend");
     },
   };
 
-  function loadChunk(chunkPath: ChunkPath, source: SourceInfo) {
-    if (!chunkPath.endsWith(".js")) {
-      // We only support loading JS chunks in Node.js.
-      // This branch can be hit when trying to load a CSS chunk.
-      return;
-    }
-
+  function resolveFromSource(source: SourceInfo, chunkPath: ChunkPath) {
     let fromChunkPath = undefined;
     switch (source.type) {
       case SourceType.Runtime:
@@ -116,12 +123,29 @@ let BACKEND: RuntimeBackend;
         break;
     }
 
-    // We'll only mark the chunk as loaded once the script has been executed,
-    // which happens in `registerChunk`. Hence the absence of `resolve()`.
     const path = require("path");
-    const resolved = require.resolve(
+    return require.resolve(
       "./" + path.relative(path.dirname(fromChunkPath), chunkPath)
     );
+  }
+
+  function readWebAssemblyAsset(source: SourceInfo, wasmPath: string) {
+    const fs = require("fs");
+    // Static assets are referenced by their server-relative URL, which maps
+    // to a path relative to the output root.
+    return fs.readFileSync(resolveFromSource(source, wasmPath.slice(1)));
+  }
+
+  function loadChunk(chunkPath: ChunkPath, source: SourceInfo) {
+    if (!chunkPath.endsWith(".js")) {
+      // We only support loading JS chunks in Node.js.
+      // This branch can be hit when trying to load a CSS chunk.
+      return;
+    }
+
+    // We'll only mark the chunk as loaded once the script has been executed,
+    // which happens in `registerChunk`. Hence the absence of `resolve()`.
+    const resolved = resolveFromSource(source, chunkPath);
     delete require.cache[resolved];
     require(resolved);
   }
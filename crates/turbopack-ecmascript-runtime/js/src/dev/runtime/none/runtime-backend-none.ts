@@ -63,6 +63,14 @@ function commonJsRequireContext(
       throw new Error("chunk loading is not supported");
     },
 
+    loadWebAssembly: () => {
+      throw new Error("loading WebAssembly is not supported");
+    },
+
+    loadWebAssemblyModule: () => {
+      throw new Error("loading WebAssembly is not supported");
+    },
+
     restart: () => {
       throw new Error("restart is not supported");
     },
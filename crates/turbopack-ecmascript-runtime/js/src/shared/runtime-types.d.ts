@@ -31,5 +31,31 @@ type ExportValue = (value: any) => void;
 
 type LoadChunk = (chunkPath: ChunkPath) => Promise<any> | undefined;
 
+type WebAssemblyExports = Record<string, any>;
+type WebAssemblyImports = Record<string, Record<string, any>>;
+
+/**
+ * Instantiates the WebAssembly module served at `wasmPath` with the given
+ * imports and resolves to the exports of the instance.
+ */
+type LoadWebAssembly = (
+  wasmPath: string,
+  imports: WebAssemblyImports
+) => Promise<WebAssemblyExports>;
+/**
+ * Compiles the WebAssembly module served at `wasmPath` without instantiating
+ * it.
+ */
+type LoadWebAssemblyModule = (wasmPath: string) => Promise<any>;
+
+/**
+ * The body of an async module. It waits for the async modules it imports with
+ * `handleAsyncDependencies` before evaluating the rest of the module.
+ */
+type AsyncModuleBody = (
+  handleAsyncDependencies: (ids: ModuleId[]) => Promise<void>
+) => Promise<void>;
+type AsyncModule = (body: AsyncModuleBody) => void;
+
 type ModuleCache = Record<ModuleId, Module>;
 type ModuleFactories = Record<ModuleId, ModuleFactory>;
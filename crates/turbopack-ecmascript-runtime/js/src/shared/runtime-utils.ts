@@ -24,6 +24,10 @@ interface BaseModule {
   children: ModuleId[];
   parents: ModuleId[];
   namespaceObject?: EsmNamespaceObject;
+  /**
+   * Settles once an async module has been evaluated.
+   */
+  promise?: Promise<void>;
 }
 
 interface Module extends BaseModule {}
@@ -148,6 +152,30 @@ function esmImport(sourceModule: Module, id: ModuleId): EsmNamespaceObject {
   return ns;
 }
 
+/**
+ * Waits for the async modules an async module imports, given by their ids, to
+ * be evaluated.
+ */
+async function handleAsyncDependencies(ids: ModuleId[]): Promise<void> {
+  await Promise.all(ids.map((id) => moduleCache[id]?.promise));
+}
+
+/**
+ * Evaluates an async module, i.e. a module that uses top-level `await` or
+ * imports an async module.
+ *
+ * The exports of the module are left as they are, so CommonJS importers get
+ * them right away. ES module importers wait for `module.promise` instead. A
+ * module that is imported again while its body runs is part of an import
+ * cycle, and has no promise to wait for yet.
+ */
+function asyncModule(module: Module, body: AsyncModuleBody) {
+  module.promise = body(handleAsyncDependencies).catch((error) => {
+    module.error = error;
+    throw error;
+  });
+}
+
 function commonJsRequire(sourceModule: Module, id: ModuleId): Exports {
   const module = getOrInstantiateModuleFromParent(id, sourceModule);
   if (module.error) throw module.error;
@@ -13,7 +13,10 @@ use swc_core::{
     },
 };
 
-use super::{ConstantNumber, ConstantValue, ImportMap, JsValue, ObjectPart, WellKnownFunctionKind};
+use super::{
+    ConstantNumber, ConstantValue, ImportMap, JsValue, ObjectPart, WellKnownFunctionKind,
+    WellKnownObjectKind,
+};
 use crate::{analyzer::is_unresolved, utils::unparen};
 
 #[derive(Debug, Clone, Default)]
@@ -170,6 +173,15 @@ pub enum Effect {
         span: Span,
         in_try: bool,
     },
+    /// A reference to `new Worker(new URL(..., import.meta.url), ...)` or
+    /// `new SharedWorker(new URL(..., import.meta.url), ...)`.
+    Worker {
+        func: JsValue,
+        input: JsValue,
+        ast_path: Vec<AstParentKind>,
+        span: Span,
+        in_try: bool,
+    },
 }
 
 impl Effect {
@@ -209,6 +221,10 @@ impl Effect {
             Effect::Url { input, .. } => {
                 input.normalize();
             }
+            Effect::Worker { func, input, .. } => {
+                func.normalize();
+                input.normalize();
+            }
         }
     }
 }
@@ -337,6 +353,10 @@ impl EvalContext {
             Expr::Paren(e) => self.eval(&e.expr),
             Expr::Lit(e) => JsValue::Constant(e.clone().into()),
             Expr::Ident(i) => self.eval_ident(i),
+            Expr::MetaProp(MetaPropExpr {
+                kind: MetaPropKind::ImportMeta,
+                ..
+            }) => JsValue::WellKnownObject(WellKnownObjectKind::ImportMeta),
 
             Expr::Unary(UnaryExpr {
                 op: op!("!"), arg, ..
@@ -923,6 +943,36 @@ impl Analyzer<'_> {
         }
     }
 
+    /// Returns the first argument of a `new URL(input, import.meta.url)`
+    /// expression.
+    fn url_import_meta_input<'ast>(&self, new_expr: &'ast NewExpr) -> Option<&'ast Expr> {
+        let box Expr::Ident(callee) = &new_expr.callee else {
+            return None;
+        };
+        if &*callee.sym != "URL" || !is_unresolved(callee, self.eval_context.unresolved_mark) {
+            return None;
+        }
+        let args = new_expr.args.as_ref()?;
+        if args.len() != 2 {
+            return None;
+        }
+        if let Expr::Member(MemberExpr {
+            obj:
+                box Expr::MetaProp(MetaPropExpr {
+                    kind: MetaPropKind::ImportMeta,
+                    ..
+                }),
+            prop: MemberProp::Ident(prop),
+            ..
+        }) = &*args[1].expr
+        {
+            if &*prop.sym == "url" {
+                return Some(&args[0].expr);
+            }
+        }
+        None
+    }
+
     fn check_member_expr_for_effects<'ast: 'r, 'r>(
         &mut self,
         member_expr: &'ast MemberExpr,
@@ -1131,34 +1181,70 @@ impl VisitAstPath for Analyzer<'_> {
         new_expr: &'ast NewExpr,
         ast_path: &mut AstNodePath<AstParentNodeRef<'r>>,
     ) {
-        // new URL("path", import.meta.url)
+        // new Worker(new URL("path", import.meta.url), ...)
         if let box Expr::Ident(ref callee) = &new_expr.callee {
-            if &*callee.sym == "URL" && is_unresolved(callee, self.eval_context.unresolved_mark) {
-                if let Some(args) = &new_expr.args {
-                    if args.len() == 2 {
-                        if let Expr::Member(MemberExpr {
-                            obj:
-                                box Expr::MetaProp(MetaPropExpr {
-                                    kind: MetaPropKind::ImportMeta,
-                                    ..
-                                }),
-                            prop: MemberProp::Ident(prop),
-                            ..
-                        }) = &*args[1].expr
+            if matches!(&*callee.sym, "Worker" | "SharedWorker")
+                && is_unresolved(callee, self.eval_context.unresolved_mark)
+            {
+                if let Some(ExprOrSpread {
+                    spread: None,
+                    expr: box Expr::New(url_expr),
+                }) = new_expr.args.as_ref().and_then(|args| args.first())
+                {
+                    if let Some(input) = self.url_import_meta_input(url_expr) {
+                        self.add_effect(Effect::Worker {
+                            func: self.eval_context.eval(&new_expr.callee),
+                            input: self.eval_context.eval(input),
+                            ast_path: as_parent_path(ast_path),
+                            span: new_expr.span(),
+                            in_try: is_in_try(ast_path),
+                        });
+
+                        // The inner `new URL(...)` is handled by the worker reference, so we
+                        // skip it here to avoid emitting a separate `Effect::Url` for it.
                         {
-                            if &*prop.sym == "url" {
-                                self.add_effect(Effect::Url {
-                                    input: self.eval_context.eval(&args[0].expr),
-                                    ast_path: as_parent_path(ast_path),
-                                    span: new_expr.span(),
-                                    in_try: is_in_try(ast_path),
-                                });
-                            }
+                            let mut ast_path = ast_path.with_guard(AstParentNodeRef::NewExpr(
+                                new_expr,
+                                NewExprField::Args(0),
+                            ));
+                            let arg = &new_expr.args.as_ref().unwrap()[0];
+                            let mut ast_path = ast_path.with_guard(AstParentNodeRef::ExprOrSpread(
+                                arg,
+                                ExprOrSpreadField::Expr,
+                            ));
+                            let mut ast_path = ast_path
+                                .with_guard(AstParentNodeRef::Expr(&arg.expr, ExprField::New));
+                            url_expr.visit_children_with_path(self, &mut ast_path);
                         }
+                        {
+                            let mut ast_path = ast_path.with_guard(AstParentNodeRef::NewExpr(
+                                new_expr,
+                                NewExprField::Callee,
+                            ));
+                            new_expr.callee.visit_with_path(self, &mut ast_path);
+                        }
+                        for (i, arg) in new_expr.args.iter().flatten().enumerate().skip(1) {
+                            let mut ast_path = ast_path.with_guard(AstParentNodeRef::NewExpr(
+                                new_expr,
+                                NewExprField::Args(i),
+                            ));
+                            arg.visit_with_path(self, &mut ast_path);
+                        }
+                        return;
                     }
                 }
             }
         }
+
+        // new URL("path", import.meta.url)
+        if let Some(input) = self.url_import_meta_input(new_expr) {
+            self.add_effect(Effect::Url {
+                input: self.eval_context.eval(input),
+                ast_path: as_parent_path(ast_path),
+                span: new_expr.span(),
+                in_try: is_in_try(ast_path),
+            });
+        }
         new_expr.visit_children_with_path(self, ast_path);
     }
 
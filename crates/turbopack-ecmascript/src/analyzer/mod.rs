@@ -1390,6 +1390,14 @@ impl JsValue {
                         "require.cache",
                         "The CommonJS require.cache object: https://nodejs.org/api/modules.html#requirecache"
                     ),
+                    WellKnownObjectKind::ImportMeta => (
+                        "import.meta",
+                        "The import.meta object from the ESM specification: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/import.meta"
+                    ),
+                    WellKnownObjectKind::ImportMetaEnv => (
+                        "import.meta.env",
+                        "The import.meta.env object with environment variables inlined at compile time"
+                    ),
                 };
                 if depth > 0 {
                     let i = hints.len();
@@ -1496,6 +1504,18 @@ impl JsValue {
                       "load/loadSync".to_string(),
                       "require('@grpc/proto-loader').load(filepath, { includeDirs: [root] }) https://github.com/grpc/grpc-node"
                     ),
+                    WellKnownFunctionKind::WorkerConstructor => (
+                      "Worker".to_string(),
+                      "The Worker constructor: https://developer.mozilla.org/en-US/docs/Web/API/Worker/Worker"
+                    ),
+                    WellKnownFunctionKind::SharedWorkerConstructor => (
+                      "SharedWorker".to_string(),
+                      "The SharedWorker constructor: https://developer.mozilla.org/en-US/docs/Web/API/SharedWorker/SharedWorker"
+                    ),
+                    WellKnownFunctionKind::ImportMetaGlob => (
+                      "import.meta.glob".to_string(),
+                      "The import.meta.glob method from Vite: https://vitejs.dev/guide/features.html#glob-import"
+                    ),
                 };
                 if depth > 0 {
                     let i = hints.len();
@@ -2962,6 +2982,8 @@ pub enum WellKnownObjectKind {
     NodeProtobufLoader,
     NodeBuffer,
     RequireCache,
+    ImportMeta,
+    ImportMetaEnv,
 }
 
 impl WellKnownObjectKind {
@@ -2977,6 +2999,7 @@ impl WellKnownObjectKind {
             Self::NodeProcessEnv => Some(&["process", "env"]),
             Self::NodeBuffer => Some(&["Buffer"]),
             Self::RequireCache => Some(&["require", "cache"]),
+            Self::ImportMetaEnv => Some(&["import", "meta", "env"]),
             _ => None,
         }
     }
@@ -3071,6 +3094,136 @@ pub fn parse_require_context(args: &Vec<JsValue>) -> Result<RequireContextOption
     })
 }
 
+/// The parsed arguments of an `import.meta.glob(patterns, options)` call.
+#[turbo_tasks::value(shared)]
+#[derive(Debug, Clone)]
+pub struct ImportMetaGlobOptions {
+    /// Patterns of modules to include, relative to the importing module.
+    pub patterns: Vec<String>,
+    /// Patterns of modules to exclude (`!` prefixed in the source).
+    pub negative_patterns: Vec<String>,
+    /// Import all modules statically instead of returning loader functions.
+    pub eager: bool,
+    /// Only expose a single export of each module.
+    pub import: Option<String>,
+    /// A query to append to each request, including the leading `?`.
+    pub query: Option<String>,
+}
+
+pub fn parse_import_meta_glob(args: &[JsValue]) -> Result<ImportMetaGlobOptions> {
+    if !(1..=2).contains(&args.len()) {
+        bail!("import.meta.glob() only supports 1-2 arguments");
+    }
+
+    let patterns = match &args[0] {
+        JsValue::Array { items, .. } => items.iter().map(|item| item.as_str()).collect(),
+        pattern => vec![pattern.as_str()],
+    };
+    let Some(patterns) = patterns.into_iter().collect::<Option<Vec<_>>>() else {
+        bail!(
+            "import.meta.glob(patterns, ...) requires patterns to be a constant string or an \
+             array of constant strings"
+        );
+    };
+
+    let mut options = ImportMetaGlobOptions {
+        patterns: vec![],
+        negative_patterns: vec![],
+        eager: false,
+        import: None,
+        query: None,
+    };
+
+    for pattern in patterns {
+        let (negative, pattern) = match pattern.strip_prefix('!') {
+            Some(pattern) => (true, pattern),
+            None => (false, pattern),
+        };
+        if !pattern.starts_with("./") && !pattern.starts_with("../") {
+            bail!("import.meta.glob() only supports relative patterns, got \"{pattern}\"");
+        }
+        if negative {
+            options.negative_patterns.push(pattern.to_string());
+        } else {
+            options.patterns.push(pattern.to_string());
+        }
+    }
+    if options.patterns.is_empty() {
+        bail!("import.meta.glob() requires at least one positive pattern");
+    }
+
+    let Some(arg) = args.get(1) else {
+        return Ok(options);
+    };
+    let JsValue::Object { parts, .. } = arg else {
+        bail!("import.meta.glob(..., options) requires options to be an object literal");
+    };
+    for part in parts {
+        let ObjectPart::KeyValue(key, value) = part else {
+            bail!("import.meta.glob(..., options) does not support spread options");
+        };
+        match key.as_str() {
+            Some("eager") => {
+                let Some(eager) = value.as_bool() else {
+                    bail!("import.meta.glob(..., {{ eager }}) requires eager to be a constant boolean");
+                };
+                options.eager = eager;
+            }
+            Some("import") => {
+                let Some(import) = value.as_str() else {
+                    bail!("import.meta.glob(..., {{ import }}) requires import to be a constant string");
+                };
+                options.import = Some(import.to_string());
+            }
+            Some("query") => {
+                options.query = Some(match value {
+                    JsValue::Object { parts, .. } => {
+                        let mut query = String::new();
+                        for part in parts {
+                            let ObjectPart::KeyValue(JsValue::Constant(key), JsValue::Constant(value)) = part else {
+                                bail!(
+                                    "import.meta.glob(..., {{ query }}) requires query to only \
+                                     contain constant values"
+                                );
+                            };
+                            let Some(key) = key.as_str() else {
+                                bail!("import.meta.glob(..., {{ query }}) requires string keys");
+                            };
+                            query.push(if query.is_empty() { '?' } else { '&' });
+                            query.push_str(&urlencoding::encode(key));
+                            // `true` values are bare keys, e.g. `{ raw: true }` becomes `?raw`.
+                            if !matches!(value, ConstantValue::True) {
+                                let value = value
+                                    .as_str()
+                                    .map_or_else(|| value.to_string(), str::to_string);
+                                query.push('=');
+                                query.push_str(&urlencoding::encode(&value));
+                            }
+                        }
+                        query
+                    }
+                    _ => {
+                        let Some(query) = value.as_str() else {
+                            bail!(
+                                "import.meta.glob(..., {{ query }}) requires query to be a \
+                                 constant string or object"
+                            );
+                        };
+                        if query.starts_with('?') || query.is_empty() {
+                            query.to_string()
+                        } else {
+                            format!("?{query}")
+                        }
+                    }
+                });
+            }
+            _ => bail!("import.meta.glob(..., {{ {} }}) is not supported", key),
+        }
+    }
+
+    Ok(options)
+}
+
 #[turbo_tasks::value(transparent)]
 #[derive(Debug, Clone)]
 pub struct RequireContextValue(IndexMap<String, String>);
@@ -3139,6 +3292,9 @@ pub enum WellKnownFunctionKind {
     NodeStrongGlobalizeSetRootDir,
     NodeResolveFrom,
     NodeProtobufLoad,
+    WorkerConstructor,
+    SharedWorkerConstructor,
+    ImportMetaGlob,
 }
 
 impl WellKnownFunctionKind {
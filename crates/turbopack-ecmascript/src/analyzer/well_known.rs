@@ -552,6 +552,7 @@ pub async fn well_known_object_member(
         WellKnownObjectKind::NodePreGyp => node_pre_gyp(prop),
         WellKnownObjectKind::NodeExpressApp => express(prop),
         WellKnownObjectKind::NodeProtobufLoader => protobuf_loader(prop),
+        WellKnownObjectKind::ImportMeta => import_meta(prop),
         #[allow(unreachable_patterns)]
         _ => {
             return Ok((
@@ -762,3 +763,17 @@ fn protobuf_loader(prop: JsValue) -> JsValue {
         ),
     }
 }
+
+fn import_meta(prop: JsValue) -> JsValue {
+    match prop.as_str() {
+        Some("glob") => JsValue::WellKnownFunction(WellKnownFunctionKind::ImportMetaGlob),
+        Some("env") => JsValue::WellKnownObject(WellKnownObjectKind::ImportMetaEnv),
+        _ => JsValue::unknown(
+            JsValue::member(
+                Box::new(JsValue::WellKnownObject(WellKnownObjectKind::ImportMeta)),
+                Box::new(prop),
+            ),
+            "unsupported property on import.meta",
+        ),
+    }
+}
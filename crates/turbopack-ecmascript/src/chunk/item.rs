@@ -53,6 +53,7 @@ impl EcmascriptChunkItemContentVc {
                 EcmascriptChunkItemOptions {
                     refresh,
                     externals,
+                    async_module: content.is_async,
                     ..Default::default()
                 }
             } else {
@@ -94,6 +95,13 @@ impl EcmascriptChunkItemContentVc {
         if this.options.refresh {
             args.push("k: __turbopack_refresh__");
         }
+        if this.options.wasm {
+            args.push("w: __turbopack_wasm__");
+            args.push("u: __turbopack_wasm_module__");
+        }
+        if this.options.async_module {
+            args.push("a: __turbopack_async_module__");
+        }
         if this.options.module {
             args.push("m: module");
         }
@@ -133,6 +141,12 @@ pub struct EcmascriptChunkItemOptions {
     /// Whether this chunk item's module factory should include a
     /// `__turbopack_external_require__` argument.
     pub externals: bool,
+    /// Whether this chunk item's module factory should include
+    /// `__turbopack_wasm__` and `__turbopack_wasm_module__` arguments.
+    pub wasm: bool,
+    /// Whether this chunk item's module factory should include an
+    /// `__turbopack_async_module__` argument.
+    pub async_module: bool,
     pub this: bool,
     pub placeholder_for_future_extensions: (),
 }
@@ -16,7 +16,8 @@ use turbo_tasks_fs::FileSystemPathOptionVc;
 use turbopack_core::{
     asset::{Asset, AssetContentVc, AssetVc},
     chunk::{
-        availability_info::AvailabilityInfo, Chunk, ChunkGroupReferenceVc, ChunkItem, ChunkVc,
+        availability_info::AvailabilityInfo, Chunk, ChunkGroupReferenceVc, ChunkItem,
+        ChunkItemSize, ChunkItemSizesVc, ChunkVc, ChunkWithItemSizes, ChunkWithItemSizesVc,
         ChunkingContextVc, ChunksVc, ModuleIdsVc,
     },
     ident::{AssetIdent, AssetIdentVc},
@@ -400,6 +401,34 @@ impl Asset for EcmascriptChunk {
     }
 }
 
+#[turbo_tasks::value_impl]
+impl ChunkWithItemSizes for EcmascriptChunk {
+    #[turbo_tasks::function]
+    async fn chunk_item_sizes(&self) -> Result<ChunkItemSizesVc> {
+        let content = ecmascript_chunk_content(
+            self.context,
+            self.main_entries,
+            self.omit_entries,
+            Value::new(self.availability_info),
+        )
+        .await?;
+        let availability_info = content.availability_info;
+        let sizes = content
+            .chunk_items
+            .iter()
+            .map(|&chunk_item| async move {
+                let code = chunk_item.code(Value::new(availability_info)).await?;
+                Ok(ChunkItemSize {
+                    chunk_item: chunk_item.into(),
+                    size: code.source_code().len(),
+                })
+            })
+            .try_join()
+            .await?;
+        Ok(ChunkItemSizesVc::cell(sizes))
+    }
+}
+
 #[turbo_tasks::function]
 fn introspectable_type() -> StringVc {
     StringVc::cell("ecmascript chunk".to_string())
@@ -1,4 +1,5 @@
 use anyhow::Result;
+use turbo_tasks::primitives::BoolVc;
 use turbopack_core::{
     asset::{Asset, AssetVc},
     chunk::{ChunkableAsset, ChunkableAssetVc},
@@ -11,6 +12,11 @@ use crate::references::esm::EsmExportsVc;
 pub trait EcmascriptChunkPlaceable: ChunkableAsset + Asset {
     fn as_chunk_item(&self, context: EcmascriptChunkingContextVc) -> EcmascriptChunkItemVc;
     fn get_exports(&self) -> EcmascriptExportsVc;
+    /// Whether the module uses top-level `await`, which makes it and the
+    /// modules that import it async modules.
+    fn has_top_level_await(&self) -> BoolVc {
+        BoolVc::cell(false)
+    }
 }
 
 #[turbo_tasks::value(transparent)]
@@ -25,6 +25,7 @@ pub mod tree_shake;
 pub mod typescript;
 pub mod utils;
 pub mod webpack;
+pub mod worker_loader_asset;
 
 use anyhow::Result;
 use chunk::{
@@ -36,7 +37,10 @@ use parse::{parse, ParseResult};
 pub use parse::{ParseResultSourceMap, ParseResultSourceMapVc};
 use path_visitor::ApplyVisitors;
 use references::AnalyzeEcmascriptModuleResult;
-pub use references::TURBOPACK_HELPER;
+pub use references::{
+    esm::{EsmExport, EsmExports, EsmExportsVc},
+    TURBOPACK_HELPER,
+};
 pub use static_code::{StaticEcmascriptCode, StaticEcmascriptCodeVc};
 use swc_core::{
     common::GLOBALS,
@@ -51,14 +55,16 @@ pub use transform::{
     TransformPluginVc, UnsupportedServerActionIssue,
 };
 use turbo_tasks::{
-    primitives::StringVc, trace::TraceRawVcs, RawVc, ReadRef, TryJoinIterExt, Value, ValueToString,
+    primitives::{BoolVc, StringVc},
+    trace::TraceRawVcs,
+    RawVc, ReadRef, TryJoinIterExt, Value, ValueToString,
 };
 use turbo_tasks_fs::{rope::Rope, FileSystemPathVc};
 use turbopack_core::{
     asset::{Asset, AssetContentVc, AssetOptionVc, AssetVc},
     chunk::{
         availability_info::AvailabilityInfo, ChunkItem, ChunkItemVc, ChunkVc, ChunkableAsset,
-        ChunkableAssetVc, ChunkingContextVc, EvaluatableAsset, EvaluatableAssetVc,
+        ChunkableAssetVc, ChunkingContextVc, EvaluatableAsset, EvaluatableAssetVc, ModuleIdReadRef,
     },
     compile_time_info::CompileTimeInfoVc,
     context::AssetContextVc,
@@ -87,7 +93,10 @@ use self::{
 use crate::{
     chunk::{EcmascriptChunkPlaceable, EcmascriptChunkPlaceableVc},
     code_gen::CodeGenerateable,
-    references::analyze_ecmascript_module,
+    references::{
+        analyze_ecmascript_module,
+        async_module::{get_async_module, wrap_async_module, OptionAsyncModuleVc},
+    },
     transform::remove_shebang,
 };
 
@@ -379,6 +388,7 @@ impl EcmascriptModuleAssetVc {
             chunking_context,
             self.analyze(),
             availability_info,
+            get_async_module(self, availability_info),
         ))
     }
 }
@@ -445,6 +455,13 @@ impl EcmascriptChunkPlaceable for EcmascriptModuleAsset {
     async fn get_exports(self_vc: EcmascriptModuleAssetVc) -> Result<EcmascriptExportsVc> {
         Ok(self_vc.failsafe_analyze().await?.exports)
     }
+
+    #[turbo_tasks::function]
+    async fn has_top_level_await(self_vc: EcmascriptModuleAssetVc) -> Result<BoolVc> {
+        Ok(BoolVc::cell(
+            self_vc.failsafe_analyze().await?.has_top_level_await,
+        ))
+    }
 }
 
 #[turbo_tasks::value_impl]
@@ -526,6 +543,7 @@ pub struct EcmascriptModuleContent {
     pub inner_code: Rope,
     pub source_map: Option<ParseResultSourceMapVc>,
     pub is_esm: bool,
+    pub is_async: bool,
 }
 
 #[turbo_tasks::value_impl]
@@ -538,6 +556,7 @@ impl EcmascriptModuleContentVc {
         context: EcmascriptChunkingContextVc,
         analyzed: AnalyzeEcmascriptModuleResultVc,
         availability_info: Value<AvailabilityInfo>,
+        async_module: OptionAsyncModuleVc,
     ) -> Result<Self> {
         let AnalyzeEcmascriptModuleResult {
             references,
@@ -580,13 +599,25 @@ impl EcmascriptModuleContentVc {
             }
         }
 
-        gen_content_with_visitors(parsed, ident, visitors, root_visitors).await
+        let async_import_ids = match *async_module.await? {
+            Some(async_module) => Some(async_module.async_import_ids(context).await?),
+            None => None,
+        };
+
+        gen_content_with_visitors(
+            parsed,
+            ident,
+            visitors,
+            root_visitors,
+            async_import_ids.as_deref().map(|ids| &ids[..]),
+        )
+        .await
     }
 
     /// Creates a new [`EcmascriptModuleContentVc`] without an analysis pass.
     #[turbo_tasks::function]
     pub async fn new_without_analysis(parsed: ParseResultVc, ident: AssetIdentVc) -> Result<Self> {
-        gen_content_with_visitors(parsed, ident, Vec::new(), Vec::new()).await
+        gen_content_with_visitors(parsed, ident, Vec::new(), Vec::new(), None).await
     }
 }
 
@@ -598,6 +629,7 @@ async fn gen_content_with_visitors(
         &dyn VisitorFactory,
     )>,
     root_visitors: Vec<&dyn VisitorFactory>,
+    async_import_ids: Option<&[ModuleIdReadRef]>,
 ) -> Result<EcmascriptModuleContentVc> {
     let parsed = parsed.await?;
 
@@ -621,6 +653,9 @@ async fn gen_content_with_visitors(
             for visitor in root_visitors {
                 program.visit_mut_with(&mut visitor.create());
             }
+            if let Some(async_import_ids) = async_import_ids {
+                wrap_async_module(&mut program, async_import_ids);
+            }
             program.visit_mut_with(&mut swc_core::ecma::transforms::base::hygiene::hygiene());
             program.visit_mut_with(&mut swc_core::ecma::transforms::base::fixer::fixer(None));
 
@@ -652,6 +687,7 @@ async fn gen_content_with_visitors(
             inner_code: bytes.into(),
             source_map: Some(srcmap),
             is_esm: eval_context.is_esm(),
+            is_async: async_import_ids.is_some(),
         }
         .cell())
     } else {
@@ -664,6 +700,7 @@ async fn gen_content_with_visitors(
             .into(),
             source_map: None,
             is_esm: false,
+            is_async: false,
         }
         .cell())
     }
@@ -1,11 +1,14 @@
 use std::io::Write as _;
 
 use anyhow::{anyhow, Result};
-use indoc::writedoc;
+use indoc::{formatdoc, writedoc};
 use turbo_tasks::{primitives::StringVc, TryJoinIterExt};
 use turbopack_core::{
-    asset::Asset,
-    chunk::{ChunkDataVc, ChunkItem, ChunkItemVc, ChunkingContext, ChunksDataVc},
+    asset::{Asset, AssetVc, AssetsVc},
+    chunk::{
+        ChunkDataVc, ChunkGroupLoader, ChunkGroupLoaderVc, ChunkItem, ChunkItemVc, ChunkingContext,
+        ChunksDataVc,
+    },
     ident::AssetIdentVc,
     reference::{AssetReferencesVc, SingleAssetReferenceVc},
 };
@@ -17,6 +20,7 @@ use crate::{
         EcmascriptChunkItemContentVc, EcmascriptChunkItemVc, EcmascriptChunkPlaceable,
         EcmascriptChunkPlaceableVc, EcmascriptChunkingContextVc,
     },
+    references::async_module::is_async_module,
     utils::StringifyJs,
 };
 
@@ -96,6 +100,19 @@ impl ChunkItem for ManifestLoaderItem {
     }
 }
 
+#[turbo_tasks::value_impl]
+impl ChunkGroupLoader for ManifestLoaderItem {
+    #[turbo_tasks::function]
+    async fn loaded_asset(&self) -> Result<AssetVc> {
+        Ok(self.manifest.await?.asset.into())
+    }
+
+    #[turbo_tasks::function]
+    fn loaded_chunks(&self) -> AssetsVc {
+        self.manifest.manifest_chunks()
+    }
+}
+
 #[turbo_tasks::value_impl]
 impl EcmascriptChunkItem for ManifestLoaderItem {
     #[turbo_tasks::function]
@@ -135,6 +152,19 @@ impl EcmascriptChunkItem for ManifestLoaderItem {
             .id()
             .await?;
 
+        // An async module is only imported once it has been evaluated.
+        let wait_for_async_module = if *is_async_module(asset).await? {
+            formatdoc!(
+                r#"
+                    .then((namespace) => {{
+                            return __turbopack_cache__[{dynamic_id}].promise.then(() => namespace);
+                        }})"#,
+                dynamic_id = StringifyJs(dynamic_id),
+            )
+        } else {
+            String::new()
+        };
+
         // This is the code that will be executed when the dynamic import is reached.
         // It will load the manifest chunk, which will load all the chunks needed by
         // the dynamic import, and finally we'll be able to import the module we're
@@ -151,7 +181,7 @@ impl EcmascriptChunkItem for ManifestLoaderItem {
                         return Promise.all(chunks.map((chunk) => __turbopack_load__(chunk)));
                     }}).then(() => {{
                         return __turbopack_import__({dynamic_id});
-                    }});
+                    }}){wait_for_async_module};
                 }});
             "#,
             chunks_server_data = StringifyJs(
@@ -0,0 +1,253 @@
+use std::{collections::HashMap, mem::take};
+
+use anyhow::Result;
+use indexmap::IndexSet;
+use swc_core::{
+    common::DUMMY_SP,
+    ecma::ast::{
+        ArrayLit, ArrowExpr, AwaitExpr, BindingIdent, BlockStmt, BlockStmtOrExpr, Expr,
+        ExprOrSpread, ExprStmt, Ident, Lit, ModuleItem, Pat, Program, Stmt,
+    },
+    quote, quote_expr,
+};
+use turbo_tasks::{primitives::BoolVc, TryJoinIterExt, Value};
+use turbopack_core::{
+    asset::{AssetVc, AssetsVc},
+    chunk::{
+        availability_info::AvailabilityInfo, available_assets::chunkable_assets_set,
+        ChunkableAssetReference, ChunkingType, ModuleIdReadRef,
+    },
+};
+
+use super::esm::{
+    base::{ReferencedAsset, ESM_HOISTING_LOCATION},
+    EsmAssetReferenceVc,
+};
+use crate::{
+    chunk::{EcmascriptChunkPlaceable, EcmascriptChunkPlaceableVc, EcmascriptChunkingContextVc},
+    utils::module_id_to_lit,
+    EcmascriptModuleAssetVc,
+};
+
+/// An async module is a module that uses top-level `await` or imports an async
+/// module. Its body is evaluated in an async function that waits for the
+/// async modules it imports, and its ES module importers wait for it in turn.
+#[turbo_tasks::value(shared)]
+pub struct AsyncModule {
+    /// The imported modules that are async.
+    pub async_imports: Vec<EcmascriptChunkPlaceableVc>,
+}
+
+#[turbo_tasks::value_impl]
+impl AsyncModuleVc {
+    /// The module ids of the imported async modules.
+    #[turbo_tasks::function]
+    pub(crate) async fn async_import_ids(
+        self,
+        context: EcmascriptChunkingContextVc,
+    ) -> Result<ModuleIdsVc> {
+        Ok(ModuleIdsVc::cell(
+            self.await?
+                .async_imports
+                .iter()
+                .map(|placeable| placeable.as_chunk_item(context).id())
+                .try_join()
+                .await?,
+        ))
+    }
+}
+
+#[turbo_tasks::value(transparent)]
+pub(crate) struct ModuleIds(Vec<ModuleIdReadRef>);
+
+#[turbo_tasks::value(transparent)]
+pub struct OptionAsyncModule(Option<AsyncModuleVc>);
+
+/// The modules that are imported by ESM imports of `asset`, which are
+/// evaluated before it.
+#[turbo_tasks::function]
+async fn esm_imported_modules(asset: AssetVc) -> Result<AssetsVc> {
+    let Some(module) = EcmascriptModuleAssetVc::resolve_from(asset).await? else {
+        return Ok(AssetsVc::empty());
+    };
+    let references = module.failsafe_analyze().await?.references.await?;
+    let mut modules = Vec::new();
+    for &reference in references.iter() {
+        let Some(esm_reference) = EsmAssetReferenceVc::resolve_from(reference).await? else {
+            continue;
+        };
+        // Separate chunks are not imported, see the code generation of
+        // `EsmAssetReference`.
+        if matches!(
+            *esm_reference.chunking_type().await?,
+            None | Some(ChunkingType::Separate)
+        ) {
+            continue;
+        }
+        if let ReferencedAsset::Some(placeable) = &*esm_reference.get_referenced_asset().await? {
+            modules.push(placeable.as_asset().resolve().await?);
+        }
+    }
+    Ok(AssetsVc::cell(modules))
+}
+
+/// The async modules in the chunk group of `root`.
+#[turbo_tasks::value(transparent)]
+struct AsyncModulesSet(IndexSet<AssetVc>);
+
+#[turbo_tasks::function]
+async fn async_modules_set(root: AssetVc) -> Result<AsyncModulesSetVc> {
+    let assets = chunkable_assets_set(root).await?;
+    let imports = assets
+        .iter()
+        .map(|&asset| async move { Ok((asset, esm_imported_modules(asset).await?)) })
+        .try_join()
+        .await?;
+
+    let mut importers: HashMap<AssetVc, Vec<AssetVc>> = HashMap::new();
+    let mut queue = Vec::new();
+    for (asset, imported) in imports.iter() {
+        for &imported in imported.iter() {
+            importers.entry(imported).or_default().push(*asset);
+        }
+        if let Some(placeable) = EcmascriptChunkPlaceableVc::resolve_from(asset).await? {
+            if *placeable.has_top_level_await().await? {
+                queue.push(*asset);
+            }
+        }
+    }
+
+    // Every importer of an async module is async too. Import cycles are
+    // handled at runtime, so all modules of a cycle are async as soon as one of
+    // them is.
+    let mut async_modules = IndexSet::new();
+    while let Some(asset) = queue.pop() {
+        if async_modules.insert(asset) {
+            queue.extend(importers.get(&asset).into_iter().flatten().copied());
+        }
+    }
+    Ok(AsyncModulesSetVc::cell(async_modules))
+}
+
+/// Returns whether `module` is an async module, and which of the modules it
+/// imports are async.
+#[turbo_tasks::function]
+pub(crate) async fn get_async_module(
+    module: EcmascriptModuleAssetVc,
+    availability_info: Value<AvailabilityInfo>,
+) -> Result<OptionAsyncModuleVc> {
+    let asset = AssetVc::from(module).resolve().await?;
+    // The module is usually placed in the chunk group of the availability
+    // root. Otherwise, e.g. for the other entries of an evaluated chunk group,
+    // its own chunk group contains all of the modules it imports.
+    let mut async_modules = None;
+    if let Some(root) = availability_info.current_availability_root() {
+        if chunkable_assets_set(root).await?.contains(&asset) {
+            async_modules = Some(async_modules_set(root).await?);
+        }
+    }
+    let async_modules = match async_modules {
+        Some(async_modules) => async_modules,
+        None => async_modules_set(asset).await?,
+    };
+    if !async_modules.contains(&asset) {
+        return Ok(OptionAsyncModuleVc::cell(None));
+    }
+
+    let mut async_imports = IndexSet::new();
+    for &imported in esm_imported_modules(asset).await?.iter() {
+        if async_modules.contains(&imported) {
+            if let Some(placeable) = EcmascriptChunkPlaceableVc::resolve_from(imported).await? {
+                async_imports.insert(placeable);
+            }
+        }
+    }
+    Ok(OptionAsyncModuleVc::cell(Some(
+        AsyncModule {
+            async_imports: async_imports.into_iter().collect(),
+        }
+        .cell(),
+    )))
+}
+
+/// Returns whether `asset` is an async module, which its dynamic importers
+/// wait for.
+#[turbo_tasks::function]
+pub(crate) async fn is_async_module(asset: AssetVc) -> Result<BoolVc> {
+    let asset = asset.resolve().await?;
+    Ok(BoolVc::cell(
+        async_modules_set(asset).await?.contains(&asset),
+    ))
+}
+
+/// Wraps the body of an async module into an async function, which waits for
+/// the async modules it imports right after the hoisted imports.
+pub(crate) fn wrap_async_module(program: &mut Program, async_import_ids: &[ModuleIdReadRef]) {
+    let Program::Module(module) = program else {
+        return;
+    };
+
+    let mut items = Vec::new();
+    let mut stmts = Vec::new();
+    for item in take(&mut module.body) {
+        match item {
+            ModuleItem::Stmt(stmt) => stmts.push(stmt),
+            // Module declarations can't be moved into a function.
+            item => items.push(item),
+        }
+    }
+
+    if !async_import_ids.is_empty() {
+        let ids = Expr::Array(ArrayLit {
+            span: DUMMY_SP,
+            elems: async_import_ids
+                .iter()
+                .map(|id| {
+                    Some(ExprOrSpread {
+                        spread: None,
+                        expr: Box::new(module_id_to_lit(id)),
+                    })
+                })
+                .collect(),
+        });
+        let stmt = Stmt::Expr(ExprStmt {
+            span: DUMMY_SP,
+            expr: Box::new(Expr::Await(AwaitExpr {
+                span: DUMMY_SP,
+                arg: quote_expr!(
+                    "__turbopack_handle_async_dependencies__($ids)",
+                    ids: Expr = ids
+                ),
+            })),
+        });
+        let hoisting_location = stmts.iter().position(|stmt| {
+            matches!(
+                stmt,
+                Stmt::Expr(ExprStmt { expr: box Expr::Lit(Lit::Str(s)), .. })
+                    if &*s.value == *ESM_HOISTING_LOCATION
+            )
+        });
+        stmts.insert(hoisting_location.map_or(0, |pos| pos + 1), stmt);
+    }
+
+    let body = Expr::Arrow(ArrowExpr {
+        span: DUMMY_SP,
+        params: vec![Pat::Ident(BindingIdent::from(Ident::new(
+            "__turbopack_handle_async_dependencies__".into(),
+            DUMMY_SP,
+        )))],
+        body: Box::new(BlockStmtOrExpr::BlockStmt(BlockStmt {
+            span: DUMMY_SP,
+            stmts,
+        })),
+        is_async: true,
+        is_generator: false,
+        type_params: None,
+        return_type: None,
+    });
+    items.push(ModuleItem::Stmt(quote!(
+        "__turbopack_async_module__($body);" as Stmt,
+        body: Expr = body
+    )));
+    module.body = items;
+}
@@ -1,5 +1,11 @@
 use anyhow::Result;
-use swc_core::quote;
+use swc_core::{
+    ecma::{
+        ast::{KeyValueProp, Prop, PropName},
+        visit::{fields::PropField, AstParentKind},
+    },
+    quote,
+};
 use turbo_tasks::Value;
 use turbopack_core::compile_time_info::CompileTimeDefineValue;
 
@@ -35,16 +41,47 @@ impl CodeGenerateable for ConstantValue {
         _context: EcmascriptChunkingContextVc,
     ) -> Result<CodeGenerationVc> {
         let value = self.value.clone();
-        let visitors = [
-            create_visitor!(exact &self.path.await?, visit_mut_expr(expr: &mut Expr) {
-                *expr = match value {
-                    CompileTimeDefineValue::Bool(true) => quote!("(\"TURBOPACK compile-time value\", true)" as Expr),
-                    CompileTimeDefineValue::Bool(false) => quote!("(\"TURBOPACK compile-time value\", false)" as Expr),
-                    CompileTimeDefineValue::String(ref s) => quote!("(\"TURBOPACK compile-time value\", $e)" as Expr, e: Expr = s.to_string().into()),
-                };
-            }),
-        ]
-        .into();
+        let make_expr = move || match value {
+            CompileTimeDefineValue::Bool(true) => {
+                quote!("(\"TURBOPACK compile-time value\", true)" as Expr)
+            }
+            CompileTimeDefineValue::Bool(false) => {
+                quote!("(\"TURBOPACK compile-time value\", false)" as Expr)
+            }
+            CompileTimeDefineValue::String(ref s) => {
+                quote!(
+                    "(\"TURBOPACK compile-time value\", $e)" as Expr,
+                    e: Expr = s.to_string().into()
+                )
+            }
+        };
+
+        // The path points to the free variable or member expression, so the
+        // visitor needs to replace the expression or property containing it.
+        let mut path = self.path.await?.clone_value();
+        let visitors = match path.pop() {
+            // Shorthand properties need to be rewritten to key-value pairs to keep their key.
+            Some(AstParentKind::Prop(PropField::Shorthand)) => {
+                vec![
+                    create_visitor!(exact path, visit_mut_prop(prop: &mut Prop) {
+                        if let Prop::Shorthand(ident) = prop {
+                            *prop = Prop::KeyValue(KeyValueProp {
+                                key: PropName::Ident(ident.clone()),
+                                value: Box::new(make_expr()),
+                            });
+                        }
+                    }),
+                ]
+            }
+            Some(AstParentKind::Expr(_)) => {
+                vec![
+                    create_visitor!(exact path, visit_mut_expr(expr: &mut Expr) {
+                        *expr = make_expr();
+                    }),
+                ]
+            }
+            _ => Vec::new(),
+        };
 
         Ok(CodeGeneration { visitors }.cell())
     }
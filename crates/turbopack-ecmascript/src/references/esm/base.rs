@@ -117,7 +117,7 @@ impl EsmAssetReference {
 #[turbo_tasks::value_impl]
 impl EsmAssetReferenceVc {
     #[turbo_tasks::function]
-    pub(super) async fn get_referenced_asset(self) -> Result<ReferencedAssetVc> {
+    pub(crate) async fn get_referenced_asset(self) -> Result<ReferencedAssetVc> {
         let this = self.await?;
 
         Ok(ReferencedAssetVc::from_resolve_result(
@@ -270,9 +270,9 @@ impl CodeGenerateable for EsmAssetReference {
 }
 
 lazy_static! {
-    static ref ESM_HOISTING_LOCATION: &'static str = Box::leak(Box::new(magic_identifier::mangle(
-        "ecmascript hoisting location"
-    )));
+    pub(crate) static ref ESM_HOISTING_LOCATION: &'static str = Box::leak(Box::new(
+        magic_identifier::mangle("ecmascript hoisting location")
+    ));
 }
 
 pub(crate) fn insert_hoisted_stmt(program: &mut Program, stmt: Stmt) {
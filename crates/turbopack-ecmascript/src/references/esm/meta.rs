@@ -1,18 +1,46 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::VecDeque};
 
 use anyhow::Result;
+use indexmap::IndexMap;
 use swc_core::{
     common::DUMMY_SP,
-    ecma::ast::{Expr, Ident},
+    ecma::ast::{Expr, Ident, KeyValueProp, Lit, ObjectLit, Prop, PropName, PropOrSpread},
     quote,
 };
-use turbo_tasks_fs::FileSystemPathVc;
+use turbo_tasks::{primitives::StringVc, Value, ValueToString, ValueToStringVc};
+use turbo_tasks_fs::{
+    glob::{Glob, GlobVc},
+    DirectoryEntry, FileSystemPathVc,
+};
+use turbopack_core::{
+    chunk::{
+        availability_info::AvailabilityInfo, ChunkableAssetReference, ChunkableAssetReferenceVc,
+        ChunkingType, ChunkingTypeOptionVc,
+    },
+    compile_time_info::{CompileTimeDefineValue, CompileTimeDefinesVc},
+    issue::{IssueSeverityVc, IssueSourceVc, OptionIssueSourceVc},
+    reference::{AssetReference, AssetReferenceVc},
+    resolve::{
+        origin::{ResolveOrigin, ResolveOriginVc},
+        parse::RequestVc,
+        ResolveResultVc,
+    },
+};
 
+use super::super::pattern_mapping::{
+    PatternMapping, PatternMappingVc,
+    ResolveType::{Cjs, EsmAsync},
+};
 use crate::{
+    analyzer::ImportMetaGlobOptionsVc,
     chunk::EcmascriptChunkingContextVc,
-    code_gen::{CodeGenerateable, CodeGenerateableVc, CodeGeneration, CodeGenerationVc},
+    code_gen::{
+        CodeGenerateable, CodeGenerateableVc, CodeGenerateableWithAvailabilityInfo,
+        CodeGenerateableWithAvailabilityInfoVc, CodeGeneration, CodeGenerationVc,
+    },
     create_visitor, magic_identifier,
     references::{as_abs_path, esm::base::insert_hoisted_stmt, AstPathVc},
+    resolve::{esm_resolve, try_to_severity},
 };
 
 /// Responsible for initializing the `import.meta` object binding, so that it
@@ -20,17 +48,22 @@ use crate::{
 ///
 /// There can be many references to import.meta, and they appear at any nesting
 /// in the file. But we must only initialize the binding a single time.
+///
+/// When `import.meta.env.*` values are defined, the binding also gets an `env`
+/// object holding them, so other `import.meta.env` properties read as
+/// `undefined` instead of throwing.
 #[turbo_tasks::value(shared)]
 #[derive(Hash, Debug)]
 pub struct ImportMetaBinding {
     path: FileSystemPathVc,
+    defines: CompileTimeDefinesVc,
 }
 
 #[turbo_tasks::value_impl]
 impl ImportMetaBindingVc {
     #[turbo_tasks::function]
-    pub fn new(path: FileSystemPathVc) -> Self {
-        ImportMetaBinding { path }.cell()
+    pub fn new(path: FileSystemPathVc, defines: CompileTimeDefinesVc) -> Self {
+        ImportMetaBinding { path, defines }.cell()
     }
 }
 
@@ -51,12 +84,45 @@ impl CodeGenerateable for ImportMetaBinding {
             |path| format!("file://{}", encode_path(path)).into(),
         );
 
+        let env = self
+            .defines
+            .await?
+            .iter()
+            .filter_map(|(name, value)| match name.as_slice() {
+                [import, meta, env, key]
+                    if import == "import" && meta == "meta" && env == "env" =>
+                {
+                    let value = match value {
+                        CompileTimeDefineValue::Bool(b) => Expr::Lit(Lit::Bool((*b).into())),
+                        CompileTimeDefineValue::String(s) => s.clone().into(),
+                    };
+                    Some(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                        key: PropName::Str(key.clone().into()),
+                        value: Box::new(value),
+                    }))))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
         let visitor = create_visitor!(visit_mut_program(program: &mut Program) {
-            let meta = quote!(
-                "const $name = { url: $path };" as Stmt,
-                name = meta_ident(),
-                path: Expr = path.clone(),
-            );
+            let meta = if env.is_empty() {
+                quote!(
+                    "const $name = { url: $path };" as Stmt,
+                    name = meta_ident(),
+                    path: Expr = path.clone(),
+                )
+            } else {
+                quote!(
+                    "const $name = { url: $path, env: $env };" as Stmt,
+                    name = meta_ident(),
+                    path: Expr = path.clone(),
+                    env: Expr = Expr::Object(ObjectLit {
+                        span: DUMMY_SP,
+                        props: env.clone(),
+                    }),
+                )
+            };
             insert_hoisted_stmt(program, meta);
         });
 
@@ -105,6 +171,287 @@ impl CodeGenerateable for ImportMetaRef {
     }
 }
 
+#[turbo_tasks::value]
+#[derive(Debug)]
+pub struct ImportMetaGlobMapEntry {
+    pub request: RequestVc,
+    pub result: ResolveResultVc,
+}
+
+/// The resolved modules of an `import.meta.glob(..)` call, keyed by their
+/// path relative to the importing module (e.g. `./pages/index.js`).
+#[turbo_tasks::value(transparent)]
+pub struct ImportMetaGlobMap(IndexMap<String, ImportMetaGlobMapEntry>);
+
+#[turbo_tasks::value_impl]
+impl ImportMetaGlobMapVc {
+    #[turbo_tasks::function]
+    pub(crate) async fn generate(
+        origin: ResolveOriginVc,
+        options: ImportMetaGlobOptionsVc,
+        issue_source: OptionIssueSourceVc,
+        issue_severity: IssueSeverityVc,
+    ) -> Result<Self> {
+        let options = &*options.await?;
+        let origin_dir = origin.origin_path().parent();
+
+        let negative_patterns = options
+            .negative_patterns
+            .iter()
+            .map(|pattern| Glob::parse(pattern))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut keys = Vec::new();
+        for pattern in &options.patterns {
+            let (base, rest) = split_glob_base(pattern);
+            let result = origin_dir
+                .join(base)
+                .read_glob(GlobVc::new(rest), false)
+                .await?;
+
+            let mut queue = VecDeque::from([result]);
+            while let Some(result) = queue.pop_front() {
+                for (path, entry) in &result.results {
+                    if let DirectoryEntry::File(_) = entry {
+                        keys.push(format!("{base}{path}"));
+                    }
+                }
+                for inner in result.inner.values() {
+                    queue.push_back(inner.await?);
+                }
+            }
+        }
+
+        keys.retain(|key| !negative_patterns.iter().any(|glob| glob.execute(key)));
+        keys.sort();
+        keys.dedup();
+
+        let query = options
+            .query
+            .as_deref()
+            .map(|query| query.trim_start_matches('?'))
+            .filter(|query| !query.is_empty());
+        let mut map = IndexMap::new();
+        for key in keys {
+            let request = match query {
+                Some(query) => format!("{key}?{query}"),
+                None => key.clone(),
+            };
+            let request = RequestVc::parse(Value::new(request.into()));
+            let result = esm_resolve(
+                origin,
+                request,
+                Default::default(),
+                issue_source,
+                issue_severity,
+            );
+            map.insert(key, ImportMetaGlobMapEntry { request, result });
+        }
+
+        Ok(Self::cell(map))
+    }
+}
+
+/// Splits a glob pattern into its static directory prefix (including the
+/// trailing slash) and the remaining pattern, e.g. `./pages/**/*.js` into
+/// `./pages/` and `**/*.js`.
+fn split_glob_base(pattern: &str) -> (&str, &str) {
+    let mut base_len = 0;
+    for segment in pattern.split_inclusive('/') {
+        if !segment.ends_with('/') || segment.contains(['*', '?', '[', '{']) {
+            break;
+        }
+        base_len += segment.len();
+    }
+    pattern.split_at(base_len)
+}
+
+/// A reference for `import.meta.glob(..)`. The call is replaced with an object
+/// literal mapping each matched module to either its namespace (when `eager`
+/// is set) or a function that imports it on demand.
+#[turbo_tasks::value]
+#[derive(Hash, Debug)]
+pub struct ImportMetaGlobAssetReference {
+    pub origin: ResolveOriginVc,
+    pub options: ImportMetaGlobOptionsVc,
+    pub map: ImportMetaGlobMapVc,
+    pub path: AstPathVc,
+    pub issue_source: IssueSourceVc,
+    pub in_try: bool,
+}
+
+#[turbo_tasks::value_impl]
+impl ImportMetaGlobAssetReferenceVc {
+    #[turbo_tasks::function]
+    pub fn new(
+        origin: ResolveOriginVc,
+        options: ImportMetaGlobOptionsVc,
+        path: AstPathVc,
+        issue_source: IssueSourceVc,
+        in_try: bool,
+    ) -> Self {
+        let map = ImportMetaGlobMapVc::generate(
+            origin,
+            options,
+            OptionIssueSourceVc::some(issue_source),
+            try_to_severity(in_try),
+        );
+        Self::cell(ImportMetaGlobAssetReference {
+            origin,
+            options,
+            map,
+            path,
+            issue_source,
+            in_try,
+        })
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl AssetReference for ImportMetaGlobAssetReference {
+    #[turbo_tasks::function]
+    async fn resolve_reference(&self) -> Result<ResolveResultVc> {
+        let map = &*self.map.await?;
+        Ok(ResolveResultVc::alternatives(
+            map.values().map(|entry| entry.result).collect(),
+        ))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ValueToString for ImportMetaGlobAssetReference {
+    #[turbo_tasks::function]
+    async fn to_string(&self) -> Result<StringVc> {
+        let options = &*self.options.await?;
+        let patterns = options
+            .patterns
+            .iter()
+            .cloned()
+            .chain(options.negative_patterns.iter().map(|p| format!("!{p}")))
+            .collect::<Vec<_>>();
+        Ok(StringVc::cell(format!(
+            "import.meta.glob {}",
+            patterns.join(", ")
+        )))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ChunkableAssetReference for ImportMetaGlobAssetReference {
+    #[turbo_tasks::function]
+    async fn chunking_type(&self) -> Result<ChunkingTypeOptionVc> {
+        Ok(ChunkingTypeOptionVc::cell(Some(
+            if self.options.await?.eager {
+                ChunkingType::PlacedOrParallel
+            } else {
+                ChunkingType::SeparateAsync
+            },
+        )))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl CodeGenerateableWithAvailabilityInfo for ImportMetaGlobAssetReference {
+    #[turbo_tasks::function]
+    async fn code_generation(
+        &self,
+        context: EcmascriptChunkingContextVc,
+        availability_info: Value<AvailabilityInfo>,
+    ) -> Result<CodeGenerationVc> {
+        let options = &*self.options.await?;
+        let map = &*self.map.await?;
+
+        let mut object = ObjectLit {
+            span: DUMMY_SP,
+            props: vec![],
+        };
+
+        for (key, entry) in map {
+            let pm = PatternMappingVc::resolve_request(
+                entry.request,
+                self.origin,
+                context.into(),
+                entry.result,
+                Value::new(if options.eager {
+                    Cjs
+                } else {
+                    EsmAsync(availability_info.into_value())
+                }),
+            )
+            .await?;
+
+            let request = || Expr::Lit(Lit::Str(key.as_str().into()));
+            let mut value = match (&*pm, options.eager) {
+                (PatternMapping::SingleLoader(_), false) => quote!(
+                    "__turbopack_require__($id)(__turbopack_import__)" as Expr,
+                    id: Expr = pm.create(),
+                ),
+                (_, eager) => {
+                    let import = if pm.is_internal_import() {
+                        quote!(
+                            "__turbopack_import__($id)" as Expr,
+                            id: Expr = pm.apply(request()),
+                        )
+                    } else {
+                        quote!(
+                            "__turbopack_external_require__($id, true)" as Expr,
+                            id: Expr = pm.apply(request()),
+                        )
+                    };
+                    if eager {
+                        import
+                    } else {
+                        quote!(
+                            "Promise.resolve().then(() => $import)" as Expr,
+                            import: Expr = import,
+                        )
+                    }
+                }
+            };
+
+            if let Some(name) = &options.import {
+                let name = Expr::Lit(Lit::Str(name.as_str().into()));
+                value = if options.eager {
+                    quote!(
+                        "$value[$name]" as Expr,
+                        value: Expr = value,
+                        name: Expr = name
+                    )
+                } else {
+                    quote!(
+                        "$value.then((m) => m[$name])" as Expr,
+                        value: Expr = value,
+                        name: Expr = name,
+                    )
+                };
+            }
+
+            if !options.eager {
+                value = quote!("() => $value" as Expr, value: Expr = value);
+            }
+
+            object
+                .props
+                .push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                    key: PropName::Str(key.as_str().into()),
+                    value: Box::new(value),
+                }))));
+        }
+
+        let path = &self.path.await?;
+        let visitor = create_visitor!(path, visit_mut_expr(expr: &mut Expr) {
+            if let Expr::Call(_) = expr {
+                *expr = Expr::Object(object.clone());
+            }
+        });
+
+        Ok(CodeGeneration {
+            visitors: vec![visitor],
+        }
+        .into())
+    }
+}
+
 /// URL encodes special chars that would appear in the "pathname" portion.
 /// https://github.com/nodejs/node/blob/3bed5f11e039153eff5cbfd9513b8f55fd53fc43/lib/internal/url.js#L1513-L1526
 fn encode_path(path: &'_ str) -> Cow<'_, str> {
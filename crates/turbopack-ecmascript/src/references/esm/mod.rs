@@ -6,13 +6,18 @@ pub(crate) mod meta;
 pub(crate) mod module_id;
 pub(crate) mod module_item;
 pub(crate) mod url;
+pub(crate) mod worker;
 
 pub use self::{
     base::{EsmAssetReference, EsmAssetReferenceVc},
     binding::{EsmBinding, EsmBindingVc},
     dynamic::{EsmAsyncAssetReference, EsmAsyncAssetReferenceVc},
-    export::{EsmExports, EsmExportsVc},
-    meta::{ImportMetaBinding, ImportMetaBindingVc, ImportMetaRef, ImportMetaRefVc},
+    export::{EsmExport, EsmExports, EsmExportsVc},
+    meta::{
+        ImportMetaBinding, ImportMetaBindingVc, ImportMetaGlobAssetReference,
+        ImportMetaGlobAssetReferenceVc, ImportMetaRef, ImportMetaRefVc,
+    },
     module_item::{EsmModuleItem, EsmModuleItemVc},
     url::{UrlAssetReference, UrlAssetReferenceVc},
+    worker::{WorkerAssetReference, WorkerAssetReferenceVc, WorkerType},
 };
@@ -0,0 +1,189 @@
+use anyhow::Result;
+use swc_core::{
+    ecma::ast::{Expr, ExprOrSpread, NewExpr},
+    quote,
+};
+use turbo_tasks::{primitives::StringVc, Value, ValueToString, ValueToStringVc};
+use turbopack_core::{
+    chunk::{
+        ChunkableAssetReference, ChunkableAssetReferenceVc, ChunkingType, ChunkingTypeOptionVc,
+        EvaluatableAssetVc,
+    },
+    environment::RenderingVc,
+    issue::IssueSourceVc,
+    reference::{AssetReference, AssetReferenceVc},
+    resolve::{origin::ResolveOriginVc, parse::RequestVc, ResolveResultVc},
+};
+
+use super::{
+    base::{ReferencedAsset, ReferencedAssetVc},
+    url::url_base_rewrite,
+};
+use crate::{
+    chunk::{EcmascriptChunkPlaceable, EcmascriptChunkingContextVc},
+    code_gen::{CodeGenerateable, CodeGenerateableVc, CodeGeneration, CodeGenerationVc},
+    create_visitor,
+    references::AstPathVc,
+    resolve::{try_to_severity, worker_resolve},
+    utils::module_id_to_lit,
+    worker_loader_asset::WorkerLoaderAssetVc,
+};
+
+/// The kind of worker created by a [WorkerAssetReference].
+#[turbo_tasks::value(serialization = "auto_for_input")]
+#[derive(Debug, Copy, Clone, PartialOrd, Ord, Hash)]
+pub enum WorkerType {
+    /// `new Worker(...)`
+    Worker,
+    /// `new SharedWorker(...)`
+    SharedWorker,
+}
+
+/// Worker Asset References are injected during code analysis when we find a
+/// (staticly analyzable) `new Worker(new URL("path", import.meta.url))` or
+/// `new SharedWorker(new URL("path", import.meta.url))`.
+///
+/// The referenced module becomes the entry of a separate evaluated chunk group
+/// with its own runtime, and the `URL` constructor's arguments are rewritten to
+/// point to the entry chunk of that chunk group. This works for both classic
+/// and module workers.
+#[turbo_tasks::value]
+pub struct WorkerAssetReference {
+    origin: ResolveOriginVc,
+    request: RequestVc,
+    worker_type: WorkerType,
+    rendering: RenderingVc,
+    /// The ast path to the `new URL(...)` expression.
+    ast_path: AstPathVc,
+    issue_source: IssueSourceVc,
+    in_try: bool,
+}
+
+#[turbo_tasks::value_impl]
+impl WorkerAssetReferenceVc {
+    #[turbo_tasks::function]
+    pub fn new(
+        origin: ResolveOriginVc,
+        request: RequestVc,
+        worker_type: Value<WorkerType>,
+        rendering: RenderingVc,
+        ast_path: AstPathVc,
+        issue_source: IssueSourceVc,
+        in_try: bool,
+    ) -> Self {
+        WorkerAssetReference {
+            origin,
+            request,
+            worker_type: worker_type.into_value(),
+            rendering,
+            ast_path,
+            issue_source,
+            in_try,
+        }
+        .cell()
+    }
+
+    #[turbo_tasks::function]
+    async fn get_referenced_asset(self) -> Result<ReferencedAssetVc> {
+        let this = self.await?;
+        Ok(ReferencedAssetVc::from_resolve_result(
+            self.resolve_reference(),
+            this.request,
+        ))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl AssetReference for WorkerAssetReference {
+    #[turbo_tasks::function]
+    async fn resolve_reference(&self) -> Result<ResolveResultVc> {
+        let result = worker_resolve(
+            self.origin,
+            self.request,
+            self.issue_source,
+            try_to_severity(self.in_try),
+        );
+        Ok(result
+            .await?
+            .map(
+                |asset| async move {
+                    Ok(
+                        if let Some(entry) = EvaluatableAssetVc::resolve_from(asset).await? {
+                            WorkerLoaderAssetVc::new(entry).into()
+                        } else {
+                            asset
+                        },
+                    )
+                },
+                |reference| async move { Ok(reference) },
+            )
+            .await?
+            .cell())
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ValueToString for WorkerAssetReference {
+    #[turbo_tasks::function]
+    async fn to_string(&self) -> Result<StringVc> {
+        let constructor = match self.worker_type {
+            WorkerType::Worker => "Worker",
+            WorkerType::SharedWorker => "SharedWorker",
+        };
+        Ok(StringVc::cell(format!(
+            "new {constructor}(new URL({}))",
+            self.request.to_string().await?,
+        )))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ChunkableAssetReference for WorkerAssetReference {
+    #[turbo_tasks::function]
+    fn chunking_type(&self) -> ChunkingTypeOptionVc {
+        ChunkingTypeOptionVc::cell(Some(ChunkingType::PlacedOrParallel))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl CodeGenerateable for WorkerAssetReference {
+    #[turbo_tasks::function]
+    async fn code_generation(
+        self_vc: WorkerAssetReferenceVc,
+        context: EcmascriptChunkingContextVc,
+    ) -> Result<CodeGenerationVc> {
+        let this = self_vc.await?;
+        let mut visitors = vec![];
+
+        let referenced_asset = self_vc.get_referenced_asset().await?;
+        let rewrite = url_base_rewrite(this.rendering, this.origin).await?;
+        let ast_path = this.ast_path.await?;
+
+        if let ReferencedAsset::Some(asset) = &*referenced_asset {
+            // We rewrite the `new URL()` arguments to be a require() of the worker
+            // loader, which exports the path to the entry chunk of the worker.
+            let id = asset.as_chunk_item(context).id().await?;
+
+            visitors.push(
+                create_visitor!(ast_path, visit_mut_expr(new_expr: &mut Expr) {
+                    if let Expr::New(NewExpr { args: Some(args), .. }) = new_expr {
+                        if let Some(ExprOrSpread { box expr, spread: None }) = args.get_mut(0) {
+                            *expr = quote!(
+                                "__turbopack_require__($id)" as Expr,
+                                id: Expr = module_id_to_lit(&id),
+                            );
+                        }
+
+                        if let Some(rewrite) = &rewrite {
+                            if let Some(ExprOrSpread { box expr, spread: None }) = args.get_mut(1) {
+                                *expr = rewrite.clone();
+                            }
+                        }
+                    }
+                }),
+            );
+        }
+
+        Ok(CodeGeneration { visitors }.into())
+    }
+}
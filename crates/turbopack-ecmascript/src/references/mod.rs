@@ -1,4 +1,5 @@
 pub mod amd;
+pub mod async_module;
 pub mod cjs;
 pub mod constant_condition;
 pub mod constant_value;
@@ -39,7 +40,10 @@ use swc_core::{
     ecma::{
         ast::*,
         visit::{
-            fields::{AssignExprField, ExprField, PatField, PatOrExprField},
+            fields::{
+                AssignExprField, ExprField, ExprOrSpreadField, NewExprField, PatField,
+                PatOrExprField,
+            },
             AstParentKind, AstParentNodeRef, VisitAstPath, VisitWithPath,
         },
     },
@@ -76,7 +80,8 @@ use self::{
     cjs::CjsAssetReferenceVc,
     esm::{
         export::EsmExport, EsmAssetReferenceVc, EsmAsyncAssetReferenceVc, EsmExports,
-        EsmModuleItemVc, ImportMetaBindingVc, ImportMetaRefVc, UrlAssetReferenceVc,
+        EsmModuleItemVc, ImportMetaBindingVc, ImportMetaGlobAssetReferenceVc, ImportMetaRefVc,
+        UrlAssetReferenceVc, WorkerAssetReferenceVc, WorkerType,
     },
     node::{DirAssetReferenceVc, PackageJsonReferenceVc},
     raw::SourceAssetReferenceVc,
@@ -108,7 +113,7 @@ use crate::{
         builtin::early_replace_builtin,
         graph::{ConditionalKind, EffectArg, EvalContext, VarGraph},
         imports::{ImportedSymbol, Reexport},
-        parse_require_context,
+        parse_import_meta_glob, parse_require_context,
         top_level_await::has_top_level_await,
         ModuleValue, RequireContextValueVc,
     },
@@ -855,22 +860,22 @@ pub(crate) async fn analyze_ecmascript_module(
             Effect::FreeVar {
                 var,
                 ast_path,
-                span: _,
+                span,
                 in_try: _,
             } => {
-                handle_free_var(&ast_path, var, &analysis_state, &mut analysis).await?;
+                handle_free_var(&ast_path, span, var, &analysis_state, &mut analysis).await?;
             }
             Effect::Member {
                 obj,
                 prop,
                 ast_path,
-                span: _,
+                span,
                 in_try,
             } => {
                 let obj = analysis_state.link_value(obj, in_try).await?;
                 let prop = analysis_state.link_value(prop, in_try).await?;
 
-                handle_member(&ast_path, obj, prop, &analysis_state, &mut analysis).await?;
+                handle_member(&ast_path, span, obj, prop, &analysis_state, &mut analysis).await?;
             }
             Effect::ImportedBinding {
                 esm_reference_index,
@@ -901,7 +906,10 @@ pub(crate) async fn analyze_ecmascript_module(
             } => {
                 if analysis_state.first_import_meta {
                     analysis_state.first_import_meta = false;
-                    analysis.add_code_gen(ImportMetaBindingVc::new(source.ident().path()));
+                    analysis.add_code_gen(ImportMetaBindingVc::new(
+                        source.ident().path(),
+                        compile_time_info.await?.defines,
+                    ));
                 }
 
                 analysis.add_code_gen(ImportMetaRefVc::new(AstPathVc::cell(ast_path)));
@@ -931,6 +939,64 @@ pub(crate) async fn analyze_ecmascript_module(
                     in_try,
                 ));
             }
+            Effect::Worker {
+                func,
+                input,
+                mut ast_path,
+                span,
+                in_try,
+            } => {
+                let func = analysis_state.link_value(func, in_try).await?;
+                let pat = js_value_to_pattern(&input);
+                if !pat.has_constant_parts() {
+                    handler.span_warn_with_code(
+                        span,
+                        &format!("new Worker(new URL({input}, import.meta.url)) is very dynamic"),
+                        DiagnosticId::Lint(
+                            errors::failed_to_analyse::ecmascript::NEW_WORKER.to_string(),
+                        ),
+                    )
+                }
+                let issue_source =
+                    IssueSourceVc::from_byte_offset(source, span.lo.to_usize(), span.hi.to_usize());
+                // The references rewrite the inner `new URL(...)` expression.
+                ast_path.extend([
+                    AstParentKind::NewExpr(NewExprField::Args(0)),
+                    AstParentKind::ExprOrSpread(ExprOrSpreadField::Expr),
+                    AstParentKind::Expr(ExprField::New),
+                ]);
+                let worker_type = match func {
+                    JsValue::WellKnownFunction(WellKnownFunctionKind::WorkerConstructor) => {
+                        Some(WorkerType::Worker)
+                    }
+                    JsValue::WellKnownFunction(WellKnownFunctionKind::SharedWorkerConstructor) => {
+                        Some(WorkerType::SharedWorker)
+                    }
+                    _ => None,
+                };
+                if let Some(worker_type) = worker_type {
+                    analysis.add_reference(WorkerAssetReferenceVc::new(
+                        origin,
+                        RequestVc::parse(Value::new(pat)),
+                        Value::new(worker_type),
+                        compile_time_info.environment().rendering(),
+                        AstPathVc::cell(ast_path),
+                        issue_source,
+                        in_try,
+                    ));
+                } else {
+                    // Not a worker constructor after all, so treat the argument like any other
+                    // `new URL(..., import.meta.url)`.
+                    analysis.add_reference(UrlAssetReferenceVc::new(
+                        origin,
+                        RequestVc::parse(Value::new(pat)),
+                        compile_time_info.environment().rendering(),
+                        AstPathVc::cell(ast_path),
+                        issue_source,
+                        in_try,
+                    ));
+                }
+            }
         }
     }
 
@@ -1160,6 +1226,35 @@ async fn handle_call<G: Fn(Vec<Effect>) + Send + Sync>(
             ));
         }
 
+        JsValue::WellKnownFunction(WellKnownFunctionKind::ImportMetaGlob) => {
+            let args = linked_args(args).await?;
+            let options = match parse_import_meta_glob(&args) {
+                Ok(options) => options,
+                Err(err) => {
+                    let (args, hints) = explain_args(&args);
+                    handler.span_err_with_code(
+                        span,
+                        &format!(
+                            "import.meta.glob({args}) is not statically analyze-able: {}{hints}",
+                            PrettyPrintError(&err)
+                        ),
+                        DiagnosticId::Error(
+                            errors::failed_to_analyse::ecmascript::IMPORT_META_GLOB.to_string(),
+                        ),
+                    );
+                    return Ok(());
+                }
+            };
+
+            analysis.add_reference(ImportMetaGlobAssetReferenceVc::new(
+                origin,
+                options.cell(),
+                AstPathVc::cell(ast_path.to_vec()),
+                issue_source(source, span),
+                in_try,
+            ));
+        }
+
         JsValue::WellKnownFunction(WellKnownFunctionKind::FsReadMethod(name)) => {
             let args = linked_args(args).await?;
             if !args.is_empty() {
@@ -1594,6 +1689,7 @@ async fn handle_call<G: Fn(Vec<Effect>) + Send + Sync>(
 
 async fn handle_member(
     ast_path: &[AstParentKind],
+    span: Span,
     obj: JsValue,
     prop: JsValue,
     state: &AnalysisState<'_>,
@@ -1612,7 +1708,7 @@ async fn handle_member(
                     continue;
                 }
                 if obj.iter_defineable_name_rev().eq(it)
-                    && handle_free_var_reference(ast_path, value, state, analysis).await?
+                    && handle_free_var_reference(ast_path, span, value, state, analysis).await?
                 {
                     return Ok(());
                 }
@@ -1638,6 +1734,7 @@ async fn handle_member(
 
 async fn handle_free_var(
     ast_path: &[AstParentKind],
+    span: Span,
     var: JsValue,
     state: &AnalysisState<'_>,
     analysis: &mut AnalyzeEcmascriptModuleResultBuilder,
@@ -1652,7 +1749,7 @@ async fn handle_free_var(
             if var
                 .iter_defineable_name_rev()
                 .eq(name.iter().map(Cow::Borrowed).rev())
-                && handle_free_var_reference(ast_path, value, state, analysis).await?
+                && handle_free_var_reference(ast_path, span, value, state, analysis).await?
             {
                 return Ok(());
             }
@@ -1664,6 +1761,7 @@ async fn handle_free_var(
 
 async fn handle_free_var_reference(
     ast_path: &[AstParentKind],
+    span: Span,
     value: &FreeVarReference,
     state: &AnalysisState<'_>,
     analysis: &mut AnalyzeEcmascriptModuleResultBuilder,
@@ -1688,6 +1786,15 @@ async fn handle_free_var_reference(
                 AstPathVc::cell(ast_path.to_vec()),
             ));
         }
+        FreeVarReference::Error(message) => {
+            state.handler.span_err_with_code(
+                span,
+                message,
+                DiagnosticId::Error(
+                    errors::failed_to_analyse::ecmascript::FREE_VAR_REFERENCE.to_string(),
+                ),
+            );
+        }
         FreeVarReference::EcmaScriptModule {
             request,
             context,
@@ -1987,6 +2094,10 @@ async fn value_visitor_inner(
             "process" => JsValue::WellKnownObject(WellKnownObjectKind::NodeProcess),
             "Object" => JsValue::WellKnownObject(WellKnownObjectKind::GlobalObject),
             "Buffer" => JsValue::WellKnownObject(WellKnownObjectKind::NodeBuffer),
+            "Worker" => JsValue::WellKnownFunction(WellKnownFunctionKind::WorkerConstructor),
+            "SharedWorker" => {
+                JsValue::WellKnownFunction(WellKnownFunctionKind::SharedWorkerConstructor)
+            }
             _ => return Ok((v, false)),
         },
         JsValue::Module(ModuleValue {
@@ -6,8 +6,8 @@ use turbopack_core::{
     context::AssetContext,
     issue::{IssueSeverity, IssueSeverityVc, IssueSourceVc, OptionIssueSourceVc},
     reference_type::{
-        CommonJsReferenceSubType, EcmaScriptModulesReferenceSubType, ReferenceType,
-        UrlReferenceSubType,
+        CommonJsReferenceSubType, EcmaScriptModulesReferenceSubType, EntryReferenceSubType,
+        ReferenceType, UrlReferenceSubType,
     },
     resolve::{
         handle_resolve_error,
@@ -118,6 +118,29 @@ pub async fn url_resolve(
     Ok(origin.context().process_resolve_result(result, ty))
 }
 
+/// Resolves the entry module of a `new Worker(new URL(...))`. The request is
+/// resolved relative to the origin like a URL, but the result is processed as
+/// an entry module.
+#[turbo_tasks::function]
+pub async fn worker_resolve(
+    origin: ResolveOriginVc,
+    request: RequestVc,
+    issue_source: IssueSourceVc,
+    issue_severity: IssueSeverityVc,
+) -> Result<ResolveResultVc> {
+    let ty = Value::new(ReferenceType::Entry(EntryReferenceSubType::Worker));
+    let options = apply_esm_specific_options(origin.resolve_options(ty.clone()));
+    specific_resolve(
+        origin,
+        request.as_relative(),
+        options,
+        ty,
+        OptionIssueSourceVc::some(issue_source),
+        issue_severity,
+    )
+    .await
+}
+
 async fn specific_resolve(
     origin: ResolveOriginVc,
     request: RequestVc,
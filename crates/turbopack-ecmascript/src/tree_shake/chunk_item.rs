@@ -13,6 +13,7 @@ use crate::{
         EcmascriptChunkItem, EcmascriptChunkItemContentVc, EcmascriptChunkItemVc,
         EcmascriptChunkingContextVc,
     },
+    references::async_module::OptionAsyncModuleVc,
     EcmascriptModuleContentVc,
 };
 
@@ -54,6 +55,7 @@ impl EcmascriptChunkItem for EcmascriptModulePartChunkItem {
             this.context,
             this.module.analyze(),
             availability_info,
+            OptionAsyncModuleVc::cell(None),
         );
 
         Ok(EcmascriptChunkItemContentVc::new(content, this.context))
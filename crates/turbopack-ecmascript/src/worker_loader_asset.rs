@@ -0,0 +1,206 @@
+use anyhow::{bail, Result};
+use turbo_tasks::{primitives::StringVc, Value};
+use turbopack_core::{
+    asset::{Asset, AssetContentVc, AssetVc, AssetsVc},
+    chunk::{
+        availability_info::AvailabilityInfo, ChunkItem, ChunkItemVc, ChunkVc, ChunkableAsset,
+        ChunkableAssetVc, ChunkingContext, ChunkingContextVc, EvaluatableAssetVc,
+        EvaluatableAssetsVc,
+    },
+    ident::AssetIdentVc,
+    reference::{AssetReferencesVc, SingleAssetReferenceVc},
+};
+
+use crate::{
+    chunk::{
+        EcmascriptChunkItem, EcmascriptChunkItemContent, EcmascriptChunkItemContentVc,
+        EcmascriptChunkItemVc, EcmascriptChunkPlaceable, EcmascriptChunkPlaceableVc,
+        EcmascriptChunkVc, EcmascriptChunkingContextVc, EcmascriptExports, EcmascriptExportsVc,
+    },
+    utils::StringifyJs,
+};
+
+#[turbo_tasks::function]
+fn modifier() -> StringVc {
+    StringVc::cell("worker loader".to_string())
+}
+
+/// An asset that exports the URL of the entry chunk of a worker.
+///
+/// The worker [entry] is placed into its own evaluated chunk group, which
+/// contains a separate runtime, as workers don't share the module registry of
+/// the page that created them.
+#[turbo_tasks::value]
+pub struct WorkerLoaderAsset {
+    pub entry: EvaluatableAssetVc,
+}
+
+#[turbo_tasks::value_impl]
+impl WorkerLoaderAssetVc {
+    #[turbo_tasks::function]
+    pub fn new(entry: EvaluatableAssetVc) -> Self {
+        Self::cell(WorkerLoaderAsset { entry })
+    }
+
+    #[turbo_tasks::function]
+    async fn chunks(self, chunking_context: ChunkingContextVc) -> Result<AssetsVc> {
+        let this = self.await?;
+        Ok(chunking_context.evaluated_chunk_group(
+            this.entry.as_root_chunk(chunking_context),
+            EvaluatableAssetsVc::one(this.entry),
+        ))
+    }
+
+    /// The chunk of the worker chunk group that evaluates the entry, which is
+    /// the script the worker is started with.
+    #[turbo_tasks::function]
+    async fn entry_chunk(self, chunking_context: ChunkingContextVc) -> Result<AssetVc> {
+        let this = self.await?;
+        Ok(chunking_context.evaluated_chunk_group_entry(
+            this.entry.as_root_chunk(chunking_context),
+            EvaluatableAssetsVc::one(this.entry),
+        ))
+    }
+}
+
+#[turbo_tasks::function]
+fn worker_chunk_reference_description() -> StringVc {
+    StringVc::cell("worker chunk".to_string())
+}
+
+#[turbo_tasks::value_impl]
+impl Asset for WorkerLoaderAsset {
+    #[turbo_tasks::function]
+    fn ident(&self) -> AssetIdentVc {
+        self.entry.ident().with_modifier(modifier())
+    }
+
+    #[turbo_tasks::function]
+    fn content(&self) -> Result<AssetContentVc> {
+        bail!("worker loader asset has no content")
+    }
+
+    #[turbo_tasks::function]
+    fn references(&self) -> AssetReferencesVc {
+        // The worker chunks are referenced by the chunk item, as they depend on
+        // the chunking context.
+        AssetReferencesVc::empty()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ChunkableAsset for WorkerLoaderAsset {
+    #[turbo_tasks::function]
+    fn as_chunk(
+        self_vc: WorkerLoaderAssetVc,
+        context: ChunkingContextVc,
+        availability_info: Value<AvailabilityInfo>,
+    ) -> ChunkVc {
+        EcmascriptChunkVc::new(
+            context,
+            self_vc.as_ecmascript_chunk_placeable(),
+            availability_info,
+        )
+        .into()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl EcmascriptChunkPlaceable for WorkerLoaderAsset {
+    #[turbo_tasks::function]
+    fn as_chunk_item(
+        self_vc: WorkerLoaderAssetVc,
+        context: EcmascriptChunkingContextVc,
+    ) -> EcmascriptChunkItemVc {
+        WorkerLoaderChunkItem {
+            context,
+            inner: self_vc,
+        }
+        .cell()
+        .into()
+    }
+
+    #[turbo_tasks::function]
+    fn get_exports(&self) -> EcmascriptExportsVc {
+        EcmascriptExports::Value.cell()
+    }
+}
+
+#[turbo_tasks::value]
+struct WorkerLoaderChunkItem {
+    context: EcmascriptChunkingContextVc,
+    inner: WorkerLoaderAssetVc,
+}
+
+#[turbo_tasks::value_impl]
+impl WorkerLoaderChunkItemVc {
+    #[turbo_tasks::function]
+    async fn chunks(self) -> Result<AssetsVc> {
+        let this = self.await?;
+        Ok(this.inner.chunks(this.context.into()))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl EcmascriptChunkItem for WorkerLoaderChunkItem {
+    #[turbo_tasks::function]
+    fn chunking_context(&self) -> EcmascriptChunkingContextVc {
+        self.context
+    }
+
+    #[turbo_tasks::function]
+    async fn content(self_vc: WorkerLoaderChunkItemVc) -> Result<EcmascriptChunkItemContentVc> {
+        let this = self_vc.await?;
+        let chunking_context: ChunkingContextVc = this.context.into();
+        // Chunk paths are relative to the output root, which is served at the
+        // root of the server, see how the runtime loads chunks.
+        let output_root = chunking_context.output_root().await?;
+        let entry_chunk_path = this
+            .inner
+            .entry_chunk(chunking_context)
+            .ident()
+            .path()
+            .await?;
+        let Some(entry_chunk_path) = output_root.get_path_to(&entry_chunk_path) else {
+            bail!(
+                "worker entry chunk {} is not in the output root {}",
+                entry_chunk_path.path,
+                output_root.path
+            );
+        };
+        Ok(EcmascriptChunkItemContent {
+            inner_code: format!(
+                "__turbopack_export_value__({});\n",
+                StringifyJs(&format_args!("/{entry_chunk_path}"))
+            )
+            .into(),
+            ..Default::default()
+        }
+        .cell())
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ChunkItem for WorkerLoaderChunkItem {
+    #[turbo_tasks::function]
+    fn asset_ident(&self) -> AssetIdentVc {
+        self.inner.ident()
+    }
+
+    #[turbo_tasks::function]
+    async fn references(self_vc: WorkerLoaderChunkItemVc) -> Result<AssetReferencesVc> {
+        let chunks = self_vc.chunks();
+
+        Ok(AssetReferencesVc::cell(
+            chunks
+                .await?
+                .iter()
+                .copied()
+                .map(|chunk| {
+                    SingleAssetReferenceVc::new(chunk, worker_chunk_reference_description())
+                })
+                .map(Into::into)
+                .collect(),
+        ))
+    }
+}
@@ -0,0 +1,3 @@
+const glob = import.meta.glob;
+const modules = import.meta.glob("./dir/*.js", { eager: true });
+const url = import.meta.url;
@@ -4,13 +4,21 @@ use turbo_tasks_env::{
     CommandLineProcessEnvVc, CustomProcessEnvVc, EnvMapVc, ProcessEnv, ProcessEnvVc,
 };
 use turbo_tasks_fs::FileSystemPathVc;
+use turborepo_env::dotenv_files;
 
-use crate::TryDotenvProcessEnvVc;
+use crate::TryDotenvFilesProcessEnvVc;
 
 /// Loads a series of dotenv files according to the precedence rules set by
 /// https://nextjs.org/docs/basic-features/environment-variables#environment-variable-load-order
+///
+/// The files are selected by the `mode`, e.g. `.env.staging` for `staging`,
+/// which defaults to `NODE_ENV`. turborepo hashes the same files with
+/// `--dotenv-mode`.
 #[turbo_tasks::function]
-pub async fn load_env(project_path: FileSystemPathVc) -> Result<ProcessEnvVc> {
+pub async fn load_env(
+    project_path: FileSystemPathVc,
+    mode: Option<String>,
+) -> Result<ProcessEnvVc> {
     let env = CommandLineProcessEnvVc::new().as_process_env();
 
     let node_env = env.read("NODE_ENV").await?;
@@ -24,23 +32,10 @@ pub async fn load_env(project_path: FileSystemPathVc) -> Result<ProcessEnvVc> {
     )
     .as_process_env();
 
-    let files = [
-        Some(format!(".env.{node_env}.local")),
-        if node_env == "test" {
-            None
-        } else {
-            Some(".env.local".into())
-        },
-        Some(format!(".env.{node_env}")),
-        Some(".env".into()),
-    ]
-    .into_iter()
-    .flatten();
+    let paths = dotenv_files(mode.as_deref().unwrap_or(node_env))
+        .iter()
+        .map(|file| project_path.join(file))
+        .collect();
 
-    let env = files.fold(env, |prior, f| {
-        let path = project_path.join(&f);
-        TryDotenvProcessEnvVc::new(prior, path).as_process_env()
-    });
-
-    Ok(env)
+    Ok(TryDotenvFilesProcessEnvVc::new(env, paths).into())
 }
@@ -4,10 +4,14 @@
 //! process's env (immutable and by passing `FOO=BAR` keys when executing the
 //! turbopack binary) or loaded via dotenv files.
 //!
-//! Dotenv file loading is a chain. Dotenv files that come first in the chain
+//! Dotenv files are loaded in order of precedence. Dotenv files that come first
 //! have higher priority to define a environment variable (later dotenv files
-//! cannot override it). Later dotenv files can reference variables prior
-//! defined variables.
+//! cannot override it). Variables can reference other variables with `${NAME}`
+//! or `${NAME:-default}`, which are expanded once all dotenv files are loaded.
+//!
+//! Variables with a public prefix can be inlined into client code as
+//! `process.env.NAME` and `import.meta.env.NAME`, while references to other
+//! variables are reported.
 
 #![feature(async_closure)]
 #![feature(min_specialization)]
@@ -16,12 +20,14 @@ mod asset;
 pub mod dotenv;
 mod embeddable;
 mod issue;
+mod public;
 mod try_env;
 
 pub use asset::{ProcessEnvAsset, ProcessEnvAssetVc};
 pub use embeddable::EmbeddableProcessEnvVc;
 pub use issue::{ProcessEnvIssue, ProcessEnvIssueVc};
-pub use try_env::TryDotenvProcessEnvVc;
+pub use public::{ClientEnv, ClientEnvVc};
+pub use try_env::TryDotenvFilesProcessEnvVc;
 
 pub fn register() {
     turbo_tasks::register();
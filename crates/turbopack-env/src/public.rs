@@ -0,0 +1,82 @@
+use anyhow::Result;
+use indexmap::IndexMap;
+use turbo_tasks_env::{CommandLineProcessEnvVc, FilterProcessEnvVc, ProcessEnv, ProcessEnvVc};
+use turbopack_core::compile_time_info::{
+    CompileTimeDefineValue, CompileTimeDefinesVc, FreeVarReference, FreeVarReferencesVc,
+};
+
+/// The names an environment variable can be referenced with in client code.
+fn env_var_names(name: &str) -> [Vec<String>; 2] {
+    [
+        vec!["process".to_string(), "env".to_string(), name.to_string()],
+        vec![
+            "import".to_string(),
+            "meta".to_string(),
+            "env".to_string(),
+            name.to_string(),
+        ],
+    ]
+}
+
+/// The compile time defines and free var references of client code, which
+/// inline public environment variables.
+#[turbo_tasks::value(shared)]
+pub struct ClientEnv {
+    pub defines: CompileTimeDefinesVc,
+    pub free_var_references: FreeVarReferencesVc,
+}
+
+#[turbo_tasks::value_impl]
+impl ClientEnvVc {
+    /// Inlines the `defines` and the variables of `env` starting with one of
+    /// the `prefixes` as `process.env.NAME` and `import.meta.env.NAME` into
+    /// client code. `import.meta` gets an `env` object holding the public
+    /// variables, so other `import.meta.env` properties read as `undefined`.
+    ///
+    /// References to the other variables defined by dotenv files are reported
+    /// as errors, as they're not available in client code. Variables that are
+    /// set in the process environment, like `PATH`, aren't reported, as
+    /// dependencies commonly check for them.
+    #[turbo_tasks::function]
+    pub async fn new(
+        defines: CompileTimeDefinesVc,
+        env: ProcessEnvVc,
+        prefixes: Vec<String>,
+    ) -> Result<Self> {
+        let mut defines = defines.await?.clone_value();
+        let mut free_var_references = IndexMap::new();
+        if !prefixes.is_empty() {
+            let public = FilterProcessEnvVc::new(env, prefixes.clone())
+                .read_all()
+                .await?;
+            let process_env = CommandLineProcessEnvVc::new().read_all().await?;
+            let prefixes = prefixes.join("`, `");
+            for (name, value) in env.read_all().await?.iter() {
+                if public.contains_key(name) {
+                    for key in env_var_names(name) {
+                        defines.insert(key, CompileTimeDefineValue::String(value.clone()));
+                    }
+                } else if !process_env.contains_key(name) {
+                    for key in env_var_names(name) {
+                        let message = format!(
+                            "`{}` is not a public environment variable and is not available in \
+                             client code. Only variables starting with `{prefixes}` are inlined.",
+                            key.join(".")
+                        );
+                        free_var_references.insert(key, FreeVarReference::Error(message));
+                    }
+                }
+            }
+        }
+        // Variables like `process.env.NODE_ENV` are defined by us.
+        for (name, value) in defines.iter() {
+            free_var_references.insert(name.clone(), value.clone().into());
+        }
+
+        Ok(ClientEnv {
+            defines: CompileTimeDefinesVc::cell(defines),
+            free_var_references: FreeVarReferencesVc::cell(free_var_references),
+        }
+        .cell())
+    }
+}
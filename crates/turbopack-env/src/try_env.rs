@@ -1,61 +1,72 @@
 use anyhow::Result;
 use turbo_tasks::primitives::StringVc;
-use turbo_tasks_env::{DotenvProcessEnvVc, EnvMapVc, ProcessEnv, ProcessEnvVc};
+use turbo_tasks_env::{
+    expand_dotenv, DotenvProcessEnvVc, EnvMapVc, ExpandedDotenv, ProcessEnv, ProcessEnvVc,
+};
 use turbo_tasks_fs::FileSystemPathVc;
 
 use crate::ProcessEnvIssue;
 
+/// Loads dotenv files, ordered from the highest to the lowest precedence, on
+/// top of a prior env. References between variables are expanded once all
+/// files are loaded, so they can reference variables from any of the files.
+/// Files that fail to parse are skipped and reference cycles are reported.
 #[turbo_tasks::value]
-pub struct TryDotenvProcessEnv {
-    dotenv: DotenvProcessEnvVc,
+pub struct TryDotenvFilesProcessEnv {
     prior: ProcessEnvVc,
-    path: FileSystemPathVc,
+    paths: Vec<FileSystemPathVc>,
 }
 
 #[turbo_tasks::value_impl]
-impl TryDotenvProcessEnvVc {
+impl TryDotenvFilesProcessEnvVc {
     #[turbo_tasks::function]
-    pub fn new(prior: ProcessEnvVc, path: FileSystemPathVc) -> Self {
-        let dotenv = DotenvProcessEnvVc::new(Some(prior), path);
-        TryDotenvProcessEnv {
-            dotenv,
-            prior,
-            path,
-        }
-        .cell()
+    pub fn new(prior: ProcessEnvVc, paths: Vec<FileSystemPathVc>) -> Self {
+        TryDotenvFilesProcessEnv { prior, paths }.cell()
     }
 }
 
 #[turbo_tasks::value_impl]
-impl ProcessEnv for TryDotenvProcessEnv {
+impl ProcessEnv for TryDotenvFilesProcessEnv {
     #[turbo_tasks::function]
     async fn read_all(&self) -> Result<EnvMapVc> {
-        let dotenv = self.dotenv;
-        let prior = dotenv.read_prior();
-
-        // Ensure prior succeeds. If it doesn't, then we don't want to attempt to read
-        // the dotenv file (and potentially emit an Issue), just trust that the prior
-        // will have emitted its own.
-        prior.await?;
+        let prior = self.prior.read_all().await?;
 
-        let vars = dotenv.read_all_with_prior(prior);
-        match vars.await {
-            Ok(_) => Ok(vars),
-            Err(e) => {
-                // If parsing the dotenv file fails (but getting the prior value didn't), then
-                // we want to emit an Issue and fall back to the prior's read.
-                ProcessEnvIssue {
-                    path: self.path,
-                    // read_all_with_prior will wrap a current error with a context containing the
-                    // failing file, which we don't really care about (we report the filepath as the
-                    // Issue context, not the description). So extract the real error.
+        let mut files = Vec::new();
+        for &path in &self.paths {
+            match DotenvProcessEnvVc::new(None, path).read_entries().await {
+                Ok(entries) => files.push((path, entries)),
+                Err(e) => ProcessEnvIssue {
+                    path,
                     description: StringVc::cell(e.root_cause().to_string()),
                 }
                 .cell()
                 .as_issue()
-                .emit();
-                Ok(prior)
+                .emit(),
+            }
+        }
+
+        let ExpandedDotenv { vars, cycles } =
+            expand_dotenv(&prior, files.iter().map(|(_, entries)| &**entries));
+        for cycle in cycles {
+            // The cycle is reported on the file the variable that starts it is
+            // loaded from.
+            let Some((path, _)) = files
+                .iter()
+                .find(|(_, entries)| entries.contains_key(&cycle[0]))
+            else {
+                continue;
+            };
+            ProcessEnvIssue {
+                path: *path,
+                description: StringVc::cell(format!(
+                    "cyclic reference between variables: {}",
+                    cycle.join(" -> ")
+                )),
             }
+            .cell()
+            .as_issue()
+            .emit();
         }
+        Ok(EnvMapVc::cell(vars))
     }
 }
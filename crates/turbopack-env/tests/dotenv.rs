@@ -0,0 +1,143 @@
+#![feature(min_specialization)]
+
+use std::{fs, path::Path};
+
+use anyhow::Result;
+use turbo_tasks::{TryJoinIterExt, TurboTasks};
+use turbo_tasks_env::{CommandLineProcessEnvVc, EnvMapVc, ProcessEnv};
+use turbo_tasks_fs::{DiskFileSystemVc, FileSystem, FileSystemPathVc};
+use turbo_tasks_memory::MemoryBackend;
+use turbopack_core::issue::{Issue, IssueVc};
+use turbopack_env::{dotenv::load_env, TryDotenvFilesProcessEnvVc};
+
+fn register() {
+    turbopack_env::register();
+    include!(concat!(env!("OUT_DIR"), "/register_test_dotenv.rs"));
+}
+
+fn write_files(root: &Path, files: &[(&str, &str)]) {
+    for (name, content) in files {
+        fs::write(root.join(name), content).unwrap();
+    }
+}
+
+fn project_path(root: &Path) -> FileSystemPathVc {
+    DiskFileSystemVc::new("project".to_string(), root.to_str().unwrap().to_string()).root()
+}
+
+/// The file name and description of every issue emitted while reading `env`.
+async fn issues(env: EnvMapVc) -> Result<Vec<(String, String)>> {
+    let captured = IssueVc::peek_issues_with_path(env)
+        .await?
+        .strongly_consistent()
+        .await?;
+    captured
+        .iter_with_shortest_path()
+        .map(|(issue, _)| async move {
+            Ok((
+                issue.context().await?.path.clone(),
+                issue.description().await?.clone_value(),
+            ))
+        })
+        .try_join()
+        .await
+}
+
+fn files_env(root: &Path, files: &[&str]) -> EnvMapVc {
+    let project_path = project_path(root);
+    let paths = files.iter().map(|file| project_path.join(file)).collect();
+    TryDotenvFilesProcessEnvVc::new(CommandLineProcessEnvVc::new().into(), paths).read_all()
+}
+
+#[tokio::test]
+async fn reports_reference_cycles() {
+    register();
+    let dir = tempfile::tempdir().unwrap();
+    write_files(
+        dir.path(),
+        &[(
+            ".env",
+            "CYCLE_A=${CYCLE_B}\nCYCLE_B=${CYCLE_A}\nACYCLIC=value\n",
+        )],
+    );
+    let root = dir.path().to_path_buf();
+
+    let tt = TurboTasks::new(MemoryBackend::default());
+    tt.run_once(async move {
+        let env = files_env(&root, &[".env"]);
+        let vars = env.await?;
+        assert_eq!(vars.get("ACYCLIC").map(String::as_str), Some("value"));
+
+        let issues = issues(env).await?;
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].0, ".env");
+        assert!(issues[0]
+            .1
+            .starts_with("cyclic reference between variables: CYCLE_"));
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn skips_files_that_fail_to_parse() {
+    register();
+    let dir = tempfile::tempdir().unwrap();
+    write_files(
+        dir.path(),
+        &[
+            (".env.local", "BROKEN=\"unterminated\n"),
+            (".env", "FROM_ENV=value\nREFERENCE=${FROM_ENV}\n"),
+        ],
+    );
+    let root = dir.path().to_path_buf();
+
+    let tt = TurboTasks::new(MemoryBackend::default());
+    tt.run_once(async move {
+        let env = files_env(&root, &[".env.local", ".env"]);
+        let vars = env.await?;
+        assert_eq!(vars.get("BROKEN"), None);
+        assert_eq!(vars.get("REFERENCE").map(String::as_str), Some("value"));
+
+        let issues = issues(env).await?;
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].0, ".env.local");
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn selects_files_by_mode() {
+    register();
+    let dir = tempfile::tempdir().unwrap();
+    write_files(
+        dir.path(),
+        &[
+            (".env.staging.local", "MODE_LOCAL=staging.local\n"),
+            (
+                ".env.staging",
+                "MODE_LOCAL=staging\nMODE_NAME=staging\nMODE_URL=https://${MODE_HOST}\n",
+            ),
+            (".env.development", "MODE_NAME=development\n"),
+            (".env", "MODE_NAME=default\nMODE_HOST=example.com\n"),
+        ],
+    );
+    let root = dir.path().to_path_buf();
+
+    let tt = TurboTasks::new(MemoryBackend::default());
+    tt.run_once(async move {
+        let env = load_env(project_path(&root), Some("staging".to_string())).read_all();
+        let vars = env.await?;
+        let var = |name| vars.get(name).map(String::as_str);
+        assert_eq!(var("MODE_LOCAL"), Some("staging.local"));
+        assert_eq!(var("MODE_NAME"), Some("staging"));
+        assert_eq!(var("MODE_URL"), Some("https://example.com"));
+        assert!(issues(env).await?.is_empty());
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
@@ -0,0 +1,301 @@
+use anyhow::Result;
+use mime::TEXT_HTML_UTF_8;
+use turbo_tasks::{primitives::StringVc, TryJoinIterExt};
+use turbo_tasks_fs::{File, FileSystemPathVc};
+use turbo_tasks_hash::{encode_hex, hash_xxh3_hash64};
+use turbopack_core::{
+    asset::{Asset, AssetContentVc, AssetVc, AssetsVc},
+    chunk::{
+        ChunkableAsset, ChunkableAssetVc, ChunkingContext, ChunkingContextVc, EvaluatableAssetVc,
+        EvaluatableAssetsVc,
+    },
+    ident::AssetIdentVc,
+    reference::{AssetReference, AssetReferencesVc, SingleAssetReferenceVc},
+    version::{Version, VersionVc, VersionedContent, VersionedContentVc},
+};
+use turbopack_css::embed::{CssEmbed, CssEmbeddable, CssEmbeddableVc};
+
+use crate::{
+    parse::{parse_html, HtmlReferenceKind},
+    HtmlModuleAssetVc,
+};
+
+/// The emitted HTML page of an [HtmlModuleAsset](crate::HtmlModuleAsset).
+///
+/// Module scripts and stylesheets are replaced with the chunks of their
+/// modules, and asset URLs are rewritten to the emitted assets. URLs are
+/// relative to `path`, so that the page works with any chunking context.
+#[turbo_tasks::value]
+pub struct HtmlEntryAsset {
+    path: FileSystemPathVc,
+    module: HtmlModuleAssetVc,
+    chunking_context: ChunkingContextVc,
+    runtime_entries: Option<EvaluatableAssetsVc>,
+}
+
+/// The chunks or assets which each reference of the page is replaced with.
+/// `None` keeps the reference as it is, e.g. when it can't be resolved.
+#[turbo_tasks::value(transparent)]
+struct HtmlOutputAssets(Vec<Option<AssetsVc>>);
+
+#[turbo_tasks::function]
+fn html_entry_reference_description() -> StringVc {
+    StringVc::cell("html entry".to_string())
+}
+
+#[turbo_tasks::value_impl]
+impl HtmlEntryAssetVc {
+    /// Creates the page for `module` at `path`. Module scripts are evaluated
+    /// after `runtime_entries`.
+    #[turbo_tasks::function]
+    pub fn new(
+        path: FileSystemPathVc,
+        module: HtmlModuleAssetVc,
+        chunking_context: ChunkingContextVc,
+        runtime_entries: Option<EvaluatableAssetsVc>,
+    ) -> Self {
+        HtmlEntryAsset {
+            path,
+            module,
+            chunking_context,
+            runtime_entries,
+        }
+        .cell()
+    }
+
+    /// The chunks or assets which each reference of the page is replaced
+    /// with, in the order of the references.
+    ///
+    /// All module scripts of the page are evaluated by one chunk group, so
+    /// modules they share are only instantiated once. The chunk group replaces
+    /// the first module script and the others are removed, which keeps the
+    /// order of evaluation.
+    #[turbo_tasks::function]
+    async fn output_assets(self) -> Result<HtmlOutputAssetsVc> {
+        let this = self.await?;
+        let context = this.chunking_context;
+        let resolved = this
+            .module
+            .html_references()
+            .await?
+            .iter()
+            .map(|reference| async move {
+                let kind = reference.await?.kind;
+                let resolved = reference.resolve_reference().primary_assets().await?;
+                Ok((kind, resolved.first().copied()))
+            })
+            .try_join()
+            .await?;
+
+        let mut scripts = Vec::new();
+        for (kind, asset) in &resolved {
+            if let (HtmlReferenceKind::Script, Some(asset)) = (kind, asset) {
+                if let Some(evaluatable) = EvaluatableAssetVc::resolve_from(asset).await? {
+                    scripts.push(evaluatable);
+                }
+            }
+        }
+        let mut scripts_chunk_group = scripts.first().map(|first| {
+            let evaluatable_assets = scripts.iter().fold(
+                this.runtime_entries
+                    .unwrap_or_else(EvaluatableAssetsVc::empty),
+                |assets, script| assets.with_entry(*script),
+            );
+            context.evaluated_chunk_group(first.as_root_chunk(context), evaluatable_assets)
+        });
+
+        let mut assets = Vec::with_capacity(resolved.len());
+        for (kind, asset) in resolved {
+            let Some(asset) = asset else {
+                assets.push(None);
+                continue;
+            };
+            assets.push(match kind {
+                HtmlReferenceKind::Script | HtmlReferenceKind::Stylesheet => {
+                    if kind == HtmlReferenceKind::Script
+                        && EvaluatableAssetVc::resolve_from(asset).await?.is_some()
+                    {
+                        Some(scripts_chunk_group.take().unwrap_or_else(AssetsVc::empty))
+                    } else {
+                        ChunkableAssetVc::resolve_from(asset)
+                            .await?
+                            .map(|chunkable| context.chunk_group(chunkable.as_root_chunk(context)))
+                    }
+                }
+                HtmlReferenceKind::Asset => {
+                    CssEmbeddableVc::resolve_from(asset)
+                        .await?
+                        .map(|embeddable| {
+                            AssetsVc::cell(vec![embeddable
+                                .as_css_embed(context)
+                                .embeddable_asset()])
+                        })
+                }
+            });
+        }
+        Ok(HtmlOutputAssetsVc::cell(assets))
+    }
+
+    #[turbo_tasks::function]
+    async fn html_content(self) -> Result<HtmlEntryAssetContentVc> {
+        let this = self.await?;
+        let page_dir = this.path.parent().await?;
+        let parsed = parse_html(this.module.await?.source).await?;
+        let output_assets = self.output_assets().await?;
+
+        let mut html = String::with_capacity(parsed.html.len());
+        let mut pos = 0;
+        for (reference, assets) in parsed.references.iter().zip(output_assets.iter()) {
+            let Some(assets) = assets else {
+                continue;
+            };
+            let assets = assets.await?;
+            let mut urls = Vec::with_capacity(assets.len());
+            for asset in assets.iter() {
+                let path = asset.ident().path().await?;
+                urls.push(
+                    page_dir
+                        .get_relative_path_to(&path)
+                        .unwrap_or_else(|| format!("/{}", path.path)),
+                );
+            }
+            html.push_str(&parsed.html[pos..reference.start]);
+            match reference.kind {
+                HtmlReferenceKind::Script | HtmlReferenceKind::Stylesheet => {
+                    let tags = urls
+                        .iter()
+                        .filter_map(|url| {
+                            if url.ends_with(".js") {
+                                Some(format!("<script src=\"{}\"></script>", escape(url)))
+                            } else if url.ends_with(".css") {
+                                Some(format!(
+                                    "<link data-turbopack rel=\"stylesheet\" href=\"{}\">",
+                                    escape(url)
+                                ))
+                            } else {
+                                None
+                            }
+                        })
+                        .collect::<Vec<_>>();
+                    // Keep the indentation of the replaced element for the added tags.
+                    let line_start = parsed.html[..reference.start]
+                        .rfind('\n')
+                        .map_or(0, |newline| newline + 1);
+                    let indent = &parsed.html[line_start..reference.start];
+                    let separator = if indent.trim().is_empty() {
+                        format!("\n{indent}")
+                    } else {
+                        "\n".to_string()
+                    };
+                    if tags.is_empty() && pos <= line_start && indent.trim().is_empty() {
+                        // Drop the whole line of a removed element.
+                        let rest = &parsed.html[reference.end..];
+                        let line_end = rest.find('\n').map_or(rest.len(), |newline| newline + 1);
+                        if rest[..line_end].trim().is_empty() {
+                            html.truncate(html.len() - indent.len());
+                            pos = reference.end + line_end;
+                            continue;
+                        }
+                    }
+                    html.push_str(&tags.join(&separator));
+                }
+                HtmlReferenceKind::Asset => {
+                    if let Some(url) = urls.first() {
+                        html.push_str(&escape(url));
+                    }
+                }
+            }
+            pos = reference.end;
+        }
+        html.push_str(&parsed.html[pos..]);
+
+        Ok(HtmlEntryAssetContent { html }.cell())
+    }
+}
+
+/// Escapes a URL for use in a quoted attribute.
+fn escape(url: &str) -> String {
+    url.replace('&', "&amp;").replace('"', "&quot;")
+}
+
+#[turbo_tasks::value_impl]
+impl Asset for HtmlEntryAsset {
+    #[turbo_tasks::function]
+    fn ident(&self) -> AssetIdentVc {
+        AssetIdentVc::from_path(self.path)
+    }
+
+    #[turbo_tasks::function]
+    fn content(self_vc: HtmlEntryAssetVc) -> AssetContentVc {
+        self_vc.html_content().content()
+    }
+
+    #[turbo_tasks::function]
+    async fn references(self_vc: HtmlEntryAssetVc) -> Result<AssetReferencesVc> {
+        let mut references = Vec::new();
+        for assets in self_vc.output_assets().await?.iter().flatten() {
+            for asset in assets.await?.iter() {
+                references.push(
+                    SingleAssetReferenceVc::new(*asset, html_entry_reference_description()).into(),
+                );
+            }
+        }
+        Ok(AssetReferencesVc::cell(references))
+    }
+
+    #[turbo_tasks::function]
+    fn versioned_content(self_vc: HtmlEntryAssetVc) -> VersionedContentVc {
+        self_vc.html_content().into()
+    }
+}
+
+#[turbo_tasks::value]
+struct HtmlEntryAssetContent {
+    html: String,
+}
+
+#[turbo_tasks::value_impl]
+impl HtmlEntryAssetContentVc {
+    #[turbo_tasks::function]
+    async fn content(self) -> Result<AssetContentVc> {
+        let this = self.await?;
+        Ok(File::from(this.html.clone())
+            .with_content_type(TEXT_HTML_UTF_8)
+            .into())
+    }
+
+    #[turbo_tasks::function]
+    async fn version(self) -> Result<HtmlEntryAssetVersionVc> {
+        let this = self.await?;
+        Ok(HtmlEntryAssetVersion {
+            hash: encode_hex(hash_xxh3_hash64(&this.html)),
+        }
+        .cell())
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl VersionedContent for HtmlEntryAssetContent {
+    #[turbo_tasks::function]
+    fn content(self_vc: HtmlEntryAssetContentVc) -> AssetContentVc {
+        self_vc.content()
+    }
+
+    #[turbo_tasks::function]
+    fn version(self_vc: HtmlEntryAssetContentVc) -> VersionVc {
+        self_vc.version().into()
+    }
+}
+
+#[turbo_tasks::value]
+struct HtmlEntryAssetVersion {
+    hash: String,
+}
+
+#[turbo_tasks::value_impl]
+impl Version for HtmlEntryAssetVersion {
+    #[turbo_tasks::function]
+    fn id(&self) -> StringVc {
+        StringVc::cell(self.hash.clone())
+    }
+}
@@ -0,0 +1,105 @@
+//! HTML support for turbopack.
+//!
+//! HTML pages are used as entry points, like in a static-site bundler. The
+//! module scripts, stylesheets and assets of a page are resolved as modules,
+//! and the page is emitted by an [HtmlEntryAsset] with those references
+//! rewritten to the URLs of their chunks and assets.
+
+#![feature(min_specialization)]
+
+pub mod entry;
+pub mod parse;
+pub mod references;
+
+use anyhow::Result;
+use turbo_tasks::primitives::StringVc;
+use turbopack_core::{
+    asset::{Asset, AssetContentVc, AssetVc},
+    context::AssetContextVc,
+    ident::AssetIdentVc,
+    issue::IssueSourceVc,
+    reference::AssetReferencesVc,
+    resolve::origin::PlainResolveOriginVc,
+};
+
+pub use self::entry::{HtmlEntryAsset, HtmlEntryAssetVc};
+use self::{
+    parse::parse_html,
+    references::{HtmlAssetReferenceVc, HtmlAssetReferencesVc},
+};
+
+#[turbo_tasks::function]
+fn modifier() -> StringVc {
+    StringVc::cell("html".to_string())
+}
+
+/// A user-authored HTML page.
+#[turbo_tasks::value]
+#[derive(Clone)]
+pub struct HtmlModuleAsset {
+    pub source: AssetVc,
+    pub context: AssetContextVc,
+}
+
+#[turbo_tasks::value_impl]
+impl HtmlModuleAssetVc {
+    #[turbo_tasks::function]
+    pub fn new(source: AssetVc, context: AssetContextVc) -> Self {
+        Self::cell(HtmlModuleAsset { source, context })
+    }
+
+    /// The references of the page, in the order of their occurrence.
+    #[turbo_tasks::function]
+    pub async fn html_references(self) -> Result<HtmlAssetReferencesVc> {
+        let this = self.await?;
+        let origin =
+            PlainResolveOriginVc::new(this.context, this.source.ident().path()).as_resolve_origin();
+        let references = parse_html(this.source)
+            .await?
+            .references
+            .iter()
+            .map(|reference| {
+                HtmlAssetReferenceVc::new(
+                    origin,
+                    &reference.url,
+                    reference.kind,
+                    IssueSourceVc::from_byte_offset(this.source, reference.start, reference.end),
+                )
+            })
+            .collect();
+        Ok(HtmlAssetReferencesVc::cell(references))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Asset for HtmlModuleAsset {
+    #[turbo_tasks::function]
+    fn ident(&self) -> AssetIdentVc {
+        self.source.ident().with_modifier(modifier())
+    }
+
+    #[turbo_tasks::function]
+    fn content(&self) -> AssetContentVc {
+        self.source.content()
+    }
+
+    #[turbo_tasks::function]
+    async fn references(self_vc: HtmlModuleAssetVc) -> Result<AssetReferencesVc> {
+        Ok(AssetReferencesVc::cell(
+            self_vc
+                .html_references()
+                .await?
+                .iter()
+                .map(|reference| reference.as_asset_reference())
+                .collect(),
+        ))
+    }
+}
+
+pub fn register() {
+    turbo_tasks::register();
+    turbo_tasks_fs::register();
+    turbopack_core::register();
+    turbopack_css::register();
+    include!(concat!(env!("OUT_DIR"), "/register.rs"));
+}
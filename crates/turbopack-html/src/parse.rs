@@ -0,0 +1,367 @@
+use anyhow::Result;
+use turbo_tasks_fs::FileContent;
+use turbopack_core::asset::{Asset, AssetContent, AssetVc};
+
+/// The kind of a reference from an HTML page.
+#[turbo_tasks::value(serialization = "auto_for_input")]
+#[derive(Debug, Clone, Copy, Hash, PartialOrd, Ord)]
+pub enum HtmlReferenceKind {
+    /// A `<script type="module" src>`, which is replaced with the chunks of
+    /// the script.
+    Script,
+    /// A `<link rel="stylesheet" href>`, which is replaced with the chunks of
+    /// the stylesheet.
+    Stylesheet,
+    /// An attribute pointing to an asset, e.g. `<img src>`, which is rewritten
+    /// to the URL of the asset.
+    Asset,
+}
+
+/// A reference found in an HTML page.
+#[turbo_tasks::value(shared)]
+#[derive(Debug, Clone)]
+pub struct HtmlReference {
+    pub kind: HtmlReferenceKind,
+    /// The URL as written in the page, with HTML entities decoded.
+    pub url: String,
+    /// Start of the byte range that is replaced when the page is emitted. This
+    /// is the whole element for scripts and stylesheets, and the attribute
+    /// value for assets.
+    pub start: usize,
+    /// End of the replaced byte range.
+    pub end: usize,
+}
+
+#[turbo_tasks::value(shared)]
+#[derive(Debug)]
+pub struct ParsedHtml {
+    pub html: String,
+    pub references: Vec<HtmlReference>,
+}
+
+/// Reads an HTML page and finds its references.
+#[turbo_tasks::function]
+pub async fn parse_html(source: AssetVc) -> Result<ParsedHtmlVc> {
+    let content = source.content().await?;
+    let html = match &*content {
+        AssetContent::File(file) => match &*file.await? {
+            FileContent::Content(file) => file.content().to_str()?.into_owned(),
+            FileContent::NotFound => String::new(),
+        },
+        AssetContent::Redirect { .. } => String::new(),
+    };
+    let references = find_references(&html);
+    Ok(ParsedHtml { html, references }.cell())
+}
+
+/// An attribute of a start tag.
+struct Attribute<'a> {
+    name: &'a str,
+    value: &'a str,
+    /// Byte range of the value, without quotes.
+    start: usize,
+    end: usize,
+}
+
+/// A start tag, e.g. `<img src="a.png">`.
+struct StartTag<'a> {
+    name: &'a str,
+    attributes: Vec<Attribute<'a>>,
+    start: usize,
+    /// The byte after the closing `>`.
+    end: usize,
+}
+
+impl<'a> StartTag<'a> {
+    fn attribute(&self, name: &str) -> Option<&Attribute<'a>> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.name.eq_ignore_ascii_case(name))
+    }
+
+    fn attribute_value(&self, name: &str) -> Option<&'a str> {
+        self.attribute(name).map(|attribute| attribute.value)
+    }
+
+    /// Whether the space separated `rel` attribute contains `value`.
+    fn has_rel(&self, value: &str) -> bool {
+        self.attribute_value("rel").map_or(false, |rel| {
+            rel.split_ascii_whitespace()
+                .any(|rel| rel.eq_ignore_ascii_case(value))
+        })
+    }
+}
+
+/// Elements whose content is not parsed as HTML.
+const RAW_TEXT_ELEMENTS: &[&str] = &["script", "style", "textarea", "title"];
+
+/// Attributes which refer to assets, by element.
+const ASSET_ATTRIBUTES: &[(&str, &str)] = &[
+    ("img", "src"),
+    ("source", "src"),
+    ("video", "src"),
+    ("video", "poster"),
+    ("audio", "src"),
+    ("track", "src"),
+    ("input", "src"),
+];
+
+/// Relations of `<link>` elements which refer to assets.
+const ASSET_LINK_RELS: &[&str] = &["icon", "apple-touch-icon", "manifest"];
+
+/// Finds the module scripts, stylesheets and assets referenced by `html`.
+/// URLs with a scheme, protocol-relative URLs and fragments are skipped.
+pub fn find_references(html: &str) -> Vec<HtmlReference> {
+    let mut references = Vec::new();
+    let mut pos = 0;
+    while let Some(offset) = html[pos..].find('<') {
+        let start = pos + offset;
+        let rest = &html[start..];
+        if rest.starts_with("<!--") {
+            pos = rest
+                .find("-->")
+                .map_or(html.len(), |end| start + end + "-->".len());
+            continue;
+        }
+        if rest.starts_with("</") || rest.starts_with("<!") || rest.starts_with("<?") {
+            pos = rest.find('>').map_or(html.len(), |end| start + end + 1);
+            continue;
+        }
+        let Some(tag) = parse_start_tag(html, start) else {
+            pos = start + 1;
+            continue;
+        };
+        pos = tag.end;
+        let name = tag.name.to_ascii_lowercase();
+        // The end of the element, including the content of raw text elements.
+        let mut element_end = tag.end;
+        if RAW_TEXT_ELEMENTS.contains(&name.as_str()) {
+            let close = format!("</{name}");
+            let content_end = find_ignore_ascii_case(&html[tag.end..], &close)
+                .map_or(html.len(), |end| tag.end + end);
+            element_end = html[content_end..]
+                .find('>')
+                .map_or(html.len(), |end| content_end + end + 1);
+            pos = element_end;
+        }
+        let mut push = |kind, url: &str, start, end| {
+            let url = decode_entities(url.trim());
+            if is_local_url(&url) {
+                references.push(HtmlReference {
+                    kind,
+                    url,
+                    start,
+                    end,
+                });
+            }
+        };
+        match name.as_str() {
+            "script" => {
+                let is_module = tag
+                    .attribute_value("type")
+                    .map_or(false, |ty| ty.trim().eq_ignore_ascii_case("module"));
+                if let (true, Some(src)) = (is_module, tag.attribute_value("src")) {
+                    push(HtmlReferenceKind::Script, src, tag.start, element_end);
+                }
+            }
+            "link" => {
+                let Some(href) = tag.attribute("href") else {
+                    continue;
+                };
+                if tag.has_rel("stylesheet") {
+                    push(
+                        HtmlReferenceKind::Stylesheet,
+                        href.value,
+                        tag.start,
+                        tag.end,
+                    );
+                } else if ASSET_LINK_RELS.iter().any(|rel| tag.has_rel(rel)) {
+                    push(HtmlReferenceKind::Asset, href.value, href.start, href.end);
+                }
+            }
+            _ => {
+                for (element, attribute) in ASSET_ATTRIBUTES {
+                    if *element == name {
+                        if let Some(attribute) = tag.attribute(attribute) {
+                            push(
+                                HtmlReferenceKind::Asset,
+                                attribute.value,
+                                attribute.start,
+                                attribute.end,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+    references
+}
+
+/// Parses the start tag at `start`, which points to a `<`.
+fn parse_start_tag(html: &str, start: usize) -> Option<StartTag<'_>> {
+    let bytes = html.as_bytes();
+    let mut pos = start + 1;
+    while pos < bytes.len() && (bytes[pos].is_ascii_alphanumeric() || bytes[pos] == b'-') {
+        pos += 1;
+    }
+    if pos == start + 1 || !bytes[start + 1].is_ascii_alphabetic() {
+        return None;
+    }
+    let name = &html[start + 1..pos];
+    let mut attributes = Vec::new();
+    loop {
+        while pos < bytes.len() && (bytes[pos].is_ascii_whitespace() || bytes[pos] == b'/') {
+            pos += 1;
+        }
+        if pos >= bytes.len() {
+            return None;
+        }
+        if bytes[pos] == b'>' {
+            return Some(StartTag {
+                name,
+                attributes,
+                start,
+                end: pos + 1,
+            });
+        }
+        let name_start = pos;
+        while pos < bytes.len()
+            && !bytes[pos].is_ascii_whitespace()
+            && !matches!(bytes[pos], b'=' | b'>' | b'/')
+        {
+            pos += 1;
+        }
+        let name = &html[name_start..pos];
+        while pos < bytes.len() && bytes[pos].is_ascii_whitespace() {
+            pos += 1;
+        }
+        if pos >= bytes.len() || bytes[pos] != b'=' {
+            attributes.push(Attribute {
+                name,
+                value: "",
+                start: pos,
+                end: pos,
+            });
+            continue;
+        }
+        pos += 1;
+        while pos < bytes.len() && bytes[pos].is_ascii_whitespace() {
+            pos += 1;
+        }
+        let (value_start, value_end) = match bytes.get(pos) {
+            Some(&quote @ (b'"' | b'\'')) => {
+                let value_start = pos + 1;
+                let value_end = html[value_start..]
+                    .find(quote as char)
+                    .map(|end| value_start + end)?;
+                pos = value_end + 1;
+                (value_start, value_end)
+            }
+            _ => {
+                let value_start = pos;
+                while pos < bytes.len() && !bytes[pos].is_ascii_whitespace() && bytes[pos] != b'>' {
+                    pos += 1;
+                }
+                (value_start, pos)
+            }
+        };
+        attributes.push(Attribute {
+            name,
+            value: &html[value_start..value_end],
+            start: value_start,
+            end: value_end,
+        });
+    }
+}
+
+fn find_ignore_ascii_case(haystack: &str, needle: &str) -> Option<usize> {
+    haystack
+        .as_bytes()
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
+/// Decodes the entities which commonly occur in URLs.
+fn decode_entities(value: &str) -> String {
+    value
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+/// Whether `url` refers to a file of the project, rather than to another
+/// origin or to a part of the page.
+fn is_local_url(url: &str) -> bool {
+    if url.is_empty() || url.starts_with('#') || url.starts_with("//") {
+        return false;
+    }
+    let scheme_len = url
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.')))
+        .unwrap_or(url.len());
+    !(scheme_len > 0 && url[scheme_len..].starts_with(':'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the kind and the replaced source of each reference.
+    fn replaced(html: &str) -> Vec<(HtmlReferenceKind, &str)> {
+        find_references(html)
+            .into_iter()
+            .map(|reference| (reference.kind, &html[reference.start..reference.end]))
+            .collect()
+    }
+
+    #[test]
+    fn finds_references() {
+        let html = r##"<!DOCTYPE html>
+<html>
+  <head>
+    <link rel="stylesheet" href="./style.css">
+    <link rel="icon" href=favicon.ico>
+    <link rel="preconnect" href="https://example.com">
+    <script type="module" src="/src/main.js"></script>
+    <script src="classic.js"></script>
+  </head>
+  <body>
+    <!-- <img src="commented.png"> -->
+    <img alt="logo" src='logo.png?size=2&amp;v=1' />
+    <img src="data:image/png;base64,AAAA">
+    <textarea><img src="text.png"></textarea>
+    <a href="#top"></a>
+  </body>
+</html>"##;
+        assert_eq!(
+            replaced(html),
+            vec![
+                (
+                    HtmlReferenceKind::Stylesheet,
+                    r#"<link rel="stylesheet" href="./style.css">"#
+                ),
+                (HtmlReferenceKind::Asset, "favicon.ico"),
+                (
+                    HtmlReferenceKind::Script,
+                    r#"<script type="module" src="/src/main.js"></script>"#
+                ),
+                (HtmlReferenceKind::Asset, "logo.png?size=2&amp;v=1"),
+            ]
+        );
+        let urls: Vec<_> = find_references(html)
+            .into_iter()
+            .map(|reference| reference.url)
+            .collect();
+        assert_eq!(
+            urls,
+            [
+                "./style.css",
+                "favicon.ico",
+                "/src/main.js",
+                "logo.png?size=2&v=1"
+            ]
+        );
+    }
+}
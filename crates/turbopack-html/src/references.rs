@@ -0,0 +1,95 @@
+use anyhow::Result;
+use turbo_tasks::{primitives::StringVc, Value, ValueToString, ValueToStringVc};
+use turbopack_core::{
+    issue::{IssueSeverity, IssueSourceVc, OptionIssueSourceVc},
+    reference::{AssetReference, AssetReferenceVc},
+    reference_type::{EntryReferenceSubType, ReferenceType, UrlReferenceSubType},
+    resolve::{
+        handle_resolve_error,
+        origin::{ResolveOrigin, ResolveOriginVc},
+        parse::RequestVc,
+        ResolveResultVc,
+    },
+};
+
+use crate::parse::HtmlReferenceKind;
+
+/// A reference from an HTML page to a module script, a stylesheet or an asset.
+#[turbo_tasks::value]
+#[derive(Hash, Debug)]
+pub struct HtmlAssetReference {
+    pub origin: ResolveOriginVc,
+    pub request: RequestVc,
+    pub kind: HtmlReferenceKind,
+    pub issue_source: IssueSourceVc,
+}
+
+#[turbo_tasks::value(transparent)]
+pub struct HtmlAssetReferences(Vec<HtmlAssetReferenceVc>);
+
+impl HtmlAssetReferenceVc {
+    /// Creates a reference to `url`. URLs are always relative to the page, as
+    /// in the browser, and absolute paths are relative to the directory of the
+    /// page.
+    pub fn new(
+        origin: ResolveOriginVc,
+        url: &str,
+        kind: HtmlReferenceKind,
+        issue_source: IssueSourceVc,
+    ) -> Self {
+        let url = url.split('#').next().unwrap_or_default();
+        let url = url.trim_start_matches('/');
+        let request = if url.starts_with("./") || url.starts_with("../") {
+            url.to_string()
+        } else {
+            format!("./{url}")
+        };
+        Self::cell(HtmlAssetReference {
+            origin,
+            request: RequestVc::parse(Value::new(request.into())),
+            kind,
+            issue_source,
+        })
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl AssetReference for HtmlAssetReference {
+    #[turbo_tasks::function]
+    async fn resolve_reference(&self) -> Result<ResolveResultVc> {
+        let ty = Value::new(match self.kind {
+            HtmlReferenceKind::Script | HtmlReferenceKind::Stylesheet => {
+                ReferenceType::Entry(EntryReferenceSubType::Web)
+            }
+            HtmlReferenceKind::Asset => ReferenceType::Url(UrlReferenceSubType::Undefined),
+        });
+        let options = self.origin.resolve_options(ty.clone());
+        let result = self.origin.resolve_asset(self.request, options, ty.clone());
+        handle_resolve_error(
+            result,
+            ty,
+            self.origin.origin_path(),
+            self.request,
+            options,
+            OptionIssueSourceVc::some(self.issue_source),
+            IssueSeverity::Error.cell(),
+        )
+        .await
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ValueToString for HtmlAssetReference {
+    #[turbo_tasks::function]
+    async fn to_string(&self) -> Result<StringVc> {
+        let kind = match self.kind {
+            HtmlReferenceKind::Script => "script",
+            HtmlReferenceKind::Stylesheet => "stylesheet",
+            HtmlReferenceKind::Asset => "asset",
+        };
+        Ok(StringVc::cell(format!(
+            "html {kind} {}",
+            self.request.to_string().await?
+        )))
+    }
+}
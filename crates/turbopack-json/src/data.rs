@@ -0,0 +1,351 @@
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+use serde_json::Value as JsonValue;
+use turbo_tasks::{primitives::StringVc, Value, ValueToString};
+use turbo_tasks_fs::{FileContent, FileSystemPathVc};
+use turbopack_core::{
+    asset::{Asset, AssetContentVc, AssetVc},
+    chunk::{
+        availability_info::AvailabilityInfo, ChunkItem, ChunkItemVc, ChunkVc, ChunkableAsset,
+        ChunkableAssetVc, ChunkingContextVc,
+    },
+    ident::AssetIdentVc,
+    issue::{Issue, IssueSeverity, IssueSeverityVc, IssueSourceVc, IssueVc, OptionIssueSourceVc},
+    reference::AssetReferencesVc,
+};
+use turbopack_ecmascript::{
+    chunk::{
+        EcmascriptChunkItem, EcmascriptChunkItemContent, EcmascriptChunkItemContentVc,
+        EcmascriptChunkItemVc, EcmascriptChunkPlaceable, EcmascriptChunkPlaceableVc,
+        EcmascriptChunkVc, EcmascriptChunkingContextVc, EcmascriptExports, EcmascriptExportsVc,
+    },
+    utils::StringifyJs,
+    EsmExport, EsmExports,
+};
+
+/// A structured data format that can be imported as a module.
+#[turbo_tasks::value(serialization = "auto_for_input")]
+#[derive(PartialOrd, Ord, Hash, Debug, Copy, Clone)]
+pub enum DataFormat {
+    Yaml,
+    Toml,
+    Json5,
+}
+
+struct ParseError {
+    message: String,
+    /// The byte range in the source where the error occurred.
+    span: Option<(usize, usize)>,
+}
+
+impl DataFormat {
+    fn name(&self) -> &'static str {
+        match self {
+            DataFormat::Yaml => "YAML",
+            DataFormat::Toml => "TOML",
+            DataFormat::Json5 => "JSON5",
+        }
+    }
+
+    fn parse(&self, text: &str) -> Result<JsonValue, ParseError> {
+        match self {
+            DataFormat::Yaml => serde_yaml::from_str(text).map_err(|e| ParseError {
+                message: e.to_string(),
+                span: e
+                    .location()
+                    .map(|location| (location.index(), location.index())),
+            }),
+            DataFormat::Toml => toml::from_str::<toml::Table>(text)
+                .map(|table| toml_to_json(toml::Value::Table(table)))
+                .map_err(|e| ParseError {
+                    message: e.message().to_string(),
+                    span: e.span().map(|span| (span.start, span.end)),
+                }),
+            DataFormat::Json5 => json5::from_str(text).map_err(|e| {
+                let json5::Error::Message { msg, location } = e;
+                ParseError {
+                    message: msg,
+                    span: location.map(|location| {
+                        let offset = byte_offset(text, location.line, location.column);
+                        (offset, offset)
+                    }),
+                }
+            }),
+        }
+    }
+}
+
+/// Converts a 1-based line and column (in characters) to a byte offset.
+fn byte_offset(text: &str, line: usize, column: usize) -> usize {
+    let line_start: usize = text
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum();
+    line_start
+        + text[line_start..]
+            .chars()
+            .take(column.saturating_sub(1))
+            .map(char::len_utf8)
+            .sum::<usize>()
+}
+
+/// Converts a TOML value to JSON. Dates and times become strings.
+fn toml_to_json(value: toml::Value) -> JsonValue {
+    match value {
+        toml::Value::String(value) => JsonValue::String(value),
+        toml::Value::Integer(value) => value.into(),
+        toml::Value::Float(value) => value.into(),
+        toml::Value::Boolean(value) => value.into(),
+        toml::Value::Datetime(value) => JsonValue::String(value.to_string()),
+        toml::Value::Array(values) => values.into_iter().map(toml_to_json).collect(),
+        toml::Value::Table(table) => JsonValue::Object(
+            table
+                .into_iter()
+                .map(|(key, value)| (key, toml_to_json(value)))
+                .collect(),
+        ),
+    }
+}
+
+/// The parsed content of a data module.
+#[turbo_tasks::value(shared, serialization = "none")]
+enum DataContent {
+    Content(JsonValue),
+    Unparseable(String),
+    NotFound,
+}
+
+/// A module created from a YAML, TOML or JSON5 file. The parsed value is the
+/// default export, and the keys of a top-level object are also available as
+/// named exports.
+#[turbo_tasks::value]
+pub struct DataModuleAsset {
+    source: AssetVc,
+    format: DataFormat,
+}
+
+#[turbo_tasks::value_impl]
+impl DataModuleAssetVc {
+    #[turbo_tasks::function]
+    pub fn new(source: AssetVc, format: Value<DataFormat>) -> Self {
+        Self::cell(DataModuleAsset {
+            source,
+            format: format.into_value(),
+        })
+    }
+
+    #[turbo_tasks::function]
+    async fn data(self) -> Result<DataContentVc> {
+        let this = self.await?;
+        let FileContent::Content(file) = &*this.source.content().file_content().await? else {
+            return Ok(DataContent::NotFound.cell());
+        };
+        let text = file.content().to_str()?;
+        Ok(match this.format.parse(&text) {
+            Ok(value) => DataContent::Content(value),
+            Err(ParseError { message, span }) => {
+                DataParseIssue {
+                    path: this.source.ident().path(),
+                    format: this.format,
+                    message: StringVc::cell(message.clone()),
+                    source: span.map(|(start, end)| {
+                        IssueSourceVc::from_byte_offset(this.source, start, end)
+                    }),
+                }
+                .cell()
+                .as_issue()
+                .emit();
+                DataContent::Unparseable(message)
+            }
+        }
+        .cell())
+    }
+}
+
+#[turbo_tasks::function]
+fn modifier(format: Value<DataFormat>) -> StringVc {
+    StringVc::cell(format.name().to_lowercase())
+}
+
+#[turbo_tasks::value_impl]
+impl Asset for DataModuleAsset {
+    #[turbo_tasks::function]
+    fn ident(&self) -> AssetIdentVc {
+        self.source
+            .ident()
+            .with_modifier(modifier(Value::new(self.format)))
+    }
+
+    #[turbo_tasks::function]
+    fn content(&self) -> AssetContentVc {
+        self.source.content()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ChunkableAsset for DataModuleAsset {
+    #[turbo_tasks::function]
+    fn as_chunk(
+        self_vc: DataModuleAssetVc,
+        context: ChunkingContextVc,
+        availability_info: Value<AvailabilityInfo>,
+    ) -> ChunkVc {
+        EcmascriptChunkVc::new(
+            context,
+            self_vc.as_ecmascript_chunk_placeable(),
+            availability_info,
+        )
+        .into()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl EcmascriptChunkPlaceable for DataModuleAsset {
+    #[turbo_tasks::function]
+    fn as_chunk_item(
+        self_vc: DataModuleAssetVc,
+        context: EcmascriptChunkingContextVc,
+    ) -> EcmascriptChunkItemVc {
+        DataChunkItemVc::cell(DataChunkItem {
+            module: self_vc,
+            context,
+        })
+        .into()
+    }
+
+    #[turbo_tasks::function]
+    async fn get_exports(self_vc: DataModuleAssetVc) -> Result<EcmascriptExportsVc> {
+        let mut exports = BTreeMap::new();
+        exports.insert(
+            "default".to_string(),
+            EsmExport::LocalBinding("default".to_string()),
+        );
+        if let DataContent::Content(JsonValue::Object(object)) = &*self_vc.data().await? {
+            for key in object.keys() {
+                exports
+                    .entry(key.clone())
+                    .or_insert_with(|| EsmExport::LocalBinding(key.clone()));
+            }
+        }
+        Ok(EcmascriptExports::EsmExports(
+            EsmExports {
+                exports,
+                star_exports: Vec::new(),
+            }
+            .cell(),
+        )
+        .cell())
+    }
+}
+
+#[turbo_tasks::value]
+struct DataChunkItem {
+    module: DataModuleAssetVc,
+    context: EcmascriptChunkingContextVc,
+}
+
+#[turbo_tasks::value_impl]
+impl ChunkItem for DataChunkItem {
+    #[turbo_tasks::function]
+    fn asset_ident(&self) -> AssetIdentVc {
+        self.module.ident()
+    }
+
+    #[turbo_tasks::function]
+    fn references(&self) -> AssetReferencesVc {
+        self.module.references()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl EcmascriptChunkItem for DataChunkItem {
+    #[turbo_tasks::function]
+    fn chunking_context(&self) -> EcmascriptChunkingContextVc {
+        self.context
+    }
+
+    #[turbo_tasks::function]
+    async fn content(&self) -> Result<EcmascriptChunkItemContentVc> {
+        let inner_code =
+            match &*self.module.data().await? {
+                DataContent::Content(value) => {
+                    let json = serde_json::to_string(value)?;
+                    let mut getters = vec!["default: () => data".to_string()];
+                    if let JsonValue::Object(object) = value {
+                        getters.extend(object.keys().filter(|key| *key != "default").map(|key| {
+                            format!("{key}: () => data[{key}]", key = StringifyJs(key))
+                        }));
+                    }
+                    format!(
+                        "const data = JSON.parse({});\n__turbopack_esm__({{\n    {}\n}});\n",
+                        StringifyJs(&json),
+                        getters.join(",\n    ")
+                    )
+                }
+                DataContent::Unparseable(message) => {
+                    let module = self.module.await?;
+                    format!(
+                        "throw new Error({});\n",
+                        StringifyJs(&format!(
+                            "Unable to make a module from invalid {}: {message}",
+                            module.format.name()
+                        ))
+                    )
+                }
+                DataContent::NotFound => {
+                    bail!(
+                        "Data file not found: {}",
+                        self.module.ident().to_string().await?
+                    );
+                }
+            };
+        Ok(EcmascriptChunkItemContent {
+            inner_code: inner_code.into(),
+            ..Default::default()
+        }
+        .into())
+    }
+}
+
+#[turbo_tasks::value(shared)]
+struct DataParseIssue {
+    path: FileSystemPathVc,
+    format: DataFormat,
+    message: StringVc,
+    source: Option<IssueSourceVc>,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for DataParseIssue {
+    #[turbo_tasks::function]
+    fn severity(&self) -> IssueSeverityVc {
+        IssueSeverity::Error.into()
+    }
+
+    #[turbo_tasks::function]
+    fn category(&self) -> StringVc {
+        StringVc::cell("parse".to_string())
+    }
+
+    #[turbo_tasks::function]
+    fn title(&self) -> StringVc {
+        StringVc::cell(format!("Unable to parse {} file", self.format.name()))
+    }
+
+    #[turbo_tasks::function]
+    fn context(&self) -> FileSystemPathVc {
+        self.path
+    }
+
+    #[turbo_tasks::function]
+    fn description(&self) -> StringVc {
+        self.message
+    }
+
+    #[turbo_tasks::function]
+    fn source(&self) -> OptionIssueSourceVc {
+        OptionIssueSourceVc::cell(self.source)
+    }
+}
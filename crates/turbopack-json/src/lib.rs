@@ -4,9 +4,15 @@
 //!
 //! When imported from ES modules, they produce a module that exports the
 //! JSON value as an object.
+//!
+//! YAML, TOML and JSON5 files are converted to JSON values as well. They
+//! produce ES modules with the value as the default export and the keys of a
+//! top-level object as named exports.
 
 #![feature(min_specialization)]
 
+mod data;
+
 use std::fmt::Write;
 
 use anyhow::{bail, Error, Result};
@@ -27,6 +33,8 @@ use turbopack_ecmascript::chunk::{
     EcmascriptChunkingContextVc, EcmascriptExports, EcmascriptExportsVc,
 };
 
+pub use self::data::{DataFormat, DataModuleAsset, DataModuleAssetVc};
+
 #[turbo_tasks::function]
 fn modifier() -> StringVc {
     StringVc::cell("json".to_string())
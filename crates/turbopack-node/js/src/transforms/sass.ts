@@ -0,0 +1,97 @@
+// @ts-ignore
+import sass from "@vercel/turbopack/sass";
+import type { Ipc } from "../ipc/evaluate";
+
+type Stylesheets = {
+  // The path of the stylesheet to compile.
+  entry: string;
+  // The content of every stylesheet that is part of the compilation, by path.
+  files: Record<string, string>;
+  // The path of the stylesheet an import was resolved to, by the path Sass
+  // looks up for the import.
+  imports: Record<string, string>;
+  // The name of every stylesheet in the generated source map, by path.
+  sourceNames: Record<string, string>;
+};
+
+type Location = {
+  line: number;
+  column: number;
+};
+
+// Stylesheets are only read from the resolved files, so they are identified by
+// urls of a scheme that Sass doesn't load from the file system.
+const SCHEME = "turbopack:";
+
+const toUrl = (path: string) =>
+  new URL(`${SCHEME}///${path.split("/").map(encodeURIComponent).join("/")}`);
+
+const toPath = (url: URL) =>
+  url.pathname.slice(1).split("/").map(decodeURIComponent).join("/");
+
+const syntaxOf = (path: string) =>
+  path.endsWith(".sass") ? "indented" : path.endsWith(".css") ? "css" : "scss";
+
+const transform = (
+  _ipc: Ipc,
+  { entry, files, imports, sourceNames }: Stylesheets
+) => {
+  const importer = {
+    canonicalize(url: string) {
+      if (!url.startsWith(SCHEME)) {
+        return null;
+      }
+      const path = toPath(new URL(url));
+      const resolved = imports[path] ?? (path in files ? path : undefined);
+      return resolved === undefined ? null : toUrl(resolved);
+    },
+    load(canonicalUrl: URL) {
+      const path = toPath(canonicalUrl);
+      return {
+        contents: files[path],
+        syntax: syntaxOf(path),
+      };
+    },
+  };
+
+  let result;
+  try {
+    result = sass.compileString(files[entry], {
+      url: toUrl(entry),
+      syntax: syntaxOf(entry),
+      importer,
+      sourceMap: true,
+      sourceMapIncludeSources: true,
+    });
+  } catch (err: any) {
+    if (!(err instanceof sass.Exception)) {
+      throw err;
+    }
+    // The locations of the span are read through getters, which aren't
+    // serialized to JSON.
+    const span = err.span;
+    const toLocation = ({ line, column }: Location) => ({ line, column });
+    return {
+      type: "error",
+      message: err.sassMessage,
+      path:
+        span.url && span.url.protocol === SCHEME ? toPath(span.url) : undefined,
+      start: toLocation(span.start),
+      end: toLocation(span.end),
+    };
+  }
+
+  const map = result.sourceMap;
+  map.sources = map.sources.map((source: string) =>
+    source.startsWith(SCHEME)
+      ? sourceNames[toPath(new URL(source))] ?? source
+      : source
+  );
+  return {
+    type: "compiled",
+    css: result.css,
+    map: JSON.stringify(map),
+  };
+};
+
+export { transform as default };
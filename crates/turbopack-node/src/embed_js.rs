@@ -11,6 +11,6 @@ pub(crate) fn embed_file(path: &str) -> FileContentVc {
 }
 
 #[turbo_tasks::function]
-pub(crate) fn embed_file_path(path: &str) -> FileSystemPathVc {
+pub fn embed_file_path(path: &str) -> FileSystemPathVc {
     embed_fs().root().join(path)
 }
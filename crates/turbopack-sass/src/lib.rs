@@ -0,0 +1,780 @@
+//! Sass and SCSS support for turbopack.
+//!
+//! Stylesheets are compiled to plain CSS and then handed to the regular CSS
+//! and CSS module pipeline.
+//!
+//! `@use`, `@forward` and `@import` rules are resolved with turbopack's
+//! resolver before compiling, following the Sass rules for partials and index
+//! files. The compiler only sees an in-memory file system made of the resolved
+//! files, so every stylesheet that is part of the compilation has been read
+//! through turbo-tasks and editing a partial recompiles the stylesheets that
+//! depend on it.
+//!
+//! When a [SassNodeCompiler] is configured and the project depends on the
+//! `sass` package, stylesheets are compiled by that package in the Node.js
+//! evaluation pool. Its source map points into the Sass sources and is
+//! composed into the source map of the CSS. Otherwise they are compiled with
+//! [grass], a native Rust Sass compiler, which doesn't generate source maps,
+//! so no source map is emitted for them.
+
+#![feature(min_specialization)]
+
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    io,
+    path::{Component, Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use grass_compiler as grass;
+use serde::{Deserialize, Serialize};
+use turbo_tasks::{
+    primitives::{JsonValueVc, StringVc},
+    CompletionVc, TryJoinIterExt, Value, ValueToString,
+};
+use turbo_tasks_bytes::stream::SingleValue;
+use turbo_tasks_fs::{json::parse_json_with_source_context, File, FileContent, FileSystemPathVc};
+use turbopack_core::{
+    asset::{Asset, AssetContent, AssetContentVc, AssetVc},
+    context::{AssetContext, AssetContextVc},
+    ident::AssetIdentVc,
+    issue::{Issue, IssueSeverity, IssueSeverityVc, IssueSourceVc, IssueVc, OptionIssueSourceVc},
+    reference::{AssetReferencesVc, SingleAssetReferenceVc},
+    reference_type::{CssReferenceSubType, InnerAssetsVc, ReferenceType},
+    resolve::{parse::RequestVc, resolve},
+    source_asset::SourceAssetVc,
+    source_map::{GenerateSourceMap, GenerateSourceMapVc, OptionSourceMapVc, SourceMap},
+    SOURCE_MAP_ROOT_NAME,
+};
+use turbopack_node::{
+    debug::should_debug,
+    embed_js::embed_file_path,
+    evaluate::evaluate,
+    execution_context::{ExecutionContext, ExecutionContextVc},
+};
+
+/// Compiles stylesheets with the `sass` package of the project in the Node.js
+/// evaluation pool.
+#[turbo_tasks::value(shared)]
+pub struct SassNodeCompiler {
+    evaluate_context: AssetContextVc,
+    execution_context: ExecutionContextVc,
+}
+
+#[turbo_tasks::value_impl]
+impl SassNodeCompilerVc {
+    #[turbo_tasks::function]
+    pub fn new(evaluate_context: AssetContextVc, execution_context: ExecutionContextVc) -> Self {
+        SassNodeCompiler {
+            evaluate_context,
+            execution_context,
+        }
+        .cell()
+    }
+}
+
+/// A Sass or SCSS stylesheet compiled to plain CSS. The compiled asset keeps
+/// the identity of its source, so it can be used as the source of a CSS
+/// module asset.
+#[turbo_tasks::value]
+pub struct SassCompiledAsset {
+    source: AssetVc,
+    context: AssetContextVc,
+    node_compiler: Option<SassNodeCompilerVc>,
+}
+
+#[turbo_tasks::value]
+struct SassCompileResult {
+    content: AssetContentVc,
+    source_map: OptionSourceMapVc,
+}
+
+#[turbo_tasks::value_impl]
+impl SassCompiledAssetVc {
+    #[turbo_tasks::function]
+    pub fn new(
+        source: AssetVc,
+        context: AssetContextVc,
+        node_compiler: Option<SassNodeCompilerVc>,
+    ) -> Self {
+        SassCompiledAsset {
+            source,
+            context,
+            node_compiler,
+        }
+        .cell()
+    }
+
+    #[turbo_tasks::function]
+    async fn compile(self) -> Result<SassCompileResultVc> {
+        let this = self.await?;
+        let entry = sass_path(this.source).await?;
+
+        let mut fs = SassFileSystem::default();
+        let mut imports = HashMap::new();
+        let mut queue = VecDeque::from([(this.source, entry.clone())]);
+        let mut sources = HashMap::new();
+        while let Some((source, path)) = queue.pop_front() {
+            if sources.contains_key(&path) {
+                continue;
+            }
+            sources.insert(path.clone(), source);
+            let FileContent::Content(file) = &*source.content().file_content().await? else {
+                continue;
+            };
+            fs.files
+                .insert(path.clone(), file.content().to_str()?.into_owned());
+
+            let dir = path.parent().unwrap_or_else(|| Path::new(""));
+            for import in sass_imports(this.context, source).await?.iter() {
+                let import = import.await?;
+                let import_path = sass_path(import.source).await?;
+                fs.aliases.insert(
+                    normalize_path(&dir.join(&import.lookup_path)),
+                    import_path.clone(),
+                );
+                if let Some(lookup_dir) = &import.lookup_dir {
+                    fs.dirs.insert(normalize_path(&dir.join(lookup_dir)));
+                }
+                imports.insert(normalize_path(&dir.join(&import.url)), import_path.clone());
+                queue.push_back((import.source, import_path));
+            }
+        }
+
+        if !fs.files.contains_key(&entry) {
+            return Ok(SassCompileResult {
+                content: AssetContent::File(FileContent::NotFound.cell()).cell(),
+                source_map: OptionSourceMapVc::cell(None),
+            }
+            .cell());
+        }
+
+        let node_compiler = match this.node_compiler {
+            Some(node_compiler) if has_sass_package(node_compiler, this.source).await? => {
+                Some(node_compiler)
+            }
+            _ => None,
+        };
+        let output = match node_compiler {
+            Some(node_compiler) => {
+                let mut source_names = HashMap::new();
+                for (path, source) in &sources {
+                    source_names.insert(
+                        path.clone(),
+                        format!(
+                            "/{SOURCE_MAP_ROOT_NAME}/{}",
+                            source.ident().to_string().await?
+                        ),
+                    );
+                }
+                let stylesheets = NodeStylesheets {
+                    entry,
+                    files: fs.files,
+                    imports,
+                    source_names,
+                };
+                compile_with_node(node_compiler, this.source, &stylesheets).await?
+            }
+            None => compile_with_grass(&entry, &fs),
+        };
+
+        let (css, source_map) = match output {
+            SassOutput::Compiled { css, map } => (css, map),
+            SassOutput::Error {
+                message,
+                path,
+                start,
+                end,
+            } => {
+                // The error can be located in any of the imported stylesheets.
+                let source = path
+                    .and_then(|path| sources.get(&normalize_path(&path)))
+                    .map(|&asset| {
+                        IssueSourceVc::from_line_col(
+                            asset,
+                            start.line,
+                            start.column,
+                            end.line,
+                            end.column,
+                        )
+                    });
+                SassCompileIssue {
+                    path: this.source.ident().path(),
+                    message: StringVc::cell(message),
+                    source: OptionIssueSourceVc::cell(source),
+                }
+                .cell()
+                .as_issue()
+                .emit();
+                // Emit an empty stylesheet, the error is reported by the issue.
+                (String::new(), None)
+            }
+        };
+        let source_map = match source_map {
+            Some(map) => Some(
+                SourceMap::new_regular(
+                    sourcemap::SourceMap::from_slice(map.as_bytes())
+                        .context("Unable to parse the source map of the compiled Sass")?,
+                )
+                .cell(),
+            ),
+            None => None,
+        };
+        Ok(SassCompileResult {
+            content: AssetContent::File(FileContent::Content(File::from(css)).cell()).cell(),
+            source_map: OptionSourceMapVc::cell(source_map),
+        }
+        .cell())
+    }
+}
+
+/// The stylesheets of a compilation, as they are passed to the `sass` package.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct NodeStylesheets {
+    entry: PathBuf,
+    files: HashMap<PathBuf, String>,
+    /// The path of the stylesheet an import was resolved to, by the import url
+    /// joined to the directory of the importing stylesheet.
+    imports: HashMap<PathBuf, PathBuf>,
+    /// The name of every stylesheet in the generated source map.
+    source_names: HashMap<PathBuf, String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum SassOutput {
+    Compiled {
+        css: String,
+        map: Option<String>,
+    },
+    Error {
+        message: String,
+        path: Option<PathBuf>,
+        start: SassLocation,
+        end: SassLocation,
+    },
+}
+
+/// A 0-indexed location in a stylesheet.
+#[derive(Debug, Default, Deserialize)]
+struct SassLocation {
+    line: usize,
+    column: usize,
+}
+
+/// Checks that the `sass` package can be resolved from the stylesheet.
+async fn has_sass_package(node_compiler: SassNodeCompilerVc, source: AssetVc) -> Result<bool> {
+    let evaluate_context = node_compiler.await?.evaluate_context;
+    let origin_path = source.ident().path();
+    let result = resolve(
+        origin_path.parent(),
+        RequestVc::parse(Value::new("@vercel/turbopack/sass".to_string().into())),
+        evaluate_context.resolve_options(origin_path, Value::new(ReferenceType::Undefined)),
+    );
+    Ok(result.first_asset().await?.is_some())
+}
+
+async fn compile_with_node(
+    node_compiler: SassNodeCompilerVc,
+    source: AssetVc,
+    stylesheets: &NodeStylesheets,
+) -> Result<SassOutput> {
+    let SassNodeCompiler {
+        evaluate_context,
+        execution_context,
+    } = *node_compiler.await?;
+    let ExecutionContext {
+        project_path,
+        chunking_context,
+        env,
+    } = *execution_context.await?;
+    let executor = evaluate_context.process(
+        SourceAssetVc::new(embed_file_path("transforms/sass.ts")).into(),
+        Value::new(ReferenceType::Internal(InnerAssetsVc::empty())),
+    );
+
+    let result = evaluate(
+        executor,
+        project_path,
+        env,
+        source.ident(),
+        evaluate_context,
+        chunking_context,
+        None,
+        vec![JsonValueVc::cell(serde_json::to_value(stylesheets)?)],
+        CompletionVc::immutable(),
+        should_debug("sass"),
+    )
+    .await?;
+
+    let SingleValue::Single(val) = result.try_into_single().await? else {
+        // An error happened, which has already been converted into an issue.
+        return Ok(SassOutput::Compiled {
+            css: String::new(),
+            map: None,
+        });
+    };
+    parse_json_with_source_context(val.to_str()?)
+        .context("Unable to deserialize response from Sass compilation")
+}
+
+fn compile_with_grass(entry: &Path, fs: &SassFileSystem) -> SassOutput {
+    let options = grass::Options::default().fs(fs).logger(&grass::NullLogger);
+    match grass::from_path(entry, &options) {
+        Ok(css) => SassOutput::Compiled { css, map: None },
+        Err(err) => match err.kind() {
+            grass::ErrorKind::ParseError { message, loc, .. } => SassOutput::Error {
+                message,
+                path: Some(PathBuf::from(loc.file.name())),
+                start: SassLocation {
+                    line: loc.begin.line,
+                    column: loc.begin.column,
+                },
+                end: SassLocation {
+                    line: loc.end.line,
+                    column: loc.end.column,
+                },
+            },
+            kind => SassOutput::Error {
+                message: match kind {
+                    grass::ErrorKind::IoError(err) => err.to_string(),
+                    grass::ErrorKind::FromUtf8Error(message) => message,
+                    _ => "unknown error".to_string(),
+                },
+                path: None,
+                start: SassLocation::default(),
+                end: SassLocation::default(),
+            },
+        },
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Asset for SassCompiledAsset {
+    #[turbo_tasks::function]
+    fn ident(&self) -> AssetIdentVc {
+        self.source.ident()
+    }
+
+    #[turbo_tasks::function]
+    async fn content(self_vc: SassCompiledAssetVc) -> Result<AssetContentVc> {
+        Ok(self_vc.compile().await?.content)
+    }
+
+    #[turbo_tasks::function]
+    async fn references(&self) -> Result<AssetReferencesVc> {
+        // Imported stylesheets are inlined by the compiler, so they are
+        // referenced as sources rather than as separate modules.
+        Ok(AssetReferencesVc::cell(
+            sass_imports(self.context, self.source)
+                .await?
+                .iter()
+                .map(|import| async move {
+                    Ok(
+                        SingleAssetReferenceVc::new(
+                            import.await?.source,
+                            sass_import_description(),
+                        )
+                        .into(),
+                    )
+                })
+                .try_join()
+                .await?,
+        ))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl GenerateSourceMap for SassCompiledAsset {
+    /// The source map of the compiled CSS, when the compiler generated one.
+    #[turbo_tasks::function]
+    async fn generate_source_map(self_vc: SassCompiledAssetVc) -> Result<OptionSourceMapVc> {
+        Ok(self_vc.compile().await?.source_map)
+    }
+}
+
+#[turbo_tasks::function]
+fn sass_import_description() -> StringVc {
+    StringVc::cell("sass import".to_string())
+}
+
+/// A stylesheet imported by a `@use`, `@forward` or `@import` rule.
+#[turbo_tasks::value]
+struct SassImport {
+    /// The url of the import rule.
+    url: String,
+    /// The path the compiler looks up for the import, relative to the
+    /// directory of the importing stylesheet.
+    lookup_path: String,
+    /// When the import resolved to an index file, the directory the compiler
+    /// checks for before looking up the index file.
+    lookup_dir: Option<String>,
+    source: AssetVc,
+}
+
+#[turbo_tasks::value(transparent)]
+struct SassImports(Vec<SassImportVc>);
+
+/// Finds and resolves the stylesheets imported by a Sass stylesheet.
+///
+/// Like Sass, an import is first looked up relative to the importing
+/// stylesheet and then as a module request, which also supports the
+/// `~package/path` syntax of sass-loader.
+#[turbo_tasks::function]
+async fn sass_imports(context: AssetContextVc, source: AssetVc) -> Result<SassImportsVc> {
+    let FileContent::Content(file) = &*source.content().file_content().await? else {
+        return Ok(SassImportsVc::cell(Vec::new()));
+    };
+    let content = file.content().to_str()?;
+
+    let origin_path = source.ident().path();
+    let options = context.resolve_options(
+        origin_path,
+        Value::new(ReferenceType::Css(CssReferenceSubType::AtImport)),
+    );
+
+    let mut imports = Vec::new();
+    'urls: for url in import_urls(&content) {
+        if url.starts_with("sass:") || url.contains("://") || url.starts_with("//") {
+            continue;
+        }
+
+        let (module_only, request_url) = match url.strip_prefix('~') {
+            Some(request_url) => (true, request_url),
+            None => (false, url.as_str()),
+        };
+        let relative = request_url.starts_with("./") || request_url.starts_with("../");
+        let candidates = sass_candidates(request_url);
+
+        let requests = if module_only {
+            vec![false]
+        } else if relative {
+            vec![true]
+        } else {
+            vec![true, false]
+        };
+        for as_relative in requests {
+            for candidate in &candidates {
+                let request = if as_relative && !relative {
+                    format!("./{}", candidate.path)
+                } else {
+                    candidate.path.clone()
+                };
+                let result = resolve(
+                    origin_path.parent(),
+                    RequestVc::parse(Value::new(request.into())),
+                    options,
+                );
+                if let Some(asset) = *result.first_asset().await? {
+                    let prefix = if module_only { "~" } else { "" };
+                    imports.push(
+                        SassImport {
+                            url: url.clone(),
+                            lookup_path: format!("{prefix}{}", candidate.path),
+                            lookup_dir: candidate.index.then(|| url.clone()),
+                            source: asset,
+                        }
+                        .cell(),
+                    );
+                    continue 'urls;
+                }
+            }
+        }
+    }
+
+    Ok(SassImportsVc::cell(imports))
+}
+
+/// Returns the urls of the `@use`, `@forward` and `@import` rules of a
+/// stylesheet, skipping comments, strings and `url()` arguments.
+fn import_urls(content: &str) -> Vec<String> {
+    let mut urls = Vec::new();
+    let mut rest = content;
+    while let Some(c) = rest.chars().next() {
+        if let Some(comment) = rest.strip_prefix("//") {
+            rest = comment.find('\n').map_or("", |end| &comment[end..]);
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            rest = comment.find("*/").map_or("", |end| &comment[end + 2..]);
+        } else if c == '"' || c == '\'' {
+            rest = split_string(rest).1;
+        } else if let Some(arg) = rest.strip_prefix("url(") {
+            rest = arg.find(')').map_or("", |end| &arg[end + 1..]);
+        } else if let Some(rule) = ["@use", "@forward", "@import"].iter().find_map(|keyword| {
+            rest.strip_prefix(keyword)
+                .filter(|rule| rule.starts_with(char::is_whitespace))
+        }) {
+            rest = rule;
+            // An `@import` rule can import multiple comma-separated urls.
+            loop {
+                let rule = rest.trim_start();
+                if !rule.starts_with(['"', '\'']) {
+                    break;
+                }
+                let (url, after) = split_string(rule);
+                urls.extend(url);
+                rest = after;
+                match rest.trim_start().strip_prefix(',') {
+                    Some(after) => rest = after,
+                    None => break,
+                }
+            }
+        } else {
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    urls
+}
+
+/// Splits a quoted string off the start of `input`, returning its value and
+/// the rest of the input. Unterminated strings end at the end of the line.
+fn split_string(input: &str) -> (Option<String>, &str) {
+    let mut chars = input.char_indices();
+    let Some((_, quote)) = chars.next() else {
+        return (None, input);
+    };
+    let mut value = String::new();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some((_, escaped)) = chars.next() {
+                    value.push(escaped);
+                }
+            }
+            '\n' => return (None, &input[index..]),
+            c if c == quote => return (Some(value), &input[index + 1..]),
+            c => value.push(c),
+        }
+    }
+    (None, "")
+}
+
+struct SassCandidate {
+    path: String,
+    index: bool,
+}
+
+/// Returns the files an import url can refer to, in the order Sass checks
+/// them: the file itself, then its partial (`_` prefixed) and, when the url
+/// has no extension, an `index` file in a directory of that name.
+fn sass_candidates(url: &str) -> Vec<SassCandidate> {
+    let (dir, name) = match url.rsplit_once('/') {
+        Some((dir, name)) => (format!("{dir}/"), name),
+        None => (String::new(), url),
+    };
+
+    let with_partial = |dir: &str, name: &str, index: bool| {
+        [
+            SassCandidate {
+                path: format!("{dir}{name}"),
+                index,
+            },
+            SassCandidate {
+                path: format!("{dir}_{name}"),
+                index,
+            },
+        ]
+    };
+
+    if [".scss", ".sass", ".css"]
+        .iter()
+        .any(|ext| name.ends_with(ext))
+    {
+        return with_partial(&dir, name, false).into_iter().collect();
+    }
+
+    let mut candidates = Vec::new();
+    for ext in ["sass", "scss", "css"] {
+        candidates.extend(with_partial(&dir, &format!("{name}.{ext}"), false));
+    }
+    for ext in ["sass", "scss", "css"] {
+        candidates.extend(with_partial(
+            &format!("{dir}{name}/"),
+            &format!("index.{ext}"),
+            true,
+        ));
+    }
+    candidates
+}
+
+async fn sass_path(source: AssetVc) -> Result<PathBuf> {
+    Ok(normalize_path(Path::new(
+        &source.ident().path().await?.path,
+    )))
+}
+
+/// Lexically normalizes a path, as the compiler joins import urls to the path
+/// of the importing stylesheet without resolving `..` segments.
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// The in-memory file system the compiler reads stylesheets from.
+///
+/// `files` contains the stylesheets by their real path, while `aliases` maps
+/// the paths the compiler looks up for an import to the real path of the
+/// stylesheet it was resolved to.
+#[derive(Debug, Default)]
+struct SassFileSystem {
+    files: HashMap<PathBuf, String>,
+    aliases: HashMap<PathBuf, PathBuf>,
+    dirs: HashSet<PathBuf>,
+}
+
+impl grass::Fs for SassFileSystem {
+    fn is_dir(&self, path: &Path) -> bool {
+        self.dirs.contains(&normalize_path(path))
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        let path = normalize_path(path);
+        self.aliases.contains_key(&path) || self.files.contains_key(&path)
+    }
+
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        let path = self.canonicalize(path)?;
+        self.files
+            .get(&path)
+            .map(|content| content.as_bytes().to_vec())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "stylesheet not found"))
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        let path = normalize_path(path);
+        Ok(self.aliases.get(&path).cloned().unwrap_or(path))
+    }
+}
+
+#[turbo_tasks::value(shared)]
+struct SassCompileIssue {
+    path: FileSystemPathVc,
+    message: StringVc,
+    source: OptionIssueSourceVc,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for SassCompileIssue {
+    #[turbo_tasks::function]
+    fn severity(&self) -> IssueSeverityVc {
+        IssueSeverity::Error.into()
+    }
+
+    #[turbo_tasks::function]
+    fn category(&self) -> StringVc {
+        StringVc::cell("parse".to_string())
+    }
+
+    #[turbo_tasks::function]
+    fn title(&self) -> StringVc {
+        StringVc::cell("Sass compilation failed".to_string())
+    }
+
+    #[turbo_tasks::function]
+    fn context(&self) -> FileSystemPathVc {
+        self.path
+    }
+
+    #[turbo_tasks::function]
+    fn description(&self) -> StringVc {
+        self.message
+    }
+
+    #[turbo_tasks::function]
+    fn source(&self) -> OptionIssueSourceVc {
+        self.source
+    }
+}
+
+pub fn register() {
+    turbo_tasks::register();
+    turbo_tasks_fs::register();
+    turbopack_core::register();
+    include!(concat!(env!("OUT_DIR"), "/register.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::{import_urls, normalize_path, sass_candidates};
+
+    #[test]
+    fn finds_import_urls() {
+        assert_eq!(
+            import_urls(
+                r#"@use "sass:math";
+@use './partials/colors' as c;
+@forward "theme" show $spacing;
+@import "a", 'b';
+.card { color: red; }"#
+            ),
+            vec!["sass:math", "./partials/colors", "theme", "a", "b"]
+        );
+    }
+
+    #[test]
+    fn skips_comments_and_strings() {
+        assert_eq!(
+            import_urls(
+                r#"// @import "line-comment";
+/* @use "block-comment";
+   @import "still-comment"; */
+.a::before { content: "@import 'string'"; }
+.b { background: url(//cdn.example.com/@import.png); }
+@import "real";"#
+            ),
+            vec!["real"]
+        );
+    }
+
+    #[test]
+    fn keeps_escaped_quotes() {
+        assert_eq!(import_urls(r#"@import "we\"ird";"#), vec![r#"we"ird"#]);
+        assert_eq!(
+            import_urls("@import \"unterminated\n.a {}"),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            import_urls("@imported \"not-a-rule\";"),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn lists_candidates_in_sass_order() {
+        let candidates = sass_candidates("dir/theme")
+            .into_iter()
+            .map(|candidate| (candidate.path, candidate.index))
+            .collect::<Vec<_>>();
+        assert_eq!(candidates[0], ("dir/theme.sass".to_string(), false));
+        assert_eq!(candidates[1], ("dir/_theme.sass".to_string(), false));
+        assert_eq!(candidates[5], ("dir/_theme.css".to_string(), false));
+        assert_eq!(candidates[6], ("dir/theme/index.sass".to_string(), true));
+        assert_eq!(candidates[7], ("dir/theme/_index.sass".to_string(), true));
+        assert_eq!(candidates.len(), 12);
+
+        let candidates = sass_candidates("colors.scss")
+            .into_iter()
+            .map(|candidate| candidate.path)
+            .collect::<Vec<_>>();
+        assert_eq!(candidates, vec!["colors.scss", "_colors.scss"]);
+    }
+
+    #[test]
+    fn normalizes_paths() {
+        assert_eq!(
+            normalize_path(Path::new("styles/./partials/../theme/_index.scss")),
+            PathBuf::from("styles/theme/_index.scss")
+        );
+    }
+}
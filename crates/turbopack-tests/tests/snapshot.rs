@@ -13,7 +13,7 @@ use serde::Deserialize;
 use turbo_tasks::{debug::ValueDebug, NothingVc, TryJoinIterExt, TurboTasks, Value, ValueToString};
 use turbo_tasks_env::DotenvProcessEnvVc;
 use turbo_tasks_fs::{
-    json::parse_json_with_source_context, util::sys_to_unix, DiskFileSystemVc, FileSystem,
+    json::parse_json_with_source_context, util::sys_to_unix, DiskFileSystemVc, File, FileSystem,
     FileSystemPathReadRef, FileSystemPathVc,
 };
 use turbo_tasks_memory::MemoryBackend;
@@ -30,7 +30,7 @@ use turbopack::{
 };
 use turbopack_build::BuildChunkingContextVc;
 use turbopack_core::{
-    asset::{Asset, AssetVc},
+    asset::{Asset, AssetContent, AssetVc, AssetsVc},
     chunk::{
         ChunkableAsset, ChunkableAssetVc, ChunkingContext, ChunkingContextVc, EvaluatableAssetVc,
         EvaluatableAssetsVc,
@@ -42,15 +42,18 @@ use turbopack_core::{
     issue::IssueVc,
     reference::all_referenced_assets,
     reference_type::{EntryReferenceSubType, ReferenceType},
+    resolve::{options::LockedVersionsVc, pnp::find_pnp_root},
     source_asset::SourceAssetVc,
 };
 use turbopack_dev::DevChunkingContextVc;
+use turbopack_dev_server::analyze::{bundle_stats, BundleEntrypointsVc};
 use turbopack_ecmascript_plugins::transform::{
     emotion::{EmotionTransformConfig, EmotionTransformer},
     styled_components::{StyledComponentsTransformConfig, StyledComponentsTransformer},
 };
 use turbopack_ecmascript_runtime::RuntimeType;
-use turbopack_env::ProcessEnvAssetVc;
+use turbopack_env::{ClientEnvVc, ProcessEnvAssetVc};
+use turbopack_html::{HtmlEntryAssetVc, HtmlModuleAssetVc};
 use turbopack_test_utils::snapshot::{diff, expected, matches_expected, snapshot_issues};
 
 fn register() {
@@ -60,6 +63,7 @@ fn register() {
     turbopack::register();
     turbopack_build::register();
     turbopack_dev::register();
+    turbopack_dev_server::register();
     turbopack_env::register();
     turbopack_ecmascript_plugins::register();
     turbopack_ecmascript_runtime::register();
@@ -92,6 +96,23 @@ struct SnapshotOptions {
     runtime_type: RuntimeType,
     #[serde(default)]
     environment: Environment,
+    #[serde(default)]
+    registry: Option<RegistryOptions>,
+    /// Inline the defines and the variables from `input/.env` that start with
+    /// one of these prefixes, and report references to the other variables.
+    #[serde(default)]
+    public_env_prefixes: Vec<String>,
+    /// Write the bundle analysis of the entry to `output/stats.json`.
+    #[serde(default)]
+    bundle_stats: bool,
+}
+
+/// A registry filesystem to resolve packages from, with paths relative to the
+/// test directory.
+#[derive(Debug, Deserialize)]
+struct RegistryOptions {
+    path: String,
+    lockfile: String,
 }
 
 #[derive(Debug, Deserialize, Default)]
@@ -116,6 +137,9 @@ impl Default for SnapshotOptions {
             runtime: Default::default(),
             runtime_type: default_runtime_type(),
             environment: Default::default(),
+            registry: None,
+            public_env_prefixes: vec![],
+            bundle_stats: false,
         }
     }
 }
@@ -178,7 +202,7 @@ async fn run(resource: PathBuf) -> Result<()> {
         .context("Unable to handle issues")?;
         Ok(NothingVc::new().into())
     });
-    tt.wait_task_completion(task, true).await?;
+    tt.wait_task_completion(task.id(), true).await?;
 
     Ok(())
 }
@@ -230,17 +254,24 @@ async fn run_test(resource: &str) -> Result<FileSystemPathVc> {
             )
         }
     }));
-    let compile_time_info = CompileTimeInfo::builder(env)
-        .defines(
-            compile_time_defines!(
-                process.env.NODE_ENV = "development",
-                DEFINED_VALUE = "value",
-                DEFINED_TRUE = true,
-                A.VERY.LONG.DEFINED.VALUE = "value",
-            )
-            .cell(),
-        )
-        .cell();
+    let defines = compile_time_defines!(
+        process.env.NODE_ENV = "development",
+        DEFINED_VALUE = "value",
+        DEFINED_TRUE = true,
+        A.VERY.LONG.DEFINED.VALUE = "value",
+    )
+    .cell();
+    let compile_time_info = if options.public_env_prefixes.is_empty() {
+        CompileTimeInfo::builder(env).defines(defines).cell()
+    } else {
+        let process_env = DotenvProcessEnvVc::new(None, project_path.join("input/.env")).into();
+        let client_env =
+            ClientEnvVc::new(defines, process_env, options.public_env_prefixes.clone()).await?;
+        CompileTimeInfo::builder(env)
+            .defines(client_env.defines)
+            .free_var_references(client_env.free_var_references)
+            .cell()
+    };
 
     let custom_ecma_transform_plugins = Some(CustomEcmascriptTransformPluginsVc::cell(
         CustomEcmascriptTransformPlugins {
@@ -259,6 +290,7 @@ async fn run_test(resource: &str) -> Result<FileSystemPathVc> {
             output_transforms: vec![],
         },
     ));
+    let pnp_root = find_pnp_root(project_path).await?;
     let context: AssetContextVc = ModuleAssetContextVc::new(
         TransitionsByNameVc::cell(HashMap::new()),
         compile_time_info,
@@ -283,11 +315,19 @@ async fn run_test(resource: &str) -> Result<FileSystemPathVc> {
             enable_typescript: true,
             enable_react: true,
             enable_node_modules: Some(project_root),
+            enable_registry: options.registry.as_ref().map(|registry| {
+                (
+                    project_path.join(&registry.path),
+                    LockedVersionsVc::from_package_lock(project_path.join(&registry.lockfile)),
+                )
+            }),
+            enable_pnp: pnp_root,
             custom_conditions: vec!["development".to_string()],
             rules: vec![(
                 ContextCondition::InDirectory("node_modules".to_string()),
                 ResolveOptionsContext {
                     enable_node_modules: Some(project_root),
+                    enable_pnp: pnp_root,
                     custom_conditions: vec!["development".to_string()],
                     ..Default::default()
                 }
@@ -350,6 +390,14 @@ async fn run_test(resource: &str) -> Result<FileSystemPathVc> {
                         .unwrap_or_else(EvaluatableAssetsVc::empty)
                         .with_entry(ecmascript.into()),
                 ))
+            } else if let Some(html) = HtmlModuleAssetVc::resolve_from(module).await? {
+                Ok(AssetsVc::cell(vec![HtmlEntryAssetVc::new(
+                    chunk_root_path.join("index.html"),
+                    html,
+                    chunking_context,
+                    runtime_entries,
+                )
+                .into()]))
             } else if let Some(chunkable) = ChunkableAssetVc::resolve_from(module).await? {
                 Ok(chunking_context.chunk_group(chunkable.as_root_chunk(chunking_context)))
             } else {
@@ -365,12 +413,30 @@ async fn run_test(resource: &str) -> Result<FileSystemPathVc> {
 
     let mut seen = HashSet::new();
     let mut queue = VecDeque::with_capacity(32);
-    for chunks in chunk_groups {
+    for chunks in &chunk_groups {
         for chunk in &*chunks.await? {
             queue.push_back(*chunk);
         }
     }
 
+    if options.bundle_stats {
+        let entrypoints = BundleEntrypointsVc::cell(
+            chunk_groups
+                .iter()
+                .map(|&chunks| (options.entry.clone(), chunks))
+                .collect(),
+        );
+        // Pretty print the stats to make the snapshot readable.
+        let stats: serde_json::Value = serde_json::from_str(&bundle_stats(entrypoints).await?)?;
+        let stats_path = chunk_root_path.join("stats.json").resolve().await?;
+        diff(
+            stats_path,
+            AssetContent::File(File::from(serde_json::to_string_pretty(&stats)?).into()).cell(),
+        )
+        .await?;
+        seen.insert(stats_path);
+    }
+
     let output_path = path.await?;
     while let Some(asset) = queue.pop_front() {
         walk_asset(asset, &output_path, &mut seen, &mut queue)
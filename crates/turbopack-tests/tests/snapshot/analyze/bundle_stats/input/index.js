@@ -0,0 +1,4 @@
+import { log } from "./log";
+
+import("./a").then(({ a }) => log(a));
+import("./b").then(({ b }) => log(b));
@@ -0,0 +1,16 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push(["output/crates_turbopack-tests_tests_snapshot_analyze_bundle_stats_input_b_3b59ac.js", {
+
+"[project]/crates/turbopack-tests/tests/snapshot/analyze/bundle_stats/input/b.js (ecmascript, manifest chunk)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname }) => (() => {
+
+__turbopack_export_value__([
+  {
+    "path": "output/crates_turbopack-tests_tests_snapshot_analyze_bundle_stats_input_b_e4d7a9.js",
+    "included": [
+      "[project]/crates/turbopack-tests/tests/snapshot/analyze/bundle_stats/input/b.js (ecmascript)"
+    ]
+  },
+  "output/crates_turbopack-tests_tests_snapshot_analyze_bundle_stats_input_b_b0ce70.js"
+]);
+
+})()),
+}]);
\ No newline at end of file
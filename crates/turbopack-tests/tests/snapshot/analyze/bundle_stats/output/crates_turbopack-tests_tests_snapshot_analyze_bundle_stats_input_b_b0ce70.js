@@ -0,0 +1,11 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+    "output/crates_turbopack-tests_tests_snapshot_analyze_bundle_stats_input_b_b0ce70.js",
+    {},
+]);
+(globalThis.TURBOPACK_CHUNK_LISTS = globalThis.TURBOPACK_CHUNK_LISTS || []).push({
+  "path": "output/crates_turbopack-tests_tests_snapshot_analyze_bundle_stats_input_b_b0ce70.js",
+  "chunks": [
+    "output/crates_turbopack-tests_tests_snapshot_analyze_bundle_stats_input_b_e4d7a9.js"
+  ],
+  "source": "dynamic"
+});
\ No newline at end of file
@@ -0,0 +1,11 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+    "output/crates_turbopack-tests_tests_snapshot_analyze_bundle_stats_input_b_d77a3b.js",
+    {},
+]);
+(globalThis.TURBOPACK_CHUNK_LISTS = globalThis.TURBOPACK_CHUNK_LISTS || []).push({
+  "path": "output/crates_turbopack-tests_tests_snapshot_analyze_bundle_stats_input_b_d77a3b.js",
+  "chunks": [
+    "output/crates_turbopack-tests_tests_snapshot_analyze_bundle_stats_input_b_3b59ac.js"
+  ],
+  "source": "dynamic"
+});
\ No newline at end of file
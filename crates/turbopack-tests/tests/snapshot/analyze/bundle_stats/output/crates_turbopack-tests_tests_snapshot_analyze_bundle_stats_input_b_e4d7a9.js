@@ -0,0 +1,24 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push(["output/crates_turbopack-tests_tests_snapshot_analyze_bundle_stats_input_b_e4d7a9.js", {
+
+"[project]/crates/turbopack-tests/tests/snapshot/analyze/bundle_stats/input/shared.js (ecmascript)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname, k: __turbopack_refresh__ }) => (() => {
+
+__turbopack_esm__({
+    "shared": ()=>shared
+});
+const shared = "shared";
+
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/analyze/bundle_stats/input/b.js (ecmascript)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname, k: __turbopack_refresh__ }) => (() => {
+
+__turbopack_esm__({
+    "b": ()=>b
+});
+var __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$analyze$2f$bundle_stats$2f$input$2f$shared$2e$js__$28$ecmascript$29$__ = __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/analyze/bundle_stats/input/shared.js (ecmascript)");
+"__TURBOPACK__ecmascript__hoisting__location__";
+;
+const b = `b ${__TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$analyze$2f$bundle_stats$2f$input$2f$shared$2e$js__$28$ecmascript$29$__["shared"]}`;
+
+})()),
+}]);
+
+//# sourceMappingURL=crates_turbopack-tests_tests_snapshot_analyze_bundle_stats_input_b_e4d7a9.js.map
\ No newline at end of file
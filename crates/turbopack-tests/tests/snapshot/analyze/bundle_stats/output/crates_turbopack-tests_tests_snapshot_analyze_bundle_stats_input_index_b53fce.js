@@ -0,0 +1,50 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push(["output/crates_turbopack-tests_tests_snapshot_analyze_bundle_stats_input_index_b53fce.js", {
+
+"[project]/crates/turbopack-tests/tests/snapshot/analyze/bundle_stats/input/b.js (ecmascript, manifest chunk, loader)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname }) => (() => {
+
+__turbopack_export_value__((__turbopack_import__) => {
+    return Promise.all([{"path":"output/crates_turbopack-tests_tests_snapshot_analyze_bundle_stats_input_b_3b59ac.js","included":["[project]/crates/turbopack-tests/tests/snapshot/analyze/bundle_stats/input/b.js (ecmascript, manifest chunk)"]},"output/crates_turbopack-tests_tests_snapshot_analyze_bundle_stats_input_b_d77a3b.js"].map((chunk) => __turbopack_load__(chunk))).then(() => {
+        return __turbopack_require__("[project]/crates/turbopack-tests/tests/snapshot/analyze/bundle_stats/input/b.js (ecmascript, manifest chunk)");
+    }).then((chunks) => {
+        return Promise.all(chunks.map((chunk) => __turbopack_load__(chunk)));
+    }).then(() => {
+        return __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/analyze/bundle_stats/input/b.js (ecmascript)");
+    });
+});
+
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/analyze/bundle_stats/input/a.js (ecmascript, manifest chunk, loader)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname }) => (() => {
+
+__turbopack_export_value__((__turbopack_import__) => {
+    return Promise.all([{"path":"output/crates_turbopack-tests_tests_snapshot_analyze_bundle_stats_input_a_73b8a3.js","included":["[project]/crates/turbopack-tests/tests/snapshot/analyze/bundle_stats/input/a.js (ecmascript, manifest chunk)"]},"output/crates_turbopack-tests_tests_snapshot_analyze_bundle_stats_input_a_d7efc3.js"].map((chunk) => __turbopack_load__(chunk))).then(() => {
+        return __turbopack_require__("[project]/crates/turbopack-tests/tests/snapshot/analyze/bundle_stats/input/a.js (ecmascript, manifest chunk)");
+    }).then((chunks) => {
+        return Promise.all(chunks.map((chunk) => __turbopack_load__(chunk)));
+    }).then(() => {
+        return __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/analyze/bundle_stats/input/a.js (ecmascript)");
+    });
+});
+
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/analyze/bundle_stats/input/log.js (ecmascript)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname, k: __turbopack_refresh__ }) => (() => {
+
+__turbopack_esm__({
+    "log": ()=>log
+});
+function log(value) {
+    console.log(value);
+}
+
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/analyze/bundle_stats/input/index.js (ecmascript)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname, k: __turbopack_refresh__ }) => (() => {
+
+var __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$analyze$2f$bundle_stats$2f$input$2f$log$2e$js__$28$ecmascript$29$__ = __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/analyze/bundle_stats/input/log.js (ecmascript)");
+"__TURBOPACK__ecmascript__hoisting__location__";
+;
+__turbopack_require__("[project]/crates/turbopack-tests/tests/snapshot/analyze/bundle_stats/input/a.js (ecmascript, manifest chunk, loader)")(__turbopack_import__).then(({ a })=>__TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$analyze$2f$bundle_stats$2f$input$2f$log$2e$js__$28$ecmascript$29$__["log"](a));
+__turbopack_require__("[project]/crates/turbopack-tests/tests/snapshot/analyze/bundle_stats/input/b.js (ecmascript, manifest chunk, loader)")(__turbopack_import__).then(({ b })=>__TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$analyze$2f$bundle_stats$2f$input$2f$log$2e$js__$28$ecmascript$29$__["log"](b));
+
+})()),
+}]);
+
+//# sourceMappingURL=crates_turbopack-tests_tests_snapshot_analyze_bundle_stats_input_index_b53fce.js.map
\ No newline at end of file
@@ -0,0 +1,6 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+    "output/crates_turbopack-tests_tests_snapshot_css_preset_env_input_index_59134c.js",
+    {},
+    {"otherChunks":[{"path":"output/crates_turbopack-tests_tests_snapshot_css_preset_env_input_index_b53fce.js","included":["[project]/crates/turbopack-tests/tests/snapshot/css/preset_env/input/index.js (ecmascript)"]},{"path":"output/crates_turbopack-tests_tests_snapshot_css_preset_env_input_style.css","included":["[project]/crates/turbopack-tests/tests/snapshot/css/preset_env/input/style.css (css)"],"moduleChunks":["output/crates_turbopack-tests_tests_snapshot_css_preset_env_input_style_c9a116.css"]}],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/css/preset_env/input/index.js (ecmascript)"]}
+]);
+// Dummy runtime
\ No newline at end of file
@@ -0,0 +1,10 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push(["output/crates_turbopack-tests_tests_snapshot_css_preset_env_input_index_b53fce.js", {
+
+"[project]/crates/turbopack-tests/tests/snapshot/css/preset_env/input/index.js (ecmascript)": (function({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname, k: __turbopack_refresh__, m: module, e: exports }) { !function() {
+
+;
+
+}.call(this) }),
+}]);
+
+//# sourceMappingURL=crates_turbopack-tests_tests_snapshot_css_preset_env_input_index_b53fce.js.map
\ No newline at end of file
@@ -0,0 +1 @@
+import "./style.scss";
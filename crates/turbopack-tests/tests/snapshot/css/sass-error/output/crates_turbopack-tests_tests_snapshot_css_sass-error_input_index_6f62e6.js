@@ -0,0 +1,6 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+    "output/crates_turbopack-tests_tests_snapshot_css_sass-error_input_index_6f62e6.js",
+    {},
+    {"otherChunks":[{"path":"output/crates_turbopack-tests_tests_snapshot_css_sass-error_input_index_b53fce.js","included":["[project]/crates/turbopack-tests/tests/snapshot/css/sass-error/input/index.js (ecmascript)"]},{"path":"output/crates_turbopack-tests_tests_snapshot_css_sass-error_input_style_scss._.css","included":["[project]/crates/turbopack-tests/tests/snapshot/css/sass-error/input/style.scss (css)"],"moduleChunks":["output/crates_turbopack-tests_tests_snapshot_css_sass-error_input_style_scss_c9a116._.css"]}],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/css/sass-error/input/index.js (ecmascript)"]}
+]);
+// Dummy runtime
\ No newline at end of file
@@ -0,0 +1,5 @@
+import "./style.scss";
+import "./indented.sass";
+import style from "./style.module.scss";
+
+console.log(style);
@@ -0,0 +1,6 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+    "output/crates_turbopack-tests_tests_snapshot_css_sass_input_index_1ca84e.js",
+    {},
+    {"otherChunks":[{"path":"output/crates_turbopack-tests_tests_snapshot_css_sass_input_index_b53fce.js","included":["[project]/crates/turbopack-tests/tests/snapshot/css/sass/input/index.js (ecmascript)"]},{"path":"output/crates_turbopack-tests_tests_snapshot_css_sass_input_style_scss._.css","included":["[project]/crates/turbopack-tests/tests/snapshot/css/sass/input/style.scss (css)"],"moduleChunks":["output/crates_turbopack-tests_tests_snapshot_css_sass_input_style_scss_c9a116._.css"]},{"path":"output/crates_turbopack-tests_tests_snapshot_css_sass_input_indented_sass._.css","included":["[project]/crates/turbopack-tests/tests/snapshot/css/sass/input/indented.sass (css)"],"moduleChunks":["output/crates_turbopack-tests_tests_snapshot_css_sass_input_indented_sass_c9a116._.css"]},{"path":"output/crates_turbopack-tests_tests_snapshot_css_sass_input_style_module_scss_b5a149._.css","included":["[project]/crates/turbopack-tests/tests/snapshot/css/sass/input/style.module.scss (css, css module)"],"moduleChunks":["output/crates_turbopack-tests_tests_snapshot_css_sass_input_style_module_scss_fb38f0._.css"]}],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/css/sass/input/index.js (ecmascript)"]}
+]);
+// Dummy runtime
\ No newline at end of file
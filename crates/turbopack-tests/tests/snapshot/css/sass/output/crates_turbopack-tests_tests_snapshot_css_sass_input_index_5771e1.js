@@ -0,0 +1,14 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+    "output/crates_turbopack-tests_tests_snapshot_css_sass_input_index_5771e1.js",
+    {},
+]);
+(globalThis.TURBOPACK_CHUNK_LISTS = globalThis.TURBOPACK_CHUNK_LISTS || []).push({
+  "path": "output/crates_turbopack-tests_tests_snapshot_css_sass_input_index_5771e1.js",
+  "chunks": [
+    "output/crates_turbopack-tests_tests_snapshot_css_sass_input_index_b53fce.js",
+    "output/crates_turbopack-tests_tests_snapshot_css_sass_input_style_scss._.css",
+    "output/crates_turbopack-tests_tests_snapshot_css_sass_input_indented_sass._.css",
+    "output/crates_turbopack-tests_tests_snapshot_css_sass_input_style_module_scss_b5a149._.css"
+  ],
+  "source": "entry"
+});
\ No newline at end of file
@@ -0,0 +1,17 @@
+console.log(process.env.PUBLIC_GREETING);
+console.log(import.meta.env.PUBLIC_GREETING);
+console.log(process.env.SECRET);
+
+// Inside other expressions.
+console.log(`${import.meta.env.PUBLIC_GREETING}, world`);
+console.log(import.meta.env.PUBLIC_GREETING + "!");
+console.log(import.meta.env.PUBLIC_GREETING.toUpperCase());
+console.log(import.meta.env.PUBLIC_MISSING ?? "fallback");
+console.log({ greeting: import.meta.env.PUBLIC_GREETING });
+
+// Shorthand properties keep their name.
+const PUBLIC_GREETING = "local";
+console.log({ PUBLIC_GREETING, DEFINED_VALUE });
+
+// Variables that aren't defined read as `undefined` from `import.meta.env`.
+console.log("PUBLIC_MISSING" in import.meta.env);
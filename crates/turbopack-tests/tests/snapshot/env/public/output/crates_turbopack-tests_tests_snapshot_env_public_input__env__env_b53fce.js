@@ -0,0 +1,14 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push(["output/crates_turbopack-tests_tests_snapshot_env_public_input__env__env_b53fce.js", {
+
+"[project]/crates/turbopack-tests/tests/snapshot/env/public/input/.env/.env.js (ecmascript)": (function({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname, k: __turbopack_refresh__, m: module, e: exports }) { !function() {
+
+const env = process.env = {
+    ...process.env
+};
+env["PUBLIC_GREETING"] = hello;
+env["SECRET"] = secret;
+
+}.call(this) }),
+}]);
+
+//# sourceMappingURL=crates_turbopack-tests_tests_snapshot_env_public_input__env__env_b53fce.js.map
\ No newline at end of file
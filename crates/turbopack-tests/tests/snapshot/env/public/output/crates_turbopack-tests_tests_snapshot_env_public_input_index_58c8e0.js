@@ -0,0 +1,6 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+    "output/crates_turbopack-tests_tests_snapshot_env_public_input_index_58c8e0.js",
+    {},
+    {"otherChunks":[{"path":"output/crates_turbopack-tests_tests_snapshot_env_public_input_index_b53fce.js","included":["[project]/crates/turbopack-tests/tests/snapshot/env/public/input/index.js (ecmascript)"]},{"path":"output/crates_turbopack-tests_tests_snapshot_env_public_input__env__env_b53fce.js","included":["[project]/crates/turbopack-tests/tests/snapshot/env/public/input/.env/.env.js (ecmascript)"]}],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/env/public/input/.env/.env.js (ecmascript)","[project]/crates/turbopack-tests/tests/snapshot/env/public/input/index.js (ecmascript)"]}
+]);
+// Dummy runtime
\ No newline at end of file
@@ -0,0 +1,32 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push(["output/crates_turbopack-tests_tests_snapshot_env_public_input_index_b53fce.js", {
+
+"[project]/crates/turbopack-tests/tests/snapshot/env/public/input/index.js (ecmascript)": (function({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname, k: __turbopack_refresh__, m: module, e: exports }) { !function() {
+
+const __TURBOPACK__import$2e$meta__ = {
+    url: "file:///ROOT/crates/turbopack-tests/tests/snapshot/env/public/input/index.js",
+    env: {
+        "PUBLIC_GREETING": "hello"
+    }
+};
+"__TURBOPACK__ecmascript__hoisting__location__";
+console.log(("TURBOPACK compile-time value", "hello"));
+console.log(("TURBOPACK compile-time value", "hello"));
+console.log(process.env.SECRET);
+console.log(`${("TURBOPACK compile-time value", "hello")}, world`);
+console.log(("TURBOPACK compile-time value", "hello") + "!");
+console.log(("TURBOPACK compile-time value", "hello").toUpperCase());
+console.log(__TURBOPACK__import$2e$meta__.env.PUBLIC_MISSING ?? "fallback");
+console.log({
+    greeting: ("TURBOPACK compile-time value", "hello")
+});
+const PUBLIC_GREETING = "local";
+console.log({
+    PUBLIC_GREETING,
+    DEFINED_VALUE: ("TURBOPACK compile-time value", "value")
+});
+console.log("PUBLIC_MISSING" in __TURBOPACK__import$2e$meta__.env);
+
+}.call(this) }),
+}]);
+
+//# sourceMappingURL=crates_turbopack-tests_tests_snapshot_env_public_input_index_b53fce.js.map
\ No newline at end of file
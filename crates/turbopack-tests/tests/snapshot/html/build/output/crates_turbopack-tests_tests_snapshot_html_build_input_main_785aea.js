@@ -0,0 +1,4 @@
+const CHUNK_PUBLIC_PATH = "output/crates_turbopack-tests_tests_snapshot_html_build_input_main_785aea.js";
+const runtime = require("./[turbopack]_runtime.js");
+runtime.loadChunk("output/crates_turbopack-tests_tests_snapshot_html_build_input_main_e254c5.js");
+runtime.getOrInstantiateRuntimeModule("[project]/crates/turbopack-tests/tests/snapshot/html/build/input/main.js (ecmascript)", CHUNK_PUBLIC_PATH);
\ No newline at end of file
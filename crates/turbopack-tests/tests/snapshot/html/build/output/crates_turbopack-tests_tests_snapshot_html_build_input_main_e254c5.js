@@ -0,0 +1,22 @@
+module.exports = {
+
+"[project]/crates/turbopack-tests/tests/snapshot/html/build/input/message.js (ecmascript)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname }) => (() => {
+
+__turbopack_esm__({
+    "message": ()=>message
+});
+const message = "hello from main.js";
+
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/html/build/input/main.js (ecmascript)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname }) => (() => {
+
+var __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$html$2f$build$2f$input$2f$message$2e$js__$28$ecmascript$29$__ = __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/html/build/input/message.js (ecmascript)");
+"__TURBOPACK__ecmascript__hoisting__location__";
+;
+console.log(__TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$html$2f$build$2f$input$2f$message$2e$js__$28$ecmascript$29$__["message"]);
+
+})()),
+
+};
+
+//# sourceMappingURL=crates_turbopack-tests_tests_snapshot_html_build_input_main_e254c5.js.map
\ No newline at end of file
@@ -0,0 +1,11 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+    "output/crates_turbopack-tests_tests_snapshot_html_page_input_main_5771e1.js",
+    {},
+]);
+(globalThis.TURBOPACK_CHUNK_LISTS = globalThis.TURBOPACK_CHUNK_LISTS || []).push({
+  "path": "output/crates_turbopack-tests_tests_snapshot_html_page_input_main_5771e1.js",
+  "chunks": [
+    "output/crates_turbopack-tests_tests_snapshot_html_page_input_d504d7._.js"
+  ],
+  "source": "entry"
+});
\ No newline at end of file
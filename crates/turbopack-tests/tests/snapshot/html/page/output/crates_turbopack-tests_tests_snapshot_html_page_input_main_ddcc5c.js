@@ -0,0 +1,6 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+    "output/crates_turbopack-tests_tests_snapshot_html_page_input_main_ddcc5c.js",
+    {},
+    {"otherChunks":[{"path":"output/crates_turbopack-tests_tests_snapshot_html_page_input_d504d7._.js","included":["[project]/crates/turbopack-tests/tests/snapshot/html/page/input/other.js (ecmascript)","[project]/crates/turbopack-tests/tests/snapshot/html/page/input/main.js (ecmascript)"]}],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/html/page/input/main.js (ecmascript)","[project]/crates/turbopack-tests/tests/snapshot/html/page/input/other.js (ecmascript)"]}
+]);
+// Dummy runtime
\ No newline at end of file
@@ -0,0 +1,11 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+    "output/da2f1_output_crates_turbopack-tests_tests_snapshot_html_page_input_style_css_5771e1._.js",
+    {},
+]);
+(globalThis.TURBOPACK_CHUNK_LISTS = globalThis.TURBOPACK_CHUNK_LISTS || []).push({
+  "path": "output/da2f1_output_crates_turbopack-tests_tests_snapshot_html_page_input_style_css_5771e1._.js",
+  "chunks": [
+    "output/crates_turbopack-tests_tests_snapshot_html_page_input_style.css"
+  ],
+  "source": "dynamic"
+});
\ No newline at end of file
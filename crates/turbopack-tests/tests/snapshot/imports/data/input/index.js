@@ -0,0 +1,6 @@
+import config, { name } from "./config.yaml";
+import { package as pkg } from "./config.toml";
+import json5 from "./config.json5";
+console.log(config, name, pkg, json5);
+import invalid from "./invalid.yaml";
+console.log(invalid);
@@ -0,0 +1,54 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push(["output/crates_turbopack-tests_tests_snapshot_imports_data_input_index_b53fce.js", {
+
+"[project]/crates/turbopack-tests/tests/snapshot/imports/data/input/invalid.yaml (yaml)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname }) => (() => {
+
+throw new Error("Unable to make a module from invalid YAML: did not find expected key at line 3 column 2, while parsing a block mapping");
+
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/imports/data/input/config.json5 (json5)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname }) => (() => {
+
+const data = JSON.parse("{\"name\":\"data-snapshot\",\"version\":1}");
+__turbopack_esm__({
+    default: () => data,
+    "name": () => data["name"],
+    "version": () => data["version"]
+});
+
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/imports/data/input/config.toml (toml)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname }) => (() => {
+
+const data = JSON.parse("{\"package\":{\"name\":\"data-snapshot\",\"released\":\"2023-05-01\"}}");
+__turbopack_esm__({
+    default: () => data,
+    "package": () => data["package"]
+});
+
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/imports/data/input/config.yaml (yaml)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname }) => (() => {
+
+const data = JSON.parse("{\"name\":\"data-snapshot\",\"features\":[\"yaml\",\"toml\"]}");
+__turbopack_esm__({
+    default: () => data,
+    "name": () => data["name"],
+    "features": () => data["features"]
+});
+
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/imports/data/input/index.js (ecmascript)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname, k: __turbopack_refresh__ }) => (() => {
+
+var __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$imports$2f$data$2f$input$2f$config$2e$yaml__$28$yaml$29$__ = __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/imports/data/input/config.yaml (yaml)");
+var __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$imports$2f$data$2f$input$2f$config$2e$toml__$28$toml$29$__ = __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/imports/data/input/config.toml (toml)");
+var __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$imports$2f$data$2f$input$2f$config$2e$json5__$28$json5$29$__ = __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/imports/data/input/config.json5 (json5)");
+var __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$imports$2f$data$2f$input$2f$invalid$2e$yaml__$28$yaml$29$__ = __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/imports/data/input/invalid.yaml (yaml)");
+"__TURBOPACK__ecmascript__hoisting__location__";
+;
+;
+;
+console.log(__TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$imports$2f$data$2f$input$2f$config$2e$yaml__$28$yaml$29$__["default"], __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$imports$2f$data$2f$input$2f$config$2e$yaml__$28$yaml$29$__["name"], __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$imports$2f$data$2f$input$2f$config$2e$toml__$28$toml$29$__["package"], __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$imports$2f$data$2f$input$2f$config$2e$json5__$28$json5$29$__["default"]);
+;
+console.log(__TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$imports$2f$data$2f$input$2f$invalid$2e$yaml__$28$yaml$29$__["default"]);
+
+})()),
+}]);
+
+//# sourceMappingURL=crates_turbopack-tests_tests_snapshot_imports_data_input_index_b53fce.js.map
\ No newline at end of file
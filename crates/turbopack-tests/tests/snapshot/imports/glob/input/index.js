@@ -0,0 +1,11 @@
+const pages = import.meta.glob(["./pages/**/*.js", "!./pages/**/*.test.js"]);
+const locales = import.meta.glob("./locales/*.json", { eager: true });
+const titles = import.meta.glob("./pages/*.js", { import: "title" });
+const defaults = import.meta.glob("./pages/*.js", { query: { v: 1 }, eager: true, import: "default" });
+const tagged = import.meta.glob("./locales/*.json", { query: { raw: true, tag: "a&b c" } });
+const versioned = import.meta.glob("./locales/*.json", { query: "?v=2" });
+
+for (const [path, load] of Object.entries(pages)) {
+  load().then((page) => console.log(path, page.default));
+}
+console.log(locales, titles, defaults, tagged, versioned);
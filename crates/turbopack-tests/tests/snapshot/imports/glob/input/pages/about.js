@@ -0,0 +1,2 @@
+export const title = "About";
+export default "about";
@@ -0,0 +1,2 @@
+export const title = "Home";
+export default "index";
@@ -0,0 +1,11 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+    "output/79fb1_turbopack-tests_tests_snapshot_imports_glob_input_pages_nested_deep_7b2de6.js",
+    {},
+]);
+(globalThis.TURBOPACK_CHUNK_LISTS = globalThis.TURBOPACK_CHUNK_LISTS || []).push({
+  "path": "output/79fb1_turbopack-tests_tests_snapshot_imports_glob_input_pages_nested_deep_7b2de6.js",
+  "chunks": [
+    "output/79fb1_turbopack-tests_tests_snapshot_imports_glob_input_pages_nested_deep_df528d.js"
+  ],
+  "source": "dynamic"
+});
\ No newline at end of file
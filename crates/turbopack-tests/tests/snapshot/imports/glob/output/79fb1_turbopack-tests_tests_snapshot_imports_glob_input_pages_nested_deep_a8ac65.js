@@ -0,0 +1,16 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push(["output/79fb1_turbopack-tests_tests_snapshot_imports_glob_input_pages_nested_deep_a8ac65.js", {
+
+"[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/nested/deep.js (ecmascript, manifest chunk)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname }) => (() => {
+
+__turbopack_export_value__([
+  {
+    "path": "output/79fb1_turbopack-tests_tests_snapshot_imports_glob_input_pages_nested_deep_df528d.js",
+    "included": [
+      "[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/nested/deep.js (ecmascript)"
+    ]
+  },
+  "output/79fb1_turbopack-tests_tests_snapshot_imports_glob_input_pages_nested_deep_7b2de6.js"
+]);
+
+})()),
+}]);
\ No newline at end of file
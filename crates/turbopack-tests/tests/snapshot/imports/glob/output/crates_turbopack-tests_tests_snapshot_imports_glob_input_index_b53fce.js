@@ -0,0 +1,184 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push(["output/crates_turbopack-tests_tests_snapshot_imports_glob_input_index_b53fce.js", {
+
+"[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/locales/en.json?v=2 (json, manifest chunk, loader)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname }) => (() => {
+
+__turbopack_export_value__((__turbopack_import__) => {
+    return Promise.all([{"path":"output/crates_turbopack-tests_tests_snapshot_imports_glob_input_locales_en_json_592d1a._.js","included":["[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/locales/en.json?v=2 (json, manifest chunk)"]},"output/crates_turbopack-tests_tests_snapshot_imports_glob_input_locales_en_json_355ae7._.js"].map((chunk) => __turbopack_load__(chunk))).then(() => {
+        return __turbopack_require__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/locales/en.json?v=2 (json, manifest chunk)");
+    }).then((chunks) => {
+        return Promise.all(chunks.map((chunk) => __turbopack_load__(chunk)));
+    }).then(() => {
+        return __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/locales/en.json?v=2 (json)");
+    });
+});
+
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/locales/de.json?v=2 (json, manifest chunk, loader)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname }) => (() => {
+
+__turbopack_export_value__((__turbopack_import__) => {
+    return Promise.all([{"path":"output/crates_turbopack-tests_tests_snapshot_imports_glob_input_locales_de_json_103df9._.js","included":["[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/locales/de.json?v=2 (json, manifest chunk)"]},"output/crates_turbopack-tests_tests_snapshot_imports_glob_input_locales_de_json_48cdde._.js"].map((chunk) => __turbopack_load__(chunk))).then(() => {
+        return __turbopack_require__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/locales/de.json?v=2 (json, manifest chunk)");
+    }).then((chunks) => {
+        return Promise.all(chunks.map((chunk) => __turbopack_load__(chunk)));
+    }).then(() => {
+        return __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/locales/de.json?v=2 (json)");
+    });
+});
+
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/locales/en.json?raw&tag=a%26b%20c (json, manifest chunk, loader)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname }) => (() => {
+
+__turbopack_export_value__((__turbopack_import__) => {
+    return Promise.all([{"path":"output/crates_turbopack-tests_tests_snapshot_imports_glob_input_locales_en_json_4d5bff._.js","included":["[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/locales/en.json?raw&tag=a%26b%20c (json, manifest chunk)"]},"output/crates_turbopack-tests_tests_snapshot_imports_glob_input_locales_en_json_5aab91._.js"].map((chunk) => __turbopack_load__(chunk))).then(() => {
+        return __turbopack_require__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/locales/en.json?raw&tag=a%26b%20c (json, manifest chunk)");
+    }).then((chunks) => {
+        return Promise.all(chunks.map((chunk) => __turbopack_load__(chunk)));
+    }).then(() => {
+        return __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/locales/en.json?raw&tag=a%26b%20c (json)");
+    });
+});
+
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/locales/de.json?raw&tag=a%26b%20c (json, manifest chunk, loader)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname }) => (() => {
+
+__turbopack_export_value__((__turbopack_import__) => {
+    return Promise.all([{"path":"output/crates_turbopack-tests_tests_snapshot_imports_glob_input_locales_de_json_c38c19._.js","included":["[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/locales/de.json?raw&tag=a%26b%20c (json, manifest chunk)"]},"output/crates_turbopack-tests_tests_snapshot_imports_glob_input_locales_de_json_d51cb5._.js"].map((chunk) => __turbopack_load__(chunk))).then(() => {
+        return __turbopack_require__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/locales/de.json?raw&tag=a%26b%20c (json, manifest chunk)");
+    }).then((chunks) => {
+        return Promise.all(chunks.map((chunk) => __turbopack_load__(chunk)));
+    }).then(() => {
+        return __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/locales/de.json?raw&tag=a%26b%20c (json)");
+    });
+});
+
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/index.js?v=1 (ecmascript)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname, k: __turbopack_refresh__ }) => (() => {
+
+__turbopack_esm__({
+    "default": ()=>__TURBOPACK__default__export__,
+    "title": ()=>title
+});
+const title = "Home";
+const __TURBOPACK__default__export__ = "index";
+
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/about.test.js?v=1 (ecmascript)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname, k: __turbopack_refresh__ }) => (() => {
+
+__turbopack_esm__({
+    "default": ()=>__TURBOPACK__default__export__
+});
+const __TURBOPACK__default__export__ = "excluded from pages";
+
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/about.js?v=1 (ecmascript)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname, k: __turbopack_refresh__ }) => (() => {
+
+__turbopack_esm__({
+    "default": ()=>__TURBOPACK__default__export__,
+    "title": ()=>title
+});
+const title = "About";
+const __TURBOPACK__default__export__ = "about";
+
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/index.js (ecmascript, manifest chunk, loader)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname }) => (() => {
+
+__turbopack_export_value__((__turbopack_import__) => {
+    return Promise.all([{"path":"output/crates_turbopack-tests_tests_snapshot_imports_glob_input_pages_index_1a7cad.js","included":["[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/index.js (ecmascript, manifest chunk)"]},"output/crates_turbopack-tests_tests_snapshot_imports_glob_input_pages_index_cebbb7.js"].map((chunk) => __turbopack_load__(chunk))).then(() => {
+        return __turbopack_require__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/index.js (ecmascript, manifest chunk)");
+    }).then((chunks) => {
+        return Promise.all(chunks.map((chunk) => __turbopack_load__(chunk)));
+    }).then(() => {
+        return __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/index.js (ecmascript)");
+    });
+});
+
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/about.test.js (ecmascript, manifest chunk, loader)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname }) => (() => {
+
+__turbopack_export_value__((__turbopack_import__) => {
+    return Promise.all([{"path":"output/crates_turbopack-tests_tests_snapshot_imports_glob_input_pages_about_test_b16605.js","included":["[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/about.test.js (ecmascript, manifest chunk)"]},"output/crates_turbopack-tests_tests_snapshot_imports_glob_input_pages_about_test_22d705.js"].map((chunk) => __turbopack_load__(chunk))).then(() => {
+        return __turbopack_require__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/about.test.js (ecmascript, manifest chunk)");
+    }).then((chunks) => {
+        return Promise.all(chunks.map((chunk) => __turbopack_load__(chunk)));
+    }).then(() => {
+        return __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/about.test.js (ecmascript)");
+    });
+});
+
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/about.js (ecmascript, manifest chunk, loader)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname }) => (() => {
+
+__turbopack_export_value__((__turbopack_import__) => {
+    return Promise.all([{"path":"output/crates_turbopack-tests_tests_snapshot_imports_glob_input_pages_about_68b12c.js","included":["[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/about.js (ecmascript, manifest chunk)"]},"output/crates_turbopack-tests_tests_snapshot_imports_glob_input_pages_about_65c2ab.js"].map((chunk) => __turbopack_load__(chunk))).then(() => {
+        return __turbopack_require__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/about.js (ecmascript, manifest chunk)");
+    }).then((chunks) => {
+        return Promise.all(chunks.map((chunk) => __turbopack_load__(chunk)));
+    }).then(() => {
+        return __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/about.js (ecmascript)");
+    });
+});
+
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/locales/en.json (json)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname }) => (() => {
+
+__turbopack_export_value__(JSON.parse("{\"hello\":\"Hello\"}"));
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/locales/de.json (json)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname }) => (() => {
+
+__turbopack_export_value__(JSON.parse("{\"hello\":\"Hallo\"}"));
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/nested/deep.js (ecmascript, manifest chunk, loader)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname }) => (() => {
+
+__turbopack_export_value__((__turbopack_import__) => {
+    return Promise.all([{"path":"output/79fb1_turbopack-tests_tests_snapshot_imports_glob_input_pages_nested_deep_a8ac65.js","included":["[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/nested/deep.js (ecmascript, manifest chunk)"]},"output/79fb1_turbopack-tests_tests_snapshot_imports_glob_input_pages_nested_deep_8237d7.js"].map((chunk) => __turbopack_load__(chunk))).then(() => {
+        return __turbopack_require__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/nested/deep.js (ecmascript, manifest chunk)");
+    }).then((chunks) => {
+        return Promise.all(chunks.map((chunk) => __turbopack_load__(chunk)));
+    }).then(() => {
+        return __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/nested/deep.js (ecmascript)");
+    });
+});
+
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/index.js (ecmascript)": (function({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname, k: __turbopack_refresh__, m: module, e: exports }) { !function() {
+
+const __TURBOPACK__import$2e$meta__ = {
+    url: "file:///ROOT/crates/turbopack-tests/tests/snapshot/imports/glob/input/index.js"
+};
+"__TURBOPACK__ecmascript__hoisting__location__";
+const pages = {
+    "./pages/about.js": ()=>__turbopack_require__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/about.js (ecmascript, manifest chunk, loader)")(__turbopack_import__),
+    "./pages/index.js": ()=>__turbopack_require__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/index.js (ecmascript, manifest chunk, loader)")(__turbopack_import__),
+    "./pages/nested/deep.js": ()=>__turbopack_require__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/nested/deep.js (ecmascript, manifest chunk, loader)")(__turbopack_import__)
+};
+const locales = {
+    "./locales/de.json": __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/locales/de.json (json)"),
+    "./locales/en.json": __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/locales/en.json (json)")
+};
+const titles = {
+    "./pages/about.js": ()=>__turbopack_require__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/about.js (ecmascript, manifest chunk, loader)")(__turbopack_import__).then((m)=>m["title"]),
+    "./pages/about.test.js": ()=>__turbopack_require__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/about.test.js (ecmascript, manifest chunk, loader)")(__turbopack_import__).then((m)=>m["title"]),
+    "./pages/index.js": ()=>__turbopack_require__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/index.js (ecmascript, manifest chunk, loader)")(__turbopack_import__).then((m)=>m["title"])
+};
+const defaults = {
+    "./pages/about.js": __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/about.js?v=1 (ecmascript)")["default"],
+    "./pages/about.test.js": __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/about.test.js?v=1 (ecmascript)")["default"],
+    "./pages/index.js": __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/pages/index.js?v=1 (ecmascript)")["default"]
+};
+const tagged = {
+    "./locales/de.json": ()=>__turbopack_require__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/locales/de.json?raw&tag=a%26b%20c (json, manifest chunk, loader)")(__turbopack_import__),
+    "./locales/en.json": ()=>__turbopack_require__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/locales/en.json?raw&tag=a%26b%20c (json, manifest chunk, loader)")(__turbopack_import__)
+};
+const versioned = {
+    "./locales/de.json": ()=>__turbopack_require__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/locales/de.json?v=2 (json, manifest chunk, loader)")(__turbopack_import__),
+    "./locales/en.json": ()=>__turbopack_require__("[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/locales/en.json?v=2 (json, manifest chunk, loader)")(__turbopack_import__)
+};
+for (const [path, load] of Object.entries(pages)){
+    load().then((page)=>console.log(path, page.default));
+}
+console.log(locales, titles, defaults, tagged, versioned);
+
+}.call(this) }),
+}]);
+
+//# sourceMappingURL=crates_turbopack-tests_tests_snapshot_imports_glob_input_index_b53fce.js.map
\ No newline at end of file
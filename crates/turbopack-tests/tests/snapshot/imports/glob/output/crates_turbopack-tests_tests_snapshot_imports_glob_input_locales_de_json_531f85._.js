@@ -0,0 +1,11 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+    "output/crates_turbopack-tests_tests_snapshot_imports_glob_input_locales_de_json_531f85._.js",
+    {},
+]);
+(globalThis.TURBOPACK_CHUNK_LISTS = globalThis.TURBOPACK_CHUNK_LISTS || []).push({
+  "path": "output/crates_turbopack-tests_tests_snapshot_imports_glob_input_locales_de_json_531f85._.js",
+  "chunks": [
+    "output/crates_turbopack-tests_tests_snapshot_imports_glob_input_locales_de_json_eb2a41._.js"
+  ],
+  "source": "dynamic"
+});
\ No newline at end of file
@@ -0,0 +1,11 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+    "output/crates_turbopack-tests_tests_snapshot_imports_glob_input_locales_de_json_d51cb5._.js",
+    {},
+]);
+(globalThis.TURBOPACK_CHUNK_LISTS = globalThis.TURBOPACK_CHUNK_LISTS || []).push({
+  "path": "output/crates_turbopack-tests_tests_snapshot_imports_glob_input_locales_de_json_d51cb5._.js",
+  "chunks": [
+    "output/crates_turbopack-tests_tests_snapshot_imports_glob_input_locales_de_json_c38c19._.js"
+  ],
+  "source": "dynamic"
+});
\ No newline at end of file
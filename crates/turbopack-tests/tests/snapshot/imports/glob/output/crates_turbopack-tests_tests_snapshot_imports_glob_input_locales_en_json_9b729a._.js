@@ -0,0 +1,7 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push(["output/crates_turbopack-tests_tests_snapshot_imports_glob_input_locales_en_json_9b729a._.js", {
+
+"[project]/crates/turbopack-tests/tests/snapshot/imports/glob/input/locales/en.json?v=2 (json)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname }) => (() => {
+
+__turbopack_export_value__(JSON.parse("{\"hello\":\"Hello\"}"));
+})()),
+}]);
\ No newline at end of file
@@ -0,0 +1,9 @@
+import { add, "add-numbers" as addNumbers } from "./add.wasm";
+import addModule from "./add.wasm?module";
+import addUrl from "./add.wasm?url";
+
+// The exports of the instance are available as soon as the module runs.
+console.log(add(1, 2), addNumbers(3, 4), addModule, addUrl);
+
+// Dynamic imports resolve once the instance has been created.
+import("./add.wasm").then(({ add }) => console.log(add(5, 6)));
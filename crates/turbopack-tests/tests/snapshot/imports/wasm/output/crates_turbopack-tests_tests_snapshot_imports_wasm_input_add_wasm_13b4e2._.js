@@ -0,0 +1,16 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push(["output/crates_turbopack-tests_tests_snapshot_imports_wasm_input_add_wasm_13b4e2._.js", {
+
+"[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm (wasm, manifest chunk)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname }) => (() => {
+
+__turbopack_export_value__([
+  {
+    "path": "output/crates_turbopack-tests_tests_snapshot_imports_wasm_input_add_wasm_e13b7c._.js",
+    "included": [
+      "[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm.loader.mjs/(WASM_PATH)/[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm (static) (ecmascript)"
+    ]
+  },
+  "output/crates_turbopack-tests_tests_snapshot_imports_wasm_input_add_wasm_2f3c86._.js"
+]);
+
+})()),
+}]);
\ No newline at end of file
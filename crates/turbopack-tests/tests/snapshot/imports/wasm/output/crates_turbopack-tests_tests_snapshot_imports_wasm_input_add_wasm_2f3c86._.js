@@ -0,0 +1,11 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+    "output/crates_turbopack-tests_tests_snapshot_imports_wasm_input_add_wasm_2f3c86._.js",
+    {},
+]);
+(globalThis.TURBOPACK_CHUNK_LISTS = globalThis.TURBOPACK_CHUNK_LISTS || []).push({
+  "path": "output/crates_turbopack-tests_tests_snapshot_imports_wasm_input_add_wasm_2f3c86._.js",
+  "chunks": [
+    "output/crates_turbopack-tests_tests_snapshot_imports_wasm_input_add_wasm_e13b7c._.js"
+  ],
+  "source": "dynamic"
+});
\ No newline at end of file
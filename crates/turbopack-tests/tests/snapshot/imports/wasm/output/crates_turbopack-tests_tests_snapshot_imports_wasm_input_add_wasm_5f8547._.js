@@ -0,0 +1,11 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+    "output/crates_turbopack-tests_tests_snapshot_imports_wasm_input_add_wasm_5f8547._.js",
+    {},
+]);
+(globalThis.TURBOPACK_CHUNK_LISTS = globalThis.TURBOPACK_CHUNK_LISTS || []).push({
+  "path": "output/crates_turbopack-tests_tests_snapshot_imports_wasm_input_add_wasm_5f8547._.js",
+  "chunks": [
+    "output/crates_turbopack-tests_tests_snapshot_imports_wasm_input_add_wasm_13b4e2._.js"
+  ],
+  "source": "dynamic"
+});
\ No newline at end of file
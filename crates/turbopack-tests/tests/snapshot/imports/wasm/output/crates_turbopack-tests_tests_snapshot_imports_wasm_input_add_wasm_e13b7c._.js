@@ -0,0 +1,24 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push(["output/crates_turbopack-tests_tests_snapshot_imports_wasm_input_add_wasm_e13b7c._.js", {
+
+"[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm.loader.mjs/(WASM_PATH)/[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm (static) (ecmascript)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname, k: __turbopack_refresh__, w: __turbopack_wasm__, u: __turbopack_wasm_module__, a: __turbopack_async_module__ }) => (() => {
+
+__turbopack_async_module__(async (__turbopack_handle_async_dependencies__)=>{
+    __turbopack_esm__({
+        "add": ()=>__turbopack_wasm_export_0__,
+        "add-numbers": ()=>__turbopack_wasm_export_1__
+    });
+    var __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$imports$2f$wasm$2f$input$2f$add$2e$wasm__$28$static$29$__ = __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm (static)");
+    var __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$imports$2f$wasm$2f$input$2f$env$2e$js__$28$ecmascript$29$__ = __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/env.js (ecmascript)");
+    "__TURBOPACK__ecmascript__hoisting__location__";
+    ;
+    ;
+    const { "add": __turbopack_wasm_export_0__, "add-numbers": __turbopack_wasm_export_1__ } = await __turbopack_wasm__(__TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$imports$2f$wasm$2f$input$2f$add$2e$wasm__$28$static$29$__["default"], {
+        "./env.js": __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$imports$2f$wasm$2f$input$2f$env$2e$js__$28$ecmascript$29$__
+    });
+    ;
+});
+
+})()),
+}]);
+
+//# sourceMappingURL=crates_turbopack-tests_tests_snapshot_imports_wasm_input_add_wasm_e13b7c._.js.map
\ No newline at end of file
@@ -0,0 +1,90 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push(["output/crates_turbopack-tests_tests_snapshot_imports_wasm_input_index_b53fce.js", {
+
+"[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm (wasm, manifest chunk, loader)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname }) => (() => {
+
+__turbopack_export_value__((__turbopack_import__) => {
+    return Promise.all([{"path":"output/crates_turbopack-tests_tests_snapshot_imports_wasm_input_add_wasm_13b4e2._.js","included":["[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm (wasm, manifest chunk)"]},"output/crates_turbopack-tests_tests_snapshot_imports_wasm_input_add_wasm_5f8547._.js"].map((chunk) => __turbopack_load__(chunk))).then(() => {
+        return __turbopack_require__("[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm (wasm, manifest chunk)");
+    }).then((chunks) => {
+        return Promise.all(chunks.map((chunk) => __turbopack_load__(chunk)));
+    }).then(() => {
+        return __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm.loader.mjs/(WASM_PATH)/[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm (static) (ecmascript)");
+    }).then((namespace) => {
+        return __turbopack_cache__["[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm.loader.mjs/(WASM_PATH)/[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm (static) (ecmascript)"].promise.then(() => namespace);
+    });
+});
+
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm?url (static)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname }) => (() => {
+
+__turbopack_export_value__("/crates/turbopack-tests/tests/snapshot/imports/wasm/static/add.e110498a.wasm");
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm?module (static)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname }) => (() => {
+
+__turbopack_export_value__("/crates/turbopack-tests/tests/snapshot/imports/wasm/static/add.e110498a.wasm");
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm.module.mjs/(WASM_PATH)/[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm?module (static) (ecmascript)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname, k: __turbopack_refresh__, w: __turbopack_wasm__, u: __turbopack_wasm_module__ }) => (() => {
+
+__turbopack_esm__({
+    "default": ()=>__TURBOPACK__default__export__
+});
+var __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$imports$2f$wasm$2f$input$2f$add$2e$wasm$3f$module__$28$static$29$__ = __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm?module (static)");
+"__TURBOPACK__ecmascript__hoisting__location__";
+;
+const __TURBOPACK__default__export__ = __turbopack_wasm_module__(__TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$imports$2f$wasm$2f$input$2f$add$2e$wasm$3f$module__$28$static$29$__["default"]);
+
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/env.js (ecmascript)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname, k: __turbopack_refresh__ }) => (() => {
+
+__turbopack_esm__({
+    "log": ()=>log
+});
+function log(value) {
+    console.log(value);
+}
+
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm (static)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname }) => (() => {
+
+__turbopack_export_value__("/crates/turbopack-tests/tests/snapshot/imports/wasm/static/add.e110498a.wasm");
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm.loader.mjs/(WASM_PATH)/[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm (static) (ecmascript)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname, k: __turbopack_refresh__, w: __turbopack_wasm__, u: __turbopack_wasm_module__, a: __turbopack_async_module__ }) => (() => {
+
+__turbopack_async_module__(async (__turbopack_handle_async_dependencies__)=>{
+    __turbopack_esm__({
+        "add": ()=>__turbopack_wasm_export_0__,
+        "add-numbers": ()=>__turbopack_wasm_export_1__
+    });
+    var __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$imports$2f$wasm$2f$input$2f$add$2e$wasm__$28$static$29$__ = __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm (static)");
+    var __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$imports$2f$wasm$2f$input$2f$env$2e$js__$28$ecmascript$29$__ = __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/env.js (ecmascript)");
+    "__TURBOPACK__ecmascript__hoisting__location__";
+    ;
+    ;
+    const { "add": __turbopack_wasm_export_0__, "add-numbers": __turbopack_wasm_export_1__ } = await __turbopack_wasm__(__TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$imports$2f$wasm$2f$input$2f$add$2e$wasm__$28$static$29$__["default"], {
+        "./env.js": __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$imports$2f$wasm$2f$input$2f$env$2e$js__$28$ecmascript$29$__
+    });
+    ;
+});
+
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/index.js (ecmascript)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname, k: __turbopack_refresh__, a: __turbopack_async_module__ }) => (() => {
+
+__turbopack_async_module__(async (__turbopack_handle_async_dependencies__)=>{
+    var __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$imports$2f$wasm$2f$input$2f$add$2e$wasm__$28$wasm$29$__ = __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm.loader.mjs/(WASM_PATH)/[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm (static) (ecmascript)");
+    var __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$imports$2f$wasm$2f$input$2f$add$2e$wasm$3f$module__$28$wasm$29$__ = __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm.module.mjs/(WASM_PATH)/[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm?module (static) (ecmascript)");
+    var __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$imports$2f$wasm$2f$input$2f$add$2e$wasm$3f$url__$28$static$29$__ = __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm?url (static)");
+    "__TURBOPACK__ecmascript__hoisting__location__";
+    await __turbopack_handle_async_dependencies__([
+        "[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm.loader.mjs/(WASM_PATH)/[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm (static) (ecmascript)"
+    ]);
+    ;
+    ;
+    ;
+    console.log(__TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$imports$2f$wasm$2f$input$2f$add$2e$wasm__$28$wasm$29$__["add"](1, 2), __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$imports$2f$wasm$2f$input$2f$add$2e$wasm__$28$wasm$29$__["add-numbers"](3, 4), __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$imports$2f$wasm$2f$input$2f$add$2e$wasm$3f$module__$28$wasm$29$__["default"], __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$imports$2f$wasm$2f$input$2f$add$2e$wasm$3f$url__$28$static$29$__["default"]);
+    __turbopack_require__("[project]/crates/turbopack-tests/tests/snapshot/imports/wasm/input/add.wasm (wasm, manifest chunk, loader)")(__turbopack_import__).then(({ add })=>console.log(add(5, 6)));
+});
+
+})()),
+}]);
+
+//# sourceMappingURL=crates_turbopack-tests_tests_snapshot_imports_wasm_input_index_b53fce.js.map
\ No newline at end of file
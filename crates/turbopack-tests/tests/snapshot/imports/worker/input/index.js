@@ -0,0 +1,7 @@
+const worker = new Worker(new URL("./worker.js", import.meta.url), {
+  type: "module",
+});
+worker.postMessage("ping");
+
+const sharedWorker = new SharedWorker(new URL("./shared-worker.js", import.meta.url));
+sharedWorker.port.postMessage("ping");
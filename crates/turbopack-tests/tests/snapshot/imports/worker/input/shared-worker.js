@@ -0,0 +1,8 @@
+import { reply } from "./reply.js";
+
+self.onconnect = (event) => {
+  const port = event.ports[0];
+  port.onmessage = (event) => {
+    port.postMessage(reply(event.data));
+  };
+};
@@ -0,0 +1,5 @@
+import { reply } from "./reply.js";
+
+self.onmessage = (event) => {
+  self.postMessage(reply(event.data));
+};
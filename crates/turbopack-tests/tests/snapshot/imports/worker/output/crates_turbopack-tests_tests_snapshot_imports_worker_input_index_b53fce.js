@@ -0,0 +1,29 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push(["output/crates_turbopack-tests_tests_snapshot_imports_worker_input_index_b53fce.js", {
+
+"[project]/crates/turbopack-tests/tests/snapshot/imports/worker/input/shared-worker.js (ecmascript, worker loader)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname }) => (() => {
+
+__turbopack_export_value__("/output/crates_turbopack-tests_tests_snapshot_imports_worker_input_shared-worker_78061e.js");
+
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/imports/worker/input/worker.js (ecmascript, worker loader)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname }) => (() => {
+
+__turbopack_export_value__("/output/crates_turbopack-tests_tests_snapshot_imports_worker_input_worker_f77149.js");
+
+})()),
+"[project]/crates/turbopack-tests/tests/snapshot/imports/worker/input/index.js (ecmascript)": (function({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname, k: __turbopack_refresh__, m: module, e: exports }) { !function() {
+
+const __TURBOPACK__import$2e$meta__ = {
+    url: "file:///ROOT/crates/turbopack-tests/tests/snapshot/imports/worker/input/index.js"
+};
+"__TURBOPACK__ecmascript__hoisting__location__";
+const worker = new Worker(new URL(__turbopack_require__("[project]/crates/turbopack-tests/tests/snapshot/imports/worker/input/worker.js (ecmascript, worker loader)"), location.origin), {
+    type: "module"
+});
+worker.postMessage("ping");
+const sharedWorker = new SharedWorker(new URL(__turbopack_require__("[project]/crates/turbopack-tests/tests/snapshot/imports/worker/input/shared-worker.js (ecmascript, worker loader)"), location.origin));
+sharedWorker.port.postMessage("ping");
+
+}.call(this) }),
+}]);
+
+//# sourceMappingURL=crates_turbopack-tests_tests_snapshot_imports_worker_input_index_b53fce.js.map
\ No newline at end of file
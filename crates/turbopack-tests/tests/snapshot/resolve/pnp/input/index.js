@@ -0,0 +1,6 @@
+import foo from "foo";
+import bar from "bar/sub";
+import dep from "dep";
+import pnpapi from "pnpapi";
+
+console.log(foo, bar, dep, pnpapi);
@@ -0,0 +1,16 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push(["output/59ea9_foo_main_26da23.js", {
+
+"[./.yarn/cache/foo-npm-1.0.0-0a1b2c.zip]/node_modules/foo/main.js (ecmascript)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname, k: __turbopack_refresh__ }) => (() => {
+
+__turbopack_esm__({
+    "default": ()=>__TURBOPACK__default__export__
+});
+var __TURBOPACK__imported__module__$5b2e2f2e$yarn$2f$cache$2f$dep$2d$npm$2d$2$2e$0$2e$0$2d$6a7b8c$2e$zip$5d2f$node_modules$2f$dep$2f$index$2e$js__$28$ecmascript$29$__ = __turbopack_import__("[./.yarn/cache/dep-npm-2.0.0-6a7b8c.zip]/node_modules/dep/index.js (ecmascript)");
+"__TURBOPACK__ecmascript__hoisting__location__";
+;
+const __TURBOPACK__default__export__ = `foo 1.0.0 with ${__TURBOPACK__imported__module__$5b2e2f2e$yarn$2f$cache$2f$dep$2d$npm$2d$2$2e$0$2e$0$2d$6a7b8c$2e$zip$5d2f$node_modules$2f$dep$2f$index$2e$js__$28$ecmascript$29$__["default"]}`;
+
+})()),
+}]);
+
+//# sourceMappingURL=59ea9_foo_main_26da23.js.map
\ No newline at end of file
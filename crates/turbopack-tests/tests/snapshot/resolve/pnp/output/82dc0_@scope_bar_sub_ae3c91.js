@@ -0,0 +1,13 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push(["output/82dc0_@scope_bar_sub_ae3c91.js", {
+
+"[./.yarn/cache/@scope-bar-npm-2.0.0-3d4e5f.zip]/node_modules/@scope/bar/sub.js (ecmascript)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname, k: __turbopack_refresh__ }) => (() => {
+
+__turbopack_esm__({
+    "default": ()=>__TURBOPACK__default__export__
+});
+const __TURBOPACK__default__export__ = "@scope/bar 2.0.0";
+
+})()),
+}]);
+
+//# sourceMappingURL=82dc0_@scope_bar_sub_ae3c91.js.map
\ No newline at end of file
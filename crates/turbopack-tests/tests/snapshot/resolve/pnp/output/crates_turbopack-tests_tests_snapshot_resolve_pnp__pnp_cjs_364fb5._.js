@@ -0,0 +1,161 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push(["output/crates_turbopack-tests_tests_snapshot_resolve_pnp__pnp_cjs_364fb5._.js", {
+
+"[project]/crates/turbopack-tests/tests/snapshot/resolve/pnp/.pnp.cjs (ecmascript)": (function({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname, k: __turbopack_refresh__, m: module, e: exports }) { !function() {
+
+"use strict";
+const RAW_RUNTIME_STATE = '{\
+  "__info": [\
+    "This file is automatically generated. Do not touch it."\
+  ],\
+  "dependencyTreeRoots": [\
+    {\
+      "name": "pnp",\
+      "reference": "workspace:."\
+    }\
+  ],\
+  "enableTopLevelFallback": true,\
+  "ignorePatternData": null,\
+  "fallbackExclusionList": [],\
+  "fallbackPool": [],\
+  "packageRegistryData": [\
+    [\
+      null,\
+      [\
+        [\
+          null,\
+          {\
+            "packageLocation": "./",\
+            "packageDependencies": [\
+              [\
+                "bar",\
+                [\
+                  "@scope/bar",\
+                  "npm:2.0.0"\
+                ]\
+              ],\
+              [\
+                "dep",\
+                "npm:1.0.0"\
+              ],\
+              [\
+                "foo",\
+                "npm:1.0.0"\
+              ]\
+            ],\
+            "linkType": "SOFT"\
+          }\
+        ]\
+      ]\
+    ],\
+    [\
+      "@scope/bar",\
+      [\
+        [\
+          "npm:2.0.0",\
+          {\
+            "packageLocation": "./.yarn/cache/@scope-bar-npm-2.0.0-3d4e5f.zip/node_modules/@scope/bar/",\
+            "packageDependencies": [\
+              [\
+                "@scope/bar",\
+                "npm:2.0.0"\
+              ]\
+            ],\
+            "linkType": "HARD"\
+          }\
+        ]\
+      ]\
+    ],\
+    [\
+      "dep",\
+      [\
+        [\
+          "npm:1.0.0",\
+          {\
+            "packageLocation": "./.yarn/unplugged/dep-npm-1.0.0/node_modules/dep/",\
+            "packageDependencies": [\
+              [\
+                "dep",\
+                "npm:1.0.0"\
+              ]\
+            ],\
+            "linkType": "HARD"\
+          }\
+        ],\
+        [\
+          "npm:2.0.0",\
+          {\
+            "packageLocation": "./.yarn/cache/dep-npm-2.0.0-6a7b8c.zip/node_modules/dep/",\
+            "packageDependencies": [\
+              [\
+                "dep",\
+                "npm:2.0.0"\
+              ]\
+            ],\
+            "linkType": "HARD"\
+          }\
+        ]\
+      ]\
+    ],\
+    [\
+      "foo",\
+      [\
+        [\
+          "npm:1.0.0",\
+          {\
+            "packageLocation": "./.yarn/cache/foo-npm-1.0.0-0a1b2c.zip/node_modules/foo/",\
+            "packageDependencies": [\
+              [\
+                "dep",\
+                "npm:2.0.0"\
+              ],\
+              [\
+                "foo",\
+                "npm:1.0.0"\
+              ]\
+            ],\
+            "linkType": "HARD"\
+          }\
+        ]\
+      ]\
+    ],\
+    [\
+      "pnp",\
+      [\
+        [\
+          "workspace:.",\
+          {\
+            "packageLocation": "./",\
+            "packageDependencies": [\
+              [\
+                "bar",\
+                [\
+                  "@scope/bar",\
+                  "npm:2.0.0"\
+                ]\
+              ],\
+              [\
+                "dep",\
+                "npm:1.0.0"\
+              ],\
+              [\
+                "foo",\
+                "npm:1.0.0"\
+              ]\
+            ],\
+            "linkType": "SOFT"\
+          }\
+        ]\
+      ]\
+    ]\
+  ]\
+}';
+function $$SETUP_STATE(hydrateRuntimeState, basePath) {
+    return hydrateRuntimeState(JSON.parse(RAW_RUNTIME_STATE), {
+        basePath: basePath || __dirname
+    });
+}
+
+}.call(this) }),
+}]);
+
+//# sourceMappingURL=crates_turbopack-tests_tests_snapshot_resolve_pnp__pnp_cjs_364fb5._.js.map
\ No newline at end of file
@@ -0,0 +1,16 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+    "output/crates_turbopack-tests_tests_snapshot_resolve_pnp_input_index_5771e1.js",
+    {},
+]);
+(globalThis.TURBOPACK_CHUNK_LISTS = globalThis.TURBOPACK_CHUNK_LISTS || []).push({
+  "path": "output/crates_turbopack-tests_tests_snapshot_resolve_pnp_input_index_5771e1.js",
+  "chunks": [
+    "output/08058_dep_index_b94c8b.js",
+    "output/59ea9_foo_main_26da23.js",
+    "output/82dc0_@scope_bar_sub_ae3c91.js",
+    "output/crates_turbopack-tests_tests_snapshot_resolve_pnp__pnp_cjs_364fb5._.js",
+    "output/crates_turbopack-tests_tests_snapshot_resolve_pnp_input_index_b53fce.js",
+    "output/d474e_dep_index_94e5a1.js"
+  ],
+  "source": "entry"
+});
\ No newline at end of file
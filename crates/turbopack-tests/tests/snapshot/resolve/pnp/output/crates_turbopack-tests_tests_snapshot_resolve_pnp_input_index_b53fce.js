@@ -0,0 +1,19 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push(["output/crates_turbopack-tests_tests_snapshot_resolve_pnp_input_index_b53fce.js", {
+
+"[project]/crates/turbopack-tests/tests/snapshot/resolve/pnp/input/index.js (ecmascript)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname, k: __turbopack_refresh__ }) => (() => {
+
+var __TURBOPACK__imported__module__$5b2e2f2e$yarn$2f$cache$2f$foo$2d$npm$2d$1$2e$0$2e$0$2d$0a1b2c$2e$zip$5d2f$node_modules$2f$foo$2f$main$2e$js__$28$ecmascript$29$__ = __turbopack_import__("[./.yarn/cache/foo-npm-1.0.0-0a1b2c.zip]/node_modules/foo/main.js (ecmascript)");
+var __TURBOPACK__imported__module__$5b2e2f2e$yarn$2f$cache$2f40$scope$2d$bar$2d$npm$2d$2$2e$0$2e$0$2d$3d4e5f$2e$zip$5d2f$node_modules$2f40$scope$2f$bar$2f$sub$2e$js__$28$ecmascript$29$__ = __turbopack_import__("[./.yarn/cache/@scope-bar-npm-2.0.0-3d4e5f.zip]/node_modules/@scope/bar/sub.js (ecmascript)");
+var __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$resolve$2f$pnp$2f2e$yarn$2f$unplugged$2f$dep$2d$npm$2d$1$2e$0$2e$0$2f$node_modules$2f$dep$2f$index$2e$js__$28$ecmascript$29$__ = __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/resolve/pnp/.yarn/unplugged/dep-npm-1.0.0/node_modules/dep/index.js (ecmascript)");
+var __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$resolve$2f$pnp$2f2e$pnp$2e$cjs__$28$ecmascript$29$__ = __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/resolve/pnp/.pnp.cjs (ecmascript)");
+"__TURBOPACK__ecmascript__hoisting__location__";
+;
+;
+;
+;
+console.log(__TURBOPACK__imported__module__$5b2e2f2e$yarn$2f$cache$2f$foo$2d$npm$2d$1$2e$0$2e$0$2d$0a1b2c$2e$zip$5d2f$node_modules$2f$foo$2f$main$2e$js__$28$ecmascript$29$__["default"], __TURBOPACK__imported__module__$5b2e2f2e$yarn$2f$cache$2f40$scope$2d$bar$2d$npm$2d$2$2e$0$2e$0$2d$3d4e5f$2e$zip$5d2f$node_modules$2f40$scope$2f$bar$2f$sub$2e$js__$28$ecmascript$29$__["default"], __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$resolve$2f$pnp$2f2e$yarn$2f$unplugged$2f$dep$2d$npm$2d$1$2e$0$2e$0$2f$node_modules$2f$dep$2f$index$2e$js__$28$ecmascript$29$__["default"], __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$resolve$2f$pnp$2f2e$pnp$2e$cjs__$28$ecmascript$29$__["default"]);
+
+})()),
+}]);
+
+//# sourceMappingURL=crates_turbopack-tests_tests_snapshot_resolve_pnp_input_index_b53fce.js.map
\ No newline at end of file
@@ -0,0 +1,6 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+    "output/crates_turbopack-tests_tests_snapshot_resolve_pnp_input_index_d019af.js",
+    {},
+    {"otherChunks":[{"path":"output/08058_dep_index_b94c8b.js","included":["[./.yarn/cache/dep-npm-2.0.0-6a7b8c.zip]/node_modules/dep/index.js (ecmascript)"]},{"path":"output/59ea9_foo_main_26da23.js","included":["[./.yarn/cache/foo-npm-1.0.0-0a1b2c.zip]/node_modules/foo/main.js (ecmascript)"]},{"path":"output/82dc0_@scope_bar_sub_ae3c91.js","included":["[./.yarn/cache/@scope-bar-npm-2.0.0-3d4e5f.zip]/node_modules/@scope/bar/sub.js (ecmascript)"]},{"path":"output/crates_turbopack-tests_tests_snapshot_resolve_pnp__pnp_cjs_364fb5._.js","included":["[project]/crates/turbopack-tests/tests/snapshot/resolve/pnp/.pnp.cjs (ecmascript)"]},{"path":"output/crates_turbopack-tests_tests_snapshot_resolve_pnp_input_index_b53fce.js","included":["[project]/crates/turbopack-tests/tests/snapshot/resolve/pnp/input/index.js (ecmascript)"]},{"path":"output/d474e_dep_index_94e5a1.js","included":["[project]/crates/turbopack-tests/tests/snapshot/resolve/pnp/.yarn/unplugged/dep-npm-1.0.0/node_modules/dep/index.js (ecmascript)"]}],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/resolve/pnp/input/index.js (ecmascript)"]}
+]);
+// Dummy runtime
\ No newline at end of file
@@ -0,0 +1,13 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push(["output/d474e_dep_index_94e5a1.js", {
+
+"[project]/crates/turbopack-tests/tests/snapshot/resolve/pnp/.yarn/unplugged/dep-npm-1.0.0/node_modules/dep/index.js (ecmascript)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname, k: __turbopack_refresh__ }) => (() => {
+
+__turbopack_esm__({
+    "default": ()=>__TURBOPACK__default__export__
+});
+const __TURBOPACK__default__export__ = "dep 1.0.0";
+
+})()),
+}]);
+
+//# sourceMappingURL=d474e_dep_index_94e5a1.js.map
\ No newline at end of file
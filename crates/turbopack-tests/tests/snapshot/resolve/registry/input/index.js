@@ -0,0 +1,5 @@
+import foo from "foo";
+import sub from "@scope/bar/sub";
+import dep from "dep";
+
+console.log(foo, sub, dep);
@@ -0,0 +1,16 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push(["output/79fb1_turbopack-tests_tests_snapshot_resolve_registry_registry_foo_1_0_0_main_ee4b5f.js", {
+
+"[project]/crates/turbopack-tests/tests/snapshot/resolve/registry/registry/foo/1.0.0/main.js (ecmascript)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname, k: __turbopack_refresh__ }) => (() => {
+
+__turbopack_esm__({
+    "default": ()=>__TURBOPACK__default__export__
+});
+var __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$resolve$2f$registry$2f$registry$2f$dep$2f$2$2e$0$2e$0$2f$index$2e$js__$28$ecmascript$29$__ = __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/resolve/registry/registry/dep/2.0.0/index.js (ecmascript)");
+"__TURBOPACK__ecmascript__hoisting__location__";
+;
+const __TURBOPACK__default__export__ = `foo 1.0.0 with ${__TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$resolve$2f$registry$2f$registry$2f$dep$2f$2$2e$0$2e$0$2f$index$2e$js__$28$ecmascript$29$__["default"]}`;
+
+})()),
+}]);
+
+//# sourceMappingURL=79fb1_turbopack-tests_tests_snapshot_resolve_registry_registry_foo_1_0_0_main_ee4b5f.js.map
\ No newline at end of file
@@ -0,0 +1,13 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push(["output/a587c_tests_snapshot_resolve_registry_registry_@scope_bar_2_0_0_lib_sub_2c2b9b.js", {
+
+"[project]/crates/turbopack-tests/tests/snapshot/resolve/registry/registry/@scope/bar/2.0.0/lib/sub.js (ecmascript)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname, k: __turbopack_refresh__ }) => (() => {
+
+__turbopack_esm__({
+    "default": ()=>__TURBOPACK__default__export__
+});
+const __TURBOPACK__default__export__ = "@scope/bar 2.0.0 sub";
+
+})()),
+}]);
+
+//# sourceMappingURL=a587c_tests_snapshot_resolve_registry_registry_@scope_bar_2_0_0_lib_sub_2c2b9b.js.map
\ No newline at end of file
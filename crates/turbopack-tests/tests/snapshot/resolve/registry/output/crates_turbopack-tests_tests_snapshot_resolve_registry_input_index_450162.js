@@ -0,0 +1,6 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+    "output/crates_turbopack-tests_tests_snapshot_resolve_registry_input_index_450162.js",
+    {},
+    {"otherChunks":[{"path":"output/crates_turbopack-tests_tests_snapshot_resolve_registry_input_index_b53fce.js","included":["[project]/crates/turbopack-tests/tests/snapshot/resolve/registry/input/index.js (ecmascript)"]},{"path":"output/79fb1_turbopack-tests_tests_snapshot_resolve_registry_registry_dep_2_0_0_index_e323e2.js","included":["[project]/crates/turbopack-tests/tests/snapshot/resolve/registry/registry/dep/2.0.0/index.js (ecmascript)"]},{"path":"output/79fb1_turbopack-tests_tests_snapshot_resolve_registry_registry_dep_1_0_0_index_35225c.js","included":["[project]/crates/turbopack-tests/tests/snapshot/resolve/registry/registry/dep/1.0.0/index.js (ecmascript)"]},{"path":"output/79fb1_turbopack-tests_tests_snapshot_resolve_registry_registry_foo_1_0_0_main_ee4b5f.js","included":["[project]/crates/turbopack-tests/tests/snapshot/resolve/registry/registry/foo/1.0.0/main.js (ecmascript)"]},{"path":"output/a587c_tests_snapshot_resolve_registry_registry_@scope_bar_2_0_0_lib_sub_2c2b9b.js","included":["[project]/crates/turbopack-tests/tests/snapshot/resolve/registry/registry/@scope/bar/2.0.0/lib/sub.js (ecmascript)"]}],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/resolve/registry/input/index.js (ecmascript)"]}
+]);
+// Dummy runtime
\ No newline at end of file
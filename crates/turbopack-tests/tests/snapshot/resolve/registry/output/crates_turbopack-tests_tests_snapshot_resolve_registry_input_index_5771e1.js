@@ -0,0 +1,15 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+    "output/crates_turbopack-tests_tests_snapshot_resolve_registry_input_index_5771e1.js",
+    {},
+]);
+(globalThis.TURBOPACK_CHUNK_LISTS = globalThis.TURBOPACK_CHUNK_LISTS || []).push({
+  "path": "output/crates_turbopack-tests_tests_snapshot_resolve_registry_input_index_5771e1.js",
+  "chunks": [
+    "output/crates_turbopack-tests_tests_snapshot_resolve_registry_input_index_b53fce.js",
+    "output/79fb1_turbopack-tests_tests_snapshot_resolve_registry_registry_dep_2_0_0_index_e323e2.js",
+    "output/79fb1_turbopack-tests_tests_snapshot_resolve_registry_registry_dep_1_0_0_index_35225c.js",
+    "output/79fb1_turbopack-tests_tests_snapshot_resolve_registry_registry_foo_1_0_0_main_ee4b5f.js",
+    "output/a587c_tests_snapshot_resolve_registry_registry_@scope_bar_2_0_0_lib_sub_2c2b9b.js"
+  ],
+  "source": "entry"
+});
\ No newline at end of file
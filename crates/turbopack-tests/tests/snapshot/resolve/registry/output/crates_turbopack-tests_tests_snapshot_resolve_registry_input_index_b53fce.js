@@ -0,0 +1,17 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push(["output/crates_turbopack-tests_tests_snapshot_resolve_registry_input_index_b53fce.js", {
+
+"[project]/crates/turbopack-tests/tests/snapshot/resolve/registry/input/index.js (ecmascript)": (({ r: __turbopack_require__, f: __turbopack_require_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, l: __turbopack_load__, j: __turbopack_cjs__, g: global, __dirname, k: __turbopack_refresh__ }) => (() => {
+
+var __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$resolve$2f$registry$2f$registry$2f$foo$2f$1$2e$0$2e$0$2f$main$2e$js__$28$ecmascript$29$__ = __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/resolve/registry/registry/foo/1.0.0/main.js (ecmascript)");
+var __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$resolve$2f$registry$2f$registry$2f40$scope$2f$bar$2f$2$2e$0$2e$0$2f$lib$2f$sub$2e$js__$28$ecmascript$29$__ = __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/resolve/registry/registry/@scope/bar/2.0.0/lib/sub.js (ecmascript)");
+var __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$resolve$2f$registry$2f$registry$2f$dep$2f$1$2e$0$2e$0$2f$index$2e$js__$28$ecmascript$29$__ = __turbopack_import__("[project]/crates/turbopack-tests/tests/snapshot/resolve/registry/registry/dep/1.0.0/index.js (ecmascript)");
+"__TURBOPACK__ecmascript__hoisting__location__";
+;
+;
+;
+console.log(__TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$resolve$2f$registry$2f$registry$2f$foo$2f$1$2e$0$2e$0$2f$main$2e$js__$28$ecmascript$29$__["default"], __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$resolve$2f$registry$2f$registry$2f40$scope$2f$bar$2f$2$2e$0$2e$0$2f$lib$2f$sub$2e$js__$28$ecmascript$29$__["default"], __TURBOPACK__imported__module__$5b$project$5d2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$resolve$2f$registry$2f$registry$2f$dep$2f$1$2e$0$2e$0$2f$index$2e$js__$28$ecmascript$29$__["default"]);
+
+})()),
+}]);
+
+//# sourceMappingURL=crates_turbopack-tests_tests_snapshot_resolve_registry_input_index_b53fce.js.map
\ No newline at end of file
@@ -0,0 +1 @@
+export default "@scope/bar 2.0.0 sub";
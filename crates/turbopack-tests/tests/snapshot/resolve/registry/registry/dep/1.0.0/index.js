@@ -0,0 +1 @@
+export default "dep 1.0.0";
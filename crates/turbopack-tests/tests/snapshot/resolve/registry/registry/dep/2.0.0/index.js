@@ -0,0 +1 @@
+export default "dep 2.0.0";
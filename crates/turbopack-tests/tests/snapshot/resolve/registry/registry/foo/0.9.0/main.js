@@ -0,0 +1 @@
+export default "foo 0.9.0";
@@ -0,0 +1,3 @@
+import dep from "dep";
+
+export default `foo 1.0.0 with ${dep}`;
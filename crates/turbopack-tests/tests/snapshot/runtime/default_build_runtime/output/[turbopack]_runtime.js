@@ -71,6 +71,15 @@ function esmImport(sourceModule, id) {
     interopEsm(raw, ns, raw.__esModule);
     return ns;
 }
+async function handleAsyncDependencies(ids) {
+    await Promise.all(ids.map((id)=>moduleCache[id]?.promise));
+}
+function asyncModule(module, body) {
+    module.promise = body(handleAsyncDependencies).catch((error)=>{
+        module.error = error;
+        throw error;
+    });
+}
 function commonJsRequire(sourceModule, id) {
     const module = getOrInstantiateModuleFromParent(id, sourceModule);
     if (module.error) throw module.error;
@@ -156,6 +165,19 @@ function loadChunkAsync(source, chunkPath) {
         resolve();
     });
 }
+function readWebAssemblyAsset(wasmPath) {
+    const fs = require("fs");
+    return fs.readFileSync(path.resolve(RUNTIME_ROOT, wasmPath.slice(1)));
+}
+async function loadWebAssembly(wasmPath, imports) {
+    const { WebAssembly } = globalThis;
+    const { instance } = await WebAssembly.instantiate(readWebAssemblyAsset(wasmPath), imports);
+    return instance.exports;
+}
+async function loadWebAssemblyModule(wasmPath) {
+    const { WebAssembly } = globalThis;
+    return WebAssembly.compile(readWebAssemblyAsset(wasmPath));
+}
 function instantiateModule(id, source) {
     const moduleFactory = moduleFactories[id];
     if (typeof moduleFactory !== "function") {
@@ -208,6 +230,9 @@ function instantiateModule(id, source) {
                 type: SourceType.Parent,
                 parentId: id
             }),
+            w: loadWebAssembly,
+            u: loadWebAssemblyModule,
+            a: asyncModule.bind(null, module1),
             g: globalThis,
             __dirname: module1.id.replace(/(^|\/)[\/]+$/, "")
         });
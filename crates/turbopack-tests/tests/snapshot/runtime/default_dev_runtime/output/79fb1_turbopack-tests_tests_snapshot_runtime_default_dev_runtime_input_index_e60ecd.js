@@ -79,6 +79,15 @@ function esmImport(sourceModule, id) {
     interopEsm(raw, ns, raw.__esModule);
     return ns;
 }
+async function handleAsyncDependencies(ids) {
+    await Promise.all(ids.map((id)=>moduleCache[id]?.promise));
+}
+function asyncModule(module, body) {
+    module.promise = body(handleAsyncDependencies).catch((error)=>{
+        module.error = error;
+        throw error;
+    });
+}
 function commonJsRequire(sourceModule, id) {
     const module = getOrInstantiateModuleFromParent(id, sourceModule);
     if (module.error) throw module.error;
@@ -265,6 +274,15 @@ function instantiateModule(id, source) {
                     type: SourceType.Parent,
                     parentId: id
                 }),
+                w: loadWebAssembly.bind(null, {
+                    type: SourceType.Parent,
+                    parentId: id
+                }),
+                u: loadWebAssemblyModule.bind(null, {
+                    type: SourceType.Parent,
+                    parentId: id
+                }),
+                a: asyncModule.bind(null, module),
                 g: globalThis,
                 k: refresh,
                 __dirname: module.id.replace(/(^|\/)\/+$/, "")
@@ -280,6 +298,12 @@ function instantiateModule(id, source) {
     }
     return module;
 }
+function loadWebAssembly(source, wasmPath, imports) {
+    return BACKEND.loadWebAssembly(source, wasmPath, imports);
+}
+function loadWebAssemblyModule(source, wasmPath) {
+    return BACKEND.loadWebAssemblyModule(source, wasmPath);
+}
 function runModuleExecutionHooks(module, executeModule) {
     const cleanupReactRefreshIntercept = typeof globalThis.$RefreshInterceptModuleExecution$ === "function" ? globalThis.$RefreshInterceptModuleExecution$(module.id) : ()=>{};
     try {
@@ -980,8 +1004,16 @@ function commonJsRequireContext(entry1, sourceModule1) {
                 previousLink1.parentElement.insertBefore(link1, previousLink1.nextSibling);
             });
         },
+        async loadWebAssembly (_source1, wasmPath1, imports1) {
+            const { instance: instance1 } = await WebAssembly.instantiateStreaming(fetch(wasmPath1), imports1);
+            return instance1.exports;
+        },
+        loadWebAssemblyModule (_source1, wasmPath1) {
+            return WebAssembly.compileStreaming(fetch(wasmPath1));
+        },
         restart: ()=>self.location.reload()
     };
+    const IS_WORKER1 = typeof document === "undefined";
     const chunkResolvers1 = new Map();
     function getOrCreateResolver1(chunkPath1) {
         let resolver1 = chunkResolvers1.get(chunkPath1);
@@ -1013,6 +1045,10 @@ function commonJsRequireContext(entry1, sourceModule1) {
         if (resolver1.resolved) {
             return resolver1.promise;
         }
+        if (IS_WORKER1) {
+            loadChunkInWorker1(chunkPath1, resolver1);
+            return resolver1.promise;
+        }
         if (source1.type === SourceType.Runtime) {
             if (chunkPath1.endsWith(".css")) {
                 resolver1.resolve();
@@ -1024,7 +1060,7 @@ function commonJsRequireContext(entry1, sourceModule1) {
             link1.rel = "stylesheet";
             link1.href = `/${chunkPath1}`;
             link1.onerror = ()=>{
-                resolver1.reject();
+                resolver1.reject(new Error(`Failed to load CSS chunk ${chunkPath1}`));
             };
             link1.onload = ()=>{
                 resolver1.resolve();
@@ -1034,7 +1070,7 @@ function commonJsRequireContext(entry1, sourceModule1) {
             const script1 = document.createElement("script");
             script1.src = `/${chunkPath1}`;
             script1.onerror = ()=>{
-                resolver1.reject();
+                resolver1.reject(new Error(`Failed to load chunk ${chunkPath1}`));
             };
             document.body.appendChild(script1);
         } else {
@@ -1042,6 +1078,25 @@ function commonJsRequireContext(entry1, sourceModule1) {
         }
         return resolver1.promise;
     }
+    function loadChunkInWorker1(chunkPath1, resolver1) {
+        if (chunkPath1.endsWith(".css")) {
+            resolver1.resolve();
+            return;
+        }
+        if (!chunkPath1.endsWith(".js")) {
+            throw new Error(`can't infer type of chunk from path ${chunkPath1}`);
+        }
+        const url1 = `/${chunkPath1}`;
+        try {
+            importScripts(url1);
+        } catch (_1) {
+            fetch(url1).then((res1)=>res1.text()).then((code1)=>{
+                (0, eval)(`${code1}\n\n//# sourceURL=${location.origin}${url1}`);
+            }).catch((error1)=>{
+                resolver1.reject(error1);
+            });
+        }
+    }
 })();
 function _eval({ code, url, map }) {
     code += `\n\n//# sourceURL=${location.origin}${url}`;
@@ -0,0 +1,53 @@
+use anyhow::{bail, Context, Result};
+use indexmap::IndexMap;
+use turbo_tasks_fs::FileContent;
+use turbopack_core::asset::{Asset, AssetVc};
+use wasmparser::{Parser, Payload};
+
+/// The imports and exports of a WebAssembly binary, as declared by its import
+/// and export sections.
+#[turbo_tasks::value]
+#[derive(Debug)]
+pub(crate) struct WebAssemblyAnalysis {
+    /// Imported names, grouped by the module they are imported from.
+    pub imports: IndexMap<String, Vec<String>>,
+    /// Exported names, in declaration order.
+    pub exports: Vec<String>,
+}
+
+#[turbo_tasks::function]
+pub(crate) async fn analyze(source: AssetVc) -> Result<WebAssemblyAnalysisVc> {
+    let content = source.content().file_content().await?;
+    let FileContent::Content(file) = &*content else {
+        bail!("WebAssembly file not found");
+    };
+    let bytes = file.content().to_bytes()?;
+
+    let mut analysis = WebAssemblyAnalysis {
+        imports: IndexMap::new(),
+        exports: Vec::new(),
+    };
+
+    for payload in Parser::new(0).parse_all(&bytes) {
+        match payload.context("Unable to parse WebAssembly binary")? {
+            Payload::ImportSection(reader) => {
+                for import in reader {
+                    let import = import?;
+                    analysis
+                        .imports
+                        .entry(import.module.to_string())
+                        .or_default()
+                        .push(import.name.to_string());
+                }
+            }
+            Payload::ExportSection(reader) => {
+                for export in reader {
+                    analysis.exports.push(export?.name.to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(analysis.cell())
+}
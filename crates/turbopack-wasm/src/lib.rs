@@ -0,0 +1,240 @@
+//! WebAssembly support for turbopack.
+//!
+//! WebAssembly modules are parsed to find their imports and exports. When
+//! imported from ES modules, they are wrapped in a thin loader module that
+//! imports the WebAssembly imports as ES modules, instantiates the binary and
+//! re-exports its exports, following the WebAssembly ESM integration proposal.
+//!
+//! The binary itself is emitted as a static asset. It is instantiated with
+//! `WebAssembly.instantiateStreaming` in the browser and read from the file
+//! system in Node.js.
+
+#![feature(min_specialization)]
+
+pub(crate) mod analysis;
+pub(crate) mod loader;
+
+use anyhow::{bail, Result};
+use indexmap::indexmap;
+use turbo_tasks::{
+    primitives::{BoolVc, StringVc},
+    Value,
+};
+use turbopack_core::{
+    asset::{Asset, AssetContentVc, AssetVc},
+    chunk::{
+        availability_info::AvailabilityInfo, ChunkItem, ChunkItemVc, ChunkVc, ChunkableAsset,
+        ChunkableAssetVc, ChunkingContextVc,
+    },
+    context::{AssetContext, AssetContextVc},
+    ident::AssetIdentVc,
+    reference::AssetReferencesVc,
+    reference_type::{InnerAssetsVc, ReferenceType},
+};
+use turbopack_ecmascript::chunk::{
+    EcmascriptChunkItem, EcmascriptChunkItemContent, EcmascriptChunkItemContentVc,
+    EcmascriptChunkItemOptions, EcmascriptChunkItemVc, EcmascriptChunkPlaceable,
+    EcmascriptChunkPlaceableVc, EcmascriptChunkVc, EcmascriptChunkingContextVc,
+    EcmascriptExportsVc,
+};
+use turbopack_static::StaticModuleAssetVc;
+
+use crate::loader::{compiling_loader_source, instantiating_loader_source};
+
+/// How a WebAssembly binary is exposed to the importing module.
+#[turbo_tasks::value(serialization = "auto_for_input")]
+#[derive(PartialOrd, Ord, Hash, Debug, Copy, Clone)]
+pub enum WebAssemblySourceType {
+    /// The binary is instantiated and its exports are re-exported as named
+    /// exports.
+    Instantiated,
+    /// The default export is a promise of the compiled `WebAssembly.Module`
+    /// (`?module` imports).
+    Module,
+}
+
+#[turbo_tasks::function]
+fn modifier() -> StringVc {
+    StringVc::cell("wasm".to_string())
+}
+
+#[turbo_tasks::value]
+#[derive(Clone)]
+pub struct WebAssemblyModuleAsset {
+    source: AssetVc,
+    context: AssetContextVc,
+    source_type: WebAssemblySourceType,
+}
+
+#[turbo_tasks::value_impl]
+impl WebAssemblyModuleAssetVc {
+    #[turbo_tasks::function]
+    pub fn new(
+        source: AssetVc,
+        context: AssetContextVc,
+        source_type: Value<WebAssemblySourceType>,
+    ) -> Self {
+        Self::cell(WebAssemblyModuleAsset {
+            source,
+            context,
+            source_type: source_type.into_value(),
+        })
+    }
+
+    /// The ES module that loads the WebAssembly binary. All chunking and
+    /// export information is delegated to it.
+    #[turbo_tasks::function]
+    async fn loader(self) -> Result<EcmascriptChunkPlaceableVc> {
+        let this = self.await?;
+
+        let loader_source = match this.source_type {
+            WebAssemblySourceType::Instantiated => instantiating_loader_source(this.source),
+            WebAssemblySourceType::Module => compiling_loader_source(this.source),
+        };
+
+        let module = this.context.process(
+            loader_source,
+            Value::new(ReferenceType::Internal(InnerAssetsVc::cell(indexmap! {
+                "WASM_PATH".to_string() => StaticModuleAssetVc::new(this.source, this.context).into(),
+            }))),
+        );
+
+        let Some(module) = EcmascriptChunkPlaceableVc::resolve_from(module).await? else {
+            bail!("WebAssembly loader must be a chunk placeable module");
+        };
+
+        Ok(module)
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Asset for WebAssemblyModuleAsset {
+    #[turbo_tasks::function]
+    fn ident(&self) -> AssetIdentVc {
+        self.source.ident().with_modifier(modifier())
+    }
+
+    #[turbo_tasks::function]
+    fn content(&self) -> AssetContentVc {
+        self.source.content()
+    }
+
+    #[turbo_tasks::function]
+    fn references(self_vc: WebAssemblyModuleAssetVc) -> AssetReferencesVc {
+        self_vc.loader().references()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ChunkableAsset for WebAssemblyModuleAsset {
+    #[turbo_tasks::function]
+    fn as_chunk(
+        self_vc: WebAssemblyModuleAssetVc,
+        context: ChunkingContextVc,
+        availability_info: Value<AvailabilityInfo>,
+    ) -> ChunkVc {
+        EcmascriptChunkVc::new(
+            context,
+            self_vc.as_ecmascript_chunk_placeable(),
+            availability_info,
+        )
+        .into()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl EcmascriptChunkPlaceable for WebAssemblyModuleAsset {
+    #[turbo_tasks::function]
+    fn as_chunk_item(
+        self_vc: WebAssemblyModuleAssetVc,
+        context: EcmascriptChunkingContextVc,
+    ) -> EcmascriptChunkItemVc {
+        ModuleChunkItem {
+            loader_item: self_vc.loader().as_chunk_item(context),
+            context,
+        }
+        .cell()
+        .into()
+    }
+
+    #[turbo_tasks::function]
+    fn get_exports(self_vc: WebAssemblyModuleAssetVc) -> EcmascriptExportsVc {
+        self_vc.loader().get_exports()
+    }
+
+    #[turbo_tasks::function]
+    fn has_top_level_await(self_vc: WebAssemblyModuleAssetVc) -> BoolVc {
+        self_vc.loader().has_top_level_await()
+    }
+}
+
+/// The chunk item of the loader module, with the WebAssembly runtime functions
+/// passed to its module factory.
+#[turbo_tasks::value]
+struct ModuleChunkItem {
+    loader_item: EcmascriptChunkItemVc,
+    context: EcmascriptChunkingContextVc,
+}
+
+#[turbo_tasks::value_impl]
+impl ChunkItem for ModuleChunkItem {
+    #[turbo_tasks::function]
+    fn asset_ident(&self) -> AssetIdentVc {
+        self.loader_item.asset_ident()
+    }
+
+    #[turbo_tasks::function]
+    fn references(&self) -> AssetReferencesVc {
+        self.loader_item.references()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl EcmascriptChunkItem for ModuleChunkItem {
+    #[turbo_tasks::function]
+    fn chunking_context(&self) -> EcmascriptChunkingContextVc {
+        self.context
+    }
+
+    #[turbo_tasks::function]
+    fn content(&self) -> EcmascriptChunkItemContentVc {
+        with_wasm_runtime(self.loader_item.content())
+    }
+
+    #[turbo_tasks::function]
+    fn content_with_availability_info(
+        &self,
+        availability_info: Value<AvailabilityInfo>,
+    ) -> EcmascriptChunkItemContentVc {
+        with_wasm_runtime(
+            self.loader_item
+                .content_with_availability_info(availability_info),
+        )
+    }
+}
+
+#[turbo_tasks::function]
+async fn with_wasm_runtime(
+    content: EcmascriptChunkItemContentVc,
+) -> Result<EcmascriptChunkItemContentVc> {
+    let content = content.await?;
+    Ok(EcmascriptChunkItemContent {
+        inner_code: content.inner_code.clone(),
+        source_map: content.source_map,
+        options: EcmascriptChunkItemOptions {
+            wasm: true,
+            ..content.options.clone()
+        },
+        ..Default::default()
+    }
+    .cell())
+}
+
+pub fn register() {
+    turbo_tasks::register();
+    turbo_tasks_fs::register();
+    turbopack_core::register();
+    turbopack_ecmascript::register();
+    turbopack_static::register();
+    include!(concat!(env!("OUT_DIR"), "/register.rs"));
+}
@@ -0,0 +1,118 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+use indoc::formatdoc;
+use turbo_tasks_fs::File;
+use turbopack_core::{
+    asset::{Asset, AssetVc},
+    virtual_asset::VirtualAssetVc,
+};
+use turbopack_ecmascript::utils::StringifyJs;
+
+use crate::{analysis::analyze, WebAssemblySourceType};
+
+/// Create a javascript loader to instantiate the WebAssembly module with the
+/// imports resolved as ES module references and re-export its exports.
+///
+/// The loader waits for the instantiation with a top-level `await`, which makes
+/// it an async module that its importers wait for.
+///
+/// The WebAssembly binary itself is referenced through the `WASM_PATH` inner
+/// asset.
+#[turbo_tasks::function]
+pub(crate) async fn instantiating_loader_source(source: AssetVc) -> Result<AssetVc> {
+    let analysis = analyze(source).await?;
+
+    let mut code = String::new();
+    writeln!(code, "import wasmPath from \"WASM_PATH\";")?;
+
+    let mut imports_obj = "{".to_string();
+    for (i, module) in analysis.imports.keys().enumerate() {
+        writeln!(
+            code,
+            "import * as __turbopack_wasm_import_{i}__ from {};",
+            StringifyJs(module)
+        )?;
+        write!(
+            imports_obj,
+            "\n    {}: __turbopack_wasm_import_{i}__,",
+            StringifyJs(module)
+        )?;
+    }
+    imports_obj.push_str("\n}");
+
+    writeln!(code)?;
+    if !analysis.exports.is_empty() {
+        write!(code, "const {{")?;
+        for (i, name) in analysis.exports.iter().enumerate() {
+            write!(
+                code,
+                "\n    {}: __turbopack_wasm_export_{i}__,",
+                StringifyJs(name)
+            )?;
+        }
+        write!(code, "\n}} = ")?;
+    }
+    writeln!(code, "await __turbopack_wasm__(wasmPath, {imports_obj});")?;
+
+    if !analysis.exports.is_empty() {
+        writeln!(code)?;
+        writeln!(code, "export {{")?;
+        for (i, name) in analysis.exports.iter().enumerate() {
+            // Export names that are not identifiers are exported as string
+            // literals.
+            if is_identifier_name(name) {
+                writeln!(code, "    __turbopack_wasm_export_{i}__ as {name},")?;
+            } else {
+                writeln!(
+                    code,
+                    "    __turbopack_wasm_export_{i}__ as {},",
+                    StringifyJs(name)
+                )?;
+            }
+        }
+        writeln!(code, "}};")?;
+    }
+
+    Ok(loader_asset(
+        source,
+        WebAssemblySourceType::Instantiated,
+        code,
+    ))
+}
+
+/// Create a javascript loader that exports a promise of the compiled (but not
+/// instantiated) WebAssembly module as its default export.
+#[turbo_tasks::function]
+pub(crate) fn compiling_loader_source(source: AssetVc) -> AssetVc {
+    let code = formatdoc! {
+        r#"
+            import wasmPath from "WASM_PATH";
+
+            export default __turbopack_wasm_module__(wasmPath);
+        "#
+    };
+
+    loader_asset(source, WebAssemblySourceType::Module, code)
+}
+
+fn loader_asset(source: AssetVc, source_type: WebAssemblySourceType, code: String) -> AssetVc {
+    // The loader lives next to the WebAssembly binary so that relative imports
+    // of the binary resolve from the same directory.
+    let extension = match source_type {
+        WebAssemblySourceType::Instantiated => ".loader.mjs",
+        WebAssemblySourceType::Module => ".module.mjs",
+    };
+    VirtualAssetVc::new(
+        source.ident().path().append(extension),
+        File::from(code).into(),
+    )
+    .into()
+}
+
+/// Whether `name` can be used as an export name without quoting.
+fn is_identifier_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
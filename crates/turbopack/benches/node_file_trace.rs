@@ -105,7 +105,7 @@ fn bench_emit(b: &mut Bencher, bench_input: &BenchInput) {
 
                 Ok(NothingVc::new().into())
             });
-            tt.wait_task_completion(task, true).await.unwrap();
+            tt.wait_task_completion(task.id(), true).await.unwrap();
         }
     })
 }
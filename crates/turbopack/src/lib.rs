@@ -53,13 +53,15 @@ pub mod rebase;
 pub mod resolve;
 pub mod resolve_options_context;
 pub mod transition;
-pub(crate) mod unsupported_sass;
 
 pub use turbopack_css as css;
 pub use turbopack_ecmascript as ecmascript;
-use turbopack_json::JsonModuleAssetVc;
+use turbopack_html::HtmlModuleAssetVc;
+use turbopack_json::{DataFormat, DataModuleAssetVc, JsonModuleAssetVc};
 use turbopack_mdx::MdxModuleAssetVc;
+use turbopack_sass::SassCompiledAssetVc;
 use turbopack_static::StaticModuleAssetVc;
+use turbopack_wasm::WebAssemblyModuleAssetVc;
 
 use self::{
     resolve_options_context::ResolveOptionsContextVc,
@@ -166,6 +168,9 @@ async fn apply_module_type(
         }
 
         ModuleType::Json => JsonModuleAssetVc::new(source).into(),
+        ModuleType::Yaml => DataModuleAssetVc::new(source, Value::new(DataFormat::Yaml)).into(),
+        ModuleType::Toml => DataModuleAssetVc::new(source, Value::new(DataFormat::Toml)).into(),
+        ModuleType::Json5 => DataModuleAssetVc::new(source, Value::new(DataFormat::Json5)).into(),
         ModuleType::Raw => source,
         ModuleType::Css(transforms) => {
             CssModuleAssetVc::new(source, context.into(), *transforms).into()
@@ -173,7 +178,29 @@ async fn apply_module_type(
         ModuleType::CssModule(transforms) => {
             ModuleCssModuleAssetVc::new(source, context.into(), *transforms).into()
         }
+        ModuleType::Sass {
+            transforms,
+            node_compiler,
+        } => CssModuleAssetVc::new(
+            SassCompiledAssetVc::new(source, context.into(), *node_compiler).into(),
+            context.into(),
+            *transforms,
+        )
+        .into(),
+        ModuleType::SassModule {
+            transforms,
+            node_compiler,
+        } => ModuleCssModuleAssetVc::new(
+            SassCompiledAssetVc::new(source, context.into(), *node_compiler).into(),
+            context.into(),
+            *transforms,
+        )
+        .into(),
         ModuleType::Static => StaticModuleAssetVc::new(source, context.into()).into(),
+        ModuleType::Html => HtmlModuleAssetVc::new(source, context.into()).into(),
+        ModuleType::WebAssembly(source_type) => {
+            WebAssemblyModuleAssetVc::new(source, context.into(), Value::new(*source_type)).into()
+        }
         ModuleType::Mdx {
             transforms,
             options,
@@ -611,8 +638,11 @@ pub fn register() {
     turbopack_ecmascript::register();
     turbopack_node::register();
     turbopack_env::register();
+    turbopack_html::register();
     turbopack_mdx::register();
     turbopack_json::register();
     turbopack_static::register();
+    turbopack_sass::register();
+    turbopack_wasm::register();
     include!(concat!(env!("OUT_DIR"), "/register.rs"));
 }
@@ -9,7 +9,7 @@ pub use rule_condition::*;
 use turbo_tasks::primitives::OptionStringVc;
 use turbo_tasks_fs::{glob::GlobVc, FileSystemPathVc};
 use turbopack_core::{
-    reference_type::{ReferenceType, UrlReferenceSubType},
+    reference_type::{EntryReferenceSubType, ReferenceType, UrlReferenceSubType},
     resolve::options::{ImportMap, ImportMapVc, ImportMapping, ImportMappingVc},
     source_transform::SourceTransformsVc,
 };
@@ -19,6 +19,8 @@ use turbopack_ecmascript::{
 };
 use turbopack_mdx::MdxTransformOptions;
 use turbopack_node::transforms::{postcss::PostCssTransformVc, webpack::WebpackLoadersVc};
+use turbopack_sass::SassNodeCompilerVc;
+use turbopack_wasm::WebAssemblySourceType;
 
 use crate::evaluate_context::node_evaluate_asset_context;
 
@@ -72,6 +74,7 @@ impl ModuleOptionsVc {
             ref enable_webpack_loaders,
             preset_env_versions,
             ref custom_ecma_transform_plugins,
+            ref custom_css_transform_plugins,
             ref custom_rules,
             execution_context,
             ref rules,
@@ -178,7 +181,44 @@ impl ModuleOptionsVc {
             EcmascriptInputTransformsVc::cell(transforms.clone())
         };
 
-        let css_transforms = CssInputTransformsVc::cell(vec![CssInputTransform::Nested]);
+        let mut css_transforms = vec![];
+        let mut css_output_transforms = vec![];
+        if let Some(transform_plugins) = custom_css_transform_plugins {
+            let transform_plugins = transform_plugins.await?;
+            css_transforms.extend(
+                transform_plugins
+                    .source_transforms
+                    .iter()
+                    .copied()
+                    .map(CssInputTransform::Custom),
+            );
+            css_output_transforms.extend(
+                transform_plugins
+                    .output_transforms
+                    .iter()
+                    .copied()
+                    .map(CssInputTransform::Custom),
+            );
+        }
+        css_transforms.push(CssInputTransform::Nested);
+        if let Some(env) = preset_env_versions {
+            css_transforms.push(CssInputTransform::PresetEnv(env));
+        }
+        css_transforms.extend(css_output_transforms);
+        let css_transforms = CssInputTransformsVc::cell(css_transforms);
+        let sass_node_compiler = if let Some(execution_context) = execution_context {
+            let execution_context = execution_context.with_layer("sass");
+            Some(SassNodeCompilerVc::new(
+                node_evaluate_asset_context(
+                    execution_context,
+                    Some(package_import_map_from_context("sass", path)),
+                    None,
+                ),
+                execution_context,
+            ))
+        } else {
+            None
+        };
         let mdx_transforms = EcmascriptInputTransformsVc::cell(
             if let Some(transform) = &ts_transform {
                 if let Some(decorators_transform) = &decorators_transform {
@@ -222,6 +262,21 @@ impl ModuleOptionsVc {
                 ModuleRuleCondition::ResourcePathEndsWith(".json".to_string()),
                 vec![ModuleRuleEffect::ModuleType(ModuleType::Json)],
             ),
+            ModuleRule::new(
+                ModuleRuleCondition::any(vec![
+                    ModuleRuleCondition::ResourcePathEndsWith(".yaml".to_string()),
+                    ModuleRuleCondition::ResourcePathEndsWith(".yml".to_string()),
+                ]),
+                vec![ModuleRuleEffect::ModuleType(ModuleType::Yaml)],
+            ),
+            ModuleRule::new(
+                ModuleRuleCondition::ResourcePathEndsWith(".toml".to_string()),
+                vec![ModuleRuleEffect::ModuleType(ModuleType::Toml)],
+            ),
+            ModuleRule::new(
+                ModuleRuleCondition::ResourcePathEndsWith(".json5".to_string()),
+                vec![ModuleRuleEffect::ModuleType(ModuleType::Json5)],
+            ),
             ModuleRule::new(
                 ModuleRuleCondition::ResourcePathEndsWith(".css".to_string()),
                 [
@@ -263,6 +318,26 @@ impl ModuleOptionsVc {
                     css_transforms,
                 ))],
             ),
+            ModuleRule::new(
+                ModuleRuleCondition::any(vec![
+                    ModuleRuleCondition::ResourcePathEndsWith(".scss".to_string()),
+                    ModuleRuleCondition::ResourcePathEndsWith(".sass".to_string()),
+                ]),
+                vec![ModuleRuleEffect::ModuleType(ModuleType::Sass {
+                    transforms: css_transforms,
+                    node_compiler: sass_node_compiler,
+                })],
+            ),
+            ModuleRule::new(
+                ModuleRuleCondition::any(vec![
+                    ModuleRuleCondition::ResourcePathEndsWith(".module.scss".to_string()),
+                    ModuleRuleCondition::ResourcePathEndsWith(".module.sass".to_string()),
+                ]),
+                vec![ModuleRuleEffect::ModuleType(ModuleType::SassModule {
+                    transforms: css_transforms,
+                    node_compiler: sass_node_compiler,
+                })],
+            ),
             ModuleRule::new(
                 ModuleRuleCondition::any(vec![
                     ModuleRuleCondition::ResourcePathEndsWith(".js".to_string()),
@@ -380,6 +455,39 @@ impl ModuleOptionsVc {
                 ]),
                 vec![ModuleRuleEffect::ModuleType(ModuleType::Static)],
             ),
+            ModuleRule::new(
+                // HTML pages are only bundled as entries, other references
+                // keep them as they are.
+                ModuleRuleCondition::all(vec![
+                    ModuleRuleCondition::ResourcePathEndsWith(".html".to_string()),
+                    ModuleRuleCondition::ReferenceType(ReferenceType::Entry(
+                        EntryReferenceSubType::Undefined,
+                    )),
+                ]),
+                vec![ModuleRuleEffect::ModuleType(ModuleType::Html)],
+            ),
+            ModuleRule::new(
+                ModuleRuleCondition::ResourcePathEndsWith(".wasm".to_string()),
+                vec![ModuleRuleEffect::ModuleType(ModuleType::WebAssembly(
+                    WebAssemblySourceType::Instantiated,
+                ))],
+            ),
+            ModuleRule::new(
+                ModuleRuleCondition::all(vec![
+                    ModuleRuleCondition::ResourcePathEndsWith(".wasm".to_string()),
+                    ModuleRuleCondition::ResourceQueryEquals("module".to_string()),
+                ]),
+                vec![ModuleRuleEffect::ModuleType(ModuleType::WebAssembly(
+                    WebAssemblySourceType::Module,
+                ))],
+            ),
+            ModuleRule::new(
+                ModuleRuleCondition::all(vec![
+                    ModuleRuleCondition::ResourcePathEndsWith(".wasm".to_string()),
+                    ModuleRuleCondition::ResourceQueryEquals("url".to_string()),
+                ]),
+                vec![ModuleRuleEffect::ModuleType(ModuleType::Static)],
+            ),
             ModuleRule::new(
                 ModuleRuleCondition::ResourcePathHasNoExtension,
                 vec![ModuleRuleEffect::ModuleType(ModuleType::Ecmascript {
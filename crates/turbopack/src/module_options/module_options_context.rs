@@ -2,6 +2,7 @@ use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 use turbo_tasks::trace::TraceRawVcs;
 use turbopack_core::{environment::EnvironmentVc, resolve::options::ImportMappingVc};
+use turbopack_css::CssTransformPluginVc;
 use turbopack_ecmascript::TransformPluginVc;
 use turbopack_node::{
     execution_context::ExecutionContextVc, transforms::webpack::WebpackLoaderItemsVc,
@@ -128,6 +129,18 @@ pub struct CustomEcmascriptTransformPlugins {
     pub output_transforms: Vec<TransformPluginVc>,
 }
 
+/// Configuration options for the custom css transform to be applied.
+#[turbo_tasks::value(shared)]
+#[derive(Default, Clone)]
+pub struct CustomCssTransformPlugins {
+    /// List of plugins to be applied before the main transform.
+    /// Transform will be applied in the order of the list.
+    pub source_transforms: Vec<CssTransformPluginVc>,
+    /// List of plugins to be applied after the main transform.
+    /// Transform will be applied in the order of the list.
+    pub output_transforms: Vec<CssTransformPluginVc>,
+}
+
 #[turbo_tasks::value(shared)]
 #[derive(Default, Clone)]
 #[serde(default)]
@@ -162,6 +175,7 @@ pub struct ModuleOptionsContext {
     pub enable_mdx_rs: Option<MdxTransformModuleOptionsVc>,
     pub preset_env_versions: Option<EnvironmentVc>,
     pub custom_ecma_transform_plugins: Option<CustomEcmascriptTransformPluginsVc>,
+    pub custom_css_transform_plugins: Option<CustomCssTransformPluginsVc>,
     /// Custom rules to be applied after all default rules.
     pub custom_rules: Vec<ModuleRule>,
     pub execution_context: Option<ExecutionContextVc>,
@@ -9,6 +9,8 @@ use turbopack_core::{
 use turbopack_css::CssInputTransformsVc;
 use turbopack_ecmascript::{EcmascriptInputTransformsVc, EcmascriptOptions};
 use turbopack_mdx::MdxTransformOptionsVc;
+use turbopack_sass::SassNodeCompilerVc;
+use turbopack_wasm::WebAssemblySourceType;
 
 use super::ModuleRuleCondition;
 
@@ -69,6 +71,9 @@ pub enum ModuleType {
         options: EcmascriptOptions,
     },
     Json,
+    Yaml,
+    Toml,
+    Json5,
     Raw,
     Mdx {
         transforms: EcmascriptInputTransformsVc,
@@ -76,6 +81,16 @@ pub enum ModuleType {
     },
     Css(CssInputTransformsVc),
     CssModule(CssInputTransformsVc),
+    Sass {
+        transforms: CssInputTransformsVc,
+        node_compiler: Option<SassNodeCompilerVc>,
+    },
+    SassModule {
+        transforms: CssInputTransformsVc,
+        node_compiler: Option<SassNodeCompilerVc>,
+    },
     Static,
+    Html,
+    WebAssembly(WebAssemblySourceType),
     Custom(CustomModuleTypeVc),
 }
@@ -4,7 +4,9 @@ use serde::{Deserialize, Serialize};
 use turbo_tasks::{primitives::Regex, trace::TraceRawVcs};
 use turbo_tasks_fs::{glob::GlobReadRef, FileSystemPath, FileSystemPathReadRef};
 use turbopack_core::{
-    asset::AssetVc, reference_type::ReferenceType, virtual_asset::VirtualAssetVc,
+    asset::{Asset, AssetVc},
+    reference_type::ReferenceType,
+    virtual_asset::VirtualAssetVc,
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize, TraceRawVcs, PartialEq, Eq)]
@@ -32,6 +34,9 @@ pub enum ModuleRuleCondition {
         glob: GlobReadRef,
     },
     ResourceBasePathGlob(#[turbo_tasks(trace_ignore)] GlobReadRef),
+    /// Matches the query string of the resource (without the leading `?`),
+    /// e.g. `url` for `./file.wasm?url`.
+    ResourceQueryEquals(String),
 }
 
 impl ModuleRuleCondition {
@@ -116,6 +121,12 @@ impl ModuleRuleCondition {
                     .map_or(path.path.as_str(), |(_, b)| b);
                 glob.execute(basename)
             }
+            ModuleRuleCondition::ResourceQueryEquals(expected) => {
+                match source.ident().await?.query {
+                    Some(query) => *query.await? == *expected,
+                    None => false,
+                }
+            }
             _ => todo!("not implemented yet"),
         })
     }
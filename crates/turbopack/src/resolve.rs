@@ -14,7 +14,7 @@ use turbopack_ecmascript::typescript::resolve::{
 
 use crate::resolve_options_context::ResolveOptionsContextVc;
 
-const NODE_EXTERNALS: [&str; 51] = [
+const NODE_EXTERNALS: [&str; 50] = [
     "assert",
     "async_hooks",
     "buffer",
@@ -65,7 +65,6 @@ const NODE_EXTERNALS: [&str; 51] = [
     "wasi",
     "worker_threads",
     "zlib",
-    "pnpapi",
 ];
 
 #[turbo_tasks::function]
@@ -99,6 +98,18 @@ async fn base_resolve_options(
             );
         }
     }
+    if let Some(pnp_root) = opt.enable_pnp {
+        // The Plug'n'Play API is implemented by the runtime of the project
+        direct_mappings.insert(
+            AliasPattern::exact("pnpapi"),
+            ImportMapping::PrimaryAlternative("./.pnp.cjs".to_string(), Some(pnp_root)).into(),
+        );
+    } else if node_externals {
+        direct_mappings.insert(
+            AliasPattern::exact("pnpapi"),
+            ImportMapping::External(None).into(),
+        );
+    }
 
     let mut import_map = ImportMap::new(direct_mappings);
     if let Some(additional_import_map) = opt.import_map {
@@ -179,15 +190,29 @@ async fn base_resolve_options(
         },
         modules: if let Some(environment) = emulating {
             if *environment.resolve_node_modules().await? {
-                vec![ResolveModules::Nested(
+                let mut mods = Vec::new();
+                if let Some((registry, locked_versions)) = opt.enable_registry {
+                    mods.push(ResolveModules::Registry(registry, locked_versions));
+                }
+                if let Some(root) = opt.enable_pnp {
+                    mods.push(ResolveModules::PnP(root));
+                }
+                mods.push(ResolveModules::Nested(
                     root,
                     vec!["node_modules".to_string()],
-                )]
+                ));
+                mods
             } else {
                 Vec::new()
             }
         } else {
             let mut mods = Vec::new();
+            if let Some((registry, locked_versions)) = opt.enable_registry {
+                mods.push(ResolveModules::Registry(registry, locked_versions));
+            }
+            if let Some(root) = opt.enable_pnp {
+                mods.push(ResolveModules::PnP(root));
+            }
             if let Some(dir) = opt.enable_node_modules {
                 mods.push(ResolveModules::Nested(
                     dir,
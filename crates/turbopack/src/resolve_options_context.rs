@@ -3,7 +3,7 @@ use turbo_tasks_fs::FileSystemPathVc;
 use turbopack_core::{
     environment::EnvironmentVc,
     resolve::{
-        options::{ImportMapVc, ResolvedMapVc},
+        options::{ImportMapVc, LockedVersionsVc, ResolvedMapVc},
         plugin::ResolvePluginVc,
     },
 };
@@ -28,6 +28,17 @@ pub struct ResolveOptionsContext {
     /// directory
     pub enable_node_modules: Option<FileSystemPathVc>,
     #[serde(default)]
+    /// Enable resolving of packages from a registry filesystem with the
+    /// structure `@scope/module/version/<path-in-package>`, using the versions
+    /// locked in the lockfile. The registry is used before node_modules.
+    pub enable_registry: Option<(FileSystemPathVc, LockedVersionsVc)>,
+    #[serde(default)]
+    /// Enable resolving of packages with the Yarn Plug'n'Play manifest
+    /// (`.pnp.cjs` or `.pnp.data.json`) of the project in the provided
+    /// directory. Packages are read from the zip archives in the Yarn cache and
+    /// `pnpapi` is resolved to the `.pnp.cjs` of the project.
+    pub enable_pnp: Option<FileSystemPathVc>,
+    #[serde(default)]
     /// Mark well-known Node.js modules as external imports and load them using
     /// native `require`. e.g. url, querystring, os
     pub enable_node_externals: bool,
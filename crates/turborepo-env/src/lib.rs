@@ -249,10 +249,38 @@ pub fn get_global_hashable_env_vars(
     })
 }
 
+// dotenv_files returns the dotenv files that are loaded for a mode, from the
+// highest to the lowest precedence. `.env.local` is not loaded in the `test`
+// mode, so tests produce the same results for everyone. turbopack loads these
+// files, and `run --dotenv-mode` hashes them in each package, so task caching
+// stays consistent with the values turbopack sees. The Go task hasher mirrors
+// this list in `env.DotEnvFiles`, and both are tested against
+// `fixtures/dotenv-files.json`.
+pub fn dotenv_files(mode: &str) -> Vec<String> {
+    let mut files = vec![format!(".env.{mode}.local")];
+    if mode != "test" {
+        files.push(".env.local".to_string());
+    }
+    files.push(format!(".env.{mode}"));
+    files.push(".env".to_string());
+    files
+}
+
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+
     use test_case::test_case;
 
+    #[test]
+    fn test_dotenv_files() {
+        let cases: BTreeMap<String, Vec<String>> =
+            serde_json::from_str(include_str!("../fixtures/dotenv-files.json")).unwrap();
+        for (mode, expected) in cases {
+            assert_eq!(super::dotenv_files(&mode), expected, "mode {mode}");
+        }
+    }
+
     #[test_case("LITERAL_\\*", "LITERAL_\\*" ; "literal star")]
     #[test_case("\\*LEADING", "\\*LEADING" ; "leading literal star")]
     #[test_case("\\!LEADING", "\\\\!LEADING" ; "leading literal bang")]
@@ -0,0 +1,81 @@
+//! A watcher which falls back to polling for paths on filesystems that don't
+//! deliver native file events, such as network mounts, Docker Desktop bind
+//! mounts and Windows drives in WSL2.
+
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use notify::{
+    Config, Event, EventHandler, PollWatcher, RecommendedWatcher, RecursiveMode, Watcher,
+    WatcherKind,
+};
+use tracing::warn;
+use turbo_watch_polling::should_poll;
+
+/// Interval in which polled paths are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The watcher used by the [crate::GlobWatcher]. Paths are watched with the
+/// platform's recommended watcher, unless they are on a filesystem which
+/// doesn't deliver native events, in which case they are polled.
+///
+/// The detection can be overridden with
+/// [POLL_ENV_VAR](turbo_watch_polling::POLL_ENV_VAR).
+#[derive(Debug)]
+pub struct GlobWatcherBackend {
+    native: RecommendedWatcher,
+    poll: PollWatcher,
+    polled: HashSet<PathBuf>,
+}
+
+/// Shares an [EventHandler] between the native and the polling watcher.
+struct SharedEventHandler<F>(Arc<Mutex<F>>);
+
+impl<F: EventHandler> EventHandler for SharedEventHandler<F> {
+    fn handle_event(&mut self, event: notify::Result<Event>) {
+        match self.0.lock() {
+            Ok(mut handler) => handler.handle_event(event),
+            Err(_) => warn!("event handler lock poisoned"),
+        }
+    }
+}
+
+impl Watcher for GlobWatcherBackend {
+    fn new<F: EventHandler>(event_handler: F, config: Config) -> notify::Result<Self> {
+        let event_handler = Arc::new(Mutex::new(event_handler));
+        Ok(Self {
+            native: RecommendedWatcher::new(SharedEventHandler(event_handler.clone()), config)?,
+            poll: PollWatcher::new(
+                SharedEventHandler(event_handler),
+                config.with_poll_interval(POLL_INTERVAL),
+            )?,
+            polled: HashSet::new(),
+        })
+    }
+
+    fn watch(&mut self, path: &Path, recursive_mode: RecursiveMode) -> notify::Result<()> {
+        if should_poll(path) {
+            self.poll.watch(path, recursive_mode)?;
+            self.polled.insert(path.to_path_buf());
+            Ok(())
+        } else {
+            self.native.watch(path, recursive_mode)
+        }
+    }
+
+    fn unwatch(&mut self, path: &Path) -> notify::Result<()> {
+        if self.polled.remove(path) {
+            self.poll.unwatch(path)
+        } else {
+            self.native.unwatch(path)
+        }
+    }
+
+    fn kind() -> WatcherKind {
+        RecommendedWatcher::kind()
+    }
+}
@@ -20,6 +20,8 @@
 )]
 #![feature(drain_filter)]
 
+mod backend;
+
 use std::{
     collections::HashMap,
     fs::File,
@@ -30,6 +32,7 @@ use std::{
     },
 };
 
+pub use backend::GlobWatcherBackend;
 use camino::Utf8PathBuf;
 use futures::{channel::oneshot, future::Either, FutureExt, Stream, StreamExt as _};
 use itertools::Itertools;
@@ -60,7 +63,7 @@ impl GlobWatcher {
     #[tracing::instrument]
     pub fn new(
         flush_dir: Utf8PathBuf,
-    ) -> Result<(Self, WatchConfig<notify::RecommendedWatcher>), notify::Error> {
+    ) -> Result<(Self, WatchConfig<GlobWatcherBackend>), notify::Error> {
         let (send_event, receive_event) = tokio::sync::mpsc::unbounded_channel();
         let (send_config, receive_config) = tokio::sync::mpsc::unbounded_channel();
 
@@ -68,26 +71,29 @@ impl GlobWatcher {
         std::fs::create_dir_all(&flush_dir).ok();
         let flush_dir = flush_dir.canonicalize()?;
 
-        let watcher = notify::recommended_watcher(move |event: Result<Event, notify::Error>| {
-            let span = span!(tracing::Level::TRACE, "watcher");
-            let _ = span.enter();
-
-            let result = event.map(|e| {
-                trace!(parent: &span, "sending event: {:?}", e);
-                let tx = send_event.clone();
-                futures::executor::block_on(async move { tx.send(e) })
-            });
-
-            match result {
-                Ok(Ok(_)) => {}
-                Ok(Err(e)) => {
-                    warn!(parent: &span, "watch server closed: {:?}", e);
-                }
-                Err(e) => {
-                    warn!(parent: &span, "error from notify: {:?}", e);
+        let watcher = GlobWatcherBackend::new(
+            move |event: Result<Event, notify::Error>| {
+                let span = span!(tracing::Level::TRACE, "watcher");
+                let _ = span.enter();
+
+                let result = event.map(|e| {
+                    trace!(parent: &span, "sending event: {:?}", e);
+                    let tx = send_event.clone();
+                    futures::executor::block_on(async move { tx.send(e) })
+                });
+
+                match result {
+                    Ok(Ok(_)) => {}
+                    Ok(Err(e)) => {
+                        warn!(parent: &span, "watch server closed: {:?}", e);
+                    }
+                    Err(e) => {
+                        warn!(parent: &span, "error from notify: {:?}", e);
+                    }
                 }
-            }
-        })?;
+            },
+            notify::Config::default(),
+        )?;
 
         let watcher = Arc::new(Mutex::new(watcher));
 
@@ -428,6 +428,12 @@ pub struct RunArgs {
     pub continue_execution: bool,
     #[clap(alias = "dry", long = "dry-run", num_args = 0..=1, default_missing_value = "text")]
     pub dry_run: Option<DryRunMode>,
+    /// Hash the dotenv files that turbopack loads for this mode (e.g.
+    /// .env.staging and .env.staging.local) in each package. Without it,
+    /// dotenv files aren't hashed, although turbopack loads the ones for
+    /// NODE_ENV by default
+    #[clap(long)]
+    pub dotenv_mode: Option<String>,
     /// Run turbo in single-package mode
     #[clap(long, global = true)]
     pub single_package: bool,
@@ -1055,6 +1061,18 @@ mod test {
             }
         );
 
+        assert_eq!(
+            Args::try_parse_from(["turbo", "run", "build", "--dotenv-mode", "staging"]).unwrap(),
+            Args {
+                command: Some(Command::Run(Box::new(RunArgs {
+                    tasks: vec!["build".to_string()],
+                    dotenv_mode: Some("staging".to_string()),
+                    ..get_default_run_args()
+                }))),
+                ..Args::default()
+            }
+        );
+
         assert_eq!(
             Args::try_parse_from([
                 "turbo", "run", "build", "--filter", "water", "--filter", "earth", "--filter",
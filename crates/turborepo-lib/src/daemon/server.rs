@@ -76,7 +76,7 @@ pub enum CloseReason {
     SocketOpenError(SocketOpenError),
 }
 
-impl DaemonServer<notify::RecommendedWatcher> {
+impl DaemonServer<globwatch::GlobWatcherBackend> {
     #[tracing::instrument(skip(base), fields(repo_root = %base.repo_root))]
     pub fn new(
         base: &CommandBase,
@@ -7,9 +7,9 @@ use std::{
 
 use camino::Utf8PathBuf;
 use futures::{stream::iter, StreamExt};
-use globwatch::{ConfigError, GlobWatcher, StopToken, WatchConfig, Watcher};
+use globwatch::{ConfigError, GlobWatcher, GlobWatcherBackend, StopToken, WatchConfig, Watcher};
 use itertools::Itertools;
-use notify::{EventKind, RecommendedWatcher};
+use notify::EventKind;
 use tokio::time::timeout;
 use tracing::{trace, warn};
 use turbopath::AbsoluteSystemPathBuf;
@@ -48,7 +48,7 @@ pub struct GlobSet {
     exclude: HashSet<Glob>,
 }
 
-impl HashGlobWatcher<RecommendedWatcher> {
+impl HashGlobWatcher<GlobWatcherBackend> {
     #[tracing::instrument]
     pub fn new(
         relative_to: AbsoluteSystemPathBuf,
@@ -44,6 +44,7 @@ Test help flag
         --concurrency <CONCURRENCY>      Limit the concurrency of task execution. Use 1 for serial (i.e. one-at-a-time) execution
         --continue                       Continue execution even if a task exits with an error or non-zero exit code. The default behavior is to bail
         --dry-run [<DRY_RUN>]            [possible values: text, json]
+        --dotenv-mode <DOTENV_MODE>      Hash the dotenv files that turbopack loads for this mode (e.g. .env.staging and .env.staging.local) in each package. Without it, dotenv files aren't hashed, although turbopack loads the ones for NODE_ENV by default
         --single-package                 Run turbo in single-package mode
     -F, --filter <FILTER>                Use the given selector to specify package(s) to act as entry points. The syntax mirrors pnpm's syntax, and additional documentation and examples can be found in turbo's documentation https://turbo.build/repo/docs/reference/command-line-reference/run#--filter
         --force [<FORCE>]                Ignore the existing cache (to force execution) [env: TURBO_FORCE=] [possible values: true, false]
@@ -112,6 +113,7 @@ Test help flag
         --concurrency <CONCURRENCY>      Limit the concurrency of task execution. Use 1 for serial (i.e. one-at-a-time) execution
         --continue                       Continue execution even if a task exits with an error or non-zero exit code. The default behavior is to bail
         --dry-run [<DRY_RUN>]            [possible values: text, json]
+        --dotenv-mode <DOTENV_MODE>      Hash the dotenv files that turbopack loads for this mode (e.g. .env.staging and .env.staging.local) in each package. Without it, dotenv files aren't hashed, although turbopack loads the ones for NODE_ENV by default
         --single-package                 Run turbo in single-package mode
     -F, --filter <FILTER>                Use the given selector to specify package(s) to act as entry points. The syntax mirrors pnpm's syntax, and additional documentation and examples can be found in turbo's documentation https://turbo.build/repo/docs/reference/command-line-reference/run#--filter
         --force [<FORCE>]                Ignore the existing cache (to force execution) [env: TURBO_FORCE=] [possible values: true, false]